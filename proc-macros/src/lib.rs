@@ -189,64 +189,53 @@ pub fn shw_assemble(input: TokenStream) -> TokenStream {
 
 #[proc_macro]
 pub fn amo_assemble(input: TokenStream) -> TokenStream {
-    if let TokenTree::Ident(i) = input.into_iter().next().unwrap() {
+    let mut tokens = input.into_iter();
+    if let TokenTree::Ident(i) = tokens.next().unwrap() {
         let name = i.to_string();
         let lower = name.to_lowercase();
-        let dname = name.clone() + "D";
-        let wname = name.clone() + "W";
-        let p = format!(
-            "
-            if mnemonics.len() == 1 {{
-                Err(\"{lower} must have size (w/d)\".to_owned())
-            }} else if mnemonics.len() == 2 {{
-                if mnemonics[1] == \"w\" {{
-                    Ok(Instruction::{wname}{{
-                        dest: IRegister::try_from(operands[0])?,
-                        addr: IRegister::try_from(operands[1])?,
-                        src: IRegister::try_from(operands[2])?,
-                        rl: false,
-                        aq: false,
-                }})
-                }} else if mnemonics[1] == \"d\" {{
-                    Ok(Instruction::{dname}{{
-                        dest: IRegister::try_from(operands[0])?,
-                        addr: IRegister::try_from(operands[1])?,
-                        src: IRegister::try_from(operands[2])?,
-                        rl: false,
-                        aq: false,
-                    }})
-                }} else {{
-                    Err(\"size of {lower} instruction must be word (w) or doubleword (d)\".to_owned())
-                }}
-            }} else if mnemonics.len() == 3 {{
-                let (aq, rl) = match mnemonics[2] {{
-                    \"\" => (false, false),
-                    \"aq\" => (true, false),
-                    \"rl\" => (false, true),
-                    \"aqrl\" => (true, true),
-                    _ => return Err(\"ordering should be (aq)(rl)\".to_owned()),
-                }};
-                if mnemonics[1] == \"w\" {{
-                    Ok(Instruction::{wname}{{
-                        dest: IRegister::try_from(operands[0])?,
-                        addr: IRegister::try_from(operands[1])?,
-                        src: IRegister::try_from(operands[2])?,
-                        aq,
-                        rl,
-                    }})
-                }} else if mnemonics[1] == \"d\" {{
-                    Ok(Instruction::{dname}{{
+        // the remaining identifiers are the sizes that this instruction supports
+        let sizes: Vec<String> = tokens
+            .map(|t| match t {
+                TokenTree::Ident(s) => s.to_string(),
+                _ => panic!("expected size identifier"),
+            })
+            .collect();
+        let size_list = sizes.join("/");
+        let arms: String = sizes
+            .iter()
+            .map(|size| {
+                format!(
+                    "\"{size}\" => Ok(Instruction::{name}{upper}{{
                         dest: IRegister::try_from(operands[0])?,
                         addr: IRegister::try_from(operands[1])?,
                         src: IRegister::try_from(operands[2])?,
                         aq,
                         rl,
-                    }})
-                }} else {{
-                    Err(\"size of {lower} isntruction must be word (w) or doubleword (d)\".to_owned())
-                }}
-            }} else {{
+                    }}),",
+                    upper = size.to_uppercase()
+                )
+            })
+            .collect();
+        let p = format!(
+            "
+            if mnemonics.len() == 1 {{
+                Err(\"{lower} must have size ({size_list})\".to_owned())
+            }} else if mnemonics.len() > 3 {{
                 Err(\"{lower} instruction has too many suffixes, expected {lower}.size.ordering\".to_owned())
+            }} else if operands.len() != 3 {{
+                Err(\"{lower} instruction requires 3 operands\".to_owned())
+            }} else {{
+                let (aq, rl) = match mnemonics.get(2) {{
+                    None | Some(&\"\") => (false, false),
+                    Some(&\"aq\") => (true, false),
+                    Some(&\"rl\") => (false, true),
+                    Some(&\"aqrl\") => (true, true),
+                    _ => return Err(\"ordering should be (aq)(rl)\".to_owned()),
+                }};
+                match mnemonics[1] {{
+                    {arms}
+                    _ => Err(\"size of {lower} instruction must be one of {size_list}\".to_owned()),
+                }}
             }}
         "
         );
@@ -269,6 +258,7 @@ pub fn fr_assemble(input: TokenStream) -> TokenStream {
         let name = n.to_string();
         let sname = name.clone() + "S";
         let dname = name.clone() + "D";
+        let qname = name.clone() + "Q";
         let lower = name.to_lowercase();
         let rm = rm.value();
 
@@ -299,24 +289,31 @@ pub fn fr_assemble(input: TokenStream) -> TokenStream {
 
             match mnemonics.get(1) {{
                 Some(&\"s\") => Ok(Instruction::{sname} {{
-                    dest: FRegister::try_from(operands[0])?,
-                    src1: FRegister::try_from(operands[1])?,
-                    src2: FRegister::try_from(operands[2])?,
+                    dest: freg(operands[0])?,
+                    src1: freg(operands[1])?,
+                    src2: freg(operands[2])?,
                     {}
                 }}),
                 Some(&\"d\") => Ok(Instruction::{dname} {{
-                    dest: FRegister::try_from(operands[0])?,
-                    src1: FRegister::try_from(operands[1])?,
-                    src2: FRegister::try_from(operands[2])?,
+                    dest: freg(operands[0])?,
+                    src1: freg(operands[1])?,
+                    src2: freg(operands[2])?,
+                    {}
+                }}),
+                Some(&\"q\") => Ok(Instruction::{qname} {{
+                    dest: freg(operands[0])?,
+                    src1: freg(operands[1])?,
+                    src2: freg(operands[2])?,
                     {}
                 }}),
-                Some(_) => Err(\"{lower} instructions requires prefix {{s,d}}\".to_owned()),
-                None => Err(\"{lower} instructions requires prefix {{s,d}}\".to_owned()),
+                Some(_) => Err(\"{lower} instructions requires prefix {{s,d,q}}\".to_owned()),
+                None => Err(\"{lower} instructions requires prefix {{s,d,q}}\".to_owned()),
             }}
         }}
             ",
             if rm { "rm," } else { "" },
             if rm { "rm," } else { "" },
+            if rm { "rm," } else { "" },
         )
         .parse()
         .unwrap()
@@ -332,6 +329,7 @@ pub fn fr3_assemble(input: TokenStream) -> TokenStream {
         let name = i.to_string();
         let sname = name.clone() + "S";
         let dname = name.clone() + "D";
+        let qname = name.clone() + "Q";
         let lower = name.to_lowercase();
         format!(
             "
@@ -346,21 +344,28 @@ pub fn fr3_assemble(input: TokenStream) -> TokenStream {
 
                 match mnemonics.get(1) {{
                     Some(&\"s\") => Ok(Instruction::{sname} {{
-                        dest: FRegister::try_from(operands[0])?,
-                        src1: FRegister::try_from(operands[1])?,
-                        src2: FRegister::try_from(operands[2])?,
-                        src3: FRegister::try_from(operands[3])?,
+                        dest: freg(operands[0])?,
+                        src1: freg(operands[1])?,
+                        src2: freg(operands[2])?,
+                        src3: freg(operands[3])?,
                         rm,
                     }}),
                     Some(&\"d\") => Ok(Instruction::{dname} {{
-                        dest: FRegister::try_from(operands[0])?,
-                        src1: FRegister::try_from(operands[1])?,
-                        src2: FRegister::try_from(operands[2])?,
-                        src3: FRegister::try_from(operands[3])?,
+                        dest: freg(operands[0])?,
+                        src1: freg(operands[1])?,
+                        src2: freg(operands[2])?,
+                        src3: freg(operands[3])?,
                         rm,
                     }}),
-                    Some(_) => Err(\"{lower} instructions requires prefix {{s,d}}\".to_owned()),
-                    None => Err(\"{lower} instructions requires prefix {{s,d}}\".to_owned()),
+                    Some(&\"q\") => Ok(Instruction::{qname} {{
+                        dest: freg(operands[0])?,
+                        src1: freg(operands[1])?,
+                        src2: freg(operands[2])?,
+                        src3: freg(operands[3])?,
+                        rm,
+                    }}),
+                    Some(_) => Err(\"{lower} instructions requires prefix {{s,d,q}}\".to_owned()),
+                    None => Err(\"{lower} instructions requires prefix {{s,d,q}}\".to_owned()),
                 }}
             }}
             "
@@ -540,6 +545,11 @@ pub fn make_immediate(input: TokenStream) -> TokenStream {
             format!("value > 2i64.pow({}) - 1 || value < 0", size)
         };
 
+        let layout: String = parts
+            .iter()
+            .map(|part| format!("({}, {}, {}),", part.base, part.size, part.location))
+            .collect();
+
         let impl_string = format!(
             "
         impl TryFrom<i64> for {name} {{
@@ -566,6 +576,9 @@ pub fn make_immediate(input: TokenStream) -> TokenStream {
             pub fn val(self) -> i64 {{
                 return self.into()
             }}
+
+            /// The parts of the immediate as `(immediate bit, size, instruction bit)`
+            pub const LAYOUT: &'static [(u8, u8, u8)] = &[{layout}];
         }}
 
         "
@@ -1,4 +1,5 @@
 use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
 use alloc::{format, vec};
@@ -7,12 +8,49 @@ use riscv_codec_proc_macros::{
     l_assemble, r_assemble, s_assemble, sh_assemble, shw_assemble,
 };
 
+use crate::extension::{CustomInstruction, Extensions};
 use crate::immediates::*;
-use crate::instruction::RoundingMode;
-use crate::register::{CFRegister, CIRegister, FRegister, IRegister};
+use crate::instruction::{FliConstant, RoundingMode, is_link_register};
+use crate::isa::IsaConfig;
+use crate::raw::{RawCInstruction, RawInstruction};
+use crate::register::{
+    CFRegister, CIRegister, CSRegister, FRegister, IRegister, RegisterList, VRegister,
+};
 use crate::{cinstruction::CInstruction, instruction::Instruction};
 
-fn parse_int(str: &str) -> Result<i64, String> {
+/// Removes a trailing `v0.t` mask operand, returning the remaining operands and whether it was present
+fn parse_vector_mask<'a>(operands: &[&'a str]) -> (Vec<&'a str>, bool) {
+    match operands.split_last() {
+        Some((&"v0.t", rest)) => (rest.to_vec(), true),
+        _ => (operands.to_vec(), false),
+    }
+}
+
+/// Parses an address operand without an offset, written as `(reg)`, `0(reg)` or `reg`
+pub(crate) fn parse_register_address(str: &str) -> Result<IRegister, String> {
+    if let Some(r) = str.strip_prefix('(').and_then(|r| r.strip_suffix(')')) {
+        IRegister::try_from(r)
+    } else if str.contains('(') {
+        match parse_address_expression(str)? {
+            (r, 0) => Ok(r),
+            _ => Err(format!("expected an address with no offset: {str}")),
+        }
+    } else {
+        IRegister::try_from(str)
+    }
+}
+
+/// Parses the optional virtual address and ASID operands of the address translation fences, both default to `zero`
+fn parse_sfence_operands(operands: &[&str]) -> Result<(IRegister, IRegister), String> {
+    match operands {
+        [] => Ok((IRegister::Zero, IRegister::Zero)),
+        [vaddr] => Ok((IRegister::try_from(*vaddr)?, IRegister::Zero)),
+        [vaddr, asid] => Ok((IRegister::try_from(*vaddr)?, IRegister::try_from(*asid)?)),
+        _ => Err("fence requires at most 2 operands".to_owned()),
+    }
+}
+
+pub(crate) fn parse_int(str: &str) -> Result<i64, String> {
     match str.parse::<i64>() {
         Ok(e) => Ok(e),
         Err(_) => Err(format!("unable to parse int:{str}").to_owned()),
@@ -20,10 +58,10 @@ fn parse_int(str: &str) -> Result<i64, String> {
 }
 
 fn parse_address_expression(str: &str) -> Result<(IRegister, i64), String> {
-    let (offset, register): (&str, &str) = if let Some(x) = str.split_once("(") {
-        x
-    } else {
-        panic!("no (");
+    let Some((offset, register)) = str.split_once("(") else {
+        return Err(format!(
+            "expected an address written as offset(base): {str}"
+        ));
     };
     match register.strip_suffix(")") {
         Some(y) => {
@@ -36,10 +74,10 @@ fn parse_address_expression(str: &str) -> Result<(IRegister, i64), String> {
 }
 
 fn parse_address_expression_compressed(str: &str) -> Result<(CIRegister, i64), String> {
-    let (offset, register): (&str, &str) = if let Some(x) = str.split_once("(") {
-        x
-    } else {
-        panic!("no (");
+    let Some((offset, register)) = str.split_once("(") else {
+        return Err(format!(
+            "expected an address written as offset(base): {str}"
+        ));
     };
     match register.strip_suffix(")") {
         Some(y) => {
@@ -51,6 +89,37 @@ fn parse_address_expression_compressed(str: &str) -> Result<(CIRegister, i64), S
     }
 }
 
+/// Splits the operands of an instruction on commas, except for those inside a `{}` register list
+fn split_operands(operands: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in operands.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                result.push(&operands[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    result.push(&operands[start..]);
+    result
+}
+
+/// Parses the suffix of a Zihintntl hint into the register used as rs2 in its `add` encoding
+fn parse_ntl_hint(suffix: &[&str]) -> Result<IRegister, String> {
+    match suffix {
+        ["p1"] => Ok(IRegister::StackPointer),
+        ["pall"] => Ok(IRegister::GlobalPointer),
+        ["s1"] => Ok(IRegister::ThreadPointer),
+        ["all"] => Ok(IRegister::T0),
+        _ => Err("ntl hint must be one of ntl.p1, ntl.pall, ntl.s1 or ntl.all".to_owned()),
+    }
+}
+
 /// Converts a string representing operations into a fence u8
 fn parse_fence_set(s: &str) -> u8 {
     let mut x = 0;
@@ -73,24 +142,34 @@ fn parse_fence_set(s: &str) -> u8 {
 pub enum AssemblyResult {
     I(Instruction),
     C(CInstruction),
+    /// An instruction from a registered custom or vendor extension
+    Custom(Box<dyn CustomInstruction>),
+    /// An instruction emitted with the `.insn` directive, compressed if the lowest two bits aren't `0b11`
+    Raw(u32),
 }
 impl AssemblyResult {
     pub fn c(self) -> CInstruction {
         match self {
-            AssemblyResult::I(_) => panic!("c called on regular instruction"),
             AssemblyResult::C(cinstruction) => cinstruction,
+            _ => panic!("c called on non-compressed instruction"),
         }
     }
     pub fn i(self) -> Instruction {
         match self {
             AssemblyResult::I(instruction) => instruction,
-            AssemblyResult::C(_) => panic!("i called on compressed instruction"),
+            _ => panic!("i called on non-standard or compressed instruction"),
+        }
+    }
+    pub fn custom(self) -> Box<dyn CustomInstruction> {
+        match self {
+            AssemblyResult::Custom(instruction) => instruction,
+            _ => panic!("custom called on standard instruction"),
         }
     }
 }
 
-/// Constructs an `Instruction` from a line of assembly.
-pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
+/// Splits a line of assembly into its mnemonic, split on `.`, and its operands.
+fn split_line(line: &str) -> (Vec<&str>, Vec<&str>) {
     let (mnemonic, operands): (&str, &str) = if let Some(x) = line.split_once(" ") {
         x
     } else {
@@ -102,18 +181,69 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
     let operands: Vec<&str> = if operands.is_empty() {
         vec![]
     } else {
-        operands.split(',').collect()
+        split_operands(operands)
     };
     let operands: Vec<&str> = operands
         .iter()
         .map(|operand| operand.to_owned().trim())
         .collect();
+    (mnemonics, operands)
+}
+
+/// Constructs an instruction from a line of assembly, using the registered extensions for mnemonics that aren't part
+/// of the standard extensions.
+pub fn assemble_line_with_extensions(
+    line: &str,
+    isa: &IsaConfig,
+    extensions: &Extensions,
+) -> Result<AssemblyResult, String> {
+    match assemble_line_with(line, isa) {
+        Ok(result) => Ok(result),
+        Err(e) => {
+            let (mnemonics, operands) = split_line(line);
+            match extensions.assemble(&mnemonics, &operands, isa) {
+                Some(result) => Ok(AssemblyResult::Custom(result?)),
+                None => Err(e),
+            }
+        }
+    }
+}
+
+/// Constructs an `Instruction` from a line of assembly.
+pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
+    assemble_line_with(line, &IsaConfig::default())
+}
+
+/// Constructs an `Instruction` from a line of assembly for the given ISA configuration.
+pub fn assemble_line_with(line: &str, isa: &IsaConfig) -> Result<AssemblyResult, String> {
+    // float operands are written with integer register names when float instructions use the integer registers
+    let freg = |name: &str| -> Result<FRegister, String> {
+        if isa.floats_in_integer_registers() {
+            Ok(FRegister::Integer(IRegister::try_from(name)?))
+        } else {
+            FRegister::try_from(name)
+        }
+    };
 
-    if mnemonics[0] == "c" {
+    let (mnemonics, operands) = split_line(line);
+
+    if mnemonics == ["", "insn"] {
+        Ok(AssemblyResult::Raw(insn_assemble(&operands)?))
+    } else if mnemonics[0] == "c" {
         if mnemonics.len() == 1 {
             Err("compressed instruction must be specified".to_owned())
         } else {
-            compressed_assemble(&mnemonics[1..], operands).map(AssemblyResult::C)
+            let c = compressed_assemble(&mnemonics[1..], operands)?;
+            c.check_isa(isa)?;
+            Ok(AssemblyResult::C(c))
+        }
+    } else if mnemonics[0] == "cm" {
+        if mnemonics.len() == 1 {
+            Err("compressed instruction must be specified".to_owned())
+        } else {
+            let c = zcm_assemble(mnemonics[1], operands, isa)?;
+            c.check_isa(isa)?;
+            Ok(AssemblyResult::C(c))
         }
     } else {
         let x = match mnemonics[0] {
@@ -133,6 +263,22 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
             "slliw" => shw_assemble!(Slliw),
             // register-register instructions
             "add" => r_assemble!(Add),
+            "czero" => match mnemonics[1..] {
+                ["eqz"] => r_assemble!(CzeroEqz),
+                ["nez"] => r_assemble!(CzeroNez),
+                _ => Err("czero must be czero.eqz or czero.nez".to_owned()),
+            },
+            "ntl" => {
+                if !operands.is_empty() {
+                    Err("ntl hints take no operands".to_owned())
+                } else {
+                    Ok(Instruction::Add {
+                        dest: IRegister::Zero,
+                        src1: IRegister::Zero,
+                        src2: parse_ntl_hint(&mnemonics[1..])?,
+                    })
+                }
+            }
             "addw" => r_assemble!(Addw),
             "subw" => r_assemble!(Subw),
             "and" => r_assemble!(And),
@@ -160,6 +306,43 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
             "divuw" => r_assemble!(Divuw),
             "remw" => r_assemble!(Remw),
             "remuw" => r_assemble!(Remuw),
+            // load-acquire and store-release instructions (Zalasr)
+            "lb" | "lh" | "lw" | "ld" if mnemonics.len() > 1 => {
+                let rl = match mnemonics[1..] {
+                    ["aq"] => false,
+                    ["aqrl"] => true,
+                    _ => return Err(format!("{} must be ordered with aq or aqrl", mnemonics[0])),
+                };
+                if operands.len() != 2 {
+                    return Err(format!("{}.aq requires 2 operands", mnemonics[0]));
+                }
+                let dest = IRegister::try_from(operands[0])?;
+                let addr = parse_register_address(operands[1])?;
+                Ok(match mnemonics[0] {
+                    "lb" => Instruction::LbAq { dest, addr, rl },
+                    "lh" => Instruction::LhAq { dest, addr, rl },
+                    "lw" => Instruction::LwAq { dest, addr, rl },
+                    _ => Instruction::LdAq { dest, addr, rl },
+                })
+            }
+            "sb" | "sh" | "sw" | "sd" if mnemonics.len() > 1 => {
+                let aq = match mnemonics[1..] {
+                    ["rl"] => false,
+                    ["aqrl"] => true,
+                    _ => return Err(format!("{} must be ordered with rl or aqrl", mnemonics[0])),
+                };
+                if operands.len() != 2 {
+                    return Err(format!("{}.rl requires 2 operands", mnemonics[0]));
+                }
+                let src = IRegister::try_from(operands[0])?;
+                let addr = parse_register_address(operands[1])?;
+                Ok(match mnemonics[0] {
+                    "sb" => Instruction::SbRl { src, addr, aq },
+                    "sh" => Instruction::ShRl { src, addr, aq },
+                    "sw" => Instruction::SwRl { src, addr, aq },
+                    _ => Instruction::SdRl { src, addr, aq },
+                })
+            }
             // load instructions
             "lb" => l_assemble!(Lb),
             "lbu" => l_assemble!(Lbu),
@@ -266,7 +449,7 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                         }
                     }
                 } else if mnemonics[1] == "i" {
-                    if operands.len() != 0 {
+                    if !operands.is_empty() {
                         Err("fence.i requires 0 operands".to_owned())
                     } else {
                         Ok(Instruction::FenceI)
@@ -275,6 +458,19 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                     Err("invalid fence".to_owned())
                 }
             }
+            "pause" => {
+                if !operands.is_empty() {
+                    Err("pause takes no operands".to_owned())
+                } else {
+                    // a fence with a predecessor set of w and an empty successor set
+                    Ok(Instruction::Fence {
+                        rd: IRegister::Zero,
+                        rs1: IRegister::Zero,
+                        ops: parse_fence_set("w") << 4,
+                        fm: 0,
+                    })
+                }
+            }
             // LR can't use `amo_assemble!` because it only has two operands
             "lr" => {
                 if mnemonics.len() == 1 {
@@ -329,23 +525,86 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                     )
                 }
             }
-            "sc" => amo_assemble!(Sc),
-            "amoswap" => amo_assemble!(Amoswap),
-            "amoadd" => amo_assemble!(Amoadd),
-            "amoxor" => amo_assemble!(Amoxor),
-            "amoand" => amo_assemble!(Amoand),
-            "amoor" => amo_assemble!(Amoor),
-            "amomin" => amo_assemble!(Amomin),
-            "amomax" => amo_assemble!(Amomax),
-            "amominu" => amo_assemble!(Amominu),
-            "amomaxu" => amo_assemble!(Amomaxu),
+            "sc" => amo_assemble!(Sc w d),
+            "amoswap" => amo_assemble!(Amoswap b h w d),
+            "amoadd" => amo_assemble!(Amoadd b h w d),
+            "amoxor" => amo_assemble!(Amoxor b h w d),
+            "amoand" => amo_assemble!(Amoand b h w d),
+            "amoor" => amo_assemble!(Amoor b h w d),
+            "amomin" => amo_assemble!(Amomin b h w d),
+            "amomax" => amo_assemble!(Amomax b h w d),
+            "amominu" => amo_assemble!(Amominu b h w d),
+            "amomaxu" => amo_assemble!(Amomaxu b h w d),
+            "ssamoswap" => amo_assemble!(Ssamoswap w d),
+            "lpad" => {
+                if operands.len() != 1 {
+                    Err("lpad requires 1 operand".to_owned())
+                } else {
+                    Ok(Instruction::Lpad {
+                        label: LpadLabel::try_from(parse_int(operands[0])?)?,
+                    })
+                }
+            }
+            "sspush" | "sspopchk" => {
+                if operands.len() != 1 {
+                    Err(format!("{} requires 1 operand", mnemonics[0]))
+                } else {
+                    let src = IRegister::try_from(operands[0])?;
+                    if !is_link_register(&src) {
+                        Err(format!("{} requires ra or t0", mnemonics[0]))
+                    } else if mnemonics[0] == "sspush" {
+                        Ok(Instruction::Sspush { src })
+                    } else {
+                        Ok(Instruction::Sspopchk { src })
+                    }
+                }
+            }
+            "mop" => match (mnemonics.get(1), mnemonics.get(2).map(|n| n.parse::<u8>())) {
+                (Some(&"r"), Some(Ok(n))) if n < 32 => {
+                    if operands.len() != 2 {
+                        Err("mop.r requires 2 operands".to_owned())
+                    } else {
+                        Ok(Instruction::MopR {
+                            n,
+                            dest: IRegister::try_from(operands[0])?,
+                            src: IRegister::try_from(operands[1])?,
+                        })
+                    }
+                }
+                (Some(&"rr"), Some(Ok(n))) if n < 8 => {
+                    if operands.len() != 3 {
+                        Err("mop.rr requires 3 operands".to_owned())
+                    } else {
+                        Ok(Instruction::MopRr {
+                            n,
+                            dest: IRegister::try_from(operands[0])?,
+                            src1: IRegister::try_from(operands[1])?,
+                            src2: IRegister::try_from(operands[2])?,
+                        })
+                    }
+                }
+                _ => Err("mop must be mop.r.n (n < 32) or mop.rr.n (n < 8)".to_owned()),
+            },
+            "ssrdp" => {
+                if operands.len() != 1 {
+                    Err("ssrdp requires 1 operand".to_owned())
+                } else {
+                    let dest = IRegister::try_from(operands[0])?;
+                    if dest == IRegister::Zero {
+                        Err("ssrdp requires a non-zero destination".to_owned())
+                    } else {
+                        Ok(Instruction::Ssrdp { dest })
+                    }
+                }
+            }
+            "amocas" => amo_assemble!(Amocas b h w d q),
             "flw" => {
                 if operands.len() != 2 {
                     Err("flw instruction requires 2 operands".to_owned())
                 } else {
                     let (base, offset) = parse_address_expression(operands[1])?;
                     Ok(Instruction::Flw {
-                        dest: FRegister::try_from(operands[0])?,
+                        dest: freg(operands[0])?,
                         base,
                         offset: IImmediate::try_from(offset)?,
                     })
@@ -358,7 +617,7 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                     let (base, offset) = parse_address_expression(operands[1])?;
                     Ok(Instruction::Fsw {
                         base,
-                        src: FRegister::try_from(operands[0])?,
+                        src: freg(operands[0])?,
                         offset: SImmediate::try_from(offset)?,
                     })
                 }
@@ -374,17 +633,22 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
 
                 match mnemonics.get(1) {
                     Some(&"s") => Ok(Instruction::FsqrtS {
-                        dest: FRegister::try_from(operands[0])?,
-                        src: FRegister::try_from(operands[1])?,
+                        dest: freg(operands[0])?,
+                        src: freg(operands[1])?,
                         rm,
                     }),
                     Some(&"d") => Ok(Instruction::FsqrtD {
-                        dest: FRegister::try_from(operands[0])?,
-                        src: FRegister::try_from(operands[1])?,
+                        dest: freg(operands[0])?,
+                        src: freg(operands[1])?,
                         rm,
                     }),
-                    Some(_) => Err("fsqrt instructions requires prefix {s,d}".to_owned()),
-                    None => Err("fsqrt instructions requires prefix {s,d}".to_owned()),
+                    Some(&"q") => Ok(Instruction::FsqrtQ {
+                        dest: freg(operands[0])?,
+                        src: freg(operands[1])?,
+                        rm,
+                    }),
+                    Some(_) => Err("fsqrt instructions requires prefix {s,d,q}".to_owned()),
+                    None => Err("fsqrt instructions requires prefix {s,d,q}".to_owned()),
                 }
             }
             "fadd" => fr_assemble!(Fadd true),
@@ -414,92 +678,162 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                     match (mnemonics[1], mnemonics[2]) {
                         ("w", "s") => Ok(Instruction::FcvtWS {
                             dest: IRegister::try_from(operands[0])?,
-                            src: FRegister::try_from(operands[1])?,
+                            src: freg(operands[1])?,
                             rm,
                         }),
                         ("wu", "s") => Ok(Instruction::FcvtWuS {
                             dest: IRegister::try_from(operands[0])?,
-                            src: FRegister::try_from(operands[1])?,
+                            src: freg(operands[1])?,
                             rm,
                         }),
                         ("s", "w") => Ok(Instruction::FcvtSW {
-                            dest: FRegister::try_from(operands[0])?,
+                            dest: freg(operands[0])?,
                             src: IRegister::try_from(operands[1])?,
                             rm,
                         }),
                         ("s", "wu") => Ok(Instruction::FcvtSWu {
-                            dest: FRegister::try_from(operands[0])?,
+                            dest: freg(operands[0])?,
                             src: IRegister::try_from(operands[1])?,
                             rm,
                         }),
                         ("l", "s") => Ok(Instruction::FcvtLS {
                             dest: IRegister::try_from(operands[0])?,
-                            src: FRegister::try_from(operands[1])?,
+                            src: freg(operands[1])?,
                             rm,
                         }),
                         ("lu", "s") => Ok(Instruction::FcvtLuS {
                             dest: IRegister::try_from(operands[0])?,
-                            src: FRegister::try_from(operands[1])?,
+                            src: freg(operands[1])?,
                             rm,
                         }),
                         ("s", "l") => Ok(Instruction::FcvtSL {
-                            dest: FRegister::try_from(operands[0])?,
+                            dest: freg(operands[0])?,
                             src: IRegister::try_from(operands[1])?,
                             rm,
                         }),
                         ("s", "lu") => Ok(Instruction::FcvtSLu {
-                            dest: FRegister::try_from(operands[0])?,
+                            dest: freg(operands[0])?,
                             src: IRegister::try_from(operands[1])?,
                             rm,
                         }),
                         ("w", "d") => Ok(Instruction::FcvtWD {
                             dest: IRegister::try_from(operands[0])?,
-                            src1: FRegister::try_from(operands[1])?,
+                            src1: freg(operands[1])?,
                             rm,
                         }),
                         ("wu", "d") => Ok(Instruction::FcvtWuD {
                             dest: IRegister::try_from(operands[0])?,
-                            src1: FRegister::try_from(operands[1])?,
+                            src1: freg(operands[1])?,
                             rm,
                         }),
                         ("d", "w") => Ok(Instruction::FcvtDW {
-                            dest: FRegister::try_from(operands[0])?,
+                            dest: freg(operands[0])?,
                             src1: IRegister::try_from(operands[1])?,
                             rm,
                         }),
                         ("d", "wu") => Ok(Instruction::FcvtDWu {
-                            dest: FRegister::try_from(operands[0])?,
+                            dest: freg(operands[0])?,
                             src1: IRegister::try_from(operands[1])?,
                             rm,
                         }),
                         ("l", "d") => Ok(Instruction::FcvtLD {
                             dest: IRegister::try_from(operands[0])?,
-                            src1: FRegister::try_from(operands[1])?,
+                            src1: freg(operands[1])?,
                             rm,
                         }),
                         ("lu", "d") => Ok(Instruction::FcvtLuD {
                             dest: IRegister::try_from(operands[0])?,
-                            src1: FRegister::try_from(operands[1])?,
+                            src1: freg(operands[1])?,
                             rm,
                         }),
                         ("d", "l") => Ok(Instruction::FcvtDL {
-                            dest: FRegister::try_from(operands[0])?,
+                            dest: freg(operands[0])?,
                             src: IRegister::try_from(operands[1])?,
                             rm,
                         }),
                         ("d", "lu") => Ok(Instruction::FcvtDLu {
-                            dest: FRegister::try_from(operands[0])?,
+                            dest: freg(operands[0])?,
                             src: IRegister::try_from(operands[1])?,
                             rm,
                         }),
                         ("s", "d") => Ok(Instruction::FcvtSD {
-                            dest: FRegister::try_from(operands[0])?,
-                            src: FRegister::try_from(operands[1])?,
+                            dest: freg(operands[0])?,
+                            src: freg(operands[1])?,
                             rm,
                         }),
                         ("d", "s") => Ok(Instruction::FcvtDS {
-                            dest: FRegister::try_from(operands[0])?,
-                            src: FRegister::try_from(operands[1])?,
+                            dest: freg(operands[0])?,
+                            src: freg(operands[1])?,
+                            rm,
+                        }),
+                        ("w", "q") => Ok(Instruction::FcvtWQ {
+                            dest: IRegister::try_from(operands[0])?,
+                            src: freg(operands[1])?,
+                            rm,
+                        }),
+                        ("wu", "q") => Ok(Instruction::FcvtWuQ {
+                            dest: IRegister::try_from(operands[0])?,
+                            src: freg(operands[1])?,
+                            rm,
+                        }),
+                        ("q", "w") => Ok(Instruction::FcvtQW {
+                            dest: freg(operands[0])?,
+                            src: IRegister::try_from(operands[1])?,
+                            rm,
+                        }),
+                        ("q", "wu") => Ok(Instruction::FcvtQWu {
+                            dest: freg(operands[0])?,
+                            src: IRegister::try_from(operands[1])?,
+                            rm,
+                        }),
+                        ("l", "q") => Ok(Instruction::FcvtLQ {
+                            dest: IRegister::try_from(operands[0])?,
+                            src: freg(operands[1])?,
+                            rm,
+                        }),
+                        ("lu", "q") => Ok(Instruction::FcvtLuQ {
+                            dest: IRegister::try_from(operands[0])?,
+                            src: freg(operands[1])?,
+                            rm,
+                        }),
+                        ("q", "l") => Ok(Instruction::FcvtQL {
+                            dest: freg(operands[0])?,
+                            src: IRegister::try_from(operands[1])?,
+                            rm,
+                        }),
+                        ("q", "lu") => Ok(Instruction::FcvtQLu {
+                            dest: freg(operands[0])?,
+                            src: IRegister::try_from(operands[1])?,
+                            rm,
+                        }),
+                        ("s", "q") => Ok(Instruction::FcvtSQ {
+                            dest: freg(operands[0])?,
+                            src: freg(operands[1])?,
+                            rm,
+                        }),
+                        ("q", "s") => Ok(Instruction::FcvtQS {
+                            dest: freg(operands[0])?,
+                            src: freg(operands[1])?,
+                            rm,
+                        }),
+                        ("d", "q") => Ok(Instruction::FcvtDQ {
+                            dest: freg(operands[0])?,
+                            src: freg(operands[1])?,
+                            rm,
+                        }),
+                        ("q", "d") => Ok(Instruction::FcvtQD {
+                            dest: freg(operands[0])?,
+                            src: freg(operands[1])?,
+                            rm,
+                        }),
+                        ("bf16", "s") => Ok(Instruction::FcvtBf16S {
+                            dest: freg(operands[0])?,
+                            src: freg(operands[1])?,
+                            rm,
+                        }),
+                        ("s", "bf16") => Ok(Instruction::FcvtSBf16 {
+                            dest: freg(operands[0])?,
+                            src: freg(operands[1])?,
                             rm,
                         }),
                         _ => Err("invalid fcvt suffixes".to_owned()),
@@ -515,18 +849,18 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                     match (mnemonics[1], mnemonics[2]) {
                         ("x", "w") => Ok(Instruction::FmvXW {
                             dest: IRegister::try_from(operands[0])?,
-                            src: FRegister::try_from(operands[1])?,
+                            src: freg(operands[1])?,
                         }),
                         ("w", "x") => Ok(Instruction::FmvWX {
-                            dest: FRegister::try_from(operands[0])?,
+                            dest: freg(operands[0])?,
                             src: IRegister::try_from(operands[1])?,
                         }),
                         ("x", "d") => Ok(Instruction::FmvXD {
                             dest: IRegister::try_from(operands[0])?,
-                            src: FRegister::try_from(operands[1])?,
+                            src: freg(operands[1])?,
                         }),
                         ("d", "x") => Ok(Instruction::FmvDX {
-                            dest: FRegister::try_from(operands[0])?,
+                            dest: freg(operands[0])?,
                             src: IRegister::try_from(operands[1])?,
                         }),
                         _ => Err("invalid fmv suffixes".to_owned()),
@@ -542,20 +876,24 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                     match mnemonics[1] {
                         "s" => Ok(Instruction::FeqS {
                             dest: IRegister::try_from(operands[0])?,
-                            src1: FRegister::try_from(operands[1])?,
-                            src2: FRegister::try_from(operands[2])?,
+                            src1: freg(operands[1])?,
+                            src2: freg(operands[2])?,
                         }),
                         "d" => Ok(Instruction::FeqD {
                             dest: IRegister::try_from(operands[0])?,
-                            src1: FRegister::try_from(operands[1])?,
-                            src2: FRegister::try_from(operands[2])?,
+                            src1: freg(operands[1])?,
+                            src2: freg(operands[2])?,
+                        }),
+                        "q" => Ok(Instruction::FeqQ {
+                            dest: IRegister::try_from(operands[0])?,
+                            src1: freg(operands[1])?,
+                            src2: freg(operands[2])?,
                         }),
-                        "q" => todo!(),
                         "h" => todo!(),
-                        _ => Err("feq requires a suffix {s,d}".to_owned()),
+                        _ => Err("feq requires a suffix {s,d,q}".to_owned()),
                     }
                 } else {
-                    Err("feq requires a suffix {s,d}".to_owned())
+                    Err("feq requires a suffix {s,d,q}".to_owned())
                 }
             }
             "flt" => {
@@ -565,20 +903,24 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                     match mnemonics[1] {
                         "s" => Ok(Instruction::FltS {
                             dest: IRegister::try_from(operands[0])?,
-                            src1: FRegister::try_from(operands[1])?,
-                            src2: FRegister::try_from(operands[2])?,
+                            src1: freg(operands[1])?,
+                            src2: freg(operands[2])?,
                         }),
                         "d" => Ok(Instruction::FltD {
                             dest: IRegister::try_from(operands[0])?,
-                            src1: FRegister::try_from(operands[1])?,
-                            src2: FRegister::try_from(operands[2])?,
+                            src1: freg(operands[1])?,
+                            src2: freg(operands[2])?,
+                        }),
+                        "q" => Ok(Instruction::FltQ {
+                            dest: IRegister::try_from(operands[0])?,
+                            src1: freg(operands[1])?,
+                            src2: freg(operands[2])?,
                         }),
-                        "q" => todo!(),
                         "h" => todo!(),
-                        _ => Err("flt requires a suffix {s,d}".to_owned()),
+                        _ => Err("flt requires a suffix {s,d,q}".to_owned()),
                     }
                 } else {
-                    Err("flt requires a suffix {s,d}".to_owned())
+                    Err("flt requires a suffix {s,d,q}".to_owned())
                 }
             }
             "fle" => {
@@ -588,20 +930,24 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                     match mnemonics[1] {
                         "s" => Ok(Instruction::FleS {
                             dest: IRegister::try_from(operands[0])?,
-                            src1: FRegister::try_from(operands[1])?,
-                            src2: FRegister::try_from(operands[2])?,
+                            src1: freg(operands[1])?,
+                            src2: freg(operands[2])?,
                         }),
                         "d" => Ok(Instruction::FleD {
                             dest: IRegister::try_from(operands[0])?,
-                            src1: FRegister::try_from(operands[1])?,
-                            src2: FRegister::try_from(operands[2])?,
+                            src1: freg(operands[1])?,
+                            src2: freg(operands[2])?,
+                        }),
+                        "q" => Ok(Instruction::FleQ {
+                            dest: IRegister::try_from(operands[0])?,
+                            src1: freg(operands[1])?,
+                            src2: freg(operands[2])?,
                         }),
-                        "q" => todo!(),
                         "h" => todo!(),
-                        _ => Err("fle requires a suffix {s,d}".to_owned()),
+                        _ => Err("fle requires a suffix {s,d,q}".to_owned()),
                     }
                 } else {
-                    Err("fle requires a suffix {s,d}".to_owned())
+                    Err("fle requires a suffix {s,d,q}".to_owned())
                 }
             }
             "fclass" => {
@@ -611,18 +957,402 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                     match mnemonics[1] {
                         "s" => Ok(Instruction::FclassS {
                             dest: IRegister::try_from(operands[0])?,
-                            src: FRegister::try_from(operands[1])?,
+                            src: freg(operands[1])?,
                         }),
                         "d" => Ok(Instruction::FclassD {
                             dest: IRegister::try_from(operands[0])?,
-                            src1: FRegister::try_from(operands[1])?,
+                            src1: freg(operands[1])?,
+                        }),
+                        "q" => Ok(Instruction::FclassQ {
+                            dest: IRegister::try_from(operands[0])?,
+                            src: freg(operands[1])?,
                         }),
-                        "q" => todo!(),
                         "h" => todo!(),
-                        _ => Err("fclass requires a suffix {s,d}".to_owned()),
+                        _ => Err("fclass requires a suffix {s,d,q}".to_owned()),
                     }
                 } else {
-                    Err("fclass requires a suffix {s,d}".to_owned())
+                    Err("fclass requires a suffix {s,d,q}".to_owned())
+                }
+            }
+            "fli" => {
+                if operands.len() != 2 {
+                    Err("fli requires 2 operands".to_owned())
+                } else if mnemonics.len() == 2 {
+                    let dest = freg(operands[0])?;
+                    let imm = FliConstant::from_str(operands[1])?;
+                    match mnemonics[1] {
+                        "s" => Ok(Instruction::FliS { dest, imm }),
+                        "d" => Ok(Instruction::FliD { dest, imm }),
+                        "h" => Ok(Instruction::FliH { dest, imm }),
+                        "q" => Ok(Instruction::FliQ { dest, imm }),
+                        _ => Err("fli requires a suffix {s,d,h,q}".to_owned()),
+                    }
+                } else {
+                    Err("fli requires a suffix {s,d,h,q}".to_owned())
+                }
+            }
+            "fminm" => {
+                if operands.len() != 3 {
+                    Err("fminm requires 3 operands".to_owned())
+                } else if mnemonics.len() == 2 {
+                    let dest = freg(operands[0])?;
+                    let src1 = freg(operands[1])?;
+                    let src2 = freg(operands[2])?;
+                    match mnemonics[1] {
+                        "s" => Ok(Instruction::FminmS { dest, src1, src2 }),
+                        "d" => Ok(Instruction::FminmD { dest, src1, src2 }),
+                        "h" => Ok(Instruction::FminmH { dest, src1, src2 }),
+                        "q" => Ok(Instruction::FminmQ { dest, src1, src2 }),
+                        _ => Err("fminm requires a suffix {s,d,h,q}".to_owned()),
+                    }
+                } else {
+                    Err("fminm requires a suffix {s,d,h,q}".to_owned())
+                }
+            }
+            "fmaxm" => {
+                if operands.len() != 3 {
+                    Err("fmaxm requires 3 operands".to_owned())
+                } else if mnemonics.len() == 2 {
+                    let dest = freg(operands[0])?;
+                    let src1 = freg(operands[1])?;
+                    let src2 = freg(operands[2])?;
+                    match mnemonics[1] {
+                        "s" => Ok(Instruction::FmaxmS { dest, src1, src2 }),
+                        "d" => Ok(Instruction::FmaxmD { dest, src1, src2 }),
+                        "h" => Ok(Instruction::FmaxmH { dest, src1, src2 }),
+                        "q" => Ok(Instruction::FmaxmQ { dest, src1, src2 }),
+                        _ => Err("fmaxm requires a suffix {s,d,h,q}".to_owned()),
+                    }
+                } else {
+                    Err("fmaxm requires a suffix {s,d,h,q}".to_owned())
+                }
+            }
+            "fleq" => {
+                if operands.len() != 3 {
+                    Err("fleq requires 3 operands".to_owned())
+                } else if mnemonics.len() == 2 {
+                    let dest = IRegister::try_from(operands[0])?;
+                    let src1 = freg(operands[1])?;
+                    let src2 = freg(operands[2])?;
+                    match mnemonics[1] {
+                        "s" => Ok(Instruction::FleqS { dest, src1, src2 }),
+                        "d" => Ok(Instruction::FleqD { dest, src1, src2 }),
+                        "h" => Ok(Instruction::FleqH { dest, src1, src2 }),
+                        "q" => Ok(Instruction::FleqQ { dest, src1, src2 }),
+                        _ => Err("fleq requires a suffix {s,d,h,q}".to_owned()),
+                    }
+                } else {
+                    Err("fleq requires a suffix {s,d,h,q}".to_owned())
+                }
+            }
+            "fltq" => {
+                if operands.len() != 3 {
+                    Err("fltq requires 3 operands".to_owned())
+                } else if mnemonics.len() == 2 {
+                    let dest = IRegister::try_from(operands[0])?;
+                    let src1 = freg(operands[1])?;
+                    let src2 = freg(operands[2])?;
+                    match mnemonics[1] {
+                        "s" => Ok(Instruction::FltqS { dest, src1, src2 }),
+                        "d" => Ok(Instruction::FltqD { dest, src1, src2 }),
+                        "h" => Ok(Instruction::FltqH { dest, src1, src2 }),
+                        "q" => Ok(Instruction::FltqQ { dest, src1, src2 }),
+                        _ => Err("fltq requires a suffix {s,d,h,q}".to_owned()),
+                    }
+                } else {
+                    Err("fltq requires a suffix {s,d,h,q}".to_owned())
+                }
+            }
+            "fround" => {
+                let rm = if operands.len() == 2 {
+                    RoundingMode::DYN
+                } else if operands.len() == 3 {
+                    RoundingMode::from_str(operands[2])?
+                } else {
+                    return Err("fround instruction requires 2 or 3 operands".to_owned());
+                };
+                let dest = freg(operands[0])?;
+                let src = freg(operands[1])?;
+                match mnemonics.get(1) {
+                    Some(&"s") => Ok(Instruction::FroundS { dest, src, rm }),
+                    Some(&"d") => Ok(Instruction::FroundD { dest, src, rm }),
+                    Some(&"h") => Ok(Instruction::FroundH { dest, src, rm }),
+                    Some(&"q") => Ok(Instruction::FroundQ { dest, src, rm }),
+                    _ => Err("fround instructions requires prefix {s,d,h,q}".to_owned()),
+                }
+            }
+            "froundnx" => {
+                let rm = if operands.len() == 2 {
+                    RoundingMode::DYN
+                } else if operands.len() == 3 {
+                    RoundingMode::from_str(operands[2])?
+                } else {
+                    return Err("froundnx instruction requires 2 or 3 operands".to_owned());
+                };
+                let dest = freg(operands[0])?;
+                let src = freg(operands[1])?;
+                match mnemonics.get(1) {
+                    Some(&"s") => Ok(Instruction::FroundnxS { dest, src, rm }),
+                    Some(&"d") => Ok(Instruction::FroundnxD { dest, src, rm }),
+                    Some(&"h") => Ok(Instruction::FroundnxH { dest, src, rm }),
+                    Some(&"q") => Ok(Instruction::FroundnxQ { dest, src, rm }),
+                    _ => Err("froundnx instructions requires prefix {s,d,h,q}".to_owned()),
+                }
+            }
+            "fcvtmod" => {
+                if mnemonics.len() != 3 || mnemonics[1] != "w" || mnemonics[2] != "d" {
+                    Err("fcvtmod requires suffixes w.d".to_owned())
+                } else if operands.len() != 3 || operands[2] != "rtz" {
+                    Err("fcvtmod.w.d requires 3 operands with the rtz rounding mode".to_owned())
+                } else {
+                    Ok(Instruction::FcvtmodWD {
+                        dest: IRegister::try_from(operands[0])?,
+                        src: freg(operands[1])?,
+                    })
+                }
+            }
+            "fmvh" => {
+                if operands.len() != 2 {
+                    Err("fmvh requires 2 operands".to_owned())
+                } else if mnemonics.len() == 3 && mnemonics[1] == "x" && mnemonics[2] == "d" {
+                    Ok(Instruction::FmvhXD {
+                        dest: IRegister::try_from(operands[0])?,
+                        src: freg(operands[1])?,
+                    })
+                } else {
+                    Err("fmvh requires suffixes x.d".to_owned())
+                }
+            }
+            "fmvp" => {
+                if operands.len() != 3 {
+                    Err("fmvp requires 3 operands".to_owned())
+                } else if mnemonics.len() == 3 && mnemonics[1] == "d" && mnemonics[2] == "x" {
+                    Ok(Instruction::FmvpDX {
+                        dest: freg(operands[0])?,
+                        src1: IRegister::try_from(operands[1])?,
+                        src2: IRegister::try_from(operands[2])?,
+                    })
+                } else {
+                    Err("fmvp requires suffixes d.x".to_owned())
+                }
+            }
+            "vfncvtbf16" => {
+                let (operands, masked) = parse_vector_mask(&operands);
+                if operands.len() != 2 {
+                    Err("vfncvtbf16 requires 2 operands".to_owned())
+                } else if mnemonics[1..] != ["f", "f", "w"] {
+                    Err("vfncvtbf16 requires suffixes f.f.w".to_owned())
+                } else {
+                    Ok(Instruction::Vfncvtbf16FFW {
+                        dest: VRegister::try_from(operands[0])?,
+                        src: VRegister::try_from(operands[1])?,
+                        masked,
+                    })
+                }
+            }
+            "vfwcvtbf16" => {
+                let (operands, masked) = parse_vector_mask(&operands);
+                if operands.len() != 2 {
+                    Err("vfwcvtbf16 requires 2 operands".to_owned())
+                } else if mnemonics[1..] != ["f", "f", "v"] {
+                    Err("vfwcvtbf16 requires suffixes f.f.v".to_owned())
+                } else {
+                    Ok(Instruction::Vfwcvtbf16FFV {
+                        dest: VRegister::try_from(operands[0])?,
+                        src: VRegister::try_from(operands[1])?,
+                        masked,
+                    })
+                }
+            }
+            "vfwmaccbf16" => {
+                let (operands, masked) = parse_vector_mask(&operands);
+                if operands.len() != 3 {
+                    Err("vfwmaccbf16 requires 3 operands".to_owned())
+                } else {
+                    match mnemonics.get(1) {
+                        Some(&"vv") => Ok(Instruction::Vfwmaccbf16VV {
+                            dest: VRegister::try_from(operands[0])?,
+                            src1: VRegister::try_from(operands[1])?,
+                            src2: VRegister::try_from(operands[2])?,
+                            masked,
+                        }),
+                        Some(&"vf") => Ok(Instruction::Vfwmaccbf16VF {
+                            dest: VRegister::try_from(operands[0])?,
+                            src1: freg(operands[1])?,
+                            src2: VRegister::try_from(operands[2])?,
+                            masked,
+                        }),
+                        _ => Err("vfwmaccbf16 requires a suffix {vv,vf}".to_owned()),
+                    }
+                }
+            }
+            "ecall" => {
+                if !operands.is_empty() {
+                    Err("ecall takes no operands".to_owned())
+                } else {
+                    Ok(Instruction::Ecall)
+                }
+            }
+            "ebreak" => {
+                if !operands.is_empty() {
+                    Err("ebreak takes no operands".to_owned())
+                } else {
+                    Ok(Instruction::Ebreak)
+                }
+            }
+            "sret" => {
+                if !operands.is_empty() {
+                    Err("sret takes no operands".to_owned())
+                } else {
+                    Ok(Instruction::Sret)
+                }
+            }
+            "mret" => {
+                if !operands.is_empty() {
+                    Err("mret takes no operands".to_owned())
+                } else {
+                    Ok(Instruction::Mret)
+                }
+            }
+            "wrs" => {
+                if !operands.is_empty() {
+                    Err("wrs takes no operands".to_owned())
+                } else {
+                    match mnemonics[1..] {
+                        ["nto"] => Ok(Instruction::WrsNto),
+                        ["sto"] => Ok(Instruction::WrsSto),
+                        _ => Err("wrs must be wrs.nto or wrs.sto".to_owned()),
+                    }
+                }
+            }
+            "wfi" => {
+                if !operands.is_empty() {
+                    Err("wfi takes no operands".to_owned())
+                } else {
+                    Ok(Instruction::Wfi)
+                }
+            }
+            "mnret" => {
+                if !operands.is_empty() {
+                    Err("mnret takes no operands".to_owned())
+                } else {
+                    Ok(Instruction::Mnret)
+                }
+            }
+            "dret" => {
+                if !operands.is_empty() {
+                    Err("dret takes no operands".to_owned())
+                } else {
+                    Ok(Instruction::Dret)
+                }
+            }
+            "sfence" => match mnemonics.get(1) {
+                Some(&"vma") => {
+                    let (vaddr, asid) = parse_sfence_operands(&operands)?;
+                    Ok(Instruction::SfenceVma { vaddr, asid })
+                }
+                Some(&"w") if mnemonics.get(2) == Some(&"inval") && operands.is_empty() => {
+                    Ok(Instruction::SfenceWInval)
+                }
+                Some(&"inval") if mnemonics.get(2) == Some(&"ir") && operands.is_empty() => {
+                    Ok(Instruction::SfenceInvalIr)
+                }
+                _ => Err("sfence requires a suffix {vma,w.inval,inval.ir}".to_owned()),
+            },
+            "sinval" => {
+                if mnemonics.get(1) == Some(&"vma") {
+                    let (vaddr, asid) = parse_sfence_operands(&operands)?;
+                    Ok(Instruction::SinvalVma { vaddr, asid })
+                } else {
+                    Err("sinval requires a suffix {vma}".to_owned())
+                }
+            }
+            "hfence" | "hinval" => {
+                let (src1, src2) = parse_sfence_operands(&operands)?;
+                match (mnemonics[0], mnemonics.get(1)) {
+                    ("hfence", Some(&"vvma")) => Ok(Instruction::HfenceVvma {
+                        vaddr: src1,
+                        asid: src2,
+                    }),
+                    ("hfence", Some(&"gvma")) => Ok(Instruction::HfenceGvma {
+                        gaddr: src1,
+                        vmid: src2,
+                    }),
+                    ("hinval", Some(&"vvma")) => Ok(Instruction::HinvalVvma {
+                        vaddr: src1,
+                        asid: src2,
+                    }),
+                    ("hinval", Some(&"gvma")) => Ok(Instruction::HinvalGvma {
+                        gaddr: src1,
+                        vmid: src2,
+                    }),
+                    _ => Err(format!("{} requires a suffix {{vvma,gvma}}", mnemonics[0])),
+                }
+            }
+            "hlv" | "hlvx" => {
+                if operands.len() != 2 {
+                    Err(format!("{} requires 2 operands", mnemonics[0]))
+                } else {
+                    let dest = IRegister::try_from(operands[0])?;
+                    let addr = parse_register_address(operands[1])?;
+                    match (mnemonics[0], mnemonics.get(1)) {
+                        ("hlv", Some(&"b")) => Ok(Instruction::HlvB { dest, addr }),
+                        ("hlv", Some(&"bu")) => Ok(Instruction::HlvBu { dest, addr }),
+                        ("hlv", Some(&"h")) => Ok(Instruction::HlvH { dest, addr }),
+                        ("hlv", Some(&"hu")) => Ok(Instruction::HlvHu { dest, addr }),
+                        ("hlvx", Some(&"hu")) => Ok(Instruction::HlvxHu { dest, addr }),
+                        ("hlv", Some(&"w")) => Ok(Instruction::HlvW { dest, addr }),
+                        ("hlvx", Some(&"wu")) => Ok(Instruction::HlvxWu { dest, addr }),
+                        ("hlv", Some(&"wu")) => Ok(Instruction::HlvWu { dest, addr }),
+                        ("hlv", Some(&"d")) => Ok(Instruction::HlvD { dest, addr }),
+                        ("hlv", _) => Err("hlv requires a suffix {b,bu,h,hu,w,wu,d}".to_owned()),
+                        _ => Err("hlvx requires a suffix {hu,wu}".to_owned()),
+                    }
+                }
+            }
+            "hsv" => {
+                if operands.len() != 2 {
+                    Err("hsv requires 2 operands".to_owned())
+                } else {
+                    let src = IRegister::try_from(operands[0])?;
+                    let addr = parse_register_address(operands[1])?;
+                    match mnemonics.get(1) {
+                        Some(&"b") => Ok(Instruction::HsvB { src, addr }),
+                        Some(&"h") => Ok(Instruction::HsvH { src, addr }),
+                        Some(&"w") => Ok(Instruction::HsvW { src, addr }),
+                        Some(&"d") => Ok(Instruction::HsvD { src, addr }),
+                        _ => Err("hsv requires a suffix {b,h,w,d}".to_owned()),
+                    }
+                }
+            }
+            "cbo" => {
+                if operands.len() != 1 {
+                    Err("cbo requires 1 operand".to_owned())
+                } else {
+                    let base = parse_register_address(operands[0])?;
+                    match mnemonics.get(1) {
+                        Some(&"clean") => Ok(Instruction::CboClean { base }),
+                        Some(&"flush") => Ok(Instruction::CboFlush { base }),
+                        Some(&"inval") => Ok(Instruction::CboInval { base }),
+                        Some(&"zero") => Ok(Instruction::CboZero { base }),
+                        _ => Err("cbo requires a suffix {clean,flush,inval,zero}".to_owned()),
+                    }
+                }
+            }
+            "prefetch" => {
+                if operands.len() != 1 {
+                    Err("prefetch requires 1 operand".to_owned())
+                } else if !operands[0].contains('(') {
+                    Err("prefetch requires an offset(base) operand".to_owned())
+                } else {
+                    let (base, offset) = parse_address_expression(operands[0])?;
+                    let offset = PrefetchImmediate::try_from(offset)?;
+                    match mnemonics.get(1) {
+                        Some(&"i") => Ok(Instruction::PrefetchI { base, offset }),
+                        Some(&"r") => Ok(Instruction::PrefetchR { base, offset }),
+                        Some(&"w") => Ok(Instruction::PrefetchW { base, offset }),
+                        _ => Err("prefetch requires a suffix {i,r,w}".to_owned()),
+                    }
                 }
             }
             "csrrw" => {
@@ -697,7 +1427,7 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                 } else {
                     let (base, offset) = parse_address_expression(operands[1])?;
                     Ok(Instruction::Fld {
-                        dest: FRegister::try_from(operands[0])?,
+                        dest: freg(operands[0])?,
                         base,
                         offset: IImmediate::try_from(offset)?,
                     })
@@ -710,19 +1440,549 @@ pub fn assemble_line(line: &str) -> Result<AssemblyResult, String> {
                     let (base, offset) = parse_address_expression(operands[1])?;
                     Ok(Instruction::Fsd {
                         base,
-                        src: FRegister::try_from(operands[0])?,
+                        src: freg(operands[0])?,
                         offset: SImmediate::try_from(offset)?,
                     })
                 }
             }
-            _ => Err(format!("unknown mnemonic: {}", mnemonic)),
+            "flq" => {
+                if operands.len() != 2 {
+                    Err("flq instruction requires 2 operands".to_owned())
+                } else {
+                    let (base, offset) = parse_address_expression(operands[1])?;
+                    Ok(Instruction::Flq {
+                        dest: freg(operands[0])?,
+                        base,
+                        offset: IImmediate::try_from(offset)?,
+                    })
+                }
+            }
+            "fsq" => {
+                if operands.len() != 2 {
+                    Err("fsq instruction requires 2 operands".to_owned())
+                } else {
+                    let (base, offset) = parse_address_expression(operands[1])?;
+                    Ok(Instruction::Fsq {
+                        base,
+                        src: freg(operands[0])?,
+                        offset: SImmediate::try_from(offset)?,
+                    })
+                }
+            }
+            _ => Err(format!("unknown mnemonic: {}", mnemonics.join("."))),
+        };
+        let i = x?;
+        i.check_isa(isa)?;
+        Ok(AssemblyResult::I(i))
+    }
+}
+
+/// Parses an integer field of the `.insn` directive, which may be written in hexadecimal or binary
+fn parse_insn_int(str: &str) -> Result<i64, String> {
+    let (negative, digits) = match str.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, str),
+    };
+    let value = if let Some(hex) = digits.strip_prefix("0x") {
+        i64::from_str_radix(hex, 16)
+    } else if let Some(bin) = digits.strip_prefix("0b") {
+        i64::from_str_radix(bin, 2)
+    } else {
+        digits.parse::<i64>()
+    };
+    match value {
+        Ok(x) if negative => Ok(-x),
+        Ok(x) => Ok(x),
+        Err(_) => Err(format!("unable to parse int:{str}")),
+    }
+}
+
+/// Parses an unsigned field of the `.insn` directive with the given width in bits
+fn insn_field(str: &str, bits: u32) -> Result<u8, String> {
+    let x = parse_insn_int(str)?;
+    if x < 0 || x >= 1 << bits {
+        Err(format!("{str} does not fit in a {bits} bit field"))
+    } else {
+        Ok(x as u8)
+    }
+}
+
+/// Parses the opcode of the `.insn` directive, either as an integer or using the names from the GNU assembler
+fn insn_opcode(str: &str, compressed: bool) -> Result<u8, String> {
+    let opcode = match str {
+        "C0" => 0b00,
+        "C1" => 0b01,
+        "C2" => 0b10,
+        "LOAD" => 0b000_0011,
+        "LOAD_FP" => 0b000_0111,
+        "CUSTOM_0" => 0b000_1011,
+        "MISC_MEM" => 0b000_1111,
+        "OP_IMM" => 0b001_0011,
+        "AUIPC" => 0b001_0111,
+        "OP_IMM_32" => 0b001_1011,
+        "STORE" => 0b010_0011,
+        "STORE_FP" => 0b010_0111,
+        "CUSTOM_1" => 0b010_1011,
+        "AMO" => 0b010_1111,
+        "OP" => 0b011_0011,
+        "LUI" => 0b011_0111,
+        "OP_32" => 0b011_1011,
+        "MADD" => 0b100_0011,
+        "MSUB" => 0b100_0111,
+        "NMSUB" => 0b100_1011,
+        "NMADD" => 0b100_1111,
+        "OP_FP" => 0b101_0011,
+        "OP_V" => 0b101_0111,
+        "CUSTOM_2" => 0b101_1011,
+        "BRANCH" => 0b110_0011,
+        "JALR" => 0b110_0111,
+        "JAL" => 0b110_1111,
+        "SYSTEM" => 0b111_0011,
+        "CUSTOM_3" => 0b111_1011,
+        _ => insn_field(str, if compressed { 2 } else { 7 })?,
+    };
+    if compressed && opcode > 0b10 {
+        Err(format!("{str} is not a compressed opcode"))
+    } else if !compressed && opcode & 0b11 != 0b11 {
+        Err(format!("{str} is not an opcode for a 32-bit instruction"))
+    } else {
+        Ok(opcode)
+    }
+}
+
+/// Parses a register operand of the `.insn` directive, which may be an integer or float register
+fn insn_register(str: &str) -> Result<u8, String> {
+    match IRegister::try_from(str) {
+        Ok(r) => Ok(u32::from(r) as u8),
+        Err(_) => FRegister::try_from(str).map(|r| u32::from(r) as u8),
+    }
+}
+
+/// Parses a register operand of the `.insn` directive for the compressed formats that can only use x8-x15 or f8-f15
+fn insn_compressed_register(str: &str) -> Result<u8, String> {
+    match insn_register(str)? {
+        r @ 8..=15 => Ok(r - 8),
+        _ => Err(format!("{str} can't be used in a compressed instruction")),
+    }
+}
+
+/// Parses an address operand of the `.insn` directive, written as `offset(reg)`
+fn insn_address(str: &str) -> Result<(i64, &str), String> {
+    match str
+        .split_once('(')
+        .map(|(offset, r)| (offset, r.strip_suffix(')')))
+    {
+        Some((offset, Some(register))) => Ok((parse_insn_int(offset)?, register)),
+        _ => Err(format!(
+            "expected an address of the form offset(register): {str}"
+        )),
+    }
+}
+
+/// Assembles the `.insn` directive, which encodes an instruction from the fields of its format rather than its
+/// mnemonic. The instruction can also be given as an integer, optionally preceded by its length in bytes.
+fn insn_assemble(operands: &[&str]) -> Result<u32, String> {
+    let Some((format, first)) = operands.first().and_then(|x| x.split_once(' ')) else {
+        let (length, value) = match operands {
+            [value] => (None, *value),
+            [length, value] => (Some(parse_insn_int(length)?), *value),
+            _ => return Err(".insn requires a format or an instruction".to_owned()),
         };
-        x.map(AssemblyResult::I)
+        let bits = parse_insn_int(value)?;
+        let expected = if bits & 0b11 == 0b11 { 4 } else { 2 };
+        if !(0..1 << (8 * expected)).contains(&bits) || length.is_some_and(|x| x != expected) {
+            return Err(format!(
+                "{value} is not a valid {expected} byte instruction"
+            ));
+        }
+        return Ok(bits as u32);
+    };
+    let mut fields = vec![first.trim()];
+    fields.extend_from_slice(&operands[1..]);
+    let fields = fields.as_slice();
+    let expect = |count: usize| -> Result<(), String> {
+        if fields.len() == count {
+            Ok(())
+        } else {
+            Err(format!(".insn {format} requires {count} operands"))
+        }
+    };
+
+    let raw = match format {
+        "r" | "r4" if fields.len() == 7 => RawInstruction::R4 {
+            opcode: insn_opcode(fields[0], false)?,
+            funct3: insn_field(fields[1], 3)?,
+            fmt: insn_field(fields[2], 2)?,
+            rd: insn_register(fields[3])?,
+            rs1: insn_register(fields[4])?,
+            rs2: insn_register(fields[5])?,
+            rs3: insn_register(fields[6])?,
+        },
+        "r" => {
+            expect(6)?;
+            RawInstruction::R {
+                opcode: insn_opcode(fields[0], false)?,
+                funct3: insn_field(fields[1], 3)?,
+                funct7: insn_field(fields[2], 7)?,
+                rd: insn_register(fields[3])?,
+                rs1: insn_register(fields[4])?,
+                rs2: insn_register(fields[5])?,
+            }
+        }
+        "i" => {
+            let (rs1, imm) = match fields.len() {
+                4 => {
+                    let (imm, rs1) = insn_address(fields[3])?;
+                    (rs1, imm)
+                }
+                _ => {
+                    expect(5)?;
+                    (fields[3], parse_insn_int(fields[4])?)
+                }
+            };
+            RawInstruction::I {
+                opcode: insn_opcode(fields[0], false)?,
+                funct3: insn_field(fields[1], 3)?,
+                rd: insn_register(fields[2])?,
+                rs1: insn_register(rs1)?,
+                imm: IImmediate::try_from(imm)?,
+            }
+        }
+        "s" => {
+            expect(4)?;
+            let (imm, rs1) = insn_address(fields[3])?;
+            RawInstruction::S {
+                opcode: insn_opcode(fields[0], false)?,
+                funct3: insn_field(fields[1], 3)?,
+                rs2: insn_register(fields[2])?,
+                rs1: insn_register(rs1)?,
+                imm: SImmediate::try_from(imm)?,
+            }
+        }
+        "b" | "sb" => {
+            expect(5)?;
+            RawInstruction::B {
+                opcode: insn_opcode(fields[0], false)?,
+                funct3: insn_field(fields[1], 3)?,
+                rs1: insn_register(fields[2])?,
+                rs2: insn_register(fields[3])?,
+                imm: BImmediate::try_from(parse_insn_int(fields[4])?)?,
+            }
+        }
+        "u" => {
+            expect(3)?;
+            // the immediate may be written as an unsigned 20 bit value, like the upper bits of an address
+            let imm = match parse_insn_int(fields[2])? {
+                x @ 0x8_0000..=0xf_ffff => x - 0x10_0000,
+                x => x,
+            };
+            RawInstruction::U {
+                opcode: insn_opcode(fields[0], false)?,
+                rd: insn_register(fields[1])?,
+                imm: UImmediate::try_from(imm)?,
+            }
+        }
+        "j" | "uj" => {
+            expect(3)?;
+            RawInstruction::J {
+                opcode: insn_opcode(fields[0], false)?,
+                rd: insn_register(fields[1])?,
+                imm: JImmediate::try_from(parse_insn_int(fields[2])?)?,
+            }
+        }
+        _ => return insn_compressed_assemble(format, fields).map(|c| c.encode() as u32),
+    };
+    Ok(raw.encode())
+}
+
+/// Assembles the compressed formats of the `.insn` directive
+fn insn_compressed_assemble(format: &str, fields: &[&str]) -> Result<RawCInstruction, String> {
+    let expect = |count: usize| -> Result<(), String> {
+        if fields.len() == count {
+            Ok(())
+        } else {
+            Err(format!(".insn {format} requires {count} operands"))
+        }
+    };
+    match format {
+        "cr" => {
+            expect(4)?;
+            Ok(RawCInstruction::CR {
+                opcode: insn_opcode(fields[0], true)?,
+                funct4: insn_field(fields[1], 4)?,
+                rd: insn_register(fields[2])?,
+                rs2: insn_register(fields[3])?,
+            })
+        }
+        "ci" => {
+            expect(4)?;
+            Ok(RawCInstruction::CI {
+                opcode: insn_opcode(fields[0], true)?,
+                funct3: insn_field(fields[1], 3)?,
+                rd: insn_register(fields[2])?,
+                imm: CIImmediate::try_from(parse_insn_int(fields[3])?)?,
+            })
+        }
+        "ciw" => {
+            expect(4)?;
+            Ok(RawCInstruction::CIW {
+                opcode: insn_opcode(fields[0], true)?,
+                funct3: insn_field(fields[1], 3)?,
+                rd: insn_compressed_register(fields[2])?,
+                imm: insn_field(fields[3], 8)?,
+            })
+        }
+        "css" => {
+            expect(4)?;
+            Ok(RawCInstruction::CSS {
+                opcode: insn_opcode(fields[0], true)?,
+                funct3: insn_field(fields[1], 3)?,
+                rs2: insn_register(fields[2])?,
+                imm: insn_field(fields[3], 6)?,
+            })
+        }
+        "cl" | "cs" => {
+            expect(4)?;
+            let opcode = insn_opcode(fields[0], true)?;
+            let funct3 = insn_field(fields[1], 3)?;
+            let r2 = insn_compressed_register(fields[2])?;
+            let (imm, rs1) = insn_address(fields[3])?;
+            let rs1 = insn_compressed_register(rs1)?;
+            let imm = match imm {
+                x @ 0..=0b1_1111 => x as u8,
+                _ => return Err(format!("{} does not fit in a 5 bit field", fields[3])),
+            };
+            if format == "cl" {
+                Ok(RawCInstruction::CL {
+                    opcode,
+                    funct3,
+                    rd: r2,
+                    rs1,
+                    imm,
+                })
+            } else {
+                Ok(RawCInstruction::CS {
+                    opcode,
+                    funct3,
+                    rs1,
+                    rs2: r2,
+                    imm,
+                })
+            }
+        }
+        "ca" => {
+            expect(5)?;
+            Ok(RawCInstruction::CA {
+                opcode: insn_opcode(fields[0], true)?,
+                funct6: insn_field(fields[1], 6)?,
+                funct2: insn_field(fields[2], 2)?,
+                rd: insn_compressed_register(fields[3])?,
+                rs2: insn_compressed_register(fields[4])?,
+            })
+        }
+        "cb" => {
+            expect(4)?;
+            Ok(RawCInstruction::CB {
+                opcode: insn_opcode(fields[0], true)?,
+                funct3: insn_field(fields[1], 3)?,
+                rs1: insn_compressed_register(fields[2])?,
+                imm: CBImmediate::try_from(parse_insn_int(fields[3])?)?,
+            })
+        }
+        "cj" => {
+            expect(3)?;
+            Ok(RawCInstruction::CJ {
+                opcode: insn_opcode(fields[0], true)?,
+                funct3: insn_field(fields[1], 3)?,
+                imm: CJImmediate::try_from(parse_insn_int(fields[2])?)?,
+            })
+        }
+        _ => Err(format!("unknown .insn format: {format}")),
+    }
+}
+
+/// Assembles the Zcmp and Zcmt instructions, the stack adjustment of push and pop depends on XLEN.
+fn zcm_assemble(
+    mnemonic: &str,
+    operands: Vec<&str>,
+    isa: &IsaConfig,
+) -> Result<CInstruction, String> {
+    match mnemonic {
+        "push" | "pop" | "popretz" | "popret" => {
+            if operands.len() != 2 {
+                return Err(format!("cm.{mnemonic} requires 2 operands"));
+            }
+            let rlist = RegisterList::try_from(operands[0])?;
+            let total = parse_int(operands[1])?;
+            // push allocates the stack frame, so it is written with a negative adjustment
+            let total = if mnemonic == "push" { -total } else { total };
+            let stack_adj =
+                CStackAdjImmediate::try_from(total - rlist.base_stack_adjustment(isa.xlen) as i64)?;
+            Ok(match mnemonic {
+                "push" => CInstruction::CMPUSH { rlist, stack_adj },
+                "pop" => CInstruction::CMPOP { rlist, stack_adj },
+                "popretz" => CInstruction::CMPOPRETZ { rlist, stack_adj },
+                _ => CInstruction::CMPOPRET { rlist, stack_adj },
+            })
+        }
+        "mvsa01" => {
+            if operands.len() != 2 {
+                return Err("cm.mvsa01 requires 2 operands".to_owned());
+            }
+            let dest1 = CSRegister::try_from(operands[0])?;
+            let dest2 = CSRegister::try_from(operands[1])?;
+            if dest1 == dest2 {
+                Err("cm.mvsa01 requires different destination registers".to_owned())
+            } else {
+                Ok(CInstruction::CMMVSA01 { dest1, dest2 })
+            }
+        }
+        "mva01s" => {
+            if operands.len() != 2 {
+                Err("cm.mva01s requires 2 operands".to_owned())
+            } else {
+                Ok(CInstruction::CMMVA01S {
+                    src1: CSRegister::try_from(operands[0])?,
+                    src2: CSRegister::try_from(operands[1])?,
+                })
+            }
+        }
+        "jt" | "jalt" => {
+            if operands.len() != 1 {
+                return Err(format!("cm.{mnemonic} requires 1 operand"));
+            }
+            let index = parse_int(operands[0])?;
+            match (mnemonic, index) {
+                ("jt", 0..32) => Ok(CInstruction::CMJT { index: index as u8 }),
+                ("jalt", 32..256) => Ok(CInstruction::CMJALT { index: index as u8 }),
+                ("jt", _) => Err("cm.jt index must be between 0 and 31".to_owned()),
+                _ => Err("cm.jalt index must be between 32 and 255".to_owned()),
+            }
+        }
+        _ => Err(format!(
+            "unknown compressed instruction mnemonic: cm.{mnemonic}"
+        )),
     }
 }
 
 fn compressed_assemble(mnemonics: &[&str], operands: Vec<&str>) -> Result<CInstruction, String> {
     match mnemonics[0] {
+        "lbu" => {
+            if operands.len() != 2 {
+                Err("c.lbu requires 2 operands".to_owned())
+            } else {
+                let (base, imm) = parse_address_expression_compressed(operands[1])?;
+                Ok(CInstruction::LBU {
+                    dest: CIRegister::try_from(operands[0])?,
+                    base,
+                    offset: CByteImmediate::try_from(imm)?,
+                })
+            }
+        }
+        "lhu" => {
+            if operands.len() != 2 {
+                Err("c.lhu requires 2 operands".to_owned())
+            } else {
+                let (base, imm) = parse_address_expression_compressed(operands[1])?;
+                Ok(CInstruction::LHU {
+                    dest: CIRegister::try_from(operands[0])?,
+                    base,
+                    offset: CHalfImmediate::try_from(imm)?,
+                })
+            }
+        }
+        "lh" => {
+            if operands.len() != 2 {
+                Err("c.lh requires 2 operands".to_owned())
+            } else {
+                let (base, imm) = parse_address_expression_compressed(operands[1])?;
+                Ok(CInstruction::LH {
+                    dest: CIRegister::try_from(operands[0])?,
+                    base,
+                    offset: CHalfImmediate::try_from(imm)?,
+                })
+            }
+        }
+        "sb" => {
+            if operands.len() != 2 {
+                Err("c.sb requires 2 operands".to_owned())
+            } else {
+                let (base, imm) = parse_address_expression_compressed(operands[1])?;
+                Ok(CInstruction::SB {
+                    src: CIRegister::try_from(operands[0])?,
+                    base,
+                    offset: CByteImmediate::try_from(imm)?,
+                })
+            }
+        }
+        "sh" => {
+            if operands.len() != 2 {
+                Err("c.sh requires 2 operands".to_owned())
+            } else {
+                let (base, imm) = parse_address_expression_compressed(operands[1])?;
+                Ok(CInstruction::SH {
+                    src: CIRegister::try_from(operands[0])?,
+                    base,
+                    offset: CHalfImmediate::try_from(imm)?,
+                })
+            }
+        }
+        "zext" | "sext" | "not" => {
+            if operands.len() != 1 {
+                return Err(format!("c.{} requires 1 operand", mnemonics.join(".")));
+            }
+            let dest = CIRegister::try_from(operands[0])?;
+            match mnemonics {
+                ["zext", "b"] => Ok(CInstruction::ZEXTB { dest }),
+                ["sext", "b"] => Ok(CInstruction::SEXTB { dest }),
+                ["zext", "h"] => Ok(CInstruction::ZEXTH { dest }),
+                ["sext", "h"] => Ok(CInstruction::SEXTH { dest }),
+                ["zext", "w"] => Ok(CInstruction::ZEXTW { dest }),
+                ["not"] => Ok(CInstruction::NOT { dest }),
+                _ => Err(format!(
+                    "unknown compressed instruction mnemonic: c.{}",
+                    mnemonics.join(".")
+                )),
+            }
+        }
+        "mul" => {
+            if operands.len() != 2 {
+                Err("c.mul requires 2 operands".to_owned())
+            } else {
+                Ok(CInstruction::MUL {
+                    dest: CIRegister::try_from(operands[0])?,
+                    src: CIRegister::try_from(operands[1])?,
+                })
+            }
+        }
+        "sspush" => {
+            if operands.len() != 1 {
+                Err("c.sspush requires 1 operand".to_owned())
+            } else if IRegister::try_from(operands[0])? != IRegister::ReturnAddress {
+                Err("c.sspush can only push ra".to_owned())
+            } else {
+                Ok(CInstruction::SSPUSH)
+            }
+        }
+        "sspopchk" => {
+            if operands.len() != 1 {
+                Err("c.sspopchk requires 1 operand".to_owned())
+            } else if IRegister::try_from(operands[0])? != IRegister::T0 {
+                Err("c.sspopchk can only check t0".to_owned())
+            } else {
+                Ok(CInstruction::SSPOPCHK)
+            }
+        }
+        "mop" => {
+            if !operands.is_empty() {
+                Err("c.mop requires 0 operands".to_owned())
+            } else {
+                match mnemonics.get(1).map(|n| n.parse::<u8>()) {
+                    Some(Ok(n)) if n % 2 == 1 && n < 16 => Ok(CInstruction::MOP { n }),
+                    _ => Err("c.mop must be c.mop.n for an odd n between 1 and 15".to_owned()),
+                }
+            }
+        }
         "addi4spn" => {
             if operands.len() != 2 {
                 Err("c.addi4spn requires 2 operands".to_owned())
@@ -944,7 +2204,7 @@ fn compressed_assemble(mnemonics: &[&str], operands: Vec<&str>) -> Result<CInstr
             }
         }
         "ebreak" => {
-            if operands.len() != 0 {
+            if !operands.is_empty() {
                 Err("c.jr requires 0 operands".to_owned())
             } else {
                 Ok(CInstruction::EBREAK)
@@ -960,6 +2220,16 @@ fn compressed_assemble(mnemonics: &[&str], operands: Vec<&str>) -> Result<CInstr
                 })
             }
         }
+        "ntl" => {
+            if !operands.is_empty() {
+                Err("c.ntl hints take no operands".to_owned())
+            } else {
+                Ok(CInstruction::ADD {
+                    dest: IRegister::Zero,
+                    src: parse_ntl_hint(&mnemonics[1..])?,
+                })
+            }
+        }
         "fsdsp" => {
             if operands.len() != 2 {
                 Err("c.fsdsp requires 2 operands".to_owned())
@@ -2,15 +2,17 @@ use alloc::{borrow::ToOwned, fmt::{Display, Formatter}, format, string::String};
 
 use crate::{
     immediates::{
-        BImmediate, C16SPImmediate, CBImmediate, CDImmediate, CDSPImmediate, CIImmediate,
-        CJImmediate, CSDSPImmediate, CSWSPImmediate, CShamt, CWImmediate, CWSPImmediate,
-        CWideImmediate, IImmediate, JImmediate, SImmediate, Shamt,
+        BImmediate, C16SPImmediate, CBImmediate, CByteImmediate, CDImmediate, CDSPImmediate,
+        CHalfImmediate, CIImmediate, CJImmediate, CSDSPImmediate, CSWSPImmediate, CShamt,
+        CStackAdjImmediate, CWImmediate, CWSPImmediate, CWideImmediate, IImmediate, JImmediate,
+        SImmediate, Shamt, UImmediate,
     },
-    instruction::Instruction,
-    register::{CFRegister, CIRegister, FRegister, IRegister},
+    instruction::{Instruction, ntl_hint},
+    isa::{IsaConfig, Xlen},
+    register::{CFRegister, CIRegister, CSRegister, FRegister, IRegister, RegisterList},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum CInstruction {
     //
     // Instructions in C extension
@@ -158,6 +160,112 @@ pub enum CInstruction {
         src: IRegister,
         offset: CSDSPImmediate,
     },
+    //
+    // Instructions in Zcb extension
+    //
+    LBU {
+        dest: CIRegister,
+        base: CIRegister,
+        offset: CByteImmediate,
+    },
+    LHU {
+        dest: CIRegister,
+        base: CIRegister,
+        offset: CHalfImmediate,
+    },
+    LH {
+        dest: CIRegister,
+        base: CIRegister,
+        offset: CHalfImmediate,
+    },
+    SB {
+        src: CIRegister,
+        base: CIRegister,
+        offset: CByteImmediate,
+    },
+    SH {
+        src: CIRegister,
+        base: CIRegister,
+        offset: CHalfImmediate,
+    },
+    ZEXTB {
+        dest: CIRegister,
+    },
+    SEXTB {
+        dest: CIRegister,
+    },
+    ZEXTH {
+        dest: CIRegister,
+    },
+    SEXTH {
+        dest: CIRegister,
+    },
+    ZEXTW {
+        dest: CIRegister,
+    },
+    NOT {
+        dest: CIRegister,
+    },
+    MUL {
+        dest: CIRegister,
+        src: CIRegister,
+    },
+    //
+    // Instructions in Zcmp extension
+    //
+    /// Saves the registers in `rlist` and allocates a stack frame, `stack_adj` is the adjustment beyond the space
+    /// needed for the registers
+    CMPUSH {
+        rlist: RegisterList,
+        stack_adj: CStackAdjImmediate,
+    },
+    CMPOP {
+        rlist: RegisterList,
+        stack_adj: CStackAdjImmediate,
+    },
+    CMPOPRETZ {
+        rlist: RegisterList,
+        stack_adj: CStackAdjImmediate,
+    },
+    CMPOPRET {
+        rlist: RegisterList,
+        stack_adj: CStackAdjImmediate,
+    },
+    /// Moves a0 and a1 into two different saved registers
+    CMMVSA01 {
+        dest1: CSRegister,
+        dest2: CSRegister,
+    },
+    /// Moves two saved registers into a0 and a1
+    CMMVA01S {
+        src1: CSRegister,
+        src2: CSRegister,
+    },
+    //
+    // Instructions in Zcmt extension
+    //
+    /// Jumps through entry `index` (0-31) of the jump vector table
+    CMJT {
+        index: u8,
+    },
+    /// Jumps and links through entry `index` (32-255) of the jump vector table
+    CMJALT {
+        index: u8,
+    },
+    //
+    // Instructions in Zcmop extension
+    //
+    /// The compressed may-be-operation `c.mop.n`, where `n` is odd and between 1 and 15
+    MOP {
+        n: u8,
+    },
+    //
+    // Instructions in Zicfiss extension
+    //
+    /// Pushes ra to the shadow stack, encoded as `c.mop.1`
+    SSPUSH,
+    /// Pops from the shadow stack and checks that the value matches t0, encoded as `c.mop.5`
+    SSPOPCHK,
 }
 
 impl Display for CInstruction {
@@ -207,18 +315,131 @@ impl Display for CInstruction {
             CInstruction::MV { dest, src } => write!(f, "c.mv {dest},{src}"),
             CInstruction::EBREAK => write!(f, "c.ebreak"),
             CInstruction::JALR { src } => write!(f, "c.jalr {src}"),
+            CInstruction::ADD {
+                dest: IRegister::Zero,
+                src,
+            } if ntl_hint(src).is_some() => write!(f, "c.ntl.{}", ntl_hint(src).unwrap()),
             CInstruction::ADD { dest: rd, src: rs2 } => write!(f, "c.add {rd},{rs2}"),
             CInstruction::FSDSP { src, offset } => write!(f, "c.fsdsp {src},{offset}"),
             CInstruction::SWSP { src, offset } => write!(f, "c.swsp {src},{offset}"),
             CInstruction::SDSP { src, offset } => write!(f, "c.sdsp {src},{offset}"),
+            CInstruction::LBU { dest, base, offset } => write!(f, "c.lbu {dest},{offset}({base})"),
+            CInstruction::LHU { dest, base, offset } => write!(f, "c.lhu {dest},{offset}({base})"),
+            CInstruction::LH { dest, base, offset } => write!(f, "c.lh {dest},{offset}({base})"),
+            CInstruction::SB { src, base, offset } => write!(f, "c.sb {src},{offset}({base})"),
+            CInstruction::SH { src, base, offset } => write!(f, "c.sh {src},{offset}({base})"),
+            CInstruction::ZEXTB { dest } => write!(f, "c.zext.b {dest}"),
+            CInstruction::SEXTB { dest } => write!(f, "c.sext.b {dest}"),
+            CInstruction::ZEXTH { dest } => write!(f, "c.zext.h {dest}"),
+            CInstruction::SEXTH { dest } => write!(f, "c.sext.h {dest}"),
+            CInstruction::ZEXTW { dest } => write!(f, "c.zext.w {dest}"),
+            CInstruction::NOT { dest } => write!(f, "c.not {dest}"),
+            CInstruction::MUL { dest, src } => write!(f, "c.mul {dest},{src}"),
+            CInstruction::CMPUSH { .. }
+            | CInstruction::CMPOP { .. }
+            | CInstruction::CMPOPRETZ { .. }
+            | CInstruction::CMPOPRET { .. } => write!(f, "{}", self.fmt_stack(Xlen::Rv64)),
+            CInstruction::CMMVSA01 { dest1, dest2 } => write!(f, "cm.mvsa01 {dest1},{dest2}"),
+            CInstruction::CMMVA01S { src1, src2 } => write!(f, "cm.mva01s {src1},{src2}"),
+            CInstruction::CMJT { index } => write!(f, "cm.jt {index}"),
+            CInstruction::CMJALT { index } => write!(f, "cm.jalt {index}"),
+            CInstruction::MOP { n } => write!(f, "c.mop.{n}"),
+            CInstruction::SSPUSH => write!(f, "c.sspush ra"),
+            CInstruction::SSPOPCHK => write!(f, "c.sspopchk t0"),
         }
     }
 }
 
 impl CInstruction {
+    /// Formats a Zcmp push or pop, the total stack adjustment shown depends on the size of the saved registers.
+    fn fmt_stack(&self, xlen: Xlen) -> String {
+        let (name, rlist, stack_adj) = match self {
+            CInstruction::CMPUSH { rlist, stack_adj } => ("push", rlist, stack_adj),
+            CInstruction::CMPOP { rlist, stack_adj } => ("pop", rlist, stack_adj),
+            CInstruction::CMPOPRETZ { rlist, stack_adj } => ("popretz", rlist, stack_adj),
+            CInstruction::CMPOPRET { rlist, stack_adj } => ("popret", rlist, stack_adj),
+            _ => unreachable!(),
+        };
+        let total = rlist.base_stack_adjustment(xlen) as i64 + stack_adj.val();
+        // push allocates the stack frame, so the adjustment is negative
+        let sign = if name == "push" { "-" } else { "" };
+        format!("cm.{name} {rlist},{sign}{total}")
+    }
+
+    /// Disassembles a `CInstruction` for the given ISA configuration.
+    ///
+    /// The stack adjustment shown by the Zcmp push and pop instructions depends on XLEN, `disassemble` uses RV64.
+    pub fn disassemble_with(instruction: &CInstruction, isa: &IsaConfig) -> String {
+        match instruction {
+            CInstruction::CMPUSH { .. }
+            | CInstruction::CMPOP { .. }
+            | CInstruction::CMPOPRETZ { .. }
+            | CInstruction::CMPOPRET { .. } => instruction.fmt_stack(isa.xlen),
+            _ => format!("{}", instruction),
+        }
+    }
+
+    /// Decodes a u16 into a `CInstruction` for the given ISA configuration.
+    pub fn decode_with(instruction: u16, isa: &IsaConfig) -> Result<Self, String> {
+        let i = CInstruction::decode_fields(instruction, isa)?;
+        i.check_isa(isa)?;
+        Ok(i)
+    }
+
+    /// Checks that the instruction can be used with the given ISA configuration.
+    ///
+    /// Compressed float loads and stores are not available when float instructions use the integer registers, or when
+    /// their encodings are used by Zcmp or Zcmt. The Zcmp and Zcmt instructions must be enabled in the configuration.
+    pub fn check_isa(&self, isa: &IsaConfig) -> Result<(), String> {
+        match self {
+            CInstruction::FLD { .. }
+            | CInstruction::FSD { .. }
+            | CInstruction::FLDSP { .. }
+            | CInstruction::FSDSP { .. }
+                if isa.floats_in_integer_registers() =>
+            {
+                Err(format!(
+                    "{self} requires the float registers, which are not present with Zfinx"
+                ))
+            }
+            CInstruction::FLD { .. }
+            | CInstruction::FSD { .. }
+            | CInstruction::FLDSP { .. }
+            | CInstruction::FSDSP { .. }
+                if isa.zcmp || isa.zcmt =>
+            {
+                Err(format!("{self} is not available with Zcmp or Zcmt"))
+            }
+            CInstruction::CMPUSH { .. }
+            | CInstruction::CMPOP { .. }
+            | CInstruction::CMPOPRETZ { .. }
+            | CInstruction::CMPOPRET { .. }
+            | CInstruction::CMMVSA01 { .. }
+            | CInstruction::CMMVA01S { .. }
+                if !isa.zcmp =>
+            {
+                Err(format!("{self} requires Zcmp"))
+            }
+            CInstruction::CMJT { .. } | CInstruction::CMJALT { .. } if !isa.zcmt => {
+                Err(format!("{self} requires Zcmt"))
+            }
+            CInstruction::SSPUSH | CInstruction::SSPOPCHK if !isa.zicfiss => {
+                Err(format!("{self} requires Zicfiss"))
+            }
+            CInstruction::ZEXTW { .. } if isa.xlen == Xlen::Rv32 => {
+                Err(format!("{self} is only available on RV64"))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Decodes a u16 into a `CInstruction`.
     pub fn decode(instruction: u16) -> Result<Self, String> {
-        let crs2 = CIRegister::try_from((instruction >> 2) & 0b111).unwrap();
+        CInstruction::decode_with(instruction, &IsaConfig::default())
+    }
+
+    fn decode_fields(instruction: u16, isa: &IsaConfig) -> Result<Self, String> {
+        let crs2 = CIRegister::from((instruction >> 2) & 0b111);
         let cfrd = CFRegister::try_from((instruction >> 2) & 0b111).unwrap();
 
         let crs1 = CIRegister::from((instruction >> 7) & 0b111);
@@ -257,7 +478,35 @@ impl CInstruction {
                     base: crs1,
                     offset: CDImmediate::from_u16(instruction),
                 }),
-                0b100 => Err("reserved opcode in C instruction".to_owned()),
+                // Zcb loads and stores
+                0b100 => match ((instruction >> 10) & 0b111, (instruction >> 6) & 0b1) {
+                    (0b000, _) => Ok(CInstruction::LBU {
+                        dest: crs2,
+                        base: crs1,
+                        offset: CByteImmediate::from_u16(instruction),
+                    }),
+                    (0b001, 0) => Ok(CInstruction::LHU {
+                        dest: crs2,
+                        base: crs1,
+                        offset: CHalfImmediate::from_u16(instruction),
+                    }),
+                    (0b001, 1) => Ok(CInstruction::LH {
+                        dest: crs2,
+                        base: crs1,
+                        offset: CHalfImmediate::from_u16(instruction),
+                    }),
+                    (0b010, _) => Ok(CInstruction::SB {
+                        src: crs2,
+                        base: crs1,
+                        offset: CByteImmediate::from_u16(instruction),
+                    }),
+                    (0b011, 0) => Ok(CInstruction::SH {
+                        src: crs2,
+                        base: crs1,
+                        offset: CHalfImmediate::from_u16(instruction),
+                    }),
+                    _ => Err("reserved opcode in C instruction".to_owned()),
+                },
                 0b101 => Ok(CInstruction::FSD {
                     src: cfrd,
                     base: crs1,
@@ -289,7 +538,18 @@ impl CInstruction {
                     imm: ciimmediate,
                 }),
                 0b011 => {
-                    if (instruction >> 7) & 0b111 == 2 {
+                    let rd_field = (instruction >> 7) & 0b1_1111;
+                    if (instruction >> 2) & 0b100_0001_1111 == 0
+                        && rd_field % 2 == 1
+                        && rd_field < 16
+                    {
+                        // c.lui with a zero immediate is reserved, the odd registers below x16 are c.mop.n
+                        match rd_field {
+                            1 if isa.zicfiss => Ok(CInstruction::SSPUSH),
+                            5 if isa.zicfiss => Ok(CInstruction::SSPOPCHK),
+                            n => Ok(CInstruction::MOP { n: n as u8 }),
+                        }
+                    } else if (instruction >> 7) & 0b1_1111 == 2 {
                         Ok(CInstruction::ADDI16SP {
                             imm: C16SPImmediate::from_u16(instruction),
                         })
@@ -338,6 +598,19 @@ impl CInstruction {
                             dest: crs1,
                             src: crs2,
                         }),
+                        (0b10, 0b1) => Ok(CInstruction::MUL {
+                            dest: crs1,
+                            src: crs2,
+                        }),
+                        (0b11, 0b1) => match (instruction >> 2) & 0b111 {
+                            0b000 => Ok(CInstruction::ZEXTB { dest: crs1 }),
+                            0b001 => Ok(CInstruction::SEXTB { dest: crs1 }),
+                            0b010 => Ok(CInstruction::ZEXTH { dest: crs1 }),
+                            0b011 => Ok(CInstruction::SEXTH { dest: crs1 }),
+                            0b100 => Ok(CInstruction::ZEXTW { dest: crs1 }),
+                            0b101 => Ok(CInstruction::NOT { dest: crs1 }),
+                            _ => Err("Reserved instruction".to_owned()),
+                        },
                         _ => Err("Reserved instruction".to_owned()),
                     },
                     _ => unreachable!(),
@@ -386,6 +659,7 @@ impl CInstruction {
                         _ => unreachable!(),
                     }
                 }
+                0b101 if isa.zcmp || isa.zcmt => CInstruction::decode_zcm(instruction),
                 0b101 => Ok(CInstruction::FSDSP {
                     src: frs2,
                     offset: CSDSPImmediate::from_u16(instruction),
@@ -407,6 +681,57 @@ impl CInstruction {
         }
     }
 
+    /// Decodes the Zcmp and Zcmt instructions, which share the `c.fsdsp` encoding space.
+    fn decode_zcm(instruction: u16) -> Result<Self, String> {
+        let r1s = CSRegister::from((instruction >> 7) & 0b111);
+        let r2s = CSRegister::from((instruction >> 2) & 0b111);
+        let stack_adj = CStackAdjImmediate::from_u16(instruction);
+        match ((instruction >> 10) & 0b111, (instruction >> 8) & 0b11) {
+            (0b000, _) => {
+                let index = ((instruction >> 2) & 0xFF) as u8;
+                if index < 32 {
+                    Ok(CInstruction::CMJT { index })
+                } else {
+                    Ok(CInstruction::CMJALT { index })
+                }
+            }
+            (0b011, _) => match (instruction >> 5) & 0b11 {
+                0b01 => {
+                    if r1s == r2s {
+                        Err("cm.mvsa01 requires different destination registers".to_owned())
+                    } else {
+                        Ok(CInstruction::CMMVSA01 {
+                            dest1: r1s,
+                            dest2: r2s,
+                        })
+                    }
+                }
+                0b11 => Ok(CInstruction::CMMVA01S {
+                    src1: r1s,
+                    src2: r2s,
+                }),
+                _ => Err("reserved Zcmp instruction".to_owned()),
+            },
+            (0b110, 0b00) => Ok(CInstruction::CMPUSH {
+                rlist: RegisterList::from_u16(instruction)?,
+                stack_adj,
+            }),
+            (0b110, 0b10) => Ok(CInstruction::CMPOP {
+                rlist: RegisterList::from_u16(instruction)?,
+                stack_adj,
+            }),
+            (0b111, 0b00) => Ok(CInstruction::CMPOPRETZ {
+                rlist: RegisterList::from_u16(instruction)?,
+                stack_adj,
+            }),
+            (0b111, 0b10) => Ok(CInstruction::CMPOPRET {
+                rlist: RegisterList::from_u16(instruction)?,
+                stack_adj,
+            }),
+            _ => Err("reserved Zcmp instruction".to_owned()),
+        }
+    }
+
     pub fn disassemble(instruction: &CInstruction) -> String {
         format!("{}", instruction)
     }
@@ -417,14 +742,22 @@ impl CInstruction {
     /// > Strictly speaking, C.JALR does not expand exactly to a base RVI instruction as the value added to the PC to
     /// > form the link address is 2 rather than 4 as in the base ISA, but supporting both offsets of 2 and 4 bytes
     /// > is only a very minor change to the base microarchitecture.
-    pub fn expand(&self) -> Instruction {
-        match self {
+    ///
+    /// Returns `None` for instructions without a single 32-bit equivalent in this crate: the Zcmp, Zcmt and Zcmop
+    /// instructions, and c.sext.b, c.zext.h, c.sext.h and c.zext.w, which expand to the unsupported Zbb and Zba
+    /// extensions.
+    pub fn expand(&self) -> Option<Instruction> {
+        Some(match self {
             CInstruction::ADDI4SPN { dest, imm } => Instruction::Addi {
                 dest: dest.expand(),
                 src: IRegister::StackPointer,
                 imm: IImmediate::try_from(imm.val()).unwrap(),
             },
-            CInstruction::FLD { .. } => todo!(), // needs unimplemented double extension
+            CInstruction::FLD { dest, base, offset } => Instruction::Fld {
+                dest: dest.expand(),
+                base: base.expand(),
+                offset: IImmediate::try_from(offset.val()).unwrap(),
+            },
             CInstruction::LW { dest, base, offset } => Instruction::Lw {
                 dest: dest.expand(),
                 base: base.expand(),
@@ -435,7 +768,11 @@ impl CInstruction {
                 base: base.expand(),
                 offset: IImmediate::try_from(offset.val()).unwrap(),
             },
-            CInstruction::FSD { .. } => todo!(), // needs unimplemented double extension
+            CInstruction::FSD { src, base, offset } => Instruction::Fsd {
+                src: src.expand(),
+                base: base.expand(),
+                offset: SImmediate::try_from(offset.val()).unwrap(),
+            },
             CInstruction::SW { src, base, offset } => Instruction::Sw {
                 src: src.expand(),
                 base: base.expand(),
@@ -466,10 +803,9 @@ impl CInstruction {
                 src: IRegister::StackPointer,
                 imm: IImmediate::try_from(imm.val()).unwrap(),
             },
-            CInstruction::LUI { dest, imm } => Instruction::Addi {
+            CInstruction::LUI { dest, imm } => Instruction::Lui {
                 dest: *dest,
-                src: IRegister::Zero,
-                imm: IImmediate::try_from(imm.val()).unwrap(),
+                imm: UImmediate::try_from(imm.val()).unwrap(),
             },
             CInstruction::SRLI { dest, shamt } => Instruction::Srli {
                 dest: dest.expand(),
@@ -535,7 +871,11 @@ impl CInstruction {
                 src: *dest,
                 shamt: Shamt::try_from(shamt.val()).unwrap(),
             },
-            CInstruction::FLDSP { .. } => todo!(), // needs unimplemented double extension
+            CInstruction::FLDSP { dest, offset } => Instruction::Fld {
+                dest: *dest,
+                base: IRegister::StackPointer,
+                offset: IImmediate::try_from(offset.val()).unwrap(),
+            },
             CInstruction::LWSP { dest, offset } => Instruction::Lw {
                 dest: *dest,
                 base: IRegister::StackPointer,
@@ -568,7 +908,11 @@ impl CInstruction {
                 src1: *dest,
                 src2: *src,
             },
-            CInstruction::FSDSP { .. } => todo!(), // needs unimplemented double extension
+            CInstruction::FSDSP { src, offset } => Instruction::Fsd {
+                src: *src,
+                base: IRegister::StackPointer,
+                offset: SImmediate::try_from(offset.val()).unwrap(),
+            },
             CInstruction::SWSP { src, offset } => Instruction::Sw {
                 src: *src,
                 base: IRegister::StackPointer,
@@ -579,10 +923,69 @@ impl CInstruction {
                 base: IRegister::StackPointer,
                 offset: SImmediate::try_from(offset.val()).unwrap(),
             },
-        }
+            CInstruction::LBU { dest, base, offset } => Instruction::Lbu {
+                dest: dest.expand(),
+                base: base.expand(),
+                offset: IImmediate::try_from(offset.val()).unwrap(),
+            },
+            CInstruction::LHU { dest, base, offset } => Instruction::Lhu {
+                dest: dest.expand(),
+                base: base.expand(),
+                offset: IImmediate::try_from(offset.val()).unwrap(),
+            },
+            CInstruction::LH { dest, base, offset } => Instruction::Lh {
+                dest: dest.expand(),
+                base: base.expand(),
+                offset: IImmediate::try_from(offset.val()).unwrap(),
+            },
+            CInstruction::SB { src, base, offset } => Instruction::Sb {
+                src: src.expand(),
+                base: base.expand(),
+                offset: SImmediate::try_from(offset.val()).unwrap(),
+            },
+            CInstruction::SH { src, base, offset } => Instruction::Sh {
+                src: src.expand(),
+                base: base.expand(),
+                offset: SImmediate::try_from(offset.val()).unwrap(),
+            },
+            CInstruction::ZEXTB { dest } => Instruction::Andi {
+                dest: dest.expand(),
+                src: dest.expand(),
+                imm: IImmediate::try_from(0xFF).unwrap(),
+            },
+            CInstruction::SEXTB { .. }
+            | CInstruction::ZEXTH { .. }
+            | CInstruction::SEXTH { .. }
+            | CInstruction::ZEXTW { .. } => return None,
+            CInstruction::NOT { dest } => Instruction::Xori {
+                dest: dest.expand(),
+                src: dest.expand(),
+                imm: IImmediate::try_from(-1).unwrap(),
+            },
+            CInstruction::MUL { dest, src } => Instruction::Mul {
+                dest: dest.expand(),
+                src1: dest.expand(),
+                src2: src.expand(),
+            },
+            CInstruction::CMPUSH { .. }
+            | CInstruction::CMPOP { .. }
+            | CInstruction::CMPOPRETZ { .. }
+            | CInstruction::CMPOPRET { .. }
+            | CInstruction::CMMVSA01 { .. }
+            | CInstruction::CMMVA01S { .. }
+            | CInstruction::CMJT { .. }
+            | CInstruction::CMJALT { .. }
+            | CInstruction::MOP { .. } => return None,
+            CInstruction::SSPUSH => Instruction::Sspush {
+                src: IRegister::ReturnAddress,
+            },
+            CInstruction::SSPOPCHK => Instruction::Sspopchk { src: IRegister::T0 },
+        })
     }
 
     /// Encodes a `CInstruction` into a `u16`.
+    // zero fields are kept in the encodings to match the ISA manual tables
+    #[allow(clippy::identity_op)]
     pub fn encode(instruction: &CInstruction) -> u16 {
         match instruction {
             CInstruction::ADDI4SPN { dest, imm } => 0b000 << 13 | imm.to_u16() | dest.rs2(),
@@ -673,6 +1076,66 @@ impl CInstruction {
             CInstruction::SDSP { src, offset } => {
                 0b111 << 13 | offset.to_u16() | (src.rd() >> 5) as u16 | 0b10
             }
+            CInstruction::LBU { dest, base, offset } => {
+                0b100 << 13 | 0b000 << 10 | base.rs1() | offset.to_u16() | dest.rs2()
+            }
+            CInstruction::LHU { dest, base, offset } => {
+                0b100 << 13 | 0b001 << 10 | base.rs1() | 0 << 6 | offset.to_u16() | dest.rs2()
+            }
+            CInstruction::LH { dest, base, offset } => {
+                0b100 << 13 | 0b001 << 10 | base.rs1() | 1 << 6 | offset.to_u16() | dest.rs2()
+            }
+            CInstruction::SB { src, base, offset } => {
+                0b100 << 13 | 0b010 << 10 | base.rs1() | offset.to_u16() | src.rs2()
+            }
+            CInstruction::SH { src, base, offset } => {
+                0b100 << 13 | 0b011 << 10 | base.rs1() | 0 << 6 | offset.to_u16() | src.rs2()
+            }
+            CInstruction::ZEXTB { dest } => {
+                0b100111 << 10 | dest.rs1() | 0b11 << 5 | 0b000 << 2 | 0b01
+            }
+            CInstruction::SEXTB { dest } => {
+                0b100111 << 10 | dest.rs1() | 0b11 << 5 | 0b001 << 2 | 0b01
+            }
+            CInstruction::ZEXTH { dest } => {
+                0b100111 << 10 | dest.rs1() | 0b11 << 5 | 0b010 << 2 | 0b01
+            }
+            CInstruction::SEXTH { dest } => {
+                0b100111 << 10 | dest.rs1() | 0b11 << 5 | 0b011 << 2 | 0b01
+            }
+            CInstruction::ZEXTW { dest } => {
+                0b100111 << 10 | dest.rs1() | 0b11 << 5 | 0b100 << 2 | 0b01
+            }
+            CInstruction::NOT { dest } => {
+                0b100111 << 10 | dest.rs1() | 0b11 << 5 | 0b101 << 2 | 0b01
+            }
+            CInstruction::MUL { dest, src } => {
+                0b100111 << 10 | dest.rs1() | 0b10 << 5 | src.rs2() | 0b01
+            }
+            CInstruction::CMPUSH { rlist, stack_adj } => {
+                0b101 << 13 | 0b11000 << 8 | rlist.to_u16() | stack_adj.to_u16() | 0b10
+            }
+            CInstruction::CMPOP { rlist, stack_adj } => {
+                0b101 << 13 | 0b11010 << 8 | rlist.to_u16() | stack_adj.to_u16() | 0b10
+            }
+            CInstruction::CMPOPRETZ { rlist, stack_adj } => {
+                0b101 << 13 | 0b11100 << 8 | rlist.to_u16() | stack_adj.to_u16() | 0b10
+            }
+            CInstruction::CMPOPRET { rlist, stack_adj } => {
+                0b101 << 13 | 0b11110 << 8 | rlist.to_u16() | stack_adj.to_u16() | 0b10
+            }
+            CInstruction::CMMVSA01 { dest1, dest2 } => {
+                0b101011 << 10 | dest1.rs1() | 0b01 << 5 | dest2.rs2() | 0b10
+            }
+            CInstruction::CMMVA01S { src1, src2 } => {
+                0b101011 << 10 | src1.rs1() | 0b11 << 5 | src2.rs2() | 0b10
+            }
+            CInstruction::CMJT { index } | CInstruction::CMJALT { index } => {
+                0b101000 << 10 | (*index as u16) << 2 | 0b10
+            }
+            CInstruction::MOP { n } => 0b011 << 13 | (*n as u16) << 7 | 0b01,
+            CInstruction::SSPUSH => 0b011 << 13 | 1 << 7 | 0b01,
+            CInstruction::SSPOPCHK => 0b011 << 13 | 5 << 7 | 0b01,
         }
     }
 }
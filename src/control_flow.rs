@@ -0,0 +1,105 @@
+use crate::cinstruction::CInstruction;
+use crate::instruction::{Instruction, is_link_register};
+
+/// How an instruction changes the program counter.
+///
+/// Jumps are classified with the return address stack hints from the ISA manual, where `ra` and `t0` are link
+/// registers. A jump that writes a link register is a call, and a jump through a link register that doesn't write
+/// one is a return. A `jalr` that writes one link register and reads the other pops and then pushes the stack, which
+/// is classified as a call since the new return address is pushed.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ControlFlow {
+    /// Continues with the next instruction
+    Sequential,
+    /// A conditional branch to a PC-relative target
+    Branch,
+    /// An unconditional jump to a PC-relative target that isn't a call
+    Jump,
+    /// A jump to an address held in a register or memory that isn't a call or return
+    IndirectJump,
+    /// A direct or indirect jump that saves the return address in a link register
+    Call,
+    /// An indirect jump through a link register
+    Return,
+    /// `ecall` and `ebreak`, which transfer control to the execution environment
+    Trap,
+    /// A return from a trap handler, such as `mret` or `sret`
+    TrapReturn,
+}
+
+impl Instruction {
+    /// Classifies how the instruction changes the program counter.
+    pub fn control_flow(&self) -> ControlFlow {
+        match self {
+            Instruction::Jal { dest, .. } if is_link_register(dest) => ControlFlow::Call,
+            Instruction::Jal { .. } => ControlFlow::Jump,
+            Instruction::Jalr { dest, .. } if is_link_register(dest) => ControlFlow::Call,
+            Instruction::Jalr { base, .. } if is_link_register(base) => ControlFlow::Return,
+            Instruction::Jalr { .. } => ControlFlow::IndirectJump,
+            Instruction::Beq { .. }
+            | Instruction::Bne { .. }
+            | Instruction::Blt { .. }
+            | Instruction::Bge { .. }
+            | Instruction::Bltu { .. }
+            | Instruction::Bgeu { .. } => ControlFlow::Branch,
+            Instruction::Ecall | Instruction::Ebreak => ControlFlow::Trap,
+            Instruction::Sret | Instruction::Mret | Instruction::Mnret | Instruction::Dret => {
+                ControlFlow::TrapReturn
+            }
+            _ => ControlFlow::Sequential,
+        }
+    }
+
+    /// Returns the address that the instruction jumps or branches to when it's at `pc`, if the target doesn't depend
+    /// on a register.
+    ///
+    /// The address wraps at 64 bits, so it should be truncated to 32 bits on RV32.
+    pub fn target(&self, pc: u64) -> Option<u64> {
+        let offset = match self {
+            Instruction::Jal { offset, .. } => offset.val(),
+            Instruction::Beq { offset, .. }
+            | Instruction::Bne { offset, .. }
+            | Instruction::Blt { offset, .. }
+            | Instruction::Bge { offset, .. }
+            | Instruction::Bltu { offset, .. }
+            | Instruction::Bgeu { offset, .. } => offset.val(),
+            _ => return None,
+        };
+        Some(pc.wrapping_add_signed(offset))
+    }
+}
+
+impl CInstruction {
+    /// Classifies how the instruction changes the program counter.
+    ///
+    /// The Zcmt table jumps are indirect since their targets are loaded from the jump vector table, and the Zcmp
+    /// `cm.popret` and `cm.popretz` instructions are returns.
+    pub fn control_flow(&self) -> ControlFlow {
+        match self {
+            CInstruction::J { .. } => ControlFlow::Jump,
+            CInstruction::JR { src } if is_link_register(src) => ControlFlow::Return,
+            CInstruction::JR { .. } => ControlFlow::IndirectJump,
+            // c.jalr always writes ra
+            CInstruction::JALR { .. } => ControlFlow::Call,
+            CInstruction::BEQZ { .. } | CInstruction::BNEZ { .. } => ControlFlow::Branch,
+            CInstruction::EBREAK => ControlFlow::Trap,
+            CInstruction::CMPOPRET { .. } | CInstruction::CMPOPRETZ { .. } => ControlFlow::Return,
+            CInstruction::CMJT { .. } => ControlFlow::IndirectJump,
+            CInstruction::CMJALT { .. } => ControlFlow::Call,
+            _ => ControlFlow::Sequential,
+        }
+    }
+
+    /// Returns the address that the instruction jumps or branches to when it's at `pc`, if the target doesn't depend
+    /// on a register or memory.
+    ///
+    /// The address wraps at 64 bits, so it should be truncated to 32 bits on RV32.
+    pub fn target(&self, pc: u64) -> Option<u64> {
+        let offset = match self {
+            CInstruction::J { offset } => offset.val(),
+            CInstruction::BEQZ { offset, .. } | CInstruction::BNEZ { offset, .. } => offset.val(),
+            _ => return None,
+        };
+        Some(pc.wrapping_add_signed(offset))
+    }
+}
@@ -0,0 +1,367 @@
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::cinstruction::CInstruction;
+use crate::immediates::{
+    BImmediate, C16SPImmediate, CBImmediate, CByteImmediate, CDImmediate, CDSPImmediate,
+    CHalfImmediate, CIImmediate, CJImmediate, CSDSPImmediate, CSWSPImmediate, CShamt, CWImmediate,
+    CWSPImmediate, CWideImmediate, IImmediate, JImmediate, SImmediate, UImmediate,
+};
+use crate::instruction::{Instruction, disassemble_instruction};
+use crate::raw::{Format, RawCInstruction, RawInstruction};
+
+/// A range of bits in an instruction encoding.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BitField {
+    /// The highest bit of the range
+    pub high: u8,
+    /// The lowest bit of the range
+    pub low: u8,
+    /// The name of the field as written in the ISA manual, such as `rs2` or `imm[10:5]`
+    pub name: String,
+    /// The value of the bits in the range
+    pub value: u32,
+    /// The highest and lowest bits of the immediate that this field holds, if it's part of one
+    pub immediate: Option<(u8, u8)>,
+}
+
+impl BitField {
+    pub fn width(&self) -> u8 {
+        self.high - self.low + 1
+    }
+}
+
+/// A breakdown of an instruction encoding into its fields.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Explanation {
+    pub bits: u32,
+    pub format: Format,
+    /// The fields of the encoding, from the highest bits to the lowest
+    pub fields: Vec<BitField>,
+    /// The value of the immediate after reassembling its parts, if the format has one
+    pub immediate: Option<i64>,
+    /// The instruction, if it's decoded by the default ISA configuration
+    pub disassembly: Option<String>,
+}
+
+/// Splits an instruction into the fields of its format, using the 16-bit formats if the lowest two bits aren't
+/// `0b11`.
+///
+/// Each compressed instruction scales and orders its immediate differently, so the fields of a compressed instruction
+/// that decodes are the ones given for it in the ISA manual. The immediate layouts used by the `.insn` directive are
+/// shown for compressed encodings that don't decode.
+pub fn explain(bits: u32) -> Result<Explanation, String> {
+    if bits & 0b11 == 0b11 {
+        let raw = RawInstruction::decode(bits)?;
+        let format = raw.format();
+        Ok(Explanation {
+            bits,
+            format,
+            fields: fields(bits, format_layout(format)),
+            immediate: raw.imm(),
+            disassembly: Instruction::decode(bits)
+                .ok()
+                .map(|i| disassemble_instruction(&i)),
+        })
+    } else if bits > u16::MAX as u32 {
+        Err("compressed instruction has bits set above bit 15".to_owned())
+    } else {
+        let raw = RawCInstruction::decode(bits as u16)?;
+        let format = raw.format();
+        let decoded = CInstruction::decode(bits as u16).ok();
+        let (layout, immediate) = match decoded.as_ref().and_then(|c| instruction_layout(c, format))
+        {
+            Some((layout, imm)) => (layout, Some(imm)),
+            None => (format_layout(format), raw.imm()),
+        };
+        Ok(Explanation {
+            bits,
+            format,
+            fields: fields(bits, layout),
+            immediate,
+            disassembly: decoded.map(|c| c.to_string()),
+        })
+    }
+}
+
+/// A field that isn't part of the immediate, as `(name, high bit, low bit)`
+type NamedField = (&'static str, u8, u8);
+
+/// The fields that aren't part of the immediate, and the parts of the immediate as `(immediate bit, size, bit)`
+type Layout = (&'static [NamedField], &'static [(u8, u8, u8)]);
+
+/// The fields of c.andi, c.srli and c.srai, which use the CB format with a CI immediate
+const CB_ARITHMETIC: &[NamedField] = &[
+    ("funct3", 15, 13),
+    ("funct2", 11, 10),
+    ("rd'/rs1'", 9, 7),
+    ("op", 1, 0),
+];
+// the Zcb loads and stores have a 6-bit function code and a 1 or 2-bit offset
+const ZCB_LOAD_BYTE: &[NamedField] = &[
+    ("funct6", 15, 10),
+    ("rs1'", 9, 7),
+    ("rd'", 4, 2),
+    ("op", 1, 0),
+];
+const ZCB_STORE_BYTE: &[NamedField] = &[
+    ("funct6", 15, 10),
+    ("rs1'", 9, 7),
+    ("rs2'", 4, 2),
+    ("op", 1, 0),
+];
+const ZCB_LOAD_HALF: &[NamedField] = &[
+    ("funct6", 15, 10),
+    ("rs1'", 9, 7),
+    ("funct1", 6, 6),
+    ("rd'", 4, 2),
+    ("op", 1, 0),
+];
+const ZCB_STORE_HALF: &[NamedField] = &[
+    ("funct6", 15, 10),
+    ("rs1'", 9, 7),
+    ("funct1", 6, 6),
+    ("rs2'", 4, 2),
+    ("op", 1, 0),
+];
+/// c.lui holds bits 17 to 12 of the value loaded into `rd`
+const CLUI_IMMEDIATE: &[(u8, u8, u8)] = &[(12, 5, 2), (17, 1, 12)];
+
+/// Returns the layout and the value of the immediate of a compressed instruction whose immediate isn't laid out like
+/// the `.insn` directive for its format
+fn instruction_layout(instruction: &CInstruction, format: Format) -> Option<(Layout, i64)> {
+    let (named, _) = format_layout(format);
+    Some(match instruction {
+        CInstruction::ADDI4SPN { imm, .. } => ((named, CWideImmediate::LAYOUT), imm.val()),
+        CInstruction::LW { offset, .. } | CInstruction::SW { offset, .. } => {
+            ((named, CWImmediate::LAYOUT), offset.val())
+        }
+        CInstruction::FLD { offset, .. }
+        | CInstruction::LD { offset, .. }
+        | CInstruction::FSD { offset, .. }
+        | CInstruction::SD { offset, .. } => ((named, CDImmediate::LAYOUT), offset.val()),
+        CInstruction::ADDI16SP { imm } => ((named, C16SPImmediate::LAYOUT), imm.val()),
+        CInstruction::LUI { imm, .. } => ((named, CLUI_IMMEDIATE), imm.val() << 12),
+        CInstruction::SLLI { shamt, .. } => ((named, CShamt::LAYOUT), shamt.val()),
+        CInstruction::SRLI { shamt, .. } | CInstruction::SRAI { shamt, .. } => {
+            ((CB_ARITHMETIC, CShamt::LAYOUT), shamt.val())
+        }
+        CInstruction::ANDI { imm, .. } => ((CB_ARITHMETIC, CIImmediate::LAYOUT), imm.val()),
+        CInstruction::LWSP { offset, .. } => ((named, CWSPImmediate::LAYOUT), offset.val()),
+        CInstruction::FLDSP { offset, .. } | CInstruction::LDSP { offset, .. } => {
+            ((named, CDSPImmediate::LAYOUT), offset.val())
+        }
+        CInstruction::SWSP { offset, .. } => ((named, CSWSPImmediate::LAYOUT), offset.val()),
+        CInstruction::FSDSP { offset, .. } | CInstruction::SDSP { offset, .. } => {
+            ((named, CSDSPImmediate::LAYOUT), offset.val())
+        }
+        CInstruction::LBU { offset, .. } => ((ZCB_LOAD_BYTE, CByteImmediate::LAYOUT), offset.val()),
+        CInstruction::SB { offset, .. } => ((ZCB_STORE_BYTE, CByteImmediate::LAYOUT), offset.val()),
+        CInstruction::LHU { offset, .. } | CInstruction::LH { offset, .. } => {
+            ((ZCB_LOAD_HALF, CHalfImmediate::LAYOUT), offset.val())
+        }
+        CInstruction::SH { offset, .. } => ((ZCB_STORE_HALF, CHalfImmediate::LAYOUT), offset.val()),
+        _ => return None,
+    })
+}
+
+/// Returns the fields and immediate layout of a format, as used by the `.insn` directive
+fn format_layout(format: Format) -> Layout {
+    match format {
+        Format::R => (
+            &[
+                ("funct7", 31, 25),
+                ("rs2", 24, 20),
+                ("rs1", 19, 15),
+                ("funct3", 14, 12),
+                ("rd", 11, 7),
+                ("opcode", 6, 0),
+            ],
+            &[],
+        ),
+        Format::R4 => (
+            &[
+                ("rs3", 31, 27),
+                ("fmt", 26, 25),
+                ("rs2", 24, 20),
+                ("rs1", 19, 15),
+                ("funct3", 14, 12),
+                ("rd", 11, 7),
+                ("opcode", 6, 0),
+            ],
+            &[],
+        ),
+        Format::I => (
+            &[
+                ("rs1", 19, 15),
+                ("funct3", 14, 12),
+                ("rd", 11, 7),
+                ("opcode", 6, 0),
+            ],
+            IImmediate::LAYOUT,
+        ),
+        Format::S => (
+            &[
+                ("rs2", 24, 20),
+                ("rs1", 19, 15),
+                ("funct3", 14, 12),
+                ("opcode", 6, 0),
+            ],
+            SImmediate::LAYOUT,
+        ),
+        Format::B => (
+            &[
+                ("rs2", 24, 20),
+                ("rs1", 19, 15),
+                ("funct3", 14, 12),
+                ("opcode", 6, 0),
+            ],
+            BImmediate::LAYOUT,
+        ),
+        Format::U => (&[("rd", 11, 7), ("opcode", 6, 0)], UImmediate::LAYOUT),
+        Format::J => (&[("rd", 11, 7), ("opcode", 6, 0)], JImmediate::LAYOUT),
+        Format::CR => (
+            &[
+                ("funct4", 15, 12),
+                ("rd/rs1", 11, 7),
+                ("rs2", 6, 2),
+                ("op", 1, 0),
+            ],
+            &[],
+        ),
+        Format::CI => (
+            &[("funct3", 15, 13), ("rd/rs1", 11, 7), ("op", 1, 0)],
+            CIImmediate::LAYOUT,
+        ),
+        Format::CSS => (
+            &[("funct3", 15, 13), ("rs2", 6, 2), ("op", 1, 0)],
+            &[(0, 6, 7)],
+        ),
+        Format::CIW => (
+            &[("funct3", 15, 13), ("rd'", 4, 2), ("op", 1, 0)],
+            &[(0, 8, 5)],
+        ),
+        Format::CL => (
+            &[
+                ("funct3", 15, 13),
+                ("rs1'", 9, 7),
+                ("rd'", 4, 2),
+                ("op", 1, 0),
+            ],
+            &[(2, 3, 10), (0, 2, 5)],
+        ),
+        Format::CS => (
+            &[
+                ("funct3", 15, 13),
+                ("rs1'", 9, 7),
+                ("rs2'", 4, 2),
+                ("op", 1, 0),
+            ],
+            &[(2, 3, 10), (0, 2, 5)],
+        ),
+        Format::CA => (
+            &[
+                ("funct6", 15, 10),
+                ("rd'/rs1'", 9, 7),
+                ("funct2", 6, 5),
+                ("rs2'", 4, 2),
+                ("op", 1, 0),
+            ],
+            &[],
+        ),
+        Format::CB => (
+            &[("funct3", 15, 13), ("rs1'", 9, 7), ("op", 1, 0)],
+            CBImmediate::LAYOUT,
+        ),
+        Format::CJ => (&[("funct3", 15, 13), ("op", 1, 0)], CJImmediate::LAYOUT),
+    }
+}
+
+/// Returns the fields of a layout, from the highest bits to the lowest
+fn fields(bits: u32, (named, layout): Layout) -> Vec<BitField> {
+    let value = |high: u8, low: u8| (bits >> low) & ((1 << (high - low + 1)) - 1);
+    let mut fields: Vec<BitField> = named
+        .iter()
+        .map(|(name, high, low)| BitField {
+            high: *high,
+            low: *low,
+            name: (*name).to_owned(),
+            value: value(*high, *low),
+            immediate: None,
+        })
+        .collect();
+    fields.extend(layout.iter().map(|(base, size, location)| {
+        let (high, low) = (location + size - 1, *location);
+        let (imm_high, imm_low) = (base + size - 1, *base);
+        BitField {
+            high,
+            low,
+            name: if size == &1 {
+                format!("imm[{imm_low}]")
+            } else {
+                format!("imm[{imm_high}:{imm_low}]")
+            },
+            value: value(high, low),
+            immediate: Some((imm_high, imm_low)),
+        }
+    }));
+    fields.sort_by_key(|field| core::cmp::Reverse(field.high));
+    fields
+}
+
+/// Draws the fields in the style of the diagrams in the ISA manual
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let columns: Vec<(String, String, String)> = self
+            .fields
+            .iter()
+            .map(|field| {
+                let header = if field.high == field.low {
+                    format!("{}", field.high)
+                } else {
+                    format!("{} {}", field.high, field.low)
+                };
+                let value = format!("{:0width$b}", field.value, width = field.width() as usize);
+                (header, field.name.clone(), value)
+            })
+            .collect();
+        let widths: Vec<usize> = columns
+            .iter()
+            .map(|(header, name, value)| header.len().max(name.len()).max(value.len()) + 2)
+            .collect();
+
+        let mut header = String::from(" ");
+        let mut border = String::from("+");
+        let mut names = String::from("|");
+        let mut values = String::from("|");
+        for ((h, name, value), width) in columns.iter().zip(&widths) {
+            match h.split_once(' ') {
+                Some((high, low)) => {
+                    header.push_str(&format!("{high}{low:>w$}", w = width - high.len()))
+                }
+                None => header.push_str(&format!("{h:^width$}")),
+            }
+            header.push(' ');
+            border.push_str(&format!("{}+", "-".repeat(*width)));
+            names.push_str(&format!("{name:^width$}|"));
+            values.push_str(&format!("{value:^width$}|"));
+        }
+        writeln!(f, "{}", header.trim_end())?;
+        writeln!(f, "{border}")?;
+        writeln!(f, "{names}")?;
+        writeln!(f, "{values}")?;
+        writeln!(f, "{border}")?;
+
+        write!(f, "{:?}-type", self.format)?;
+        if let Some(imm) = self.immediate {
+            write!(f, ", imm = {imm}")?;
+        }
+        if let Some(disassembly) = &self.disassembly {
+            write!(f, ": {disassembly}")?;
+        }
+        Ok(())
+    }
+}
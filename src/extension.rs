@@ -0,0 +1,104 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt::{Debug, Display};
+
+use crate::isa::IsaConfig;
+
+/// An instruction from a custom or vendor extension, which can't be represented by `Instruction`.
+///
+/// Custom instructions are compared by their encodings.
+pub trait CustomInstruction: Debug + Display {
+    /// Encodes the instruction into a `u32`.
+    fn encode(&self) -> u32;
+
+    /// Allows converting back into the extension's own instruction type with `downcast_ref`.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl PartialEq for dyn CustomInstruction {
+    fn eq(&self, other: &Self) -> bool {
+        self.encode() == other.encode()
+    }
+}
+
+/// A custom or vendor extension, which decodes and assembles instructions that this crate doesn't know about.
+///
+/// Extensions are only consulted after the standard decoder or assembler has failed, so they can't change the meaning
+/// of standard encodings. Both methods return `None` if the encoding or mnemonic doesn't belong to the extension.
+pub trait Extension {
+    /// The name of the extension, used in error messages
+    fn name(&self) -> &str;
+
+    /// Decodes a 32-bit instruction.
+    fn decode(
+        &self,
+        instruction: u32,
+        isa: &IsaConfig,
+    ) -> Option<Result<Box<dyn CustomInstruction>, String>>;
+
+    /// Assembles an instruction from its mnemonic, split on `.`, and its operands.
+    fn assemble(
+        &self,
+        mnemonics: &[&str],
+        operands: &[&str],
+        isa: &IsaConfig,
+    ) -> Option<Result<Box<dyn CustomInstruction>, String>>;
+}
+
+/// The set of extensions that are consulted for encodings and mnemonics that this crate doesn't know about.
+///
+/// Extensions are tried in the order they were registered, and the first one to recognize an instruction is used.
+#[derive(Default)]
+pub struct Extensions {
+    extensions: Vec<Box<dyn Extension>>,
+}
+
+impl Extensions {
+    /// Creates an empty set of extensions.
+    pub fn new() -> Self {
+        Extensions {
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Adds an extension to the set.
+    pub fn register(&mut self, extension: Box<dyn Extension>) {
+        self.extensions.push(extension);
+    }
+
+    /// Adds an extension to the set, for chaining.
+    pub fn with(mut self, extension: impl Extension + 'static) -> Self {
+        self.register(Box::new(extension));
+        self
+    }
+
+    /// Returns the names of the registered extensions.
+    pub fn names(&self) -> Vec<&str> {
+        self.extensions.iter().map(|e| e.name()).collect()
+    }
+
+    /// Decodes a 32-bit instruction with the first extension that recognizes it.
+    pub fn decode(
+        &self,
+        instruction: u32,
+        isa: &IsaConfig,
+    ) -> Option<Result<Box<dyn CustomInstruction>, String>> {
+        self.extensions
+            .iter()
+            .find_map(|e| e.decode(instruction, isa))
+    }
+
+    /// Assembles an instruction with the first extension that recognizes its mnemonic.
+    pub fn assemble(
+        &self,
+        mnemonics: &[&str],
+        operands: &[&str],
+        isa: &IsaConfig,
+    ) -> Option<Result<Box<dyn CustomInstruction>, String>> {
+        self.extensions
+            .iter()
+            .find_map(|e| e.assemble(mnemonics, operands, isa))
+    }
+}
@@ -0,0 +1,1678 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::cinstruction::CInstruction;
+use crate::instruction::{Instruction, RoundingMode};
+use crate::isa::IsaConfig;
+use crate::register::{FRegister, IRegister};
+use crate::softfloat::{self, F32, F64, Format, SignInjection};
+
+/// The memory seen by a `Hart`.
+///
+/// Accesses are little-endian and may be misaligned, apart from atomics which the hart checks itself.
+pub trait Memory {
+    /// Reads `width` bytes (1, 2, 4 or 8) starting at `addr`, or returns `None` if any of them can't be read.
+    fn read(&mut self, addr: u64, width: u8) -> Option<u64>;
+
+    /// Writes the low `width` bytes (1, 2, 4 or 8) of `value` starting at `addr`, or returns `None` if any of them
+    /// can't be written.
+    fn write(&mut self, addr: u64, width: u8, value: u64) -> Option<()>;
+}
+
+/// Memory starting at address 0
+impl Memory for [u8] {
+    fn read(&mut self, addr: u64, width: u8) -> Option<u64> {
+        let start = usize::try_from(addr).ok()?;
+        let bytes = self.get(start..start.checked_add(width as usize)?)?;
+        Some(
+            bytes
+                .iter()
+                .rev()
+                .fold(0, |value, byte| value << 8 | *byte as u64),
+        )
+    }
+
+    fn write(&mut self, addr: u64, width: u8, value: u64) -> Option<()> {
+        let start = usize::try_from(addr).ok()?;
+        let bytes = self.get_mut(start..start.checked_add(width as usize)?)?;
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (value >> (8 * i)) as u8;
+        }
+        Some(())
+    }
+}
+
+impl Memory for Vec<u8> {
+    fn read(&mut self, addr: u64, width: u8) -> Option<u64> {
+        self.as_mut_slice().read(addr, width)
+    }
+
+    fn write(&mut self, addr: u64, width: u8, value: u64) -> Option<()> {
+        self.as_mut_slice().write(addr, width, value)
+    }
+}
+
+/// A synchronous exception raised by an instruction, named after the exception causes in the privileged ISA manual.
+///
+/// Addresses are the effective addresses of the accesses that failed.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Exception {
+    InstructionAccessFault(u64),
+    /// The instruction couldn't be decoded, or isn't supported by the simulator
+    IllegalInstruction(String),
+    Breakpoint,
+    LoadAddressMisaligned(u64),
+    LoadAccessFault(u64),
+    StoreAddressMisaligned(u64),
+    StoreAccessFault(u64),
+    EnvironmentCall,
+}
+
+impl Exception {
+    /// Returns the exception code written to `mcause`, with `ecall` reported as an environment call from M-mode.
+    ///
+    /// [`Hart::trap`] reports `ecall` with the code for the privilege mode it was executed in.
+    pub fn code(&self) -> u64 {
+        match self {
+            Exception::InstructionAccessFault(_) => 1,
+            Exception::IllegalInstruction(_) => 2,
+            Exception::Breakpoint => 3,
+            Exception::LoadAddressMisaligned(_) => 4,
+            Exception::LoadAccessFault(_) => 5,
+            Exception::StoreAddressMisaligned(_) => 6,
+            Exception::StoreAccessFault(_) => 7,
+            Exception::EnvironmentCall => 11,
+        }
+    }
+}
+
+impl Display for Exception {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Exception::InstructionAccessFault(addr) => {
+                write!(f, "instruction access fault at {addr:#x}")
+            }
+            Exception::IllegalInstruction(reason) => write!(f, "illegal instruction: {reason}"),
+            Exception::Breakpoint => write!(f, "breakpoint"),
+            Exception::LoadAddressMisaligned(addr) => {
+                write!(f, "misaligned load from {addr:#x}")
+            }
+            Exception::LoadAccessFault(addr) => write!(f, "load access fault at {addr:#x}"),
+            Exception::StoreAddressMisaligned(addr) => {
+                write!(f, "misaligned store to {addr:#x}")
+            }
+            Exception::StoreAccessFault(addr) => write!(f, "store access fault at {addr:#x}"),
+            Exception::EnvironmentCall => write!(f, "environment call"),
+        }
+    }
+}
+
+/// A privilege mode, with the encoding used by `mstatus.MPP`
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Privilege {
+    User = 0,
+    Supervisor = 1,
+    Machine = 3,
+}
+
+impl Privilege {
+    /// Decodes a two bit privilege field, with the reserved encoding 2 mapped to `None`
+    fn from_bits(bits: u64) -> Option<Privilege> {
+        match bits & 0b11 {
+            0 => Some(Privilege::User),
+            1 => Some(Privilege::Supervisor),
+            3 => Some(Privilege::Machine),
+            _ => None,
+        }
+    }
+}
+
+/// The operations of the atomic memory operation instructions
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum AmoOp {
+    Swap,
+    Add,
+    Xor,
+    And,
+    Or,
+    Min,
+    Max,
+    MinU,
+    MaxU,
+}
+
+const FFLAGS: u16 = 0x001;
+const FRM: u16 = 0x002;
+const FCSR: u16 = 0x003;
+const CYCLE: u16 = 0xc00;
+const TIME: u16 = 0xc01;
+const INSTRET: u16 = 0xc02;
+const SSTATUS: u16 = 0x100;
+const SIE: u16 = 0x104;
+const STVEC: u16 = 0x105;
+const SCOUNTEREN: u16 = 0x106;
+const SENVCFG: u16 = 0x10a;
+const SSCRATCH: u16 = 0x140;
+const SEPC: u16 = 0x141;
+const SCAUSE: u16 = 0x142;
+const STVAL: u16 = 0x143;
+const SIP: u16 = 0x144;
+const SATP: u16 = 0x180;
+const MSTATUS: u16 = 0x300;
+const MISA: u16 = 0x301;
+const MEDELEG: u16 = 0x302;
+const MIDELEG: u16 = 0x303;
+const MIE: u16 = 0x304;
+const MTVEC: u16 = 0x305;
+const MCOUNTEREN: u16 = 0x306;
+const MENVCFG: u16 = 0x30a;
+const MCOUNTINHIBIT: u16 = 0x320;
+const MSCRATCH: u16 = 0x340;
+const MEPC: u16 = 0x341;
+const MCAUSE: u16 = 0x342;
+const MTVAL: u16 = 0x343;
+const MIP: u16 = 0x344;
+const MCYCLE: u16 = 0xb00;
+const MINSTRET: u16 = 0xb02;
+const MVENDORID: u16 = 0xf11;
+const MARCHID: u16 = 0xf12;
+const MIMPID: u16 = 0xf13;
+const MHARTID: u16 = 0xf14;
+const MCONFIGPTR: u16 = 0xf15;
+
+const MSTATUS_SIE: u64 = 1 << 1;
+const MSTATUS_MIE: u64 = 1 << 3;
+const MSTATUS_SPIE: u64 = 1 << 5;
+const MSTATUS_MPIE: u64 = 1 << 7;
+const MSTATUS_SPP: u64 = 1 << 8;
+const MSTATUS_MPP: u64 = 0b11 << 11;
+const MSTATUS_FS: u64 = 0b11 << 13;
+const MSTATUS_MPRV: u64 = 1 << 17;
+const MSTATUS_SUM: u64 = 1 << 18;
+const MSTATUS_MXR: u64 = 1 << 19;
+const MSTATUS_TVM: u64 = 1 << 20;
+const MSTATUS_TW: u64 = 1 << 21;
+const MSTATUS_TSR: u64 = 1 << 22;
+/// UXL and SXL, which are both fixed at 64 bits
+const MSTATUS_XL: u64 = 0b1010 << 32;
+const MSTATUS_SD: u64 = 1 << 63;
+const MSTATUS_WRITABLE: u64 = MSTATUS_SIE
+    | MSTATUS_MIE
+    | MSTATUS_SPIE
+    | MSTATUS_MPIE
+    | MSTATUS_SPP
+    | MSTATUS_MPP
+    | MSTATUS_FS
+    | MSTATUS_MPRV
+    | MSTATUS_SUM
+    | MSTATUS_MXR
+    | MSTATUS_TVM
+    | MSTATUS_TW
+    | MSTATUS_TSR;
+const SSTATUS_UXL: u64 = 0b10 << 32;
+const SSTATUS_WRITABLE: u64 =
+    MSTATUS_SIE | MSTATUS_SPIE | MSTATUS_SPP | MSTATUS_FS | MSTATUS_SUM | MSTATUS_MXR;
+/// The floating point unit state in `mstatus.FS`
+const FS_INITIAL: u64 = 1 << 13;
+const FS_DIRTY: u64 = 3 << 13;
+
+/// RV64 with the A, C, D, F, I, M, S and U extensions
+const MISA_VALUE: u64 =
+    2 << 62 | 1 << 0 | 1 << 2 | 1 << 3 | 1 << 5 | 1 << 8 | 1 << 12 | 1 << 18 | 1 << 20;
+/// Every exception apart from an environment call from M-mode can be delegated
+const DELEGABLE_EXCEPTIONS: u64 = 0xb3ff;
+/// The supervisor software, timer and external interrupts
+const SUPERVISOR_INTERRUPTS: u64 = 0x222;
+/// The software, timer and external interrupts for both modes
+const INTERRUPTS: u64 = 0xaaa;
+
+/// The machine and supervisor CSRs that hold plain state, already legalized
+#[derive(Debug, Clone, Default)]
+struct Csrs {
+    mstatus: u64,
+    medeleg: u64,
+    mideleg: u64,
+    mie: u64,
+    mip: u64,
+    mtvec: u64,
+    mcounteren: u64,
+    mscratch: u64,
+    mepc: u64,
+    mcause: u64,
+    mtval: u64,
+    stvec: u64,
+    scounteren: u64,
+    sscratch: u64,
+    sepc: u64,
+    scause: u64,
+    stval: u64,
+}
+
+/// A single RV64IMAFDC hart that executes decoded instructions.
+///
+/// Floating point arithmetic is done in software, so results and exception flags are exact for every rounding mode
+/// regardless of the host. Single precision values are NaN-boxed in the 64-bit float registers, and single precision
+/// operands that aren't properly boxed are read as the canonical NaN.
+///
+/// The hart starts in M-mode and also implements S-mode and U-mode, without address translation or physical memory
+/// protection. The Zicsr instructions can access the floating point CSRs, the counters and the machine and supervisor
+/// trap CSRs, with unsupported values written to WARL fields legalized. `cycle`, `time` and `instret` all count
+/// retired instructions, and interrupts are never taken. Instructions outside of RV64IMAFDC, Zicsr, Zifencei and the
+/// privileged instructions raise an illegal instruction exception, apart from the Zcb instructions and `c.mop.n`.
+#[derive(Debug, Clone)]
+pub struct Hart {
+    pub pc: u64,
+    x: [u64; 32],
+    f: [u64; 32],
+    /// The rounding mode in bits 7:5 and the accrued exception flags in bits 4:0
+    pub fcsr: u8,
+    /// The number of instructions that have retired
+    pub instret: u64,
+    pub privilege: Privilege,
+    /// The address reserved by the last `lr`
+    reservation: Option<u64>,
+    csrs: Csrs,
+    /// The encoding of the instruction being executed, which is written to `mtval` for illegal instructions
+    bits: u32,
+    isa: IsaConfig,
+}
+
+impl Hart {
+    /// Creates a hart with every register zeroed that starts executing at `pc`.
+    pub fn new(pc: u64) -> Self {
+        Hart {
+            pc,
+            x: [0; 32],
+            f: [0; 32],
+            fcsr: 0,
+            instret: 0,
+            privilege: Privilege::Machine,
+            reservation: None,
+            csrs: Csrs {
+                mstatus: FS_INITIAL,
+                ..Csrs::default()
+            },
+            bits: 0,
+            isa: IsaConfig::default(),
+        }
+    }
+
+    /// Sets the ISA configuration used to decode instructions. The hart is always RV64.
+    pub fn with_isa(mut self, isa: IsaConfig) -> Self {
+        self.isa = isa;
+        self
+    }
+
+    pub fn x(&self, r: IRegister) -> u64 {
+        self.x[r as usize]
+    }
+
+    /// Sets an integer register, ignoring writes to `zero`.
+    pub fn set_x(&mut self, r: IRegister, value: u64) {
+        if r != IRegister::Zero {
+            self.x[r as usize] = value;
+        }
+    }
+
+    /// Returns the raw contents of a float register.
+    pub fn f(&self, r: FRegister) -> u64 {
+        self.f[u32::from(r) as usize]
+    }
+
+    pub fn set_f(&mut self, r: FRegister, value: u64) {
+        self.f[u32::from(r) as usize] = value;
+    }
+
+    /// Returns the single precision value in a float register, or the canonical NaN if it isn't NaN-boxed.
+    pub fn f32(&self, r: FRegister) -> u32 {
+        self.read_float(F32, r) as u32
+    }
+
+    /// NaN-boxes a single precision value into a float register.
+    pub fn set_f32(&mut self, r: FRegister, value: u32) {
+        self.write_float(F32, r, value as u64);
+    }
+
+    /// Fetches, decodes and executes the instruction at `pc`.
+    ///
+    /// If an exception is raised, the hart is left as it was before the instruction, with `pc` pointing at it. The
+    /// exception can be handled by the caller, which has to advance `pc` past an `ecall` or `ebreak`, or passed to
+    /// [`Hart::trap`] to enter the trap handler of the program being run.
+    pub fn step<M: Memory + ?Sized>(&mut self, memory: &mut M) -> Result<(), Exception> {
+        self.bits = 0;
+        let low = memory
+            .read(self.pc, 2)
+            .ok_or(Exception::InstructionAccessFault(self.pc))?;
+        self.bits = low as u32;
+        if low & 0b11 != 0b11 {
+            let c = CInstruction::decode_with(low as u16, &self.isa)
+                .map_err(Exception::IllegalInstruction)?;
+            return self.execute_compressed(&c, memory);
+        }
+        let high = memory
+            .read(self.pc.wrapping_add(2), 2)
+            .ok_or(Exception::InstructionAccessFault(self.pc.wrapping_add(2)))?;
+        self.bits = (high << 16 | low) as u32;
+        let i = Instruction::decode_with(self.bits, &self.isa)
+            .map_err(Exception::IllegalInstruction)?;
+        self.execute_at(&i, 4, memory)
+    }
+
+    /// Steps until an instruction raises an exception, and returns it.
+    pub fn run<M: Memory + ?Sized>(&mut self, memory: &mut M) -> Exception {
+        loop {
+            if let Err(e) = self.step(memory) {
+                return e;
+            }
+        }
+    }
+
+    /// Executes a 32-bit instruction as if it were at `pc`.
+    pub fn execute<M: Memory + ?Sized>(
+        &mut self,
+        instruction: &Instruction,
+        memory: &mut M,
+    ) -> Result<(), Exception> {
+        self.bits = Instruction::encode(instruction);
+        self.execute_at(instruction, 4, memory)
+    }
+
+    /// Executes a compressed instruction as if it were at `pc`, by expanding it where possible.
+    pub fn execute_compressed<M: Memory + ?Sized>(
+        &mut self,
+        instruction: &CInstruction,
+        memory: &mut M,
+    ) -> Result<(), Exception> {
+        self.bits = CInstruction::encode(instruction) as u32;
+        match *instruction {
+            CInstruction::SEXTB { dest } => {
+                let r = dest.expand();
+                self.set_x(r, self.x(r) as i8 as i64 as u64);
+            }
+            CInstruction::ZEXTH { dest } => {
+                let r = dest.expand();
+                self.set_x(r, self.x(r) as u16 as u64);
+            }
+            CInstruction::SEXTH { dest } => {
+                let r = dest.expand();
+                self.set_x(r, self.x(r) as i16 as i64 as u64);
+            }
+            CInstruction::ZEXTW { dest } => {
+                let r = dest.expand();
+                self.set_x(r, self.x(r) as u32 as u64);
+            }
+            CInstruction::MOP { .. } => {}
+            CInstruction::SSPUSH | CInstruction::SSPOPCHK => {
+                return Err(unsupported(instruction.mnemonic()));
+            }
+            _ => {
+                let Some(expanded) = instruction.expand() else {
+                    return Err(unsupported(instruction.mnemonic()));
+                };
+                return self.execute_at(&expanded, 2, memory);
+            }
+        }
+        self.retire(self.pc.wrapping_add(2));
+        Ok(())
+    }
+
+    /// Enters the trap handler for an exception raised by the instruction at `pc`.
+    ///
+    /// The trap is taken in S-mode if it was raised in S-mode or U-mode and is delegated by `medeleg`, and in M-mode
+    /// otherwise. `xtval` is written with the faulting address for access faults and misaligned accesses, the
+    /// encoding of the instruction for illegal instructions, and `pc` for breakpoints.
+    pub fn trap(&mut self, exception: &Exception) {
+        let cause = match exception {
+            Exception::EnvironmentCall => 8 + self.privilege as u64,
+            e => e.code(),
+        };
+        let tval = match *exception {
+            Exception::InstructionAccessFault(addr)
+            | Exception::LoadAddressMisaligned(addr)
+            | Exception::LoadAccessFault(addr)
+            | Exception::StoreAddressMisaligned(addr)
+            | Exception::StoreAccessFault(addr) => addr,
+            Exception::IllegalInstruction(_) => self.bits as u64,
+            Exception::Breakpoint => self.pc,
+            Exception::EnvironmentCall => 0,
+        };
+        let status = self.csrs.mstatus;
+        if self.privilege != Privilege::Machine && self.csrs.medeleg >> cause & 1 == 1 {
+            self.csrs.sepc = self.pc;
+            self.csrs.scause = cause;
+            self.csrs.stval = tval;
+            let spie = if status & MSTATUS_SIE != 0 {
+                MSTATUS_SPIE
+            } else {
+                0
+            };
+            let spp = if self.privilege == Privilege::Supervisor {
+                MSTATUS_SPP
+            } else {
+                0
+            };
+            self.csrs.mstatus = status & !(MSTATUS_SIE | MSTATUS_SPIE | MSTATUS_SPP) | spie | spp;
+            self.privilege = Privilege::Supervisor;
+            self.pc = self.csrs.stvec & !0b11;
+        } else {
+            self.csrs.mepc = self.pc;
+            self.csrs.mcause = cause;
+            self.csrs.mtval = tval;
+            let mpie = if status & MSTATUS_MIE != 0 {
+                MSTATUS_MPIE
+            } else {
+                0
+            };
+            let mpp = (self.privilege as u64) << 11;
+            self.csrs.mstatus = status & !(MSTATUS_MIE | MSTATUS_MPIE | MSTATUS_MPP) | mpie | mpp;
+            self.privilege = Privilege::Machine;
+            self.pc = self.csrs.mtvec & !0b11;
+        }
+    }
+
+    /// Returns the value of a CSR as a CSR instruction in M-mode would read it, or `None` if it isn't supported.
+    pub fn csr(&self, csr: u16) -> Option<u64> {
+        self.read_csr(csr).ok()
+    }
+
+    fn retire(&mut self, next: u64) {
+        self.pc = next;
+        self.instret = self.instret.wrapping_add(1);
+    }
+
+    /// Executes an instruction that is `length` bytes long, which determines the link address of jumps
+    fn execute_at<M: Memory + ?Sized>(
+        &mut self,
+        instruction: &Instruction,
+        length: u64,
+        memory: &mut M,
+    ) -> Result<(), Exception> {
+        let pc = self.pc;
+        let mut next = pc.wrapping_add(length);
+        let mut flags = 0;
+        let float = matches!(instruction.info().extension, "F" | "D");
+        if float && self.csrs.mstatus & MSTATUS_FS == 0 {
+            return Err(Exception::IllegalInstruction(String::from(
+                "the floating point unit is off in mstatus",
+            )));
+        }
+        match *instruction {
+            Instruction::Lui { dest, imm } => {
+                self.set_x(dest, ((imm.val() as i32) << 12) as i64 as u64)
+            }
+            Instruction::Auipc { dest, imm } => self.set_x(
+                dest,
+                pc.wrapping_add_signed(((imm.val() as i32) << 12) as i64),
+            ),
+            Instruction::Jal { dest, offset } => {
+                self.set_x(dest, next);
+                next = pc.wrapping_add_signed(offset.val());
+            }
+            Instruction::Jalr {
+                dest,
+                base,
+                offset: imm,
+            } => {
+                let target = self.address(base, imm.val()) & !1;
+                self.set_x(dest, next);
+                next = target;
+            }
+            Instruction::Beq { src1, src2, offset } => {
+                if self.x(src1) == self.x(src2) {
+                    next = pc.wrapping_add_signed(offset.val());
+                }
+            }
+            Instruction::Bne { src1, src2, offset } => {
+                if self.x(src1) != self.x(src2) {
+                    next = pc.wrapping_add_signed(offset.val());
+                }
+            }
+            Instruction::Blt { src1, src2, offset } => {
+                if (self.x(src1) as i64) < self.x(src2) as i64 {
+                    next = pc.wrapping_add_signed(offset.val());
+                }
+            }
+            Instruction::Bge { src1, src2, offset } => {
+                if self.x(src1) as i64 >= self.x(src2) as i64 {
+                    next = pc.wrapping_add_signed(offset.val());
+                }
+            }
+            Instruction::Bltu { src1, src2, offset } => {
+                if self.x(src1) < self.x(src2) {
+                    next = pc.wrapping_add_signed(offset.val());
+                }
+            }
+            Instruction::Bgeu { src1, src2, offset } => {
+                if self.x(src1) >= self.x(src2) {
+                    next = pc.wrapping_add_signed(offset.val());
+                }
+            }
+            Instruction::Lb {
+                dest,
+                base,
+                offset: imm,
+            } => {
+                let value = load(memory, self.address(base, imm.val()), 1)?;
+                self.set_x(dest, value as i8 as i64 as u64);
+            }
+            Instruction::Lh {
+                dest,
+                base,
+                offset: imm,
+            } => {
+                let value = load(memory, self.address(base, imm.val()), 2)?;
+                self.set_x(dest, value as i16 as i64 as u64);
+            }
+            Instruction::Lw {
+                dest,
+                base,
+                offset: imm,
+            } => {
+                let value = load(memory, self.address(base, imm.val()), 4)?;
+                self.set_x(dest, value as i32 as i64 as u64);
+            }
+            Instruction::Ld {
+                dest,
+                base,
+                offset: imm,
+            } => {
+                let value = load(memory, self.address(base, imm.val()), 8)?;
+                self.set_x(dest, value);
+            }
+            Instruction::Lbu {
+                dest,
+                base,
+                offset: imm,
+            } => {
+                let value = load(memory, self.address(base, imm.val()), 1)?;
+                self.set_x(dest, value);
+            }
+            Instruction::Lhu {
+                dest,
+                base,
+                offset: imm,
+            } => {
+                let value = load(memory, self.address(base, imm.val()), 2)?;
+                self.set_x(dest, value);
+            }
+            Instruction::Lwu {
+                dest,
+                base,
+                offset: imm,
+            } => {
+                let value = load(memory, self.address(base, imm.val()), 4)?;
+                self.set_x(dest, value);
+            }
+            Instruction::Sb {
+                src,
+                base,
+                offset: imm,
+            } => store(memory, self.address(base, imm.val()), 1, self.x(src))?,
+            Instruction::Sh {
+                src,
+                base,
+                offset: imm,
+            } => store(memory, self.address(base, imm.val()), 2, self.x(src))?,
+            Instruction::Sw {
+                src,
+                base,
+                offset: imm,
+            } => store(memory, self.address(base, imm.val()), 4, self.x(src))?,
+            Instruction::Sd {
+                src,
+                base,
+                offset: imm,
+            } => store(memory, self.address(base, imm.val()), 8, self.x(src))?,
+            Instruction::Addi { dest, src, imm } => {
+                self.set_x(dest, self.x(src).wrapping_add_signed(imm.val()))
+            }
+            Instruction::Slti { dest, src, imm } => {
+                self.set_x(dest, ((self.x(src) as i64) < imm.val()) as u64)
+            }
+            Instruction::Sltiu { dest, src, imm } => {
+                self.set_x(dest, (self.x(src) < imm.val() as u64) as u64)
+            }
+            Instruction::Xori { dest, src, imm } => {
+                self.set_x(dest, self.x(src) ^ imm.val() as u64)
+            }
+            Instruction::Ori { dest, src, imm } => self.set_x(dest, self.x(src) | imm.val() as u64),
+            Instruction::Andi { dest, src, imm } => {
+                self.set_x(dest, self.x(src) & imm.val() as u64)
+            }
+            Instruction::Slli { dest, src, shamt } => self.set_x(dest, self.x(src) << shamt.val()),
+            Instruction::Srli { dest, src, shamt } => self.set_x(dest, self.x(src) >> shamt.val()),
+            Instruction::Srai { dest, src, shamt } => {
+                self.set_x(dest, (self.x(src) as i64 >> shamt.val()) as u64)
+            }
+            Instruction::Add { dest, src1, src2 } => {
+                self.set_x(dest, self.x(src1).wrapping_add(self.x(src2)))
+            }
+            Instruction::Sub { dest, src1, src2 } => {
+                self.set_x(dest, self.x(src1).wrapping_sub(self.x(src2)))
+            }
+            Instruction::Sll { dest, src1, src2 } => {
+                self.set_x(dest, self.x(src1) << (self.x(src2) & 0x3f))
+            }
+            Instruction::Slt { dest, src1, src2 } => {
+                self.set_x(dest, ((self.x(src1) as i64) < self.x(src2) as i64) as u64)
+            }
+            Instruction::Sltu { dest, src1, src2 } => {
+                self.set_x(dest, (self.x(src1) < self.x(src2)) as u64)
+            }
+            Instruction::Xor { dest, src1, src2 } => self.set_x(dest, self.x(src1) ^ self.x(src2)),
+            Instruction::Srl { dest, src1, src2 } => {
+                self.set_x(dest, self.x(src1) >> (self.x(src2) & 0x3f))
+            }
+            Instruction::Sra { dest, src1, src2 } => {
+                self.set_x(dest, (self.x(src1) as i64 >> (self.x(src2) & 0x3f)) as u64)
+            }
+            Instruction::Or { dest, src1, src2 } => self.set_x(dest, self.x(src1) | self.x(src2)),
+            Instruction::And { dest, src1, src2 } => self.set_x(dest, self.x(src1) & self.x(src2)),
+            // a single hart with no caches has nothing to order
+            Instruction::Fence { .. } | Instruction::FenceI => {}
+            // prefetches are hints, so they have no architectural effect
+            Instruction::PrefetchI { .. }
+            | Instruction::PrefetchR { .. }
+            | Instruction::PrefetchW { .. } => {}
+            Instruction::Ecall => return Err(Exception::EnvironmentCall),
+            Instruction::Ebreak => return Err(Exception::Breakpoint),
+            Instruction::Mret => {
+                self.require(Privilege::Machine, 0, "mret")?;
+                let status = self.csrs.mstatus;
+                // MPP is always legal
+                let mpp = Privilege::from_bits(status >> 11).unwrap();
+                let mie = if status & MSTATUS_MPIE != 0 {
+                    MSTATUS_MIE
+                } else {
+                    0
+                };
+                let mprv = if mpp == Privilege::Machine {
+                    status & MSTATUS_MPRV
+                } else {
+                    0
+                };
+                self.csrs.mstatus = status & !(MSTATUS_MIE | MSTATUS_MPP | MSTATUS_MPRV)
+                    | mie
+                    | MSTATUS_MPIE
+                    | mprv;
+                self.privilege = mpp;
+                next = self.csrs.mepc;
+            }
+            Instruction::Sret => {
+                self.require(Privilege::Supervisor, MSTATUS_TSR, "sret")?;
+                let status = self.csrs.mstatus;
+                let spp = if status & MSTATUS_SPP != 0 {
+                    Privilege::Supervisor
+                } else {
+                    Privilege::User
+                };
+                let sie = if status & MSTATUS_SPIE != 0 {
+                    MSTATUS_SIE
+                } else {
+                    0
+                };
+                self.csrs.mstatus =
+                    status & !(MSTATUS_SIE | MSTATUS_SPP | MSTATUS_MPRV) | sie | MSTATUS_SPIE;
+                self.privilege = spp;
+                next = self.csrs.sepc;
+            }
+            // interrupts are never taken, so waiting for one returns immediately, which makes it legal in U-mode
+            Instruction::Wfi => {
+                if self.privilege != Privilege::Machine && self.csrs.mstatus & MSTATUS_TW != 0 {
+                    return Err(Exception::IllegalInstruction(String::from(
+                        "wfi is trapped by mstatus.TW",
+                    )));
+                }
+            }
+            // there is no address translation to invalidate
+            Instruction::SfenceVma { .. } => {
+                self.require(Privilege::Supervisor, MSTATUS_TVM, "sfence.vma")?
+            }
+            Instruction::Addiw { dest, src, imm } => {
+                self.set_x(dest, word(self.x(src).wrapping_add_signed(imm.val())))
+            }
+            Instruction::Slliw { dest, src, shamt } => {
+                self.set_x(dest, word(self.x(src) << shamt.val()))
+            }
+            Instruction::Srliw { dest, src, shamt } => {
+                self.set_x(dest, word((self.x(src) as u32 >> shamt.val()) as u64))
+            }
+            Instruction::Sraiw { dest, src, shamt } => {
+                self.set_x(dest, (self.x(src) as i32 >> shamt.val()) as i64 as u64)
+            }
+            Instruction::Addw { dest, src1, src2 } => {
+                self.set_x(dest, word(self.x(src1).wrapping_add(self.x(src2))))
+            }
+            Instruction::Subw { dest, src1, src2 } => {
+                self.set_x(dest, word(self.x(src1).wrapping_sub(self.x(src2))))
+            }
+            Instruction::Sllw { dest, src1, src2 } => {
+                self.set_x(dest, word(self.x(src1) << (self.x(src2) & 0x1f)))
+            }
+            Instruction::Srlw { dest, src1, src2 } => self.set_x(
+                dest,
+                word((self.x(src1) as u32 >> (self.x(src2) & 0x1f)) as u64),
+            ),
+            Instruction::Sraw { dest, src1, src2 } => self.set_x(
+                dest,
+                (self.x(src1) as i32 >> (self.x(src2) & 0x1f)) as i64 as u64,
+            ),
+            Instruction::Mul { dest, src1, src2 } => {
+                self.set_x(dest, self.x(src1).wrapping_mul(self.x(src2)))
+            }
+            Instruction::Mulh { dest, src1, src2 } => {
+                let product = self.x(src1) as i64 as i128 * self.x(src2) as i64 as i128;
+                self.set_x(dest, (product >> 64) as u64);
+            }
+            Instruction::Mulhsu { dest, src1, src2 } => {
+                let product = self.x(src1) as i64 as i128 * self.x(src2) as i128;
+                self.set_x(dest, (product >> 64) as u64);
+            }
+            Instruction::Mulhu { dest, src1, src2 } => {
+                let product = self.x(src1) as u128 * self.x(src2) as u128;
+                self.set_x(dest, (product >> 64) as u64);
+            }
+            // division by zero and overflow don't trap: the quotient is all ones or the dividend, and the remainder
+            // is the dividend or zero
+            Instruction::Div { dest, src1, src2 } => {
+                let (a, b) = (self.x(src1) as i64, self.x(src2) as i64);
+                let q = if b == 0 { -1 } else { a.wrapping_div(b) };
+                self.set_x(dest, q as u64);
+            }
+            Instruction::Divu { dest, src1, src2 } => {
+                let (a, b) = (self.x(src1), self.x(src2));
+                self.set_x(dest, a.checked_div(b).unwrap_or(u64::MAX));
+            }
+            Instruction::Rem { dest, src1, src2 } => {
+                let (a, b) = (self.x(src1) as i64, self.x(src2) as i64);
+                let r = if b == 0 { a } else { a.wrapping_rem(b) };
+                self.set_x(dest, r as u64);
+            }
+            Instruction::Remu { dest, src1, src2 } => {
+                let (a, b) = (self.x(src1), self.x(src2));
+                self.set_x(dest, a.checked_rem(b).unwrap_or(a));
+            }
+            Instruction::Mulw { dest, src1, src2 } => {
+                self.set_x(dest, word(self.x(src1).wrapping_mul(self.x(src2))))
+            }
+            Instruction::Divw { dest, src1, src2 } => {
+                let (a, b) = (self.x(src1) as i32, self.x(src2) as i32);
+                let q = if b == 0 { -1 } else { a.wrapping_div(b) };
+                self.set_x(dest, q as i64 as u64);
+            }
+            Instruction::Divuw { dest, src1, src2 } => {
+                let (a, b) = (self.x(src1) as u32, self.x(src2) as u32);
+                self.set_x(dest, word(a.checked_div(b).unwrap_or(u32::MAX) as u64));
+            }
+            Instruction::Remw { dest, src1, src2 } => {
+                let (a, b) = (self.x(src1) as i32, self.x(src2) as i32);
+                let r = if b == 0 { a } else { a.wrapping_rem(b) };
+                self.set_x(dest, r as i64 as u64);
+            }
+            Instruction::Remuw { dest, src1, src2 } => {
+                let (a, b) = (self.x(src1) as u32, self.x(src2) as u32);
+                self.set_x(dest, word(a.checked_rem(b).unwrap_or(a) as u64));
+            }
+            Instruction::LrW { dest, addr, .. } => self.load_reserved(memory, dest, addr, 4)?,
+            Instruction::LrD { dest, addr, .. } => self.load_reserved(memory, dest, addr, 8)?,
+            Instruction::ScW {
+                dest, addr, src, ..
+            } => self.store_conditional(memory, dest, addr, src, 4)?,
+            Instruction::ScD {
+                dest, addr, src, ..
+            } => self.store_conditional(memory, dest, addr, src, 8)?,
+            Instruction::AmoswapW {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 4, AmoOp::Swap)?,
+            Instruction::AmoaddW {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 4, AmoOp::Add)?,
+            Instruction::AmoxorW {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 4, AmoOp::Xor)?,
+            Instruction::AmoandW {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 4, AmoOp::And)?,
+            Instruction::AmoorW {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 4, AmoOp::Or)?,
+            Instruction::AmominW {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 4, AmoOp::Min)?,
+            Instruction::AmomaxW {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 4, AmoOp::Max)?,
+            Instruction::AmominuW {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 4, AmoOp::MinU)?,
+            Instruction::AmomaxuW {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 4, AmoOp::MaxU)?,
+            Instruction::AmoswapD {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 8, AmoOp::Swap)?,
+            Instruction::AmoaddD {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 8, AmoOp::Add)?,
+            Instruction::AmoxorD {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 8, AmoOp::Xor)?,
+            Instruction::AmoandD {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 8, AmoOp::And)?,
+            Instruction::AmoorD {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 8, AmoOp::Or)?,
+            Instruction::AmominD {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 8, AmoOp::Min)?,
+            Instruction::AmomaxD {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 8, AmoOp::Max)?,
+            Instruction::AmominuD {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 8, AmoOp::MinU)?,
+            Instruction::AmomaxuD {
+                dest, addr, src, ..
+            } => self.amo(memory, dest, addr, src, 8, AmoOp::MaxU)?,
+            Instruction::Flw {
+                dest,
+                base,
+                offset: imm,
+            } => {
+                let value = load(memory, self.address(base, imm.val()), 4)?;
+                self.write_float(F32, dest, value);
+            }
+            Instruction::Fld {
+                dest,
+                base,
+                offset: imm,
+            } => {
+                let value = load(memory, self.address(base, imm.val()), 8)?;
+                self.write_float(F64, dest, value);
+            }
+            // stores copy the register without checking the NaN-boxing
+            Instruction::Fsw {
+                src,
+                base,
+                offset: imm,
+            } => store(memory, self.address(base, imm.val()), 4, self.f(src))?,
+            Instruction::Fsd {
+                src,
+                base,
+                offset: imm,
+            } => store(memory, self.address(base, imm.val()), 8, self.f(src))?,
+            Instruction::FmaddS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => self.fused(F32, dest, src1, src2, src3, rm, false, false, &mut flags)?,
+            Instruction::FmsubS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => self.fused(F32, dest, src1, src2, src3, rm, false, true, &mut flags)?,
+            Instruction::FnmsubS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => self.fused(F32, dest, src1, src2, src3, rm, true, false, &mut flags)?,
+            Instruction::FnmaddS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => self.fused(F32, dest, src1, src2, src3, rm, true, true, &mut flags)?,
+            Instruction::FmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => self.fused(F64, dest, src1, src2, src3, rm, false, false, &mut flags)?,
+            Instruction::FmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => self.fused(F64, dest, src1, src2, src3, rm, false, true, &mut flags)?,
+            Instruction::FnmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => self.fused(F64, dest, src1, src2, src3, rm, true, false, &mut flags)?,
+            Instruction::FnmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => self.fused(F64, dest, src1, src2, src3, rm, true, true, &mut flags)?,
+            Instruction::FaddS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => self.arithmetic(F32, dest, src1, src2, rm, softfloat::add, &mut flags)?,
+            Instruction::FsubS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => self.arithmetic(F32, dest, src1, src2, rm, softfloat::sub, &mut flags)?,
+            Instruction::FmulS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => self.arithmetic(F32, dest, src1, src2, rm, softfloat::mul, &mut flags)?,
+            Instruction::FdivS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => self.arithmetic(F32, dest, src1, src2, rm, softfloat::div, &mut flags)?,
+            Instruction::FaddD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => self.arithmetic(F64, dest, src1, src2, rm, softfloat::add, &mut flags)?,
+            Instruction::FsubD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => self.arithmetic(F64, dest, src1, src2, rm, softfloat::sub, &mut flags)?,
+            Instruction::FmulD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => self.arithmetic(F64, dest, src1, src2, rm, softfloat::mul, &mut flags)?,
+            Instruction::FdivD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => self.arithmetic(F64, dest, src1, src2, rm, softfloat::div, &mut flags)?,
+            Instruction::FsqrtS { dest, src, rm } => {
+                let rm = self.rounding_mode(rm)?;
+                let value = softfloat::sqrt(F32, self.read_float(F32, src), rm, &mut flags);
+                self.write_float(F32, dest, value);
+            }
+            Instruction::FsqrtD { dest, src, rm } => {
+                let rm = self.rounding_mode(rm)?;
+                let value = softfloat::sqrt(F64, self.read_float(F64, src), rm, &mut flags);
+                self.write_float(F64, dest, value);
+            }
+            Instruction::FsgnjS { dest, src1, src2 } => {
+                self.sign_inject(F32, dest, src1, src2, SignInjection::Copy)
+            }
+            Instruction::FsgnjnS { dest, src1, src2 } => {
+                self.sign_inject(F32, dest, src1, src2, SignInjection::Negate)
+            }
+            Instruction::FsgnjxS { dest, src1, src2 } => {
+                self.sign_inject(F32, dest, src1, src2, SignInjection::Xor)
+            }
+            Instruction::FsgnjD { dest, src1, src2 } => {
+                self.sign_inject(F64, dest, src1, src2, SignInjection::Copy)
+            }
+            Instruction::FsgnjnD { dest, src1, src2 } => {
+                self.sign_inject(F64, dest, src1, src2, SignInjection::Negate)
+            }
+            Instruction::FsgnjxD { dest, src1, src2 } => {
+                self.sign_inject(F64, dest, src1, src2, SignInjection::Xor)
+            }
+            Instruction::FminS { dest, src1, src2 } => {
+                self.min_max(F32, dest, src1, src2, false, &mut flags)
+            }
+            Instruction::FmaxS { dest, src1, src2 } => {
+                self.min_max(F32, dest, src1, src2, true, &mut flags)
+            }
+            Instruction::FminD { dest, src1, src2 } => {
+                self.min_max(F64, dest, src1, src2, false, &mut flags)
+            }
+            Instruction::FmaxD { dest, src1, src2 } => {
+                self.min_max(F64, dest, src1, src2, true, &mut flags)
+            }
+            Instruction::FcvtWS { dest, src, rm } => {
+                self.float_to_int(F32, dest, src, rm, true, 32, &mut flags)?
+            }
+            Instruction::FcvtWuS { dest, src, rm } => {
+                self.float_to_int(F32, dest, src, rm, false, 32, &mut flags)?
+            }
+            Instruction::FcvtLS { dest, src, rm } => {
+                self.float_to_int(F32, dest, src, rm, true, 64, &mut flags)?
+            }
+            Instruction::FcvtLuS { dest, src, rm } => {
+                self.float_to_int(F32, dest, src, rm, false, 64, &mut flags)?
+            }
+            Instruction::FcvtWD { dest, src1, rm } => {
+                self.float_to_int(F64, dest, src1, rm, true, 32, &mut flags)?
+            }
+            Instruction::FcvtWuD { dest, src1, rm } => {
+                self.float_to_int(F64, dest, src1, rm, false, 32, &mut flags)?
+            }
+            Instruction::FcvtLD { dest, src1, rm } => {
+                self.float_to_int(F64, dest, src1, rm, true, 64, &mut flags)?
+            }
+            Instruction::FcvtLuD { dest, src1, rm } => {
+                self.float_to_int(F64, dest, src1, rm, false, 64, &mut flags)?
+            }
+            Instruction::FcvtSW { dest, src, rm } => {
+                self.int_to_float(F32, dest, src, rm, true, 32, &mut flags)?
+            }
+            Instruction::FcvtSWu { dest, src, rm } => {
+                self.int_to_float(F32, dest, src, rm, false, 32, &mut flags)?
+            }
+            Instruction::FcvtSL { dest, src, rm } => {
+                self.int_to_float(F32, dest, src, rm, true, 64, &mut flags)?
+            }
+            Instruction::FcvtSLu { dest, src, rm } => {
+                self.int_to_float(F32, dest, src, rm, false, 64, &mut flags)?
+            }
+            Instruction::FcvtDW { dest, src1, rm } => {
+                self.int_to_float(F64, dest, src1, rm, true, 32, &mut flags)?
+            }
+            Instruction::FcvtDWu { dest, src1, rm } => {
+                self.int_to_float(F64, dest, src1, rm, false, 32, &mut flags)?
+            }
+            Instruction::FcvtDL { dest, src, rm } => {
+                self.int_to_float(F64, dest, src, rm, true, 64, &mut flags)?
+            }
+            Instruction::FcvtDLu { dest, src, rm } => {
+                self.int_to_float(F64, dest, src, rm, false, 64, &mut flags)?
+            }
+            Instruction::FcvtSD { dest, src, rm } => {
+                let rm = self.rounding_mode(rm)?;
+                let value = softfloat::convert(F64, F32, self.read_float(F64, src), rm, &mut flags);
+                self.write_float(F32, dest, value);
+            }
+            Instruction::FcvtDS { dest, src, rm } => {
+                let rm = self.rounding_mode(rm)?;
+                let value = softfloat::convert(F32, F64, self.read_float(F32, src), rm, &mut flags);
+                self.write_float(F64, dest, value);
+            }
+            // the moves copy bits without checking or applying the NaN-boxing of their source
+            Instruction::FmvXW { dest, src } => self.set_x(dest, word(self.f(src))),
+            Instruction::FmvWX { dest, src } => {
+                self.write_float(F32, dest, self.x(src) as u32 as u64)
+            }
+            Instruction::FmvXD { dest, src } => self.set_x(dest, self.f(src)),
+            Instruction::FmvDX { dest, src } => self.set_f(dest, self.x(src)),
+            Instruction::FeqS { dest, src1, src2 } => {
+                self.compare(F32, dest, src1, src2, softfloat::eq, &mut flags)
+            }
+            Instruction::FltS { dest, src1, src2 } => {
+                self.compare(F32, dest, src1, src2, softfloat::lt, &mut flags)
+            }
+            Instruction::FleS { dest, src1, src2 } => {
+                self.compare(F32, dest, src1, src2, softfloat::le, &mut flags)
+            }
+            Instruction::FeqD { dest, src1, src2 } => {
+                self.compare(F64, dest, src1, src2, softfloat::eq, &mut flags)
+            }
+            Instruction::FltD { dest, src1, src2 } => {
+                self.compare(F64, dest, src1, src2, softfloat::lt, &mut flags)
+            }
+            Instruction::FleD { dest, src1, src2 } => {
+                self.compare(F64, dest, src1, src2, softfloat::le, &mut flags)
+            }
+            Instruction::FclassS { dest, src } => {
+                self.set_x(dest, softfloat::classify(F32, self.read_float(F32, src)))
+            }
+            Instruction::FclassD { dest, src1 } => {
+                self.set_x(dest, softfloat::classify(F64, self.read_float(F64, src1)))
+            }
+            Instruction::Csrrw { dest, src, csr } => {
+                let csr = csr.val() as u16;
+                self.check_csr(csr, true)?;
+                let value = self.x(src);
+                // csrrw with rd = zero doesn't read the CSR
+                let old = if dest == IRegister::Zero {
+                    0
+                } else {
+                    self.read_csr(csr)?
+                };
+                self.write_csr(csr, value)?;
+                self.set_x(dest, old);
+            }
+            Instruction::Csrrs { dest, src, csr } => self.csr_set_clear(
+                dest,
+                csr.val() as u16,
+                self.x(src),
+                src != IRegister::Zero,
+                true,
+            )?,
+            Instruction::Csrrc { dest, src, csr } => self.csr_set_clear(
+                dest,
+                csr.val() as u16,
+                self.x(src),
+                src != IRegister::Zero,
+                false,
+            )?,
+            Instruction::Csrrwi { dest, imm, csr } => {
+                let csr = csr.val() as u16;
+                self.check_csr(csr, true)?;
+                let old = if dest == IRegister::Zero {
+                    0
+                } else {
+                    self.read_csr(csr)?
+                };
+                self.write_csr(csr, imm.val() as u64)?;
+                self.set_x(dest, old);
+            }
+            Instruction::Csrrsi { dest, imm, csr } => self.csr_set_clear(
+                dest,
+                csr.val() as u16,
+                imm.val() as u64,
+                imm.val() != 0,
+                true,
+            )?,
+            Instruction::Csrrci { dest, imm, csr } => self.csr_set_clear(
+                dest,
+                csr.val() as u16,
+                imm.val() as u64,
+                imm.val() != 0,
+                false,
+            )?,
+            _ => return Err(unsupported(instruction.mnemonic())),
+        }
+        self.fcsr |= flags;
+        if float {
+            self.csrs.mstatus |= FS_DIRTY;
+        }
+        self.retire(next);
+        Ok(())
+    }
+
+    /// Checks that a privileged instruction can be executed in the current mode, where `trap` is the `mstatus` bit
+    /// that makes it illegal in S-mode
+    fn require(&self, privilege: Privilege, trap: u64, mnemonic: &str) -> Result<(), Exception> {
+        if self.privilege < privilege
+            || self.privilege == Privilege::Supervisor && self.csrs.mstatus & trap != 0
+        {
+            Err(Exception::IllegalInstruction(format!(
+                "{mnemonic} can't be executed in {:?} mode",
+                self.privilege
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn address(&self, base: IRegister, offset: i64) -> u64 {
+        self.x(base).wrapping_add_signed(offset)
+    }
+
+    /// Reads a float operand, replacing single precision values that aren't NaN-boxed with the canonical NaN
+    fn read_float(&self, fmt: Format, r: FRegister) -> u64 {
+        let value = self.f(r);
+        if fmt == F32 {
+            if value >> 32 == 0xffff_ffff {
+                value & 0xffff_ffff
+            } else {
+                F32.canonical_nan()
+            }
+        } else {
+            value
+        }
+    }
+
+    fn write_float(&mut self, fmt: Format, r: FRegister, value: u64) {
+        if fmt == F32 {
+            self.set_f(r, 0xffff_ffff_0000_0000 | value);
+        } else {
+            self.set_f(r, value);
+        }
+    }
+
+    /// Resolves the dynamic rounding mode, which is illegal if `frm` holds a reserved value
+    fn rounding_mode(&self, rm: RoundingMode) -> Result<RoundingMode, Exception> {
+        softfloat::resolve_rounding_mode(rm, self.fcsr >> 5).ok_or_else(|| {
+            Exception::IllegalInstruction(format!(
+                "frm holds the reserved rounding mode {}",
+                self.fcsr >> 5
+            ))
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn arithmetic(
+        &mut self,
+        fmt: Format,
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        rm: RoundingMode,
+        op: fn(Format, u64, u64, RoundingMode, &mut u8) -> u64,
+        flags: &mut u8,
+    ) -> Result<(), Exception> {
+        let rm = self.rounding_mode(rm)?;
+        let value = op(
+            fmt,
+            self.read_float(fmt, src1),
+            self.read_float(fmt, src2),
+            rm,
+            flags,
+        );
+        self.write_float(fmt, dest, value);
+        Ok(())
+    }
+
+    /// Executes the fused multiply-add instructions, which negate the product and/or the addend
+    #[allow(clippy::too_many_arguments)]
+    fn fused(
+        &mut self,
+        fmt: Format,
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        src3: FRegister,
+        rm: RoundingMode,
+        negate_product: bool,
+        negate_addend: bool,
+        flags: &mut u8,
+    ) -> Result<(), Exception> {
+        let rm = self.rounding_mode(rm)?;
+        let negate = |value: u64, negate: bool| {
+            if negate {
+                softfloat::sign_inject(fmt, value, value, SignInjection::Negate)
+            } else {
+                value
+            }
+        };
+        let value = softfloat::fma(
+            fmt,
+            negate(self.read_float(fmt, src1), negate_product),
+            self.read_float(fmt, src2),
+            negate(self.read_float(fmt, src3), negate_addend),
+            rm,
+            flags,
+        );
+        self.write_float(fmt, dest, value);
+        Ok(())
+    }
+
+    fn sign_inject(
+        &mut self,
+        fmt: Format,
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        op: SignInjection,
+    ) {
+        let value = softfloat::sign_inject(
+            fmt,
+            self.read_float(fmt, src1),
+            self.read_float(fmt, src2),
+            op,
+        );
+        self.write_float(fmt, dest, value);
+    }
+
+    fn min_max(
+        &mut self,
+        fmt: Format,
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        max: bool,
+        flags: &mut u8,
+    ) {
+        let value = softfloat::min_max(
+            fmt,
+            self.read_float(fmt, src1),
+            self.read_float(fmt, src2),
+            max,
+            flags,
+        );
+        self.write_float(fmt, dest, value);
+    }
+
+    fn compare(
+        &mut self,
+        fmt: Format,
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+        op: fn(Format, u64, u64, &mut u8) -> bool,
+        flags: &mut u8,
+    ) {
+        let result = op(
+            fmt,
+            self.read_float(fmt, src1),
+            self.read_float(fmt, src2),
+            flags,
+        );
+        self.set_x(dest, result as u64);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn float_to_int(
+        &mut self,
+        fmt: Format,
+        dest: IRegister,
+        src: FRegister,
+        rm: RoundingMode,
+        signed: bool,
+        width: u32,
+        flags: &mut u8,
+    ) -> Result<(), Exception> {
+        let rm = self.rounding_mode(rm)?;
+        let value = softfloat::to_int(fmt, self.read_float(fmt, src), signed, width, rm, flags);
+        self.set_x(dest, value);
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn int_to_float(
+        &mut self,
+        fmt: Format,
+        dest: FRegister,
+        src: IRegister,
+        rm: RoundingMode,
+        signed: bool,
+        width: u32,
+        flags: &mut u8,
+    ) -> Result<(), Exception> {
+        let rm = self.rounding_mode(rm)?;
+        let value = softfloat::from_int(fmt, self.x(src), signed, width, rm, flags);
+        self.write_float(fmt, dest, value);
+        Ok(())
+    }
+
+    fn load_reserved<M: Memory + ?Sized>(
+        &mut self,
+        memory: &mut M,
+        dest: IRegister,
+        addr: IRegister,
+        width: u8,
+    ) -> Result<(), Exception> {
+        let address = self.x(addr);
+        if !address.is_multiple_of(width as u64) {
+            return Err(Exception::LoadAddressMisaligned(address));
+        }
+        let value = load(memory, address, width)?;
+        self.set_x(dest, sign_extend(value, width));
+        self.reservation = Some(address);
+        Ok(())
+    }
+
+    /// Stores if the address is still reserved, writing 0 to `dest` on success and 1 on failure
+    fn store_conditional<M: Memory + ?Sized>(
+        &mut self,
+        memory: &mut M,
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        width: u8,
+    ) -> Result<(), Exception> {
+        let address = self.x(addr);
+        if !address.is_multiple_of(width as u64) {
+            return Err(Exception::StoreAddressMisaligned(address));
+        }
+        let success = self.reservation == Some(address);
+        if success {
+            store(memory, address, width, self.x(src))?;
+        }
+        self.reservation = None;
+        self.set_x(dest, !success as u64);
+        Ok(())
+    }
+
+    fn amo<M: Memory + ?Sized>(
+        &mut self,
+        memory: &mut M,
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        width: u8,
+        op: AmoOp,
+    ) -> Result<(), Exception> {
+        let address = self.x(addr);
+        if !address.is_multiple_of(width as u64) {
+            return Err(Exception::StoreAddressMisaligned(address));
+        }
+        // atomics report failed loads as store faults
+        let old = sign_extend(
+            memory
+                .read(address, width)
+                .ok_or(Exception::StoreAccessFault(address))?,
+            width,
+        );
+        let value = sign_extend(self.x(src), width);
+        let unsigned = |x: u64| if width == 4 { x as u32 as u64 } else { x };
+        let new = match op {
+            AmoOp::Swap => value,
+            AmoOp::Add => old.wrapping_add(value),
+            AmoOp::Xor => old ^ value,
+            AmoOp::And => old & value,
+            AmoOp::Or => old | value,
+            AmoOp::Min => (old as i64).min(value as i64) as u64,
+            AmoOp::Max => (old as i64).max(value as i64) as u64,
+            AmoOp::MinU if unsigned(old) < unsigned(value) => old,
+            AmoOp::MaxU if unsigned(old) > unsigned(value) => old,
+            AmoOp::MinU | AmoOp::MaxU => value,
+        };
+        store(memory, address, width, new)?;
+        self.set_x(dest, old);
+        Ok(())
+    }
+
+    /// Checks that a CSR exists and can be accessed from the current privilege mode
+    fn check_csr(&self, csr: u16, write: bool) -> Result<(), Exception> {
+        self.read_csr(csr)?;
+        let illegal = |reason: &str| {
+            Err(Exception::IllegalInstruction(format!(
+                "CSR {csr:#05x} {reason}"
+            )))
+        };
+        if write && csr >> 10 == 0b11 {
+            return illegal("is read-only");
+        }
+        // the privilege needed is in bits 9:8 of the address
+        if (self.privilege as u16) < csr >> 8 & 0b11 {
+            return illegal(&format!("can't be accessed in {:?} mode", self.privilege));
+        }
+        let counter = match csr {
+            CYCLE | TIME | INSTRET | 0xc03..=0xc1f => Some(csr - CYCLE),
+            _ => None,
+        };
+        if let Some(counter) = counter {
+            let enabled = match self.privilege {
+                Privilege::Machine => true,
+                Privilege::Supervisor => self.csrs.mcounteren >> counter & 1 == 1,
+                Privilege::User => {
+                    (self.csrs.mcounteren & self.csrs.scounteren) >> counter & 1 == 1
+                }
+            };
+            if !enabled {
+                return illegal(&format!("isn't enabled in {:?} mode", self.privilege));
+            }
+        }
+        if matches!(csr, FFLAGS | FRM | FCSR) && self.csrs.mstatus & MSTATUS_FS == 0 {
+            return illegal("can't be accessed while the floating point unit is off");
+        }
+        if csr == SATP
+            && self.privilege == Privilege::Supervisor
+            && self.csrs.mstatus & MSTATUS_TVM != 0
+        {
+            return illegal("is trapped by mstatus.TVM");
+        }
+        Ok(())
+    }
+
+    fn read_csr(&self, csr: u16) -> Result<u64, Exception> {
+        let status = self.csrs.mstatus | MSTATUS_XL;
+        let status = if status & MSTATUS_FS == FS_DIRTY {
+            status | MSTATUS_SD
+        } else {
+            status
+        };
+        match csr {
+            FFLAGS => Ok((self.fcsr & 0x1f) as u64),
+            FRM => Ok((self.fcsr >> 5) as u64),
+            FCSR => Ok(self.fcsr as u64),
+            CYCLE | TIME | INSTRET | MCYCLE | MINSTRET => Ok(self.instret),
+            SSTATUS => Ok(status & (SSTATUS_WRITABLE | SSTATUS_UXL | MSTATUS_SD)),
+            SIE => Ok(self.csrs.mie & self.csrs.mideleg),
+            STVEC => Ok(self.csrs.stvec),
+            SCOUNTEREN => Ok(self.csrs.scounteren),
+            SSCRATCH => Ok(self.csrs.sscratch),
+            SEPC => Ok(self.csrs.sepc),
+            SCAUSE => Ok(self.csrs.scause),
+            STVAL => Ok(self.csrs.stval),
+            SIP => Ok(self.csrs.mip & self.csrs.mideleg),
+            // only bare addressing is supported
+            SATP => Ok(0),
+            MSTATUS => Ok(status),
+            MISA => Ok(MISA_VALUE),
+            MEDELEG => Ok(self.csrs.medeleg),
+            MIDELEG => Ok(self.csrs.mideleg),
+            MIE => Ok(self.csrs.mie),
+            MTVEC => Ok(self.csrs.mtvec),
+            MCOUNTEREN => Ok(self.csrs.mcounteren),
+            MSCRATCH => Ok(self.csrs.mscratch),
+            MEPC => Ok(self.csrs.mepc),
+            MCAUSE => Ok(self.csrs.mcause),
+            MTVAL => Ok(self.csrs.mtval),
+            MIP => Ok(self.csrs.mip),
+            // the performance monitoring counters and events are hardwired to zero, and can't be inhibited
+            0xc03..=0xc1f | 0xb03..=0xb1f | 0x323..=0x33f | MCOUNTINHIBIT => Ok(0),
+            // there are no PMP entries and no optional features to enable
+            0x3a0..=0x3ef | MENVCFG | SENVCFG => Ok(0),
+            MVENDORID | MARCHID | MIMPID | MHARTID | MCONFIGPTR => Ok(0),
+            _ => Err(unsupported_csr(csr)),
+        }
+    }
+
+    /// Writes a CSR, legalizing the value written to WARL fields
+    fn write_csr(&mut self, csr: u16, value: u64) -> Result<(), Exception> {
+        let csrs = &mut self.csrs;
+        match csr {
+            FFLAGS => self.fcsr = self.fcsr & !0x1f | value as u8 & 0x1f,
+            FRM => self.fcsr = self.fcsr & 0x1f | (value as u8 & 0b111) << 5,
+            FCSR => self.fcsr = value as u8,
+            // the write takes effect after the instruction retires
+            MCYCLE | MINSTRET => self.instret = value.wrapping_sub(1),
+            SSTATUS => csrs.mstatus = csrs.mstatus & !SSTATUS_WRITABLE | value & SSTATUS_WRITABLE,
+            SIE => {
+                let mask = csrs.mideleg & SUPERVISOR_INTERRUPTS;
+                csrs.mie = csrs.mie & !mask | value & mask;
+            }
+            STVEC => csrs.stvec = legalize_tvec(csrs.stvec, value),
+            SCOUNTEREN => csrs.scounteren = value & 0b111,
+            SSCRATCH => csrs.sscratch = value,
+            SEPC => csrs.sepc = value & !1,
+            SCAUSE => csrs.scause = value,
+            STVAL => csrs.stval = value,
+            // only the supervisor software interrupt can be raised from S-mode
+            SIP => {
+                let mask = csrs.mideleg & 1 << 1;
+                csrs.mip = csrs.mip & !mask | value & mask;
+            }
+            // writes selecting an unsupported translation mode are ignored
+            SATP => {}
+            MSTATUS => {
+                let mut value = value & MSTATUS_WRITABLE;
+                if Privilege::from_bits(value >> 11).is_none() {
+                    value = value & !MSTATUS_MPP | csrs.mstatus & MSTATUS_MPP;
+                }
+                csrs.mstatus = value;
+            }
+            MISA => {}
+            MEDELEG => csrs.medeleg = value & DELEGABLE_EXCEPTIONS,
+            MIDELEG => csrs.mideleg = value & SUPERVISOR_INTERRUPTS,
+            MIE => csrs.mie = value & INTERRUPTS,
+            MTVEC => csrs.mtvec = legalize_tvec(csrs.mtvec, value),
+            MCOUNTEREN => csrs.mcounteren = value & 0b111,
+            MSCRATCH => csrs.mscratch = value,
+            MEPC => csrs.mepc = value & !1,
+            MCAUSE => csrs.mcause = value,
+            MTVAL => csrs.mtval = value,
+            // the machine interrupts are pending only while a device asserts them
+            MIP => csrs.mip = csrs.mip & !SUPERVISOR_INTERRUPTS | value & SUPERVISOR_INTERRUPTS,
+            0xb03..=0xb1f | 0x323..=0x33f | MCOUNTINHIBIT | 0x3a0..=0x3ef | MENVCFG | SENVCFG => {}
+            _ => return Err(unsupported_csr(csr)),
+        }
+        if matches!(csr, FFLAGS | FRM | FCSR) {
+            self.csrs.mstatus |= FS_DIRTY;
+        }
+        Ok(())
+    }
+
+    /// Executes `csrrs`, `csrrc` and their immediate forms, which only write the CSR if `write` is set
+    fn csr_set_clear(
+        &mut self,
+        dest: IRegister,
+        csr: u16,
+        mask: u64,
+        write: bool,
+        set: bool,
+    ) -> Result<(), Exception> {
+        self.check_csr(csr, write)?;
+        let old = self.read_csr(csr)?;
+        if write {
+            self.write_csr(csr, if set { old | mask } else { old & !mask })?;
+        }
+        self.set_x(dest, old);
+        Ok(())
+    }
+}
+
+/// Sign-extends the low 32 bits
+fn word(value: u64) -> u64 {
+    value as i32 as i64 as u64
+}
+
+fn sign_extend(value: u64, width: u8) -> u64 {
+    if width == 4 { word(value) } else { value }
+}
+
+fn load<M: Memory + ?Sized>(memory: &mut M, address: u64, width: u8) -> Result<u64, Exception> {
+    memory
+        .read(address, width)
+        .ok_or(Exception::LoadAccessFault(address))
+}
+
+fn store<M: Memory + ?Sized>(
+    memory: &mut M,
+    address: u64,
+    width: u8,
+    value: u64,
+) -> Result<(), Exception> {
+    memory
+        .write(address, width, value)
+        .ok_or(Exception::StoreAccessFault(address))
+}
+
+fn unsupported(mnemonic: &str) -> Exception {
+    Exception::IllegalInstruction(format!("{mnemonic} is not supported by the simulator"))
+}
+
+/// Keeps the previous mode of `mtvec` or `stvec` if a reserved mode is written
+fn legalize_tvec(old: u64, value: u64) -> u64 {
+    if value & 0b11 < 2 {
+        value
+    } else {
+        value & !0b11 | old & 0b11
+    }
+}
+
+fn unsupported_csr(csr: u16) -> Exception {
+    Exception::IllegalInstruction(format!("CSR {csr:#05x} is not supported by the simulator"))
+}
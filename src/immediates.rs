@@ -11,6 +11,9 @@ make_immediate!(JImmediate true false (12 8 12) (11 1 20) (1 10 21) (20 1 31));
 make_immediate!(BImmediate true false (11 1 7) (1 4 8) (5 6 25) (12 1 31));
 make_immediate!(Shamt  false false (0 6 20));
 make_immediate!(ShamtW false false (0 5 20));
+make_immediate!(PrefetchImmediate true false (5 7 25));
+make_immediate!(LpadLabel false false (0 20 12));
+make_immediate!(IncrementImmediate true false (0 5 20));
 
 make_immediate!(CWideImmediate false true (3 1 5) (2 1 6) (6 4 7) (4 2 11));
 make_immediate!(CDImmediate false true (6 2 5) (3 3 10));
@@ -24,6 +27,10 @@ make_immediate!(CWSPImmediate false true (6 2 2) (2 3 4) (5 1 12));
 make_immediate!(CSDSPImmediate false true (6 3 7) (3 3 10));
 make_immediate!(CSWSPImmediate false true (6 2 7) (2 4 9));
 make_immediate!(C16SPImmediate true true (5 1 2) (7 2 3) (6 1 5) (4 1 6) (9 1 12));
+make_immediate!(CByteImmediate false true (0 1 6) (1 1 5));
+make_immediate!(CHalfImmediate false true (1 1 5));
+// the stack adjustment of the Zcmp push and pop instructions beyond the space needed for the saved registers
+make_immediate!(CStackAdjImmediate false true (4 2 2));
 
 make_immediate!(CSR false false (0 12 20));
 make_immediate!(CSRImmediate false false (0 5 15));
@@ -1,12 +1,16 @@
 use crate::immediates::{
-    BImmediate, CSR, CSRImmediate, JImmediate, SImmediate, Shamt, ShamtW, UImmediate,
+    BImmediate, CSR, CSRImmediate, JImmediate, LpadLabel, PrefetchImmediate, SImmediate, Shamt,
+    ShamtW, UImmediate,
 };
-use crate::register::{FRegister, IRegister};
+use crate::isa::{IsaConfig, Xlen};
+use crate::register::{FRegister, IRegister, VRegister};
 use crate::{immediates::IImmediate, opcode::Opcode};
 use alloc::borrow::ToOwned;
 use alloc::fmt::{Display, Formatter};
 use alloc::format;
 use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum RoundingMode {
@@ -49,6 +53,7 @@ impl RoundingMode {
             _ => Err("attempted to create invalid rounding mode".to_owned()),
         }
     }
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(x: &str) -> Result<RoundingMode, String> {
         match x {
             "rne" => Ok(RoundingMode::RNE),
@@ -62,11 +67,102 @@ impl RoundingMode {
     }
 
     pub fn to_u32(self) -> u32 {
-        return (self as u32) << 12;
+        (self as u32) << 12
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// The values that can be loaded by the `fli` instructions in the Zfa extension, indexed by the rs1 field.
+/// The entries for `min`, `inf` and `nan` depend on the format and are handled separately.
+const FLI_VALUES: [f64; 32] = [
+    -1.0,
+    0.0,
+    1.52587890625e-5,
+    3.0517578125e-5,
+    0.00390625,
+    0.0078125,
+    0.0625,
+    0.125,
+    0.25,
+    0.3125,
+    0.375,
+    0.4375,
+    0.5,
+    0.625,
+    0.75,
+    0.875,
+    1.0,
+    1.25,
+    1.5,
+    1.75,
+    2.0,
+    2.5,
+    3.0,
+    4.0,
+    8.0,
+    16.0,
+    128.0,
+    256.0,
+    32768.0,
+    65536.0,
+    f64::INFINITY,
+    f64::NAN,
+];
+
+/// A constant loaded by the `fli` instructions
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct FliConstant(u32);
+
+impl Display for FliConstant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), alloc::fmt::Error> {
+        match self.0 {
+            1 => write!(f, "min"),
+            30 => write!(f, "inf"),
+            31 => write!(f, "nan"),
+            x => write!(f, "{:?}", FLI_VALUES[x as usize]),
+        }
+    }
+}
+
+impl FliConstant {
+    pub fn from_int(x: u32) -> Result<FliConstant, String> {
+        if x < 32 {
+            Ok(FliConstant(x))
+        } else {
+            Err("attempted to create invalid fli constant".to_owned())
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(x: &str) -> Result<FliConstant, String> {
+        match x {
+            "min" => Ok(FliConstant(1)),
+            "inf" => Ok(FliConstant(30)),
+            "nan" => Ok(FliConstant(31)),
+            _ => {
+                let value: f64 = x
+                    .parse()
+                    .map_err(|_| format!("unable to parse fli constant: {x}"))?;
+                FLI_VALUES[..30]
+                    .iter()
+                    .enumerate()
+                    .position(|(i, v)| i != 1 && *v == value)
+                    .map(|i| FliConstant(i as u32))
+                    .ok_or(format!("{x} cannot be loaded by fli"))
+            }
+        }
+    }
+
+    /// Returns the index of the constant in the fli table
+    pub fn index(self) -> u32 {
+        self.0
+    }
+
+    pub fn to_u32(self) -> u32 {
+        self.0 << 15
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Instruction {
     //
     // Instructions from RV32I
@@ -967,471 +1063,2128 @@ pub enum Instruction {
         dest: FRegister,
         src: IRegister,
     },
-}
-
-fn aq_rl_suffix(aq: &bool, rl: &bool) -> &'static str {
-    match (aq, rl) {
-        (true, true) => ".aqrl",
-        (true, false) => ".aq",
-        (false, true) => ".rl",
-        (false, false) => "",
-    }
-}
-
-/// puts the aquire bit in the correct location
-fn aqb(aq: bool) -> u32 {
-    if aq { 1 << 26 } else { 0 }
-}
-
-/// puts the release bit in the correct location
-fn rlb(rl: bool) -> u32 {
-    if rl { 1 << 25 } else { 0 }
-}
-
-impl Display for Instruction {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), alloc::fmt::Error> {
-        match self {
-            Instruction::Lui { dest, imm } => write!(f, "lui {dest},{imm}"),
-            Instruction::Auipc { dest, imm } => write!(f, "auipc {dest},{imm}"),
-            Instruction::Jal { dest, offset } => write!(f, "jal {dest},{offset}"),
-            Instruction::Jalr { dest, base, offset } => write!(f, "jalr {dest},{offset}({base})"),
-            Instruction::Beq { src1, src2, offset } => write!(f, "beq {src1},{src2},{offset}"),
-            Instruction::Bne { src1, src2, offset } => write!(f, "bne {src1},{src2},{offset}"),
-            Instruction::Blt { src1, src2, offset } => write!(f, "blt {src1},{src2},{offset}"),
-            Instruction::Bge { src1, src2, offset } => write!(f, "bge {src1},{src2},{offset}"),
-            Instruction::Bltu { src1, src2, offset } => write!(f, "bltu {src1},{src2},{offset}"),
-            Instruction::Bgeu { src1, src2, offset } => write!(f, "bgeu {src1},{src2},{offset}"),
-            Instruction::Lb { dest, base, offset } => write!(f, "lb {dest},{offset}({base})"),
-            Instruction::Lh { dest, base, offset } => write!(f, "lh {dest},{offset}({base})"),
-            Instruction::Lw { dest, base, offset } => write!(f, "lw {dest},{offset}({base})"),
-            Instruction::Lbu { dest, base, offset } => write!(f, "lbu {dest},{offset}({base})"),
-            Instruction::Lhu { dest, base, offset } => write!(f, "lhu {dest},{offset}({base})"),
-            Instruction::Sb { src, base, offset } => write!(f, "sb {src},{offset}({base})"),
-            Instruction::Sh { src, base, offset } => write!(f, "sh {src},{offset}({base})"),
-            Instruction::Sw { src, base, offset } => write!(f, "sw {src},{offset}({base})"),
-            Instruction::Addi { dest, src, imm } => write!(f, "addi {dest},{src},{imm}"),
-            Instruction::Slti { dest, src, imm } => write!(f, "slti {dest},{src},{imm}"),
-            Instruction::Sltiu { dest, src, imm } => write!(f, "sltiu {dest},{src},{imm}"),
-            Instruction::Xori { dest, src, imm } => write!(f, "xori {dest},{src},{imm}"),
-            Instruction::Ori { dest, src, imm } => write!(f, "ori {dest},{src},{imm}"),
-            Instruction::Andi { dest, src, imm } => write!(f, "andi {dest},{src},{imm}"),
-            Instruction::Slli { dest, src, shamt } => write!(f, "slli {dest},{src},{shamt}"),
-            Instruction::Srli { dest, src, shamt } => write!(f, "srli {dest},{src},{shamt}"),
-            Instruction::Srai { dest, src, shamt } => write!(f, "srai {dest},{src},{shamt}"),
-            Instruction::Add { dest, src1, src2 } => write!(f, "add {dest},{src1},{src2}"),
-            Instruction::Sub { dest, src1, src2 } => write!(f, "sub {dest},{src1},{src2}"),
-            Instruction::Sll { dest, src1, src2 } => write!(f, "sll {dest},{src1},{src2}"),
-            Instruction::Slt { dest, src1, src2 } => write!(f, "slt {dest},{src1},{src2}"),
-            Instruction::Sltu { dest, src1, src2 } => write!(f, "sltu {dest},{src1},{src2}"),
-            Instruction::Xor { dest, src1, src2 } => write!(f, "xor {dest},{src1},{src2}"),
-            Instruction::Srl { dest, src1, src2 } => write!(f, "srl {dest},{src1},{src2}"),
-            Instruction::Sra { dest, src1, src2 } => write!(f, "sra {dest},{src1},{src2}"),
-            Instruction::Or { dest, src1, src2 } => write!(f, "or {dest},{src1},{src2}"),
-            Instruction::And { dest, src1, src2 } => write!(f, "and {dest},{src1},{src2}"),
-            Instruction::Fence { .. } => write!(f, "{}", self.fmt_fence()),
-            Instruction::Ecall => write!(f, "ecall"),
-            Instruction::Ebreak => write!(f, "ebreak"),
-            Instruction::Lwu { dest, base, offset } => write!(f, "lwu {dest},{offset}({base})"),
-            Instruction::Ld { dest, base, offset } => write!(f, "ld {dest},{offset}({base})"),
-            Instruction::Sd { src, base, offset } => write!(f, "sd {src},{offset}({base})"),
-            Instruction::Addiw { dest, src, imm } => write!(f, "addiw {dest},{src},{imm}"),
-            Instruction::Slliw { dest, src, shamt } => write!(f, "slliw {dest},{src},{shamt}"),
-            Instruction::Srliw { dest, src, shamt } => write!(f, "srliw {dest},{src},{shamt}"),
-            Instruction::Sraiw { dest, src, shamt } => write!(f, "sraiw {dest},{src},{shamt}"),
-            Instruction::Addw { dest, src1, src2 } => write!(f, "addw {dest},{src1},{src2}"),
-            Instruction::Subw { dest, src1, src2 } => write!(f, "subw {dest},{src1},{src2}"),
-            Instruction::Sllw { dest, src1, src2 } => write!(f, "sllw {dest},{src1},{src2}"),
-            Instruction::Srlw { dest, src1, src2 } => write!(f, "srlw {dest},{src1},{src2}"),
-            Instruction::Sraw { dest, src1, src2 } => write!(f, "sraw {dest},{src1},{src2}"),
-            Instruction::Mul { dest, src1, src2 } => write!(f, "mul {dest},{src1},{src2}"),
-            Instruction::Mulh { dest, src1, src2 } => write!(f, "mulh {dest},{src1},{src2}"),
-            Instruction::Mulhsu { dest, src1, src2 } => write!(f, "mulhsu {dest},{src1},{src2}"),
-            Instruction::Mulhu { dest, src1, src2 } => write!(f, "mulhu {dest},{src1},{src2}"),
-            Instruction::Div { dest, src1, src2 } => write!(f, "div {dest},{src1},{src2}"),
-            Instruction::Divu { dest, src1, src2 } => write!(f, "divu {dest},{src1},{src2}"),
-            Instruction::Rem { dest, src1, src2 } => write!(f, "rem {dest},{src1},{src2}"),
-            Instruction::Remu { dest, src1, src2 } => write!(f, "remu {dest},{src1},{src2}"),
-            Instruction::Mulw { dest, src1, src2 } => write!(f, "mulw {dest},{src1},{src2}"),
-            Instruction::Divw { dest, src1, src2 } => write!(f, "divw {dest},{src1},{src2}"),
-            Instruction::Divuw { dest, src1, src2 } => write!(f, "divuw {dest},{src1},{src2}"),
-            Instruction::Remw { dest, src1, src2 } => write!(f, "remw {dest},{src1},{src2}"),
-            Instruction::Remuw { dest, src1, src2 } => write!(f, "remuw {dest},{src1},{src2}"),
-            Instruction::LrW { dest, addr, aq, rl } => {
-                write!(f, "lr.w{} {dest},{addr}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::ScW {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "sc.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmoswapW {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amoswap.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmoaddW {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amoadd.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmoxorW {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amoxor.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmoandW {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amoand.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmoorW {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amoor.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmominW {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amomin.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmomaxW {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amomax.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmominuW {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amominu.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmomaxuW {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amomaxu.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::LrD { dest, addr, aq, rl } => {
-                write!(f, "lr.d{} {dest},{addr}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::ScD {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "sc.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmoswapD {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amoswap.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmoaddD {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amoadd.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmoxorD {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amoxor.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmoandD {
-                dest,
-                addr,
-                src,
-                aq,
-                rl,
-            } => {
-                write!(f, "amoand.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
-            }
-            Instruction::AmoorD {
-                dest,
+    //
+    // Instructions in Q Extension
+    //
+    Flq {
+        dest: FRegister,
+        base: IRegister,
+        offset: IImmediate,
+    },
+    Fsq {
+        src: FRegister,
+        base: IRegister,
+        offset: SImmediate,
+    },
+    FmaddQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        src3: FRegister,
+        rm: RoundingMode,
+    },
+    FmsubQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        src3: FRegister,
+        rm: RoundingMode,
+    },
+    FnmsubQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        src3: FRegister,
+        rm: RoundingMode,
+    },
+    FnmaddQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        src3: FRegister,
+        rm: RoundingMode,
+    },
+    FaddQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        rm: RoundingMode,
+    },
+    FsubQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        rm: RoundingMode,
+    },
+    FmulQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        rm: RoundingMode,
+    },
+    FdivQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+        rm: RoundingMode,
+    },
+    FsqrtQ {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FsgnjQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FsgnjnQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FsgnjxQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FminQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FmaxQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FcvtSQ {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FcvtQS {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FcvtDQ {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FcvtQD {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FeqQ {
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FltQ {
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FleQ {
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FclassQ {
+        dest: IRegister,
+        src: FRegister,
+    },
+    FcvtWQ {
+        dest: IRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FcvtWuQ {
+        dest: IRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FcvtQW {
+        dest: FRegister,
+        src: IRegister,
+        rm: RoundingMode,
+    },
+    FcvtQWu {
+        dest: FRegister,
+        src: IRegister,
+        rm: RoundingMode,
+    },
+    //
+    // Instructions in Q Extension (RV64)
+    //
+    FcvtLQ {
+        dest: IRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FcvtLuQ {
+        dest: IRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FcvtQL {
+        dest: FRegister,
+        src: IRegister,
+        rm: RoundingMode,
+    },
+    FcvtQLu {
+        dest: FRegister,
+        src: IRegister,
+        rm: RoundingMode,
+    },
+    //
+    // Instructions in Zfa Extension
+    //
+    FliS {
+        dest: FRegister,
+        imm: FliConstant,
+    },
+    FliD {
+        dest: FRegister,
+        imm: FliConstant,
+    },
+    FliH {
+        dest: FRegister,
+        imm: FliConstant,
+    },
+    FliQ {
+        dest: FRegister,
+        imm: FliConstant,
+    },
+    FminmS {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FminmD {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FminmH {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FminmQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FmaxmS {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FmaxmD {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FmaxmH {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FmaxmQ {
+        dest: FRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FroundS {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FroundD {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FroundH {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FroundQ {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FroundnxS {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FroundnxD {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FroundnxH {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    FroundnxQ {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    /// Convert double to word with modular arithmetic, always uses the rtz rounding mode
+    FcvtmodWD {
+        dest: IRegister,
+        src: FRegister,
+    },
+    FleqS {
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FleqD {
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FleqH {
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FleqQ {
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FltqS {
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FltqD {
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FltqH {
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    FltqQ {
+        dest: IRegister,
+        src1: FRegister,
+        src2: FRegister,
+    },
+    //
+    // Instructions in Zfa Extension (RV32)
+    //
+    /// Move the upper half of a double to an integer register
+    FmvhXD {
+        dest: IRegister,
+        src: FRegister,
+    },
+    /// Move a pair of integer registers to a double
+    FmvpDX {
+        dest: FRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+    //
+    // Instructions in Zfbfmin Extension
+    //
+    /// Convert single to bfloat16
+    FcvtBf16S {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    /// Convert bfloat16 to single
+    FcvtSBf16 {
+        dest: FRegister,
+        src: FRegister,
+        rm: RoundingMode,
+    },
+    //
+    // Instructions in Zvfbfmin Extension
+    //
+    /// Narrowing convert from single to bfloat16
+    Vfncvtbf16FFW {
+        dest: VRegister,
+        src: VRegister,
+        masked: bool,
+    },
+    /// Widening convert from bfloat16 to single
+    Vfwcvtbf16FFV {
+        dest: VRegister,
+        src: VRegister,
+        masked: bool,
+    },
+    //
+    // Instructions in Zvfbfwma Extension
+    //
+    /// Widening bfloat16 multiply accumulate (vector-vector)
+    Vfwmaccbf16VV {
+        dest: VRegister,
+        src1: VRegister,
+        src2: VRegister,
+        masked: bool,
+    },
+    /// Widening bfloat16 multiply accumulate (vector-scalar)
+    Vfwmaccbf16VF {
+        dest: VRegister,
+        src1: FRegister,
+        src2: VRegister,
+        masked: bool,
+    },
+    //
+    // Privileged Instructions
+    //
+    /// Return from supervisor mode trap
+    Sret,
+    /// Return from machine mode trap
+    Mret,
+    /// Wait for interrupt
+    Wfi,
+    /// Supervisor memory-management fence
+    SfenceVma {
+        vaddr: IRegister,
+        asid: IRegister,
+    },
+    //
+    // Instructions in Svinval Extension
+    //
+    SinvalVma {
+        vaddr: IRegister,
+        asid: IRegister,
+    },
+    SfenceWInval,
+    SfenceInvalIr,
+    //
+    // Instructions in Smrnmi Extension
+    //
+    /// Return from resumable non-maskable interrupt
+    Mnret,
+    //
+    // Debug Mode Instructions
+    //
+    /// Return from debug mode
+    Dret,
+    //
+    // Instructions in H Extension
+    //
+    HfenceVvma {
+        vaddr: IRegister,
+        asid: IRegister,
+    },
+    HfenceGvma {
+        gaddr: IRegister,
+        vmid: IRegister,
+    },
+    HinvalVvma {
+        vaddr: IRegister,
+        asid: IRegister,
+    },
+    HinvalGvma {
+        gaddr: IRegister,
+        vmid: IRegister,
+    },
+    HlvB {
+        dest: IRegister,
+        addr: IRegister,
+    },
+    HlvBu {
+        dest: IRegister,
+        addr: IRegister,
+    },
+    HlvH {
+        dest: IRegister,
+        addr: IRegister,
+    },
+    HlvHu {
+        dest: IRegister,
+        addr: IRegister,
+    },
+    HlvxHu {
+        dest: IRegister,
+        addr: IRegister,
+    },
+    HlvW {
+        dest: IRegister,
+        addr: IRegister,
+    },
+    HlvxWu {
+        dest: IRegister,
+        addr: IRegister,
+    },
+    HsvB {
+        src: IRegister,
+        addr: IRegister,
+    },
+    HsvH {
+        src: IRegister,
+        addr: IRegister,
+    },
+    HsvW {
+        src: IRegister,
+        addr: IRegister,
+    },
+    //
+    // Instructions in H Extension (RV64)
+    //
+    HlvWu {
+        dest: IRegister,
+        addr: IRegister,
+    },
+    HlvD {
+        dest: IRegister,
+        addr: IRegister,
+    },
+    HsvD {
+        src: IRegister,
+        addr: IRegister,
+    },
+    //
+    // Instructions in Zicbom Extension
+    //
+    CboClean {
+        base: IRegister,
+    },
+    CboFlush {
+        base: IRegister,
+    },
+    CboInval {
+        base: IRegister,
+    },
+    //
+    // Instructions in Zicboz Extension
+    //
+    CboZero {
+        base: IRegister,
+    },
+    //
+    // Instructions in Zicbop Extension
+    //
+    PrefetchI {
+        base: IRegister,
+        offset: PrefetchImmediate,
+    },
+    PrefetchR {
+        base: IRegister,
+        offset: PrefetchImmediate,
+    },
+    PrefetchW {
+        base: IRegister,
+        offset: PrefetchImmediate,
+    },
+    //
+    // Instructions in Zacas Extension
+    //
+    AmocasW {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmocasD {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    //
+    // Instructions in Zacas Extension (RV64)
+    //
+    AmocasQ {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    //
+    // Instructions in Zabha Extension
+    //
+    AmoswapB {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmoaddB {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmoxorB {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmoandB {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmoorB {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmominB {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmomaxB {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmominuB {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmomaxuB {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmocasB {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmoswapH {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmoaddH {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmoxorH {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmoandH {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmoorH {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmominH {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmomaxH {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmominuH {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmomaxuH {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    AmocasH {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    //
+    // Instructions in Zawrs Extension
+    //
+    /// Wait for a reservation set to be invalidated, with no timeout
+    WrsNto,
+    /// Wait for a reservation set to be invalidated, with a short timeout
+    WrsSto,
+    //
+    // Instructions in Zalasr Extension
+    //
+    LbAq {
+        dest: IRegister,
+        addr: IRegister,
+        rl: bool,
+    },
+    LhAq {
+        dest: IRegister,
+        addr: IRegister,
+        rl: bool,
+    },
+    LwAq {
+        dest: IRegister,
+        addr: IRegister,
+        rl: bool,
+    },
+    SbRl {
+        src: IRegister,
+        addr: IRegister,
+        aq: bool,
+    },
+    ShRl {
+        src: IRegister,
+        addr: IRegister,
+        aq: bool,
+    },
+    SwRl {
+        src: IRegister,
+        addr: IRegister,
+        aq: bool,
+    },
+    //
+    // Instructions in Zalasr Extension (RV64)
+    //
+    LdAq {
+        dest: IRegister,
+        addr: IRegister,
+        rl: bool,
+    },
+    SdRl {
+        src: IRegister,
+        addr: IRegister,
+        aq: bool,
+    },
+    //
+    // Instructions in Zicond Extension
+    //
+    CzeroEqz {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+    CzeroNez {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+    //
+    // Instructions in Zicfilp Extension
+    //
+    /// Landing pad for indirect jumps, the label is checked against the upper bits of t2
+    Lpad {
+        label: LpadLabel,
+    },
+    //
+    // Instructions in Zicfiss Extension
+    //
+    /// Pushes ra or t0 to the shadow stack
+    Sspush {
+        src: IRegister,
+    },
+    /// Pops from the shadow stack and checks that the value matches ra or t0
+    Sspopchk {
+        src: IRegister,
+    },
+    /// Reads the shadow stack pointer
+    Ssrdp {
+        dest: IRegister,
+    },
+    SsamoswapW {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    //
+    // Instructions in Zicfiss Extension (RV64)
+    //
+    SsamoswapD {
+        dest: IRegister,
+        addr: IRegister,
+        src: IRegister,
+        aq: bool,
+        rl: bool,
+    },
+    //
+    // Instructions in Zimop Extension
+    //
+    /// The may-be-operation `mop.r.n`, where `n` is between 0 and 31
+    MopR {
+        n: u8,
+        dest: IRegister,
+        src: IRegister,
+    },
+    /// The may-be-operation `mop.rr.n`, where `n` is between 0 and 7
+    MopRr {
+        n: u8,
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+}
+
+fn mask_suffix(masked: &bool) -> &'static str {
+    if *masked { ",v0.t" } else { "" }
+}
+
+fn aq_rl_suffix(aq: &bool, rl: &bool) -> &'static str {
+    match (aq, rl) {
+        (true, true) => ".aqrl",
+        (true, false) => ".aq",
+        (false, true) => ".rl",
+        (false, false) => "",
+    }
+}
+
+/// Returns true if the register is one of the link registers (ra and t0) that the shadow stack instructions accept
+pub(crate) fn is_link_register(r: &IRegister) -> bool {
+    *r == IRegister::ReturnAddress || *r == IRegister::T0
+}
+
+/// Returns the name of the Zihintntl hint encoded as `add x0,x0,src` (or `c.add x0,src`), if there is one
+pub(crate) fn ntl_hint(src: &IRegister) -> Option<&'static str> {
+    match src {
+        IRegister::StackPointer => Some("p1"),
+        IRegister::GlobalPointer => Some("pall"),
+        IRegister::ThreadPointer => Some("s1"),
+        IRegister::T0 => Some("all"),
+        _ => None,
+    }
+}
+
+/// puts the aquire bit in the correct location
+fn aqb(aq: bool) -> u32 {
+    if aq { 1 << 26 } else { 0 }
+}
+
+/// puts the release bit in the correct location
+fn rlb(rl: bool) -> u32 {
+    if rl { 1 << 25 } else { 0 }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), alloc::fmt::Error> {
+        self.fmt_with(f, false)
+    }
+}
+
+impl Instruction {
+    /// Formats the instruction, writing float register operands with integer register names if `integer_floats` is
+    /// set.
+    fn fmt_with(
+        &self,
+        f: &mut Formatter<'_>,
+        integer_floats: bool,
+    ) -> Result<(), alloc::fmt::Error> {
+        let float = |r: FRegister| FloatOperand(r, integer_floats);
+        match self {
+            Instruction::Lui { dest, imm } => write!(f, "lui {dest},{imm}"),
+            Instruction::Auipc { dest, imm } => write!(f, "auipc {dest},{imm}"),
+            Instruction::Jal { dest, offset } => write!(f, "jal {dest},{offset}"),
+            Instruction::Jalr { dest, base, offset } => write!(f, "jalr {dest},{offset}({base})"),
+            Instruction::Beq { src1, src2, offset } => write!(f, "beq {src1},{src2},{offset}"),
+            Instruction::Bne { src1, src2, offset } => write!(f, "bne {src1},{src2},{offset}"),
+            Instruction::Blt { src1, src2, offset } => write!(f, "blt {src1},{src2},{offset}"),
+            Instruction::Bge { src1, src2, offset } => write!(f, "bge {src1},{src2},{offset}"),
+            Instruction::Bltu { src1, src2, offset } => write!(f, "bltu {src1},{src2},{offset}"),
+            Instruction::Bgeu { src1, src2, offset } => write!(f, "bgeu {src1},{src2},{offset}"),
+            Instruction::Lb { dest, base, offset } => write!(f, "lb {dest},{offset}({base})"),
+            Instruction::Lh { dest, base, offset } => write!(f, "lh {dest},{offset}({base})"),
+            Instruction::Lw { dest, base, offset } => write!(f, "lw {dest},{offset}({base})"),
+            Instruction::Lbu { dest, base, offset } => write!(f, "lbu {dest},{offset}({base})"),
+            Instruction::Lhu { dest, base, offset } => write!(f, "lhu {dest},{offset}({base})"),
+            Instruction::Sb { src, base, offset } => write!(f, "sb {src},{offset}({base})"),
+            Instruction::Sh { src, base, offset } => write!(f, "sh {src},{offset}({base})"),
+            Instruction::Sw { src, base, offset } => write!(f, "sw {src},{offset}({base})"),
+            Instruction::Addi { dest, src, imm } => write!(f, "addi {dest},{src},{imm}"),
+            Instruction::Slti { dest, src, imm } => write!(f, "slti {dest},{src},{imm}"),
+            Instruction::Sltiu { dest, src, imm } => write!(f, "sltiu {dest},{src},{imm}"),
+            Instruction::Xori { dest, src, imm } => write!(f, "xori {dest},{src},{imm}"),
+            Instruction::Ori { dest, src, imm } => write!(f, "ori {dest},{src},{imm}"),
+            Instruction::Andi { dest, src, imm } => write!(f, "andi {dest},{src},{imm}"),
+            Instruction::Slli { dest, src, shamt } => write!(f, "slli {dest},{src},{shamt}"),
+            Instruction::Srli { dest, src, shamt } => write!(f, "srli {dest},{src},{shamt}"),
+            Instruction::Srai { dest, src, shamt } => write!(f, "srai {dest},{src},{shamt}"),
+            Instruction::Add {
+                dest: IRegister::Zero,
+                src1: IRegister::Zero,
+                src2,
+            } if ntl_hint(src2).is_some() => write!(f, "ntl.{}", ntl_hint(src2).unwrap()),
+            Instruction::Add { dest, src1, src2 } => write!(f, "add {dest},{src1},{src2}"),
+            Instruction::Sub { dest, src1, src2 } => write!(f, "sub {dest},{src1},{src2}"),
+            Instruction::Sll { dest, src1, src2 } => write!(f, "sll {dest},{src1},{src2}"),
+            Instruction::Slt { dest, src1, src2 } => write!(f, "slt {dest},{src1},{src2}"),
+            Instruction::Sltu { dest, src1, src2 } => write!(f, "sltu {dest},{src1},{src2}"),
+            Instruction::Xor { dest, src1, src2 } => write!(f, "xor {dest},{src1},{src2}"),
+            Instruction::Srl { dest, src1, src2 } => write!(f, "srl {dest},{src1},{src2}"),
+            Instruction::Sra { dest, src1, src2 } => write!(f, "sra {dest},{src1},{src2}"),
+            Instruction::Or { dest, src1, src2 } => write!(f, "or {dest},{src1},{src2}"),
+            Instruction::And { dest, src1, src2 } => write!(f, "and {dest},{src1},{src2}"),
+            Instruction::Fence { .. } => write!(f, "{}", self.fmt_fence()),
+            Instruction::Ecall => write!(f, "ecall"),
+            Instruction::Ebreak => write!(f, "ebreak"),
+            Instruction::Lwu { dest, base, offset } => write!(f, "lwu {dest},{offset}({base})"),
+            Instruction::Ld { dest, base, offset } => write!(f, "ld {dest},{offset}({base})"),
+            Instruction::Sd { src, base, offset } => write!(f, "sd {src},{offset}({base})"),
+            Instruction::Addiw { dest, src, imm } => write!(f, "addiw {dest},{src},{imm}"),
+            Instruction::Slliw { dest, src, shamt } => write!(f, "slliw {dest},{src},{shamt}"),
+            Instruction::Srliw { dest, src, shamt } => write!(f, "srliw {dest},{src},{shamt}"),
+            Instruction::Sraiw { dest, src, shamt } => write!(f, "sraiw {dest},{src},{shamt}"),
+            Instruction::Addw { dest, src1, src2 } => write!(f, "addw {dest},{src1},{src2}"),
+            Instruction::Subw { dest, src1, src2 } => write!(f, "subw {dest},{src1},{src2}"),
+            Instruction::Sllw { dest, src1, src2 } => write!(f, "sllw {dest},{src1},{src2}"),
+            Instruction::Srlw { dest, src1, src2 } => write!(f, "srlw {dest},{src1},{src2}"),
+            Instruction::Sraw { dest, src1, src2 } => write!(f, "sraw {dest},{src1},{src2}"),
+            Instruction::Mul { dest, src1, src2 } => write!(f, "mul {dest},{src1},{src2}"),
+            Instruction::Mulh { dest, src1, src2 } => write!(f, "mulh {dest},{src1},{src2}"),
+            Instruction::Mulhsu { dest, src1, src2 } => write!(f, "mulhsu {dest},{src1},{src2}"),
+            Instruction::Mulhu { dest, src1, src2 } => write!(f, "mulhu {dest},{src1},{src2}"),
+            Instruction::Div { dest, src1, src2 } => write!(f, "div {dest},{src1},{src2}"),
+            Instruction::Divu { dest, src1, src2 } => write!(f, "divu {dest},{src1},{src2}"),
+            Instruction::Rem { dest, src1, src2 } => write!(f, "rem {dest},{src1},{src2}"),
+            Instruction::Remu { dest, src1, src2 } => write!(f, "remu {dest},{src1},{src2}"),
+            Instruction::Mulw { dest, src1, src2 } => write!(f, "mulw {dest},{src1},{src2}"),
+            Instruction::Divw { dest, src1, src2 } => write!(f, "divw {dest},{src1},{src2}"),
+            Instruction::Divuw { dest, src1, src2 } => write!(f, "divuw {dest},{src1},{src2}"),
+            Instruction::Remw { dest, src1, src2 } => write!(f, "remw {dest},{src1},{src2}"),
+            Instruction::Remuw { dest, src1, src2 } => write!(f, "remuw {dest},{src1},{src2}"),
+            Instruction::LrW { dest, addr, aq, rl } => {
+                write!(f, "lr.w{} {dest},{addr}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::ScW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "sc.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoswapW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoswap.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoaddW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoadd.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoxorW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoxor.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoandW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoand.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoorW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoor.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmominW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amomin.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmomaxW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amomax.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmominuW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amominu.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmomaxuW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amomaxu.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::LrD { dest, addr, aq, rl } => {
+                write!(f, "lr.d{} {dest},{addr}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::ScD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "sc.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoswapD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoswap.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoaddD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoadd.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoxorD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoxor.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoandD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoand.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoorD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoor.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmominD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amomin.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmomaxD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amomax.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmominuD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amominu.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmomaxuD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amomaxu.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::Flw { dest, base, offset } => {
+                let dest = float(*dest);
+                write!(f, "flw {dest},{offset}({base})")
+            }
+            Instruction::Fsw { base, src, offset } => {
+                let src = float(*src);
+                write!(f, "fsw {src},{offset}({base})")
+            }
+            Instruction::FmaddS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fmadd.s {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FmsubS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fmsub.s {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FnmsubS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fnmsub.s {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FnmaddS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fnmadd.s {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FaddS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fadd.s {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FsubS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsub.s {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FmulS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmul.s {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FdivS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fdiv.s {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FsqrtS { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fsqrt.s {dest},{src},{rm}")
+            }
+            Instruction::FsgnjS { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsgnj.s {dest},{src1},{src2}")
+            }
+            Instruction::FsgnjnS { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsgnjn.s {dest},{src1},{src2}")
+            }
+            Instruction::FsgnjxS { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsgnjx.s {dest},{src1},{src2}")
+            }
+            Instruction::FminS { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmin.s {dest},{src1},{src2}")
+            }
+            Instruction::FmaxS { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmax.s {dest},{src1},{src2}")
+            }
+            Instruction::FcvtWS { dest, src, rm } => {
+                let src = float(*src);
+                write!(f, "fcvt.w.s {dest},{src},{rm}")
+            }
+            Instruction::FcvtWuS { dest, src, rm } => {
+                let src = float(*src);
+                write!(f, "fcvt.wu.s {dest},{src},{rm}")
+            }
+            Instruction::FmvXW { dest, src } => {
+                let src = float(*src);
+                write!(f, "fmv.x.w {dest},{src}")
+            }
+            Instruction::FeqS { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "feq.s {dest},{src1},{src2}")
+            }
+            Instruction::FltS { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "flt.s {dest},{src1},{src2}")
+            }
+            Instruction::FleS { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "fle.s {dest},{src1},{src2}")
+            }
+            Instruction::FclassS { dest, src } => {
+                let src = float(*src);
+                write!(f, "fclass.s {dest},{src}")
+            }
+            Instruction::FcvtSW { dest, src, rm } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.s.w {dest},{src},{rm}")
+            }
+            Instruction::FcvtSWu { dest, src, rm } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.s.wu {dest},{src},{rm}")
+            }
+            Instruction::FmvWX { dest, src } => {
+                let dest = float(*dest);
+                write!(f, "fmv.w.x {dest},{src}")
+            }
+            Instruction::FcvtLS { dest, src, rm } => {
+                let src = float(*src);
+                write!(f, "fcvt.l.s {dest},{src},{rm}")
+            }
+            Instruction::FcvtLuS { dest, src, rm } => {
+                let src = float(*src);
+                write!(f, "fcvt.lu.s {dest},{src},{rm}")
+            }
+            Instruction::FcvtSL { dest, src, rm } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.s.l {dest},{src},{rm}")
+            }
+            Instruction::FcvtSLu { dest, src, rm } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.s.lu {dest},{src},{rm}")
+            }
+            Instruction::Csrrw { dest, src, csr } => write!(f, "csrrw {dest},{csr},{src}"),
+            Instruction::Csrrs { dest, src, csr } => write!(f, "csrrs {dest},{csr},{src}"),
+            Instruction::Csrrc { dest, src, csr } => write!(f, "csrrc {dest},{csr},{src}"),
+            Instruction::Csrrwi { dest, imm, csr } => write!(f, "csrrwi {dest},{csr},{imm}"),
+            Instruction::Csrrsi { dest, imm, csr } => write!(f, "csrrsi {dest},{csr},{imm}"),
+            Instruction::Csrrci { dest, imm, csr } => write!(f, "csrrci {dest},{csr},{imm}"),
+            Instruction::FenceI => write!(f, "fence.i"),
+            Instruction::Fld { dest, base, offset } => {
+                let dest = float(*dest);
+                write!(f, "fld {dest},{offset}({base})")
+            }
+            Instruction::Fsd { src, base, offset } => {
+                let src = float(*src);
+                write!(f, "fsd {src},{offset}({base})")
+            }
+            Instruction::FmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fmadd.d {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fmsub.d {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FnmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fnmadd.d {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FnmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fnmsub.d {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FaddD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fadd.d {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FsubD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsub.d {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FmulD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmul.d {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FdivD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fdiv.d {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FsqrtD {
+                dest,
+                src: src1,
+                rm,
+            } => {
+                let [dest, src1] = [*dest, *src1].map(float);
+                write!(f, "fsqrt.d {dest},{src1},{rm}")
+            }
+            Instruction::FsgnjD { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsgnj.d {dest},{src1},{src2}")
+            }
+            Instruction::FsgnjnD { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsgnjn.d {dest},{src1},{src2}")
+            }
+            Instruction::FsgnjxD { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsgnjx.d {dest},{src1},{src2}")
+            }
+            Instruction::FminD { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmin.d {dest},{src1},{src2}")
+            }
+            Instruction::FmaxD { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmax.d {dest},{src1},{src2}")
+            }
+            Instruction::FcvtSD { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fcvt.s.d {dest},{src},{rm}")
+            }
+            Instruction::FcvtDS { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fcvt.d.s {dest},{src},{rm}")
+            }
+            Instruction::FeqD { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "feq.d {dest},{src1},{src2}")
+            }
+            Instruction::FltD { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "flt.d {dest},{src1},{src2}")
+            }
+            Instruction::FleD { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "fle.d {dest},{src1},{src2}")
+            }
+            Instruction::FclassD { dest, src1 } => {
+                let src1 = float(*src1);
+                write!(f, "fclass.d {dest},{src1}")
+            }
+            Instruction::FcvtWD { dest, src1, rm } => {
+                let src1 = float(*src1);
+                write!(f, "fcvt.w.d {dest},{src1},{rm}")
+            }
+            Instruction::FcvtWuD { dest, src1, rm } => {
+                let src1 = float(*src1);
+                write!(f, "fcvt.wu.d {dest},{src1},{rm}")
+            }
+            Instruction::FcvtDW { dest, src1, rm } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.d.w {dest},{src1},{rm}")
+            }
+            Instruction::FcvtDWu { dest, src1, rm } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.d.wu {dest},{src1},{rm}")
+            }
+            Instruction::FcvtLD { dest, src1, rm } => {
+                let src1 = float(*src1);
+                write!(f, "fcvt.l.d {dest},{src1},{rm}")
+            }
+            Instruction::FcvtLuD { dest, src1, rm } => {
+                let src1 = float(*src1);
+                write!(f, "fcvt.lu.d {dest},{src1},{rm}")
+            }
+            Instruction::FmvXD { dest, src } => {
+                let src = float(*src);
+                write!(f, "fmv.x.d {dest},{src}")
+            }
+            Instruction::FcvtDL {
+                dest,
+                src: src1,
+                rm,
+            } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.d.l {dest},{src1},{rm}")
+            }
+            Instruction::FcvtDLu {
+                dest,
+                src: src1,
+                rm,
+            } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.d.lu {dest},{src1},{rm}")
+            }
+            Instruction::FmvDX { dest, src } => {
+                let dest = float(*dest);
+                write!(f, "fmv.d.x {dest},{src}")
+            }
+            Instruction::Flq { dest, base, offset } => {
+                let dest = float(*dest);
+                write!(f, "flq {dest},{offset}({base})")
+            }
+            Instruction::Fsq { src, base, offset } => {
+                let src = float(*src);
+                write!(f, "fsq {src},{offset}({base})")
+            }
+            Instruction::FmaddQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fmadd.q {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FmsubQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fmsub.q {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FnmsubQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fnmsub.q {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FnmaddQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                let [dest, src1, src2, src3] = [*dest, *src1, *src2, *src3].map(float);
+                write!(f, "fnmadd.q {dest},{src1},{src2},{src3},{rm}")
+            }
+            Instruction::FaddQ {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fadd.q {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FsubQ {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsub.q {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FmulQ {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmul.q {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FdivQ {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fdiv.q {dest},{src1},{src2},{rm}")
+            }
+            Instruction::FsqrtQ { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fsqrt.q {dest},{src},{rm}")
+            }
+            Instruction::FsgnjQ { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsgnj.q {dest},{src1},{src2}")
+            }
+            Instruction::FsgnjnQ { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsgnjn.q {dest},{src1},{src2}")
+            }
+            Instruction::FsgnjxQ { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fsgnjx.q {dest},{src1},{src2}")
+            }
+            Instruction::FminQ { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmin.q {dest},{src1},{src2}")
+            }
+            Instruction::FmaxQ { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmax.q {dest},{src1},{src2}")
+            }
+            Instruction::FcvtSQ { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fcvt.s.q {dest},{src},{rm}")
+            }
+            Instruction::FcvtQS { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fcvt.q.s {dest},{src},{rm}")
+            }
+            Instruction::FcvtDQ { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fcvt.d.q {dest},{src},{rm}")
+            }
+            Instruction::FcvtQD { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fcvt.q.d {dest},{src},{rm}")
+            }
+            Instruction::FeqQ { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "feq.q {dest},{src1},{src2}")
+            }
+            Instruction::FltQ { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "flt.q {dest},{src1},{src2}")
+            }
+            Instruction::FleQ { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "fle.q {dest},{src1},{src2}")
+            }
+            Instruction::FclassQ { dest, src } => {
+                let src = float(*src);
+                write!(f, "fclass.q {dest},{src}")
+            }
+            Instruction::FcvtWQ { dest, src, rm } => {
+                let src = float(*src);
+                write!(f, "fcvt.w.q {dest},{src},{rm}")
+            }
+            Instruction::FcvtWuQ { dest, src, rm } => {
+                let src = float(*src);
+                write!(f, "fcvt.wu.q {dest},{src},{rm}")
+            }
+            Instruction::FcvtQW { dest, src, rm } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.q.w {dest},{src},{rm}")
+            }
+            Instruction::FcvtQWu { dest, src, rm } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.q.wu {dest},{src},{rm}")
+            }
+            Instruction::FcvtLQ { dest, src, rm } => {
+                let src = float(*src);
+                write!(f, "fcvt.l.q {dest},{src},{rm}")
+            }
+            Instruction::FcvtLuQ { dest, src, rm } => {
+                let src = float(*src);
+                write!(f, "fcvt.lu.q {dest},{src},{rm}")
+            }
+            Instruction::FcvtQL { dest, src, rm } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.q.l {dest},{src},{rm}")
+            }
+            Instruction::FcvtQLu { dest, src, rm } => {
+                let dest = float(*dest);
+                write!(f, "fcvt.q.lu {dest},{src},{rm}")
+            }
+            Instruction::FliS { dest, imm } => {
+                let dest = float(*dest);
+                write!(f, "fli.s {dest},{imm}")
+            }
+            Instruction::FliD { dest, imm } => {
+                let dest = float(*dest);
+                write!(f, "fli.d {dest},{imm}")
+            }
+            Instruction::FliH { dest, imm } => {
+                let dest = float(*dest);
+                write!(f, "fli.h {dest},{imm}")
+            }
+            Instruction::FliQ { dest, imm } => {
+                let dest = float(*dest);
+                write!(f, "fli.q {dest},{imm}")
+            }
+            Instruction::FminmS { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fminm.s {dest},{src1},{src2}")
+            }
+            Instruction::FminmD { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fminm.d {dest},{src1},{src2}")
+            }
+            Instruction::FminmH { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fminm.h {dest},{src1},{src2}")
+            }
+            Instruction::FminmQ { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fminm.q {dest},{src1},{src2}")
+            }
+            Instruction::FmaxmS { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmaxm.s {dest},{src1},{src2}")
+            }
+            Instruction::FmaxmD { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmaxm.d {dest},{src1},{src2}")
+            }
+            Instruction::FmaxmH { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmaxm.h {dest},{src1},{src2}")
+            }
+            Instruction::FmaxmQ { dest, src1, src2 } => {
+                let [dest, src1, src2] = [*dest, *src1, *src2].map(float);
+                write!(f, "fmaxm.q {dest},{src1},{src2}")
+            }
+            Instruction::FroundS { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fround.s {dest},{src},{rm}")
+            }
+            Instruction::FroundD { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fround.d {dest},{src},{rm}")
+            }
+            Instruction::FroundH { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fround.h {dest},{src},{rm}")
+            }
+            Instruction::FroundQ { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fround.q {dest},{src},{rm}")
+            }
+            Instruction::FroundnxS { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "froundnx.s {dest},{src},{rm}")
+            }
+            Instruction::FroundnxD { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "froundnx.d {dest},{src},{rm}")
+            }
+            Instruction::FroundnxH { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "froundnx.h {dest},{src},{rm}")
+            }
+            Instruction::FroundnxQ { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "froundnx.q {dest},{src},{rm}")
+            }
+            Instruction::FcvtmodWD { dest, src } => {
+                let src = float(*src);
+                write!(f, "fcvtmod.w.d {dest},{src},rtz")
+            }
+            Instruction::FleqS { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "fleq.s {dest},{src1},{src2}")
+            }
+            Instruction::FleqD { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "fleq.d {dest},{src1},{src2}")
+            }
+            Instruction::FleqH { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "fleq.h {dest},{src1},{src2}")
+            }
+            Instruction::FleqQ { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "fleq.q {dest},{src1},{src2}")
+            }
+            Instruction::FltqS { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "fltq.s {dest},{src1},{src2}")
+            }
+            Instruction::FltqD { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "fltq.d {dest},{src1},{src2}")
+            }
+            Instruction::FltqH { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "fltq.h {dest},{src1},{src2}")
+            }
+            Instruction::FltqQ { dest, src1, src2 } => {
+                let [src1, src2] = [*src1, *src2].map(float);
+                write!(f, "fltq.q {dest},{src1},{src2}")
+            }
+            Instruction::FmvhXD { dest, src } => {
+                let src = float(*src);
+                write!(f, "fmvh.x.d {dest},{src}")
+            }
+            Instruction::FmvpDX { dest, src1, src2 } => {
+                let dest = float(*dest);
+                write!(f, "fmvp.d.x {dest},{src1},{src2}")
+            }
+            Instruction::FcvtBf16S { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fcvt.bf16.s {dest},{src},{rm}")
+            }
+            Instruction::FcvtSBf16 { dest, src, rm } => {
+                let [dest, src] = [*dest, *src].map(float);
+                write!(f, "fcvt.s.bf16 {dest},{src},{rm}")
+            }
+            Instruction::Vfncvtbf16FFW { dest, src, masked } => {
+                write!(f, "vfncvtbf16.f.f.w {dest},{src}{}", mask_suffix(masked))
+            }
+            Instruction::Vfwcvtbf16FFV { dest, src, masked } => {
+                write!(f, "vfwcvtbf16.f.f.v {dest},{src}{}", mask_suffix(masked))
+            }
+            Instruction::Vfwmaccbf16VV {
+                dest,
+                src1,
+                src2,
+                masked,
+            } => write!(
+                f,
+                "vfwmaccbf16.vv {dest},{src1},{src2}{}",
+                mask_suffix(masked)
+            ),
+            Instruction::Vfwmaccbf16VF {
+                dest,
+                src1,
+                src2,
+                masked,
+            } => {
+                let src1 = float(*src1);
+                write!(
+                    f,
+                    "vfwmaccbf16.vf {dest},{src1},{src2}{}",
+                    mask_suffix(masked)
+                )
+            }
+            Instruction::Sret => write!(f, "sret"),
+            Instruction::Mret => write!(f, "mret"),
+            Instruction::Wfi => write!(f, "wfi"),
+            Instruction::SfenceVma { vaddr, asid } => write!(f, "sfence.vma {vaddr},{asid}"),
+            Instruction::SinvalVma { vaddr, asid } => write!(f, "sinval.vma {vaddr},{asid}"),
+            Instruction::SfenceWInval => write!(f, "sfence.w.inval"),
+            Instruction::SfenceInvalIr => write!(f, "sfence.inval.ir"),
+            Instruction::Mnret => write!(f, "mnret"),
+            Instruction::Dret => write!(f, "dret"),
+            Instruction::HfenceVvma { vaddr, asid } => write!(f, "hfence.vvma {vaddr},{asid}"),
+            Instruction::HfenceGvma { gaddr, vmid } => write!(f, "hfence.gvma {gaddr},{vmid}"),
+            Instruction::HinvalVvma { vaddr, asid } => write!(f, "hinval.vvma {vaddr},{asid}"),
+            Instruction::HinvalGvma { gaddr, vmid } => write!(f, "hinval.gvma {gaddr},{vmid}"),
+            Instruction::HlvB { dest, addr } => write!(f, "hlv.b {dest},({addr})"),
+            Instruction::HlvBu { dest, addr } => write!(f, "hlv.bu {dest},({addr})"),
+            Instruction::HlvH { dest, addr } => write!(f, "hlv.h {dest},({addr})"),
+            Instruction::HlvHu { dest, addr } => write!(f, "hlv.hu {dest},({addr})"),
+            Instruction::HlvxHu { dest, addr } => write!(f, "hlvx.hu {dest},({addr})"),
+            Instruction::HlvW { dest, addr } => write!(f, "hlv.w {dest},({addr})"),
+            Instruction::HlvxWu { dest, addr } => write!(f, "hlvx.wu {dest},({addr})"),
+            Instruction::HlvWu { dest, addr } => write!(f, "hlv.wu {dest},({addr})"),
+            Instruction::HlvD { dest, addr } => write!(f, "hlv.d {dest},({addr})"),
+            Instruction::HsvB { src, addr } => write!(f, "hsv.b {src},({addr})"),
+            Instruction::HsvH { src, addr } => write!(f, "hsv.h {src},({addr})"),
+            Instruction::HsvW { src, addr } => write!(f, "hsv.w {src},({addr})"),
+            Instruction::HsvD { src, addr } => write!(f, "hsv.d {src},({addr})"),
+            Instruction::CboClean { base } => write!(f, "cbo.clean ({base})"),
+            Instruction::CboFlush { base } => write!(f, "cbo.flush ({base})"),
+            Instruction::CboInval { base } => write!(f, "cbo.inval ({base})"),
+            Instruction::CboZero { base } => write!(f, "cbo.zero ({base})"),
+            Instruction::PrefetchI { base, offset } => write!(f, "prefetch.i {offset}({base})"),
+            Instruction::PrefetchR { base, offset } => write!(f, "prefetch.r {offset}({base})"),
+            Instruction::PrefetchW { base, offset } => write!(f, "prefetch.w {offset}({base})"),
+            Instruction::AmocasW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amocas.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmocasD {
+                dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                write!(f, "amoor.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+                write!(f, "amocas.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
             }
-            Instruction::AmominD {
+            Instruction::AmocasQ {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                write!(f, "amomin.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+                write!(f, "amocas.q{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
             }
-            Instruction::AmomaxD {
+            Instruction::AmoswapB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                write!(f, "amomax.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+                write!(f, "amoswap.b{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
             }
-            Instruction::AmominuD {
+            Instruction::AmoaddB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                write!(f, "amominu.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+                write!(f, "amoadd.b{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
             }
-            Instruction::AmomaxuD {
+            Instruction::AmoxorB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                write!(f, "amomaxu.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+                write!(f, "amoxor.b{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
             }
-            Instruction::Flw { dest, base, offset } => write!(f, "flw {dest},{offset}({base})"),
-            Instruction::Fsw { base, src, offset } => write!(f, "fsw {src},{offset}({base})"),
-            Instruction::FmaddS {
+            Instruction::AmoandB {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
+                addr,
+                src,
+                aq,
+                rl,
             } => {
-                write!(f, "fmadd.s {dest},{src1},{src2},{src3},{rm}")
+                write!(f, "amoand.b{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
             }
-            Instruction::FmsubS {
+            Instruction::AmoorB {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
+                addr,
+                src,
+                aq,
+                rl,
             } => {
-                write!(f, "fmsub.s {dest},{src1},{src2},{src3},{rm}")
+                write!(f, "amoor.b{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
             }
-            Instruction::FnmsubS {
+            Instruction::AmominB {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
+                addr,
+                src,
+                aq,
+                rl,
             } => {
-                write!(f, "fnmsub.s {dest},{src1},{src2},{src3},{rm}")
+                write!(f, "amomin.b{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
             }
-            Instruction::FnmaddS {
+            Instruction::AmomaxB {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
+                addr,
+                src,
+                aq,
+                rl,
             } => {
-                write!(f, "fnmadd.s {dest},{src1},{src2},{src3},{rm}")
+                write!(f, "amomax.b{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
             }
-            Instruction::FaddS {
+            Instruction::AmominuB {
                 dest,
-                src1,
-                src2,
-                rm,
-            } => write!(f, "fadd.s {dest},{src1},{src2},{rm}"),
-            Instruction::FsubS {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amominu.b{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmomaxuB {
                 dest,
-                src1,
-                src2,
-                rm,
-            } => write!(f, "fsub.s {dest},{src1},{src2},{rm}"),
-            Instruction::FmulS {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amomaxu.b{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmocasB {
                 dest,
-                src1,
-                src2,
-                rm,
-            } => write!(f, "fmul.s {dest},{src1},{src2},{rm}"),
-            Instruction::FdivS {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amocas.b{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoswapH {
                 dest,
-                src1,
-                src2,
-                rm,
-            } => write!(f, "fdiv.s {dest},{src1},{src2},{rm}"),
-            Instruction::FsqrtS { dest, src, rm } => write!(f, "fsqrt.s {dest},{src},{rm}"),
-            Instruction::FsgnjS { dest, src1, src2 } => write!(f, "fsgnj.s {dest},{src1},{src2}"),
-            Instruction::FsgnjnS { dest, src1, src2 } => write!(f, "fsgnjn.s {dest},{src1},{src2}"),
-            Instruction::FsgnjxS { dest, src1, src2 } => write!(f, "fsgnjx.s {dest},{src1},{src2}"),
-            Instruction::FminS { dest, src1, src2 } => write!(f, "fmin.s {dest},{src1},{src2}"),
-            Instruction::FmaxS { dest, src1, src2 } => write!(f, "fmax.s {dest},{src1},{src2}"),
-            Instruction::FcvtWS { dest, src, rm } => write!(f, "fcvt.w.s {dest},{src},{rm}"),
-            Instruction::FcvtWuS { dest, src, rm } => write!(f, "fcvt.wu.s {dest},{src},{rm}"),
-            Instruction::FmvXW { dest, src } => write!(f, "fmv.x.w {dest},{src}"),
-            Instruction::FeqS { dest, src1, src2 } => write!(f, "feq.s {dest},{src1},{src2}"),
-            Instruction::FltS { dest, src1, src2 } => write!(f, "flt.s {dest},{src1},{src2}"),
-            Instruction::FleS { dest, src1, src2 } => write!(f, "fle.s {dest},{src1},{src2}"),
-            Instruction::FclassS { dest, src } => write!(f, "fclass.s {dest},{src}"),
-            Instruction::FcvtSW { dest, src, rm } => write!(f, "fcvt.s.w {dest},{src},{rm}"),
-            Instruction::FcvtSWu { dest, src, rm } => write!(f, "fcvt.s.wu {dest},{src},{rm}"),
-            Instruction::FmvWX { dest, src } => write!(f, "fmv.w.x {dest},{src}"),
-            Instruction::FcvtLS { dest, src, rm } => write!(f, "fcvt.l.s {dest},{src},{rm}"),
-            Instruction::FcvtLuS { dest, src, rm } => write!(f, "fcvt.lu.s {dest},{src},{rm}"),
-            Instruction::FcvtSL { dest, src, rm } => write!(f, "fcvt.s.l {dest},{src},{rm}"),
-            Instruction::FcvtSLu { dest, src, rm } => write!(f, "fcvt.s.lu {dest},{src},{rm}"),
-            Instruction::Csrrw { dest, src, csr } => write!(f, "csrrw {dest},{csr},{src}"),
-            Instruction::Csrrs { dest, src, csr } => write!(f, "csrrs {dest},{csr},{src}"),
-            Instruction::Csrrc { dest, src, csr } => write!(f, "csrrc {dest},{csr},{src}"),
-            Instruction::Csrrwi { dest, imm, csr } => write!(f, "csrrwi {dest},{csr},{imm}"),
-            Instruction::Csrrsi { dest, imm, csr } => write!(f, "csrrsi {dest},{csr},{imm}"),
-            Instruction::Csrrci { dest, imm, csr } => write!(f, "csrrci {dest},{csr},{imm}"),
-            Instruction::FenceI => write!(f, "fence.i"),
-            Instruction::Fld { dest, base, offset } => write!(f, "fld {dest},{offset}({base})"),
-            Instruction::Fsd { src, base, offset } => write!(f, "fsd {src},{offset}({base})"),
-            Instruction::FmaddD {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoswap.h{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoaddH {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
-            } => write!(f, "fmadd.d {dest},{src1},{src2},{src3},{rm}"),
-            Instruction::FmsubD {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoadd.h{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoxorH {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
-            } => write!(f, "fmsub.d {dest},{src1},{src2},{src3},{rm}"),
-            Instruction::FnmaddD {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoxor.h{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoandH {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
-            } => write!(f, "fnmadd.d {dest},{src1},{src2},{src3},{rm}"),
-            Instruction::FnmsubD {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoand.h{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmoorH {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
-            } => write!(f, "fnmsub.d {dest},{src1},{src2},{src3},{rm}"),
-            Instruction::FaddD {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amoor.h{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmominH {
                 dest,
-                src1,
-                src2,
-                rm,
-            } => write!(f, "fadd.d {dest},{src1},{src2},{rm}"),
-            Instruction::FsubD {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amomin.h{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmomaxH {
                 dest,
-                src1,
-                src2,
-                rm,
-            } => write!(f, "fsub.d {dest},{src1},{src2},{rm}"),
-            Instruction::FmulD {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amomax.h{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmominuH {
                 dest,
-                src1,
-                src2,
-                rm,
-            } => write!(f, "fmul.d {dest},{src1},{src2},{rm}"),
-            Instruction::FdivD {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amominu.h{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmomaxuH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amomaxu.h{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::AmocasH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "amocas.h{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::WrsNto => write!(f, "wrs.nto"),
+            Instruction::WrsSto => write!(f, "wrs.sto"),
+            Instruction::LbAq { dest, addr, rl } => {
+                write!(f, "lb{} {dest},{addr}", aq_rl_suffix(&true, rl))
+            }
+            Instruction::LhAq { dest, addr, rl } => {
+                write!(f, "lh{} {dest},{addr}", aq_rl_suffix(&true, rl))
+            }
+            Instruction::LwAq { dest, addr, rl } => {
+                write!(f, "lw{} {dest},{addr}", aq_rl_suffix(&true, rl))
+            }
+            Instruction::LdAq { dest, addr, rl } => {
+                write!(f, "ld{} {dest},{addr}", aq_rl_suffix(&true, rl))
+            }
+            Instruction::SbRl { src, addr, aq } => {
+                write!(f, "sb{} {src},{addr}", aq_rl_suffix(aq, &true))
+            }
+            Instruction::ShRl { src, addr, aq } => {
+                write!(f, "sh{} {src},{addr}", aq_rl_suffix(aq, &true))
+            }
+            Instruction::SwRl { src, addr, aq } => {
+                write!(f, "sw{} {src},{addr}", aq_rl_suffix(aq, &true))
+            }
+            Instruction::SdRl { src, addr, aq } => {
+                write!(f, "sd{} {src},{addr}", aq_rl_suffix(aq, &true))
+            }
+            Instruction::CzeroEqz { dest, src1, src2 } => {
+                write!(f, "czero.eqz {dest},{src1},{src2}")
+            }
+            Instruction::CzeroNez { dest, src1, src2 } => {
+                write!(f, "czero.nez {dest},{src1},{src2}")
+            }
+            Instruction::Lpad { label } => write!(f, "lpad {label}"),
+            Instruction::MopR { n, dest, src } => write!(f, "mop.r.{n} {dest},{src}"),
+            Instruction::MopRr {
+                n,
                 dest,
                 src1,
                 src2,
-                rm,
-            } => write!(f, "fdiv.d {dest},{src1},{src2},{rm}"),
-            Instruction::FsqrtD {
-                dest,
-                src: src1,
-                rm,
-            } => write!(f, "fsqrt.d {dest},{src1},{rm}"),
-            Instruction::FsgnjD { dest, src1, src2 } => write!(f, "fsgnj.d {dest},{src1},{src2}"),
-            Instruction::FsgnjnD { dest, src1, src2 } => write!(f, "fsgnjn.d {dest},{src1},{src2}"),
-            Instruction::FsgnjxD { dest, src1, src2 } => write!(f, "fsgnjx.d {dest},{src1},{src2}"),
-            Instruction::FminD { dest, src1, src2 } => write!(f, "fmin.d {dest},{src1},{src2}"),
-            Instruction::FmaxD { dest, src1, src2 } => write!(f, "fmax.d {dest},{src1},{src2}"),
-            Instruction::FcvtSD { dest, src, rm } => write!(f, "fcvt.s.d {dest},{src},{rm}"),
-            Instruction::FcvtDS { dest, src, rm } => write!(f, "fcvt.d.s {dest},{src},{rm}"),
-            Instruction::FeqD { dest, src1, src2 } => write!(f, "feq.d {dest},{src1},{src2}"),
-            Instruction::FltD { dest, src1, src2 } => write!(f, "flt.d {dest},{src1},{src2}"),
-            Instruction::FleD { dest, src1, src2 } => write!(f, "fle.d {dest},{src1},{src2}"),
-            Instruction::FclassD { dest, src1 } => write!(f, "fclass.d {dest},{src1}"),
-            Instruction::FcvtWD { dest, src1, rm } => write!(f, "fcvt.w.d {dest},{src1},{rm}"),
-            Instruction::FcvtWuD { dest, src1, rm } => write!(f, "fcvt.wu.d {dest},{src1},{rm}"),
-            Instruction::FcvtDW { dest, src1, rm } => write!(f, "fcvt.d.w {dest},{src1},{rm}"),
-            Instruction::FcvtDWu { dest, src1, rm } => write!(f, "fcvt.d.wu {dest},{src1},{rm}"),
-            Instruction::FcvtLD { dest, src1, rm } => write!(f, "fcvt.l.d {dest},{src1},{rm}"),
-            Instruction::FcvtLuD { dest, src1, rm } => write!(f, "fcvt.lu.d {dest},{src1},{rm}"),
-            Instruction::FmvXD { dest, src } => write!(f, "fmv.x.d {dest},{src}"),
-            Instruction::FcvtDL {
+            } => write!(f, "mop.rr.{n} {dest},{src1},{src2}"),
+            Instruction::Sspush { src } => write!(f, "sspush {src}"),
+            Instruction::Sspopchk { src } => write!(f, "sspopchk {src}"),
+            Instruction::Ssrdp { dest } => write!(f, "ssrdp {dest}"),
+            Instruction::SsamoswapW {
                 dest,
-                src: src1,
-                rm,
-            } => write!(f, "fcvt.d.l {dest},{src1},{rm}"),
-            Instruction::FcvtDLu {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "ssamoswap.w{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
+            Instruction::SsamoswapD {
                 dest,
-                src: src1,
-                rm,
-            } => write!(f, "fcvt.d.lu {dest},{src1},{rm}"),
-            Instruction::FmvDX { dest, src } => write!(f, "fmv.d.x {dest},{src}"),
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                write!(f, "ssamoswap.d{} {dest},{addr},{src}", aq_rl_suffix(aq, rl))
+            }
         }
     }
 }
 
 impl Instruction {
     fn fmt_fence(&self) -> String {
-        if let Instruction::Fence {
-            rd: _,
-            rs1: _,
-            ops,
-            fm,
-        } = *self
-        {
+        if let Instruction::Fence { rd, rs1, ops, fm } = *self {
             let sw = if ops & 0b0000_0001 != 0 { "w" } else { "" };
             let sr = if ops & 0b0000_0010 != 0 { "r" } else { "" };
             let so = if ops & 0b0000_0100 != 0 { "o" } else { "" };
@@ -1440,18 +3193,243 @@ impl Instruction {
             let pr = if ops & 0b0010_0000 != 0 { "r" } else { "" };
             let po = if ops & 0b0100_0000 != 0 { "o" } else { "" };
             let pi = if ops & 0b1000_0000 != 0 { "i" } else { "" };
-            if fm == 0b1000 {
+            if fm == 0 && ops == 0b0001_0000 && rd == IRegister::Zero && rs1 == IRegister::Zero {
+                // Zihintpause
+                "pause".to_owned()
+            } else if fm == 0b1000 {
                 format!("fence.tso {pi}{po}{pr}{pw},{si}{so}{sr}{sw}")
             } else {
                 format!("fence {pi}{po}{pr}{pw},{si}{so}{sr}{sw}")
             }
-        } else {
-            unreachable!();
+        } else {
+            unreachable!();
+        }
+    }
+
+    /// Constructs an `Instruction` from it's machine code representation for the given ISA configuration.
+    ///
+    /// When float instructions use the integer registers, their float operands are given as `FRegister::Integer`.
+    pub fn decode_with(instruction: u32, isa: &IsaConfig) -> Result<Instruction, String> {
+        let i = Instruction::decode_fields(instruction, isa)?;
+        i.check_isa(isa)?;
+        if isa.floats_in_integer_registers() {
+            Ok(i.with_integer_floats())
+        } else {
+            Ok(i)
+        }
+    }
+
+    /// Checks that the instruction can be used with the given ISA configuration.
+    ///
+    /// The register pair operands of `amocas.d` (on RV32) and `amocas.q` must be even registers, `amocas.q` requires
+    /// RV64, and the control-flow integrity instructions require Zicfilp or Zicfiss.
+    ///
+    /// When float instructions use the integer registers (Zfinx, Zdinx, Zhinx), instructions that only make sense with
+    /// a float register file are rejected, and double precision operands on RV32 must be even/odd register pairs.
+    pub fn check_isa(&self, isa: &IsaConfig) -> Result<(), String> {
+        // amocas operands that are wider than a register are held in even/odd register pairs
+        let pair = match self {
+            Instruction::AmocasQ { dest, src, .. } => Some((dest, src)),
+            Instruction::AmocasD { dest, src, .. } if isa.xlen == Xlen::Rv32 => Some((dest, src)),
+            _ => None,
+        };
+        if let Some((dest, src)) = pair
+            && (u32::from(*dest) % 2 != 0 || u32::from(*src) % 2 != 0)
+        {
+            return Err(format!(
+                "{self} requires even registers for the dest and src register pairs"
+            ));
+        }
+        match self {
+            Instruction::AmocasQ { .. } if isa.xlen == Xlen::Rv32 => {
+                return Err(format!("{self} requires RV64"));
+            }
+            Instruction::Lpad { .. } if !isa.zicfilp => {
+                return Err(format!("{self} requires Zicfilp"));
+            }
+            Instruction::Sspush { .. }
+            | Instruction::Sspopchk { .. }
+            | Instruction::Ssrdp { .. }
+            | Instruction::SsamoswapW { .. }
+            | Instruction::SsamoswapD { .. }
+                if !isa.zicfiss =>
+            {
+                return Err(format!("{self} requires Zicfiss"));
+            }
+            _ => {}
+        }
+        if !isa.floats_in_integer_registers() {
+            return Ok(());
+        }
+        match self {
+            Instruction::Flw { .. }
+            | Instruction::Fsw { .. }
+            | Instruction::Fld { .. }
+            | Instruction::Fsd { .. }
+            | Instruction::FmvXW { .. }
+            | Instruction::FmvWX { .. }
+            | Instruction::FmvXD { .. }
+            | Instruction::FmvDX { .. }
+            | Instruction::FliS { .. }
+            | Instruction::FliD { .. }
+            | Instruction::FliH { .. }
+            | Instruction::FmvhXD { .. }
+            | Instruction::FmvpDX { .. }
+            | Instruction::FcvtBf16S { .. }
+            | Instruction::FcvtSBf16 { .. }
+            | Instruction::Vfwmaccbf16VF { .. } => Err(format!(
+                "{self} requires the float registers, which are not present with Zfinx"
+            )),
+            Instruction::Flq { .. }
+            | Instruction::Fsq { .. }
+            | Instruction::FmaddQ { .. }
+            | Instruction::FmsubQ { .. }
+            | Instruction::FnmsubQ { .. }
+            | Instruction::FnmaddQ { .. }
+            | Instruction::FaddQ { .. }
+            | Instruction::FsubQ { .. }
+            | Instruction::FmulQ { .. }
+            | Instruction::FdivQ { .. }
+            | Instruction::FsqrtQ { .. }
+            | Instruction::FsgnjQ { .. }
+            | Instruction::FsgnjnQ { .. }
+            | Instruction::FsgnjxQ { .. }
+            | Instruction::FminQ { .. }
+            | Instruction::FmaxQ { .. }
+            | Instruction::FcvtSQ { .. }
+            | Instruction::FcvtQS { .. }
+            | Instruction::FcvtDQ { .. }
+            | Instruction::FcvtQD { .. }
+            | Instruction::FeqQ { .. }
+            | Instruction::FltQ { .. }
+            | Instruction::FleQ { .. }
+            | Instruction::FclassQ { .. }
+            | Instruction::FcvtWQ { .. }
+            | Instruction::FcvtWuQ { .. }
+            | Instruction::FcvtQW { .. }
+            | Instruction::FcvtQWu { .. }
+            | Instruction::FcvtLQ { .. }
+            | Instruction::FcvtLuQ { .. }
+            | Instruction::FcvtQL { .. }
+            | Instruction::FcvtQLu { .. }
+            | Instruction::FliQ { .. }
+            | Instruction::FminmQ { .. }
+            | Instruction::FmaxmQ { .. }
+            | Instruction::FroundQ { .. }
+            | Instruction::FroundnxQ { .. }
+            | Instruction::FleqQ { .. }
+            | Instruction::FltqQ { .. } => Err(format!(
+                "{self} requires the Q extension, which is not compatible with Zfinx"
+            )),
+            Instruction::FminmH { .. }
+            | Instruction::FmaxmH { .. }
+            | Instruction::FroundH { .. }
+            | Instruction::FroundnxH { .. }
+            | Instruction::FleqH { .. }
+            | Instruction::FltqH { .. }
+                if !isa.zhinx =>
+            {
+                Err(format!("{self} requires Zhinx"))
+            }
+            _ => {
+                let (dests, srcs) = self.double_operands();
+                let doubles: Vec<FRegister> = dests.into_iter().chain(srcs).collect();
+                if !doubles.is_empty() && !isa.zdinx {
+                    Err(format!("{self} requires Zdinx"))
+                } else if isa.xlen == Xlen::Rv32 && doubles.iter().any(|r| u32::from(*r) % 2 != 0) {
+                    Err(format!(
+                        "{self} uses an odd register for a double, which requires an even/odd register pair on RV32"
+                    ))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Returns the operands of a double precision instruction that hold double precision values, as
+    /// `(destinations, sources)`.
+    pub(crate) fn double_operands(&self) -> (Vec<FRegister>, Vec<FRegister>) {
+        match self {
+            Instruction::FmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                ..
+            }
+            | Instruction::FmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                ..
+            }
+            | Instruction::FnmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                ..
+            }
+            | Instruction::FnmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                ..
+            } => (vec![*dest], vec![*src1, *src2, *src3]),
+            Instruction::FaddD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsubD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmulD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FdivD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjD { dest, src1, src2 }
+            | Instruction::FsgnjnD { dest, src1, src2 }
+            | Instruction::FsgnjxD { dest, src1, src2 }
+            | Instruction::FminD { dest, src1, src2 }
+            | Instruction::FmaxD { dest, src1, src2 }
+            | Instruction::FminmD { dest, src1, src2 }
+            | Instruction::FmaxmD { dest, src1, src2 } => (vec![*dest], vec![*src1, *src2]),
+            Instruction::FsqrtD { dest, src, .. }
+            | Instruction::FroundD { dest, src, .. }
+            | Instruction::FroundnxD { dest, src, .. } => (vec![*dest], vec![*src]),
+            Instruction::FeqD { src1, src2, .. }
+            | Instruction::FltD { src1, src2, .. }
+            | Instruction::FleD { src1, src2, .. }
+            | Instruction::FleqD { src1, src2, .. }
+            | Instruction::FltqD { src1, src2, .. } => (vec![], vec![*src1, *src2]),
+            Instruction::FcvtSD { src, .. }
+            | Instruction::FcvtmodWD { src, .. }
+            | Instruction::FclassD { src1: src, .. }
+            | Instruction::FcvtWD { src1: src, .. }
+            | Instruction::FcvtWuD { src1: src, .. }
+            | Instruction::FcvtLD { src1: src, .. }
+            | Instruction::FcvtLuD { src1: src, .. } => (vec![], vec![*src]),
+            Instruction::FcvtDS { dest, .. }
+            | Instruction::FcvtDW { dest, .. }
+            | Instruction::FcvtDWu { dest, .. }
+            | Instruction::FcvtDL { dest, .. }
+            | Instruction::FcvtDLu { dest, .. } => (vec![*dest], vec![]),
+            _ => (vec![], vec![]),
         }
     }
 
     /// Constructs an `Instruction` from it's machine code representation.
     pub fn decode(instruction: u32) -> Result<Instruction, String> {
+        Instruction::decode_with(instruction, &IsaConfig::default())
+    }
+
+    // the unusual bit groupings are used to separate the shamt bit from func7
+    #[allow(clippy::unusual_byte_groupings)]
+    fn decode_fields(instruction: u32, isa: &IsaConfig) -> Result<Instruction, String> {
         let opcode = Opcode::from_int(instruction & 0b111_1111);
 
         let func3 = (instruction >> 12) & 0b111;
@@ -1522,6 +3500,9 @@ impl Instruction {
                 0b111 => Err("Invalid load func3".to_owned()),
                 _ => unreachable!(),
             },
+            Opcode::Auipc if isa.zicfilp && rd == IRegister::Zero => Ok(Instruction::Lpad {
+                label: LpadLabel::from_u32(instruction),
+            }),
             Opcode::Auipc => Ok(Instruction::Auipc {
                 dest: rd,
                 imm: u_immediate,
@@ -1644,6 +3625,16 @@ impl Instruction {
                     src1: rs1,
                     src2: rs2,
                 }),
+                (0b000_0111, 0b101) => Ok(Instruction::CzeroEqz {
+                    dest: rd,
+                    src1: rs1,
+                    src2: rs2,
+                }),
+                (0b000_0111, 0b111) => Ok(Instruction::CzeroNez {
+                    dest: rd,
+                    src1: rs1,
+                    src2: rs2,
+                }),
                 _ => Err(format!("unknown Op. func3: {}, func7: {}", func3, func7)),
             },
             Opcode::Op32 => match (func3, func7) {
@@ -1667,7 +3658,7 @@ impl Instruction {
                     src1: rs1,
                     src2: rs2,
                 }),
-                (0b100, 0b0000_001) => Ok(Instruction::Divw {
+                (0b100, 0b000_0001) => Ok(Instruction::Divw {
                     dest: rd,
                     src1: rs1,
                     src2: rs2,
@@ -1743,6 +3734,27 @@ impl Instruction {
                     }),
                     _ => Err(format!("unknown OpImm. func3: {}, func7: {}", func3, func7)),
                 },
+                0b110 if isa.zicbop && rd == IRegister::Zero => {
+                    match (instruction >> 20) & 0b1_1111 {
+                        0b0_0000 => Ok(Instruction::PrefetchI {
+                            base: rs1,
+                            offset: PrefetchImmediate::from_u32(instruction),
+                        }),
+                        0b0_0001 => Ok(Instruction::PrefetchR {
+                            base: rs1,
+                            offset: PrefetchImmediate::from_u32(instruction),
+                        }),
+                        0b0_0011 => Ok(Instruction::PrefetchW {
+                            base: rs1,
+                            offset: PrefetchImmediate::from_u32(instruction),
+                        }),
+                        _ => Ok(Instruction::Ori {
+                            dest: rd,
+                            src: rs1,
+                            imm: i_immediate,
+                        }),
+                    }
+                }
                 0b110 => Ok(Instruction::Ori {
                     dest: rd,
                     src: rs1,
@@ -1822,208 +3834,484 @@ impl Instruction {
                     src2: rs2,
                     offset: b_immediate,
                 }),
-                0b111 => Ok(Instruction::Bgeu {
-                    src1: rs1,
-                    src2: rs2,
-                    offset: b_immediate,
+                0b111 => Ok(Instruction::Bgeu {
+                    src1: rs1,
+                    src2: rs2,
+                    offset: b_immediate,
+                }),
+                x => Err(format!("invalid branch func3: {x}").to_owned()),
+            },
+            Opcode::MiscMem => match func3 {
+                0b000 => {
+                    if rd != IRegister::Zero || rs1 != IRegister::Zero {
+                        // technicially, we are supposed to ignore these fields
+                        Err("reserved register fields not set to zero".to_owned())
+                    } else {
+                        let fm = ((instruction >> 28) & 0b1111) as u8;
+                        if fm != 0 && fm != 0b1000 {
+                            Err(format!("reserved fence FM: {fm}").to_owned())
+                        } else if fm == 0b1000 && ((instruction >> 20) & 0xFF) != 0b0011_0011 {
+                            Err("fence.tso must be rw,rw".to_owned())
+                        } else {
+                            Ok(Instruction::Fence {
+                                rd,
+                                rs1,
+                                ops: ((instruction >> 20) & 0xFF) as u8,
+                                fm: ((instruction >> 28) & 0b1111) as u8,
+                            })
+                        }
+                    }
+                }
+                0b001 => {
+                    if rd != IRegister::Zero || rs1 != IRegister::Zero {
+                        // technicially, we are supposed to ignore these fields
+                        Err("reserved register fields not set to zero".to_owned())
+                    } else {
+                        let func12 = instruction >> 20;
+                        if func12 != 0 {
+                            Err("reserved register fields not set to zero".to_owned())
+                        } else {
+                            Ok(Instruction::FenceI)
+                        }
+                    }
+                }
+                0b010 => {
+                    if rd != IRegister::Zero {
+                        Err("cbo instructions expect rd to be 0".to_owned())
+                    } else {
+                        match instruction >> 20 {
+                            0b0000_0000_0000 => Ok(Instruction::CboInval { base: rs1 }),
+                            0b0000_0000_0001 => Ok(Instruction::CboClean { base: rs1 }),
+                            0b0000_0000_0010 => Ok(Instruction::CboFlush { base: rs1 }),
+                            0b0000_0000_0100 => Ok(Instruction::CboZero { base: rs1 }),
+                            x => Err(format!("unknown cbo func12: {x}")),
+                        }
+                    }
+                }
+                x => Err(format!("unknown fence func3: {x}")),
+            },
+            Opcode::AMO => match (func3, func7 >> 2) {
+                (0b010, 0b00010) => {
+                    if rs2 != IRegister::Zero {
+                        Err("LR.W expects rs2 to be 0".to_owned())
+                    } else {
+                        Ok(Instruction::LrW {
+                            dest: rd,
+                            addr: rs1,
+                            aq,
+                            rl,
+                        })
+                    }
+                }
+                (0b011, 0b00010) => {
+                    if rs2 != IRegister::Zero {
+                        Err("LR.D expects rs2 to be 0".to_owned())
+                    } else {
+                        Ok(Instruction::LrD {
+                            dest: rd,
+                            addr: rs1,
+                            aq,
+                            rl,
+                        })
+                    }
+                }
+                (0b010, 0b00011) => Ok(Instruction::ScW {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b011, 0b00011) => Ok(Instruction::ScD {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b010, 0b00001) => Ok(Instruction::AmoswapW {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b011, 0b00001) => Ok(Instruction::AmoswapD {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b010, 0b00000) => Ok(Instruction::AmoaddW {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b011, 0b00000) => Ok(Instruction::AmoaddD {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b010, 0b00100) => Ok(Instruction::AmoxorW {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b011, 0b00100) => Ok(Instruction::AmoxorD {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b010, 0b01100) => Ok(Instruction::AmoandW {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b011, 0b01100) => Ok(Instruction::AmoandD {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b010, 0b01000) => Ok(Instruction::AmoorW {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b011, 0b01000) => Ok(Instruction::AmoorD {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b010, 0b10000) => Ok(Instruction::AmominW {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b011, 0b10000) => Ok(Instruction::AmominD {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b010, 0b10100) => Ok(Instruction::AmomaxW {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b011, 0b10100) => Ok(Instruction::AmomaxD {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b010, 0b11000) => Ok(Instruction::AmominuW {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b011, 0b11000) => Ok(Instruction::AmominuD {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b010, 0b11100) => Ok(Instruction::AmomaxuW {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b011, 0b11100) => Ok(Instruction::AmomaxuD {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b010, 0b00101) => Ok(Instruction::AmocasW {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b011, 0b00101) => Ok(Instruction::AmocasD {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b100, 0b00101) => Ok(Instruction::AmocasQ {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b000, 0b00001) => Ok(Instruction::AmoswapB {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
+                }),
+                (0b000, 0b00000) => Ok(Instruction::AmoaddB {
+                    dest: rd,
+                    addr: rs1,
+                    src: rs2,
+                    aq,
+                    rl,
                 }),
-                x => Err(format!("invalid branch func3: {x}").to_owned()),
-            },
-            Opcode::MiscMem => match func3 {
-                0b000 => {
-                    if rd != IRegister::Zero || rs1 != IRegister::Zero {
-                        // technicially, we are supposed to ignore these fields
-                        Err("reserved register fields not set to zero".to_owned())
-                    } else {
-                        let fm = ((instruction >> 28) & 0b1111) as u8;
-                        if fm != 0 && fm != 0b1000 {
-                            Err(format!("reserved fence FM: {fm}").to_owned())
-                        } else if fm == 0b1000 && ((instruction >> 20) & 0xFF) != 0b0011_0011 {
-                            Err("fence.tso must be rw,rw".to_owned())
-                        } else {
-                            Ok(Instruction::Fence {
-                                rd,
-                                rs1,
-                                ops: ((instruction >> 20) & 0xFF) as u8,
-                                fm: ((instruction >> 28) & 0b1111) as u8,
-                            })
-                        }
-                    }
-                }
-                0b001 => {
-                    if rd != IRegister::Zero || rs1 != IRegister::Zero {
-                        // technicially, we are supposed to ignore these fields
-                        Err("reserved register fields not set to zero".to_owned())
-                    } else {
-                        let func12 = instruction >> 20;
-                        if func12 != 0 {
-                            Err("reserved register fields not set to zero".to_owned())
-                        } else {
-                            Ok(Instruction::FenceI)
-                        }
-                    }
-                }
-                x => Err(format!("unknown fence func3: {x}")),
-            },
-            Opcode::AMO => match (func3, func7 >> 2) {
-                (0b010, 0b00010) => {
-                    if rs2 != IRegister::Zero {
-                        Err("LR.W expects rs2 to be 0".to_owned())
-                    } else {
-                        Ok(Instruction::LrW {
-                            dest: rd,
-                            addr: rs1,
-                            aq,
-                            rl,
-                        })
-                    }
-                }
-                (0b011, 0b00010) => {
-                    if rs2 != IRegister::Zero {
-                        Err("LR.D expects rs2 to be 0".to_owned())
-                    } else {
-                        Ok(Instruction::LrD {
-                            dest: rd,
-                            addr: rs1,
-                            aq,
-                            rl,
-                        })
-                    }
-                }
-                (0b010, 0b00011) => Ok(Instruction::ScW {
+                (0b000, 0b00100) => Ok(Instruction::AmoxorB {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b011, 0b00011) => Ok(Instruction::ScD {
+                (0b000, 0b01100) => Ok(Instruction::AmoandB {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b010, 0b00001) => Ok(Instruction::AmoswapW {
+                (0b000, 0b01000) => Ok(Instruction::AmoorB {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b011, 0b00001) => Ok(Instruction::AmoswapD {
+                (0b000, 0b10000) => Ok(Instruction::AmominB {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b010, 0b00000) => Ok(Instruction::AmoaddW {
+                (0b000, 0b10100) => Ok(Instruction::AmomaxB {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b011, 0b00000) => Ok(Instruction::AmoaddD {
+                (0b000, 0b11000) => Ok(Instruction::AmominuB {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b010, 0b00100) => Ok(Instruction::AmoxorW {
+                (0b000, 0b11100) => Ok(Instruction::AmomaxuB {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b011, 0b00100) => Ok(Instruction::AmoxorD {
+                (0b000, 0b00101) => Ok(Instruction::AmocasB {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b010, 0b01100) => Ok(Instruction::AmoandW {
+                (0b001, 0b00001) => Ok(Instruction::AmoswapH {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b011, 0b01100) => Ok(Instruction::AmoandD {
+                (0b001, 0b00000) => Ok(Instruction::AmoaddH {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b010, 0b01000) => Ok(Instruction::AmoorW {
+                (0b001, 0b00100) => Ok(Instruction::AmoxorH {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b011, 0b01000) => Ok(Instruction::AmoorD {
+                (0b001, 0b01100) => Ok(Instruction::AmoandH {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b010, 0b10000) => Ok(Instruction::AmominW {
+                (0b001, 0b01000) => Ok(Instruction::AmoorH {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b011, 0b10000) => Ok(Instruction::AmominD {
+                (0b001, 0b10000) => Ok(Instruction::AmominH {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b010, 0b10100) => Ok(Instruction::AmomaxW {
+                (0b001, 0b10100) => Ok(Instruction::AmomaxH {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b011, 0b10100) => Ok(Instruction::AmomaxD {
+                (0b001, 0b11000) => Ok(Instruction::AmominuH {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b010, 0b11000) => Ok(Instruction::AmominuW {
+                (0b001, 0b11100) => Ok(Instruction::AmomaxuH {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b011, 0b11000) => Ok(Instruction::AmominuD {
+                (0b001, 0b00101) => Ok(Instruction::AmocasH {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b010, 0b11100) => Ok(Instruction::AmomaxuW {
+                (0b000, 0b00110) => {
+                    if rs2 != IRegister::Zero || !aq {
+                        Err("lb.aq expects rs2 to be 0 and aq to be set".to_owned())
+                    } else {
+                        Ok(Instruction::LbAq {
+                            dest: rd,
+                            addr: rs1,
+                            rl,
+                        })
+                    }
+                }
+                (0b001, 0b00110) => {
+                    if rs2 != IRegister::Zero || !aq {
+                        Err("lh.aq expects rs2 to be 0 and aq to be set".to_owned())
+                    } else {
+                        Ok(Instruction::LhAq {
+                            dest: rd,
+                            addr: rs1,
+                            rl,
+                        })
+                    }
+                }
+                (0b010, 0b00110) => {
+                    if rs2 != IRegister::Zero || !aq {
+                        Err("lw.aq expects rs2 to be 0 and aq to be set".to_owned())
+                    } else {
+                        Ok(Instruction::LwAq {
+                            dest: rd,
+                            addr: rs1,
+                            rl,
+                        })
+                    }
+                }
+                (0b011, 0b00110) => {
+                    if rs2 != IRegister::Zero || !aq {
+                        Err("ld.aq expects rs2 to be 0 and aq to be set".to_owned())
+                    } else {
+                        Ok(Instruction::LdAq {
+                            dest: rd,
+                            addr: rs1,
+                            rl,
+                        })
+                    }
+                }
+                (0b000, 0b00111) => {
+                    if rd != IRegister::Zero || !rl {
+                        Err("sb.rl expects rd to be 0 and rl to be set".to_owned())
+                    } else {
+                        Ok(Instruction::SbRl {
+                            src: rs2,
+                            addr: rs1,
+                            aq,
+                        })
+                    }
+                }
+                (0b001, 0b00111) => {
+                    if rd != IRegister::Zero || !rl {
+                        Err("sh.rl expects rd to be 0 and rl to be set".to_owned())
+                    } else {
+                        Ok(Instruction::ShRl {
+                            src: rs2,
+                            addr: rs1,
+                            aq,
+                        })
+                    }
+                }
+                (0b010, 0b00111) => {
+                    if rd != IRegister::Zero || !rl {
+                        Err("sw.rl expects rd to be 0 and rl to be set".to_owned())
+                    } else {
+                        Ok(Instruction::SwRl {
+                            src: rs2,
+                            addr: rs1,
+                            aq,
+                        })
+                    }
+                }
+                (0b011, 0b00111) => {
+                    if rd != IRegister::Zero || !rl {
+                        Err("sd.rl expects rd to be 0 and rl to be set".to_owned())
+                    } else {
+                        Ok(Instruction::SdRl {
+                            src: rs2,
+                            addr: rs1,
+                            aq,
+                        })
+                    }
+                }
+                (0b010, 0b01001) => Ok(Instruction::SsamoswapW {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
                     aq,
                     rl,
                 }),
-                (0b011, 0b11100) => Ok(Instruction::AmomaxuD {
+                (0b011, 0b01001) => Ok(Instruction::SsamoswapD {
                     dest: rd,
                     addr: rs1,
                     src: rs2,
@@ -2043,6 +4331,11 @@ impl Instruction {
                     base: rs1,
                     offset: i_immediate,
                 }),
+                0b100 => Ok(Instruction::Flq {
+                    dest: frd,
+                    base: rs1,
+                    offset: i_immediate,
+                }),
                 _ => Err(format!("unknown func3: {func3} in opcode LoadFp")),
             },
             Opcode::StoreFp => match func3 {
@@ -2056,6 +4349,11 @@ impl Instruction {
                     base: rs1,
                     offset: s_immediate,
                 }),
+                0b100 => Ok(Instruction::Fsq {
+                    src: frs2,
+                    base: rs1,
+                    offset: s_immediate,
+                }),
                 _ => Err(format!("unknown func3: {func3} in opcode StoreFp")),
             },
             Opcode::OpFp => match func7 {
@@ -2107,50 +4405,155 @@ impl Instruction {
                     src2: frs2,
                     rm: RoundingMode::from_int(func3)?,
                 }),
-                0b010_0000 => {
-                    if instruction >> 20 & 0b11111 != 0b0_0001 {
-                        Err("expected rs2=0b0_0001 in OpFp func7=0b010_0000".to_owned())
-                    } else {
-                        Ok(Instruction::FcvtSD {
-                            dest: frd,
-                            src: frs1,
-                            rm: RoundingMode::from_int(func3)?,
-                        })
-                    }
-                }
-                0b010_0001 => {
+                0b000_0011 => Ok(Instruction::FaddQ {
+                    dest: frd,
+                    src1: frs1,
+                    src2: frs2,
+                    rm: RoundingMode::from_int(func3)?,
+                }),
+                0b000_0111 => Ok(Instruction::FsubQ {
+                    dest: frd,
+                    src1: frs1,
+                    src2: frs2,
+                    rm: RoundingMode::from_int(func3)?,
+                }),
+                0b000_1011 => Ok(Instruction::FmulQ {
+                    dest: frd,
+                    src1: frs1,
+                    src2: frs2,
+                    rm: RoundingMode::from_int(func3)?,
+                }),
+                0b000_1111 => Ok(Instruction::FdivQ {
+                    dest: frd,
+                    src1: frs1,
+                    src2: frs2,
+                    rm: RoundingMode::from_int(func3)?,
+                }),
+                0b010_0000 => match (instruction >> 20) & 0b1_1111 {
+                    0b0_0001 => Ok(Instruction::FcvtSD {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0011 => Ok(Instruction::FcvtSQ {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0100 => Ok(Instruction::FroundS {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0101 => Ok(Instruction::FroundnxS {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0110 => Ok(Instruction::FcvtSBf16 {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b010_0000 rs2: {}", x)),
+                },
+                0b010_0001 => match (instruction >> 20) & 0b1_1111 {
+                    0b0_0000 => Ok(Instruction::FcvtDS {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0011 => Ok(Instruction::FcvtDQ {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0100 => Ok(Instruction::FroundD {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0101 => Ok(Instruction::FroundnxD {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b010_0001 rs2: {}", x)),
+                },
+                0b010_0010 => match (instruction >> 20) & 0b1_1111 {
+                    0b0_0100 => Ok(Instruction::FroundH {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0101 => Ok(Instruction::FroundnxH {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_1000 => Ok(Instruction::FcvtBf16S {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b010_0010 rs2: {}", x)),
+                },
+                0b010_0011 => match (instruction >> 20) & 0b1_1111 {
+                    0b0_0000 => Ok(Instruction::FcvtQS {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0001 => Ok(Instruction::FcvtQD {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0100 => Ok(Instruction::FroundQ {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0101 => Ok(Instruction::FroundnxQ {
+                        dest: frd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b010_0011 rs2: {}", x)),
+                },
+                0b010_1100 => {
                     if instruction >> 20 & 0b11111 != 0b0_0000 {
-                        Err("expected rs2=0b0_0000 in OpFp func7=0b010_0001".to_owned())
+                        Err("expected rs2=0b0_0000 in OpFp func7=0b010_1100".to_owned())
                     } else {
-                        Ok(Instruction::FcvtDS {
+                        Ok(Instruction::FsqrtS {
                             dest: frd,
                             src: frs1,
                             rm: RoundingMode::from_int(func3)?,
                         })
                     }
                 }
-                0b010_1100 => {
+                0b010_1101 => {
                     if instruction >> 20 & 0b11111 != 0b0_0000 {
-                        Err("expected rs2=0b0_0000 in OpFp func7=0b010_1100".to_owned())
+                        Err("expected rs2=0b0_0000 in OpFp func7=0b010_1101".to_owned())
                     } else {
-                        Ok(Instruction::FsqrtS {
+                        Ok(Instruction::FsqrtD {
                             dest: frd,
                             src: frs1,
                             rm: RoundingMode::from_int(func3)?,
                         })
                     }
                 }
-                0b010_1101 => {
+                0b010_1111 => {
                     if instruction >> 20 & 0b11111 != 0b0_0000 {
-                        Err("expected rs2=0b0_0000 in OpFp func7=0b010_1101".to_owned())
+                        Err("expected rs2=0b0_0000 in OpFp func7=0b010_1111".to_owned())
                     } else {
-                        Ok(Instruction::FsqrtD {
+                        Ok(Instruction::FsqrtQ {
                             dest: frd,
                             src: frs1,
                             rm: RoundingMode::from_int(func3)?,
                         })
                     }
-                },
+                }
                 0b001_0000 => match func3 {
                     0b000 => Ok(Instruction::FsgnjS {
                         dest: frd,
@@ -2187,6 +4590,24 @@ impl Instruction {
                     }),
                     x => Err(format!("unknown OpFp func7=0b001_0001 func3: {}", x)),
                 },
+                0b001_0011 => match func3 {
+                    0b000 => Ok(Instruction::FsgnjQ {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b001 => Ok(Instruction::FsgnjnQ {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b010 => Ok(Instruction::FsgnjxQ {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b001_0011 func3: {}", x)),
+                },
                 0b001_0100 => match func3 {
                     0b000 => Ok(Instruction::FminS {
                         dest: frd,
@@ -2198,6 +4619,16 @@ impl Instruction {
                         src1: frs1,
                         src2: frs2,
                     }),
+                    0b010 => Ok(Instruction::FminmS {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b011 => Ok(Instruction::FmaxmS {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
                     x => Err(format!("unknown OpFp func7=0b001_0100 func3: {}", x)),
                 },
                 0b001_0101 => match func3 {
@@ -2211,8 +4642,54 @@ impl Instruction {
                         src1: frs1,
                         src2: frs2,
                     }),
+                    0b010 => Ok(Instruction::FminmD {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b011 => Ok(Instruction::FmaxmD {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
                     x => Err(format!("unknown OpFp func7=0b001_0101 func3: {}", x)),
                 },
+                0b001_0110 => match func3 {
+                    0b010 => Ok(Instruction::FminmH {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b011 => Ok(Instruction::FmaxmH {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b001_0110 func3: {}", x)),
+                },
+                0b001_0111 => match func3 {
+                    0b000 => Ok(Instruction::FminQ {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b001 => Ok(Instruction::FmaxQ {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b010 => Ok(Instruction::FminmQ {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b011 => Ok(Instruction::FmaxmQ {
+                        dest: frd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b001_0111 func3: {}", x)),
+                },
                 0b101_0000 => match func3 {
                     0b000 => Ok(Instruction::FleS {
                         dest: rd,
@@ -2229,6 +4706,16 @@ impl Instruction {
                         src1: frs1,
                         src2: frs2,
                     }),
+                    0b100 => Ok(Instruction::FleqS {
+                        dest: rd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b101 => Ok(Instruction::FltqS {
+                        dest: rd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
                     x => Err(format!("unknown OpFp func7=0b101_0000 func3: {}", x)),
                 },
                 0b101_0001 => match func3 {
@@ -2247,7 +4734,69 @@ impl Instruction {
                         src1: frs1,
                         src2: frs2,
                     }),
-                    x => Err(format!("unknown OpFp func7=0b101_0000 func3: {}", x)),
+                    0b100 => Ok(Instruction::FleqD {
+                        dest: rd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b101 => Ok(Instruction::FltqD {
+                        dest: rd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b101_0001 func3: {}", x)),
+                },
+                0b101_0010 => match func3 {
+                    0b100 => Ok(Instruction::FleqH {
+                        dest: rd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b101 => Ok(Instruction::FltqH {
+                        dest: rd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b101_0010 func3: {}", x)),
+                },
+                0b101_1001 => {
+                    if func3 == 0 {
+                        Ok(Instruction::FmvpDX {
+                            dest: frd,
+                            src1: rs1,
+                            src2: rs2,
+                        })
+                    } else {
+                        Err(format!("unknown OpFp func7=0b101_1001 func3: {}", func3))
+                    }
+                }
+                0b101_0011 => match func3 {
+                    0b000 => Ok(Instruction::FleQ {
+                        dest: rd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b001 => Ok(Instruction::FltQ {
+                        dest: rd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b010 => Ok(Instruction::FeqQ {
+                        dest: rd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b100 => Ok(Instruction::FleqQ {
+                        dest: rd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    0b101 => Ok(Instruction::FltqQ {
+                        dest: rd,
+                        src1: frs1,
+                        src2: frs2,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b101_0011 func3: {}", x)),
                 },
                 0b110_0000 => match (instruction >> 20) & 0b1_1111 {
                     0b0_0000 => Ok(Instruction::FcvtWS {
@@ -2293,8 +4842,41 @@ impl Instruction {
                         src1: frs1,
                         rm: RoundingMode::from_int(func3)?,
                     }),
+                    0b0_1000 => {
+                        if func3 == 0b001 {
+                            Ok(Instruction::FcvtmodWD {
+                                dest: rd,
+                                src: frs1,
+                            })
+                        } else {
+                            Err("fcvtmod.w.d requires the rtz rounding mode".to_owned())
+                        }
+                    }
                     x => Err(format!("unknown OpFp func7=0b001_0100 rs2: {}", x)),
                 },
+                0b110_0011 => match (instruction >> 20) & 0b1_1111 {
+                    0b0_0000 => Ok(Instruction::FcvtWQ {
+                        dest: rd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0001 => Ok(Instruction::FcvtWuQ {
+                        dest: rd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0010 => Ok(Instruction::FcvtLQ {
+                        dest: rd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0011 => Ok(Instruction::FcvtLuQ {
+                        dest: rd,
+                        src: frs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b110_0011 rs2: {}", x)),
+                },
                 0b110_1000 => match (instruction >> 20) & 0b1_1111 {
                     0b0_0000 => Ok(Instruction::FcvtSW {
                         dest: frd,
@@ -2341,6 +4923,29 @@ impl Instruction {
                     }),
                     x => Err(format!("unknown OpFp func7=0b001_0101 rs2: {}", x)),
                 },
+                0b110_1011 => match (instruction >> 20) & 0b1_1111 {
+                    0b0_0000 => Ok(Instruction::FcvtQW {
+                        dest: frd,
+                        src: rs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0001 => Ok(Instruction::FcvtQWu {
+                        dest: frd,
+                        src: rs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0010 => Ok(Instruction::FcvtQL {
+                        dest: frd,
+                        src: rs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    0b0_0011 => Ok(Instruction::FcvtQLu {
+                        dest: frd,
+                        src: rs1,
+                        rm: RoundingMode::from_int(func3)?,
+                    }),
+                    x => Err(format!("unknown OpFp func7=0b110_1011 rs2: {}", x)),
+                },
                 0b111_0000 => {
                     if (instruction >> 20) & 0b1_1111 == 0 {
                         if func3 == 0 {
@@ -2385,6 +4990,11 @@ impl Instruction {
                                 func3
                             ))
                         }
+                    } else if (instruction >> 20) & 0b1_1111 == 1 && func3 == 0 {
+                        Ok(Instruction::FmvhXD {
+                            dest: rd,
+                            src: frs1,
+                        })
                     } else {
                         Err(format!(
                             "unknown OpFp func7=0b111_0001 unknown rs2: {} and func3: {}",
@@ -2393,6 +5003,20 @@ impl Instruction {
                         ))
                     }
                 }
+                0b111_0011 => {
+                    if (instruction >> 20) & 0b1_1111 == 0 && func3 == 1 {
+                        Ok(Instruction::FclassQ {
+                            dest: rd,
+                            src: frs1,
+                        })
+                    } else {
+                        Err(format!(
+                            "unknown OpFp func7=0b111_0011 rs2: {} and func3: {}",
+                            (instruction >> 20) & 0b1_1111,
+                            func3
+                        ))
+                    }
+                }
                 0b111_1000 => {
                     if (instruction >> 20) & 0b1_1111 == 0 {
                         if func3 == 0 {
@@ -2406,9 +5030,14 @@ impl Instruction {
                                 func3
                             ))
                         }
+                    } else if (instruction >> 20) & 0b1_1111 == 1 && func3 == 0 {
+                        Ok(Instruction::FliS {
+                            dest: frd,
+                            imm: FliConstant::from_int((instruction >> 15) & 0b1_1111)?,
+                        })
                     } else {
                         Err(format!(
-                            "unknown OpFp func7=0b111_0000 unknown rs2: {} and func3: {}",
+                            "unknown OpFp func7=0b111_1000 unknown rs2: {} and func3: {}",
                             (instruction >> 20) & 0b1_1111,
                             func3
                         ))
@@ -2427,9 +5056,42 @@ impl Instruction {
                                 func3
                             ))
                         }
+                    } else if (instruction >> 20) & 0b1_1111 == 1 && func3 == 0 {
+                        Ok(Instruction::FliD {
+                            dest: frd,
+                            imm: FliConstant::from_int((instruction >> 15) & 0b1_1111)?,
+                        })
                     } else {
                         Err(format!(
-                            "unknown OpFp func7=0b111_0001 unknown rs2: {} and func3: {}",
+                            "unknown OpFp func7=0b111_1001 unknown rs2: {} and func3: {}",
+                            (instruction >> 20) & 0b1_1111,
+                            func3
+                        ))
+                    }
+                }
+                0b111_1010 => {
+                    if (instruction >> 20) & 0b1_1111 == 1 && func3 == 0 {
+                        Ok(Instruction::FliH {
+                            dest: frd,
+                            imm: FliConstant::from_int((instruction >> 15) & 0b1_1111)?,
+                        })
+                    } else {
+                        Err(format!(
+                            "unknown OpFp func7=0b111_1010 rs2: {} and func3: {}",
+                            (instruction >> 20) & 0b1_1111,
+                            func3
+                        ))
+                    }
+                }
+                0b111_1011 => {
+                    if (instruction >> 20) & 0b1_1111 == 1 && func3 == 0 {
+                        Ok(Instruction::FliQ {
+                            dest: frd,
+                            imm: FliConstant::from_int((instruction >> 15) & 0b1_1111)?,
+                        })
+                    } else {
+                        Err(format!(
+                            "unknown OpFp func7=0b111_1011 rs2: {} and func3: {}",
                             (instruction >> 20) & 0b1_1111,
                             func3
                         ))
@@ -2437,7 +5099,45 @@ impl Instruction {
                 }
                 x => Err(format!("Unknown OpFp func7: {x}")),
             },
+            Opcode::Custom0 | Opcode::Custom1 | Opcode::Custom2 | Opcode::Custom3 => Err(format!(
+                "instruction uses custom opcode {opcode:?}, which requires an extension"
+            )),
             Opcode::Reserved => Err("instruction uses reserved opcode".to_owned()),
+            Opcode::OpV => {
+                let vd = VRegister::try_from((instruction >> 7) & 0b1_1111).unwrap();
+                let vs1 = VRegister::try_from((instruction >> 15) & 0b1_1111).unwrap();
+                let vs2 = VRegister::try_from((instruction >> 20) & 0b1_1111).unwrap();
+                let masked = (instruction >> 25) & 0b1 == 0;
+                let func6 = instruction >> 26;
+                match (func3, func6) {
+                    (0b001, 0b01_0010) => match (instruction >> 15) & 0b1_1111 {
+                        0b0_1101 => Ok(Instruction::Vfwcvtbf16FFV {
+                            dest: vd,
+                            src: vs2,
+                            masked,
+                        }),
+                        0b1_1101 => Ok(Instruction::Vfncvtbf16FFW {
+                            dest: vd,
+                            src: vs2,
+                            masked,
+                        }),
+                        x => Err(format!("unknown OpV VFUNARY0 vs1: {}", x)),
+                    },
+                    (0b001, 0b11_1011) => Ok(Instruction::Vfwmaccbf16VV {
+                        dest: vd,
+                        src1: vs1,
+                        src2: vs2,
+                        masked,
+                    }),
+                    (0b101, 0b11_1011) => Ok(Instruction::Vfwmaccbf16VF {
+                        dest: vd,
+                        src1: frs1,
+                        src2: vs2,
+                        masked,
+                    }),
+                    (x, y) => Err(format!("unknown OpV func3: {} and func6: {}", x, y)),
+                }
+            }
             Opcode::Madd => match func7 & 0b11 {
                 0b00 => Ok(Instruction::FmaddS {
                     dest: frd,
@@ -2453,6 +5153,13 @@ impl Instruction {
                     src3: frs3,
                     rm: RoundingMode::from_int(func3)?,
                 }),
+                0b11 => Ok(Instruction::FmaddQ {
+                    dest: frd,
+                    src1: frs1,
+                    src2: frs2,
+                    src3: frs3,
+                    rm: RoundingMode::from_int(func3)?,
+                }),
                 _ => Err(format!(
                     "Fmadd unknown lower 2 bits of func7: {}",
                     func7 & 0b11
@@ -2473,6 +5180,13 @@ impl Instruction {
                     src3: frs3,
                     rm: RoundingMode::from_int(func3)?,
                 }),
+                0b11 => Ok(Instruction::FmsubQ {
+                    dest: frd,
+                    src1: frs1,
+                    src2: frs2,
+                    src3: frs3,
+                    rm: RoundingMode::from_int(func3)?,
+                }),
                 _ => Err(format!(
                     "Fmsub unknown lower 2 bits of func7: {}",
                     func7 & 0b11
@@ -2493,6 +5207,13 @@ impl Instruction {
                     src3: frs3,
                     rm: RoundingMode::from_int(func3)?,
                 }),
+                0b11 => Ok(Instruction::FnmsubQ {
+                    dest: frd,
+                    src1: frs1,
+                    src2: frs2,
+                    src3: frs3,
+                    rm: RoundingMode::from_int(func3)?,
+                }),
                 _ => Err(format!(
                     "Fnmsub unknown lower 2 bits of func7: {}",
                     func7 & 0b11
@@ -2513,13 +5234,65 @@ impl Instruction {
                     src3: frs3,
                     rm: RoundingMode::from_int(func3)?,
                 }),
+                0b11 => Ok(Instruction::FnmaddQ {
+                    dest: frd,
+                    src1: frs1,
+                    src2: frs2,
+                    src3: frs3,
+                    rm: RoundingMode::from_int(func3)?,
+                }),
                 _ => Err(format!(
                     "Fmadd unknown lower 2 bits of func7: {}",
                     func7 & 0b11
                 )),
             },
             Opcode::System => match func3 {
-                0b000 => Err("Reserved func3 in Opcode SYSTEM".to_owned()),
+                0b000 => {
+                    if rd != IRegister::Zero {
+                        return Err("expected rd=0 in Opcode SYSTEM func3=0b000".to_owned());
+                    }
+                    match (func7, (instruction >> 20) & 0b1_1111, rs1) {
+                        (0b000_0000, 0b0_0000, IRegister::Zero) => Ok(Instruction::Ecall),
+                        (0b000_0000, 0b0_0001, IRegister::Zero) => Ok(Instruction::Ebreak),
+                        (0b000_1000, 0b0_0010, IRegister::Zero) => Ok(Instruction::Sret),
+                        (0b001_1000, 0b0_0010, IRegister::Zero) => Ok(Instruction::Mret),
+                        (0b011_1000, 0b0_0010, IRegister::Zero) => Ok(Instruction::Mnret),
+                        (0b011_1101, 0b1_0010, IRegister::Zero) => Ok(Instruction::Dret),
+                        (0b000_1000, 0b0_0101, IRegister::Zero) => Ok(Instruction::Wfi),
+                        (0b000_0000, 0b0_1101, IRegister::Zero) => Ok(Instruction::WrsNto),
+                        (0b000_0000, 0b1_1101, IRegister::Zero) => Ok(Instruction::WrsSto),
+                        (0b000_1001, _, _) => Ok(Instruction::SfenceVma {
+                            vaddr: rs1,
+                            asid: rs2,
+                        }),
+                        (0b000_1011, _, _) => Ok(Instruction::SinvalVma {
+                            vaddr: rs1,
+                            asid: rs2,
+                        }),
+                        (0b001_0001, _, _) => Ok(Instruction::HfenceVvma {
+                            vaddr: rs1,
+                            asid: rs2,
+                        }),
+                        (0b011_0001, _, _) => Ok(Instruction::HfenceGvma {
+                            gaddr: rs1,
+                            vmid: rs2,
+                        }),
+                        (0b001_0011, _, _) => Ok(Instruction::HinvalVvma {
+                            vaddr: rs1,
+                            asid: rs2,
+                        }),
+                        (0b011_0011, _, _) => Ok(Instruction::HinvalGvma {
+                            gaddr: rs1,
+                            vmid: rs2,
+                        }),
+                        (0b000_1100, 0b0_0000, IRegister::Zero) => Ok(Instruction::SfenceWInval),
+                        (0b000_1100, 0b0_0001, IRegister::Zero) => Ok(Instruction::SfenceInvalIr),
+                        (x, y, _) => Err(format!(
+                            "unknown Opcode SYSTEM func3=0b000 func7: {} and rs2: {}",
+                            x, y
+                        )),
+                    }
+                }
                 0b001 => Ok(Instruction::Csrrw {
                     dest: rd,
                     src: rs1,
@@ -2535,7 +5308,97 @@ impl Instruction {
                     src: rs1,
                     csr: CSR::from_u32(instruction),
                 }),
-                0b100 => Err("Reserved func3 in Opcode SYSTEM".to_owned()),
+                0b100 => match (func7, (instruction >> 20) & 0b1_1111) {
+                    // the Zicfiss instructions use may-be-operation encodings
+                    (0b110_0111, _)
+                        if isa.zicfiss
+                            && rd == IRegister::Zero
+                            && rs1 == IRegister::Zero
+                            && is_link_register(&rs2) =>
+                    {
+                        Ok(Instruction::Sspush { src: rs2 })
+                    }
+                    (0b110_0110, 0b1_1100)
+                        if isa.zicfiss && rd == IRegister::Zero && is_link_register(&rs1) =>
+                    {
+                        Ok(Instruction::Sspopchk { src: rs1 })
+                    }
+                    (0b110_0110, 0b1_1100)
+                        if isa.zicfiss && rs1 == IRegister::Zero && rd != IRegister::Zero =>
+                    {
+                        Ok(Instruction::Ssrdp { dest: rd })
+                    }
+                    (0b011_0000, 0b0_0000) => Ok(Instruction::HlvB {
+                        dest: rd,
+                        addr: rs1,
+                    }),
+                    (0b011_0000, 0b0_0001) => Ok(Instruction::HlvBu {
+                        dest: rd,
+                        addr: rs1,
+                    }),
+                    (0b011_0010, 0b0_0000) => Ok(Instruction::HlvH {
+                        dest: rd,
+                        addr: rs1,
+                    }),
+                    (0b011_0010, 0b0_0001) => Ok(Instruction::HlvHu {
+                        dest: rd,
+                        addr: rs1,
+                    }),
+                    (0b011_0010, 0b0_0011) => Ok(Instruction::HlvxHu {
+                        dest: rd,
+                        addr: rs1,
+                    }),
+                    (0b011_0100, 0b0_0000) => Ok(Instruction::HlvW {
+                        dest: rd,
+                        addr: rs1,
+                    }),
+                    (0b011_0100, 0b0_0011) => Ok(Instruction::HlvxWu {
+                        dest: rd,
+                        addr: rs1,
+                    }),
+                    (0b011_0100, 0b0_0001) => Ok(Instruction::HlvWu {
+                        dest: rd,
+                        addr: rs1,
+                    }),
+                    (0b011_0110, 0b0_0000) => Ok(Instruction::HlvD {
+                        dest: rd,
+                        addr: rs1,
+                    }),
+                    (0b011_0001, _) if rd == IRegister::Zero => Ok(Instruction::HsvB {
+                        src: rs2,
+                        addr: rs1,
+                    }),
+                    (0b011_0011, _) if rd == IRegister::Zero => Ok(Instruction::HsvH {
+                        src: rs2,
+                        addr: rs1,
+                    }),
+                    (0b011_0101, _) if rd == IRegister::Zero => Ok(Instruction::HsvW {
+                        src: rs2,
+                        addr: rs1,
+                    }),
+                    (0b011_0111, _) if rd == IRegister::Zero => Ok(Instruction::HsvD {
+                        src: rs2,
+                        addr: rs1,
+                    }),
+                    // may-be-operations, with the bits of n scattered through the upper bits
+                    _ if instruction & 0xB3C0_0000 == 0x81C0_0000 => Ok(Instruction::MopR {
+                        n: ((instruction >> 26 & 0b1_0000)
+                            | (instruction >> 24 & 0b1100)
+                            | (instruction >> 20 & 0b11)) as u8,
+                        dest: rd,
+                        src: rs1,
+                    }),
+                    _ if instruction & 0xB200_0000 == 0x8200_0000 => Ok(Instruction::MopRr {
+                        n: ((instruction >> 28 & 0b100) | (instruction >> 26 & 0b11)) as u8,
+                        dest: rd,
+                        src1: rs1,
+                        src2: rs2,
+                    }),
+                    (x, y) => Err(format!(
+                        "unknown Opcode SYSTEM func3=0b100 func7: {} and rs2: {}",
+                        x, y
+                    )),
+                },
                 0b101 => Ok(Instruction::Csrrwi {
                     dest: rd,
                     imm: CSRImmediate::from_u32(instruction),
@@ -2556,6 +5419,8 @@ impl Instruction {
         }
     }
 
+    // zero fields are kept in the encodings to match the ISA manual tables
+    #[allow(clippy::identity_op)]
     pub fn encode(instruction: &Instruction) -> u32 {
         match instruction {
             Instruction::Lui { dest, imm } => imm.to_u32() | dest.rd() | 0b0110111,
@@ -2564,234 +5429,1204 @@ impl Instruction {
             Instruction::Jalr { dest, base, offset } => {
                 offset.to_u32() | base.rs1() | dest.rd() | 0b1100111
             }
-            Instruction::Beq { src1, src2, offset } => {
-                offset.to_u32() | src2.rs2() | src1.rs1() | 0b000 << 12 | 0b1100011
+            Instruction::Beq { src1, src2, offset } => {
+                offset.to_u32() | src2.rs2() | src1.rs1() | 0b000 << 12 | 0b1100011
+            }
+            Instruction::Bne { src1, src2, offset } => {
+                offset.to_u32() | src2.rs2() | src1.rs1() | 0b001 << 12 | 0b1100011
+            }
+            Instruction::Blt { src1, src2, offset } => {
+                offset.to_u32() | src2.rs2() | src1.rs1() | 0b100 << 12 | 0b1100011
+            }
+            Instruction::Bge { src1, src2, offset } => {
+                offset.to_u32() | src2.rs2() | src1.rs1() | 0b101 << 12 | 0b1100011
+            }
+            Instruction::Bltu { src1, src2, offset } => {
+                offset.to_u32() | src2.rs2() | src1.rs1() | 0b110 << 12 | 0b1100011
+            }
+            Instruction::Bgeu { src1, src2, offset } => {
+                offset.to_u32() | src2.rs2() | src1.rs1() | 0b111 << 12 | 0b1100011
+            }
+            Instruction::Lb { dest, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b000 << 12 | dest.rd() | 0b0000011
+            }
+            Instruction::Lh { dest, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b001 << 12 | dest.rd() | 0b0000011
+            }
+            Instruction::Lw { dest, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b010 << 12 | dest.rd() | 0b0000011
+            }
+            Instruction::Lbu { dest, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b100 << 12 | dest.rd() | 0b0000011
+            }
+            Instruction::Lhu { dest, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b101 << 12 | dest.rd() | 0b0000011
+            }
+            Instruction::Sb { src, base, offset } => {
+                offset.to_u32() | src.rs2() | base.rs1() | 0b000 << 12 | 0b0100011
+            }
+            Instruction::Sh { src, base, offset } => {
+                offset.to_u32() | src.rs2() | base.rs1() | 0b001 << 12 | 0b0100011
+            }
+            Instruction::Sw { src, base, offset } => {
+                offset.to_u32() | src.rs2() | base.rs1() | 0b010 << 12 | 0b0100011
+            }
+            Instruction::Addi { dest, src, imm } => {
+                imm.to_u32() | src.rs1() | 0b000 << 12 | dest.rd() | 0b0010011
+            }
+            Instruction::Slti { dest, src, imm } => {
+                imm.to_u32() | src.rs1() | 0b010 << 12 | dest.rd() | 0b0010011
+            }
+            Instruction::Sltiu { dest, src, imm } => {
+                imm.to_u32() | src.rs1() | 0b011 << 12 | dest.rd() | 0b0010011
+            }
+            Instruction::Xori { dest, src, imm } => {
+                imm.to_u32() | src.rs1() | 0b100 << 12 | dest.rd() | 0b0010011
+            }
+            Instruction::Ori { dest, src, imm } => {
+                imm.to_u32() | src.rs1() | 0b110 << 12 | dest.rd() | 0b0010011
+            }
+            Instruction::Andi { dest, src, imm } => {
+                imm.to_u32() | src.rs1() | 0b111 << 12 | dest.rd() | 0b0010011
+            }
+            Instruction::Slli { dest, src, shamt } => {
+                shamt.to_u32() | src.rs1() | 0b001 << 12 | dest.rd() | 0b0010011
+            }
+            Instruction::Srli { dest, src, shamt } => {
+                shamt.to_u32() | src.rs1() | 0b101 << 12 | dest.rd() | 0b0010011
+            }
+            Instruction::Srai { dest, src, shamt } => {
+                0b0100000 << 25 | shamt.to_u32() | src.rs1() | 0b101 << 12 | dest.rd() | 0b0010011
+            }
+            Instruction::Add { dest, src1, src2 } => {
+                src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Sub { dest, src1, src2 } => {
+                0b0100000 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Sll { dest, src1, src2 } => {
+                src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Slt { dest, src1, src2 } => {
+                src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Sltu { dest, src1, src2 } => {
+                src2.rs2() | src1.rs1() | 0b011 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Xor { dest, src1, src2 } => {
+                src2.rs2() | src1.rs1() | 0b100 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Srl { dest, src1, src2 } => {
+                src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Sra { dest, src1, src2 } => {
+                0b0100000 << 25 | src2.rs2() | src1.rs1() | 0b0101 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Or { dest, src1, src2 } => {
+                src2.rs2() | src1.rs1() | 0b110 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::And { dest, src1, src2 } => {
+                src2.rs2() | src1.rs1() | 0b111 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Fence { rd, rs1, ops, fm } => {
+                (*fm as u32) << 28 | (*ops as u32) << 20 | rs1.rs1() | rd.rd() | 0b0001111
+            }
+            Instruction::Ecall => 0b1110011,
+            Instruction::Ebreak => 0b1 << 20 | 0b1110011,
+            Instruction::Lwu { dest, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b110 << 12 | dest.rd() | 0b0000011
+            }
+            Instruction::Ld { dest, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b011 << 12 | dest.rd() | 0b0000011
+            }
+            Instruction::Sd { src, base, offset } => {
+                offset.to_u32() | src.rs2() | base.rs1() | 0b011 << 12 | 0b0100011
+            }
+            Instruction::Addiw { dest, src, imm } => {
+                imm.to_u32() | src.rs1() | 0b000 << 12 | dest.rd() | 0b0011011
+            }
+            Instruction::Slliw { dest, src, shamt } => {
+                shamt.to_u32() | src.rs1() | 0b001 << 12 | dest.rd() | 0b0011011
+            }
+            Instruction::Srliw { dest, src, shamt } => {
+                shamt.to_u32() | src.rs1() | 0b101 << 12 | dest.rd() | 0b0011011
+            }
+            Instruction::Sraiw { dest, src, shamt } => {
+                0b0100000 << 25 | shamt.to_u32() | src.rs1() | 0b101 << 12 | dest.rd() | 0b0011011
+            }
+            Instruction::Addw { dest, src1, src2 } => {
+                src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0111011
+            }
+            Instruction::Subw { dest, src1, src2 } => {
+                0b0100000 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0111011
+            }
+            Instruction::Sllw { dest, src1, src2 } => {
+                src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b0111011
+            }
+            Instruction::Srlw { dest, src1, src2 } => {
+                src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b0111011
+            }
+            Instruction::Sraw { dest, src1, src2 } => {
+                0b0100000 << 25 | src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b0111011
+            }
+            Instruction::Mul { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Mulh { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Mulhsu { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Mulhu { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b011 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Div { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b100 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Divu { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Rem { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b110 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Remu { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b111 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Mulw { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0111011
+            }
+            Instruction::Divw { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b100 << 12 | dest.rd() | 0b0111011
+            }
+            Instruction::Divuw { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b0111011
+            }
+            Instruction::Remw { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b110 << 12 | dest.rd() | 0b0111011
+            }
+            Instruction::Remuw { dest, src1, src2 } => {
+                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b111 << 12 | dest.rd() | 0b0111011
+            }
+            Instruction::LrW { dest, addr, aq, rl } => {
+                0b00010 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::ScW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b00011 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmoswapW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b00001 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmoaddW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b00000 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmoxorW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b00100 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmoandW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b01100 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmoorW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b01000 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmominW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b10000 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmomaxW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b10100 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmominuW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b11000 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmomaxuW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b11100 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::LrD { dest, addr, aq, rl } => {
+                0b00010 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::ScD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b00011 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmoswapD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b00001 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmoaddD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b00000 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmoxorD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b00100 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmoandD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b01100 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmoorD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b01000 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmominD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b10000 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmomaxD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b10100 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmominuD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b11000 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::AmomaxuD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b11100 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::Flw { dest, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b010 << 12 | dest.rd() | 0b0000111
+            }
+            Instruction::Fsw { base, src, offset } => {
+                offset.to_u32() | src.rs2() | base.rs1() | 0b010 << 12 | 0b0100111
+            }
+            Instruction::FmaddS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => src3.rs3() | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1000011,
+            Instruction::FmsubS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => src3.rs3() | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1000111,
+            Instruction::FnmsubS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => src3.rs3() | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1001011,
+            Instruction::FnmaddS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => src3.rs3() | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1001111,
+            Instruction::FaddS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0000000 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FsubS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0000100 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FmulS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0001000 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FdivS {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0001100 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FsqrtS { dest, src, rm } => {
+                0b0101100 << 25 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::FsgnjS { dest, src1, src2 } => {
+                0b0010000 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FsgnjnS { dest, src1, src2 } => {
+                0b0010000 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FsgnjxS { dest, src1, src2 } => {
+                0b0010000 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FminS { dest, src1, src2 } => {
+                0b0010100 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FmaxS { dest, src1, src2 } => {
+                0b0010100 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FcvtWS { dest, src, rm } => {
+                0b1100000 << 25 | 0b00000 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::FcvtWuS { dest, src, rm } => {
+                0b1100000 << 25 | 0b00001 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::FmvXW { dest, src } => 0b1110000 << 25 | src.rs1() | dest.rd() | 0b1010011,
+            Instruction::FeqS { dest, src1, src2 } => {
+                0b1010000 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FltS { dest, src1, src2 } => {
+                0b1010000 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FleS { dest, src1, src2 } => {
+                0b1010000 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FclassS { dest, src } => {
+                0b1110000 << 25 | src.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FcvtSW { dest, src, rm } => {
+                0b1101000 << 25 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::FcvtSWu { dest, src, rm } => {
+                0b1101000 << 25 | 0b00001 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::FmvWX { dest, src } => 0b1111000 << 25 | src.rs1() | dest.rd() | 0b1010011,
+            Instruction::FcvtLS { dest, src, rm } => {
+                0b1100000 << 25 | 0b00010 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::FcvtLuS { dest, src, rm } => {
+                0b1100000 << 25 | 0b00011 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::FcvtSL { dest, src, rm } => {
+                0b1101000 << 25 | 0b00010 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::FcvtSLu { dest, src, rm } => {
+                0b1101000 << 25 | 0b00011 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::Csrrw { dest, src, csr } => {
+                csr.to_u32() | src.rs1() | 0b001 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::Csrrs { dest, src, csr } => {
+                csr.to_u32() | src.rs1() | 0b010 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::Csrrc { dest, src, csr } => {
+                csr.to_u32() | src.rs1() | 0b011 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::Csrrwi { dest, imm, csr } => {
+                csr.to_u32() | imm.to_u32() | 0b101 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::Csrrsi { dest, imm, csr } => {
+                csr.to_u32() | imm.to_u32() | 0b110 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::Csrrci { dest, imm, csr } => {
+                csr.to_u32() | imm.to_u32() | 0b111 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::FenceI => 0b001 << 12 | 0b0001111,
+            Instruction::Fld { dest, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b011 << 12 | dest.rd() | 0b0000111
+            }
+            Instruction::Fsd { src, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b011 << 12 | src.rs2() | 0b0100111
+            }
+            Instruction::FmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                src3.rs3()
+                    | 0b01 << 25
+                    | src2.rs2()
+                    | src1.rs1()
+                    | rm.to_u32()
+                    | dest.rd()
+                    | 0b1000011
+            }
+            Instruction::FmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                src3.rs3()
+                    | 0b01 << 25
+                    | src2.rs2()
+                    | src1.rs1()
+                    | rm.to_u32()
+                    | dest.rd()
+                    | 0b1000111
+            }
+            Instruction::FnmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                src3.rs3()
+                    | 0b01 << 25
+                    | src2.rs2()
+                    | src1.rs1()
+                    | rm.to_u32()
+                    | dest.rd()
+                    | 0b1001111
+            }
+            Instruction::FnmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                src3.rs3()
+                    | 0b01 << 25
+                    | src2.rs2()
+                    | src1.rs1()
+                    | rm.to_u32()
+                    | dest.rd()
+                    | 0b1001011
+            }
+            Instruction::FaddD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0000001 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FsubD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0000101 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FmulD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0001001 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FdivD {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0001101 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FsqrtD {
+                dest,
+                src: src1,
+                rm,
+            } => 0b0101101 << 25 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FsgnjD { dest, src1, src2 } => {
+                0b0010001 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FsgnjnD { dest, src1, src2 } => {
+                0b0010001 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FsgnjxD { dest, src1, src2 } => {
+                0b0010001 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FminD { dest, src1, src2 } => {
+                0b0010101 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FmaxD { dest, src1, src2 } => {
+                0b0010101 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FcvtSD { dest, src, rm } => {
+                0b0100000 << 25 | 0b00001 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::FcvtDS { dest, src, rm } => {
+                0b0100001 << 25 | 0b00000 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::FeqD { dest, src1, src2 } => {
+                0b1010001 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FltD { dest, src1, src2 } => {
+                0b1010001 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Bne { src1, src2, offset } => {
-                offset.to_u32() | src2.rs2() | src1.rs1() | 0b001 << 12 | 0b1100011
+            Instruction::FleD { dest, src1, src2 } => {
+                0b1010001 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Blt { src1, src2, offset } => {
-                offset.to_u32() | src2.rs2() | src1.rs1() | 0b100 << 12 | 0b1100011
+            Instruction::FclassD { dest, src1 } => {
+                0b1110001 << 25 | 0b00000 << 20 | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Bge { src1, src2, offset } => {
-                offset.to_u32() | src2.rs2() | src1.rs1() | 0b101 << 12 | 0b1100011
+            Instruction::FcvtWD { dest, src1, rm } => {
+                0b1100001 << 25 | 0b00000 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Bltu { src1, src2, offset } => {
-                offset.to_u32() | src2.rs2() | src1.rs1() | 0b110 << 12 | 0b1100011
+            Instruction::FcvtWuD { dest, src1, rm } => {
+                0b1100001 << 25 | 0b00001 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Bgeu { src1, src2, offset } => {
-                offset.to_u32() | src2.rs2() | src1.rs1() | 0b111 << 12 | 0b1100011
+            Instruction::FcvtDW { dest, src1, rm } => {
+                0b1101001 << 25 | 0b00000 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Lb { dest, base, offset } => {
-                offset.to_u32() | base.rs1() | 0b000 << 12 | dest.rd() | 0b0000011
+            Instruction::FcvtDWu { dest, src1, rm } => {
+                0b1101001 << 25 | 0b00001 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Lh { dest, base, offset } => {
-                offset.to_u32() | base.rs1() | 0b001 << 12 | dest.rd() | 0b0000011
+            Instruction::FcvtLD { dest, src1, rm } => {
+                0b1100001 << 25 | 0b00010 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Lw { dest, base, offset } => {
-                offset.to_u32() | base.rs1() | 0b010 << 12 | dest.rd() | 0b0000011
+            Instruction::FcvtLuD { dest, src1, rm } => {
+                0b1100001 << 25 | 0b00011 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Lbu { dest, base, offset } => {
-                offset.to_u32() | base.rs1() | 0b100 << 12 | dest.rd() | 0b0000011
+            Instruction::FmvXD { dest, src } => {
+                0b1110001 << 25 | 0b00000 << 20 | src.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Lhu { dest, base, offset } => {
-                offset.to_u32() | base.rs1() | 0b101 << 12 | dest.rd() | 0b0000011
+            Instruction::FcvtDL {
+                dest,
+                src: src1,
+                rm,
+            } => 0b1101001 << 25 | 0b00010 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FcvtDLu {
+                dest,
+                src: src1,
+                rm,
+            } => 0b1101001 << 25 | 0b00011 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FmvDX { dest, src } => {
+                0b1111001 << 25 | 0b00000 << 20 | src.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Sb { src, base, offset } => {
-                offset.to_u32() | src.rs2() | base.rs1() | 0b000 << 12 | 0b0100011
+            Instruction::Flq { dest, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b100 << 12 | dest.rd() | 0b0000111
             }
-            Instruction::Sh { src, base, offset } => {
-                offset.to_u32() | src.rs2() | base.rs1() | 0b001 << 12 | 0b0100011
+            Instruction::Fsq { src, base, offset } => {
+                offset.to_u32() | base.rs1() | 0b100 << 12 | src.rs2() | 0b0100111
             }
-            Instruction::Sw { src, base, offset } => {
-                offset.to_u32() | src.rs2() | base.rs1() | 0b010 << 12 | 0b0100011
+            Instruction::FmaddQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                src3.rs3()
+                    | 0b11 << 25
+                    | src2.rs2()
+                    | src1.rs1()
+                    | rm.to_u32()
+                    | dest.rd()
+                    | 0b1000011
             }
-            Instruction::Addi { dest, src, imm } => {
-                imm.to_u32() | src.rs1() | 0b000 << 12 | dest.rd() | 0b0010011
+            Instruction::FmsubQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                src3.rs3()
+                    | 0b11 << 25
+                    | src2.rs2()
+                    | src1.rs1()
+                    | rm.to_u32()
+                    | dest.rd()
+                    | 0b1000111
             }
-            Instruction::Slti { dest, src, imm } => {
-                imm.to_u32() | src.rs1() | 0b010 << 12 | dest.rd() | 0b0010011
+            Instruction::FnmaddQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                src3.rs3()
+                    | 0b11 << 25
+                    | src2.rs2()
+                    | src1.rs1()
+                    | rm.to_u32()
+                    | dest.rd()
+                    | 0b1001111
             }
-            Instruction::Sltiu { dest, src, imm } => {
-                imm.to_u32() | src.rs1() | 0b011 << 12 | dest.rd() | 0b0010011
+            Instruction::FnmsubQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+            } => {
+                src3.rs3()
+                    | 0b11 << 25
+                    | src2.rs2()
+                    | src1.rs1()
+                    | rm.to_u32()
+                    | dest.rd()
+                    | 0b1001011
             }
-            Instruction::Xori { dest, src, imm } => {
-                imm.to_u32() | src.rs1() | 0b100 << 12 | dest.rd() | 0b0010011
+            Instruction::FaddQ {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0000011 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FsubQ {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0000111 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FmulQ {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0001011 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FdivQ {
+                dest,
+                src1,
+                src2,
+                rm,
+            } => 0b0001111 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
+            Instruction::FsqrtQ { dest, src, rm } => {
+                0b0101111 << 25 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Ori { dest, src, imm } => {
-                imm.to_u32() | src.rs1() | 0b110 << 12 | dest.rd() | 0b0010011
+            Instruction::FsgnjQ { dest, src1, src2 } => {
+                0b0010011 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Andi { dest, src, imm } => {
-                imm.to_u32() | src.rs1() | 0b111 << 12 | dest.rd() | 0b0010011
+            Instruction::FsgnjnQ { dest, src1, src2 } => {
+                0b0010011 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Slli { dest, src, shamt } => {
-                shamt.to_u32() | src.rs1() | 0b001 << 12 | dest.rd() | 0b0010011
+            Instruction::FsgnjxQ { dest, src1, src2 } => {
+                0b0010011 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Srli { dest, src, shamt } => {
-                shamt.to_u32() | src.rs1() | 0b101 << 12 | dest.rd() | 0b0010011
+            Instruction::FminQ { dest, src1, src2 } => {
+                0b0010111 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Srai { dest, src, shamt } => {
-                0b0100000 << 25 | shamt.to_u32() | src.rs1() | 0b101 << 12 | dest.rd() | 0b0010011
+            Instruction::FmaxQ { dest, src1, src2 } => {
+                0b0010111 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Add { dest, src1, src2 } => {
-                src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0110011
+            Instruction::FcvtSQ { dest, src, rm } => {
+                0b0100000 << 25 | 0b00011 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Sub { dest, src1, src2 } => {
-                0b0100000 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0110011
+            Instruction::FcvtQS { dest, src, rm } => {
+                0b0100011 << 25 | 0b00000 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Sll { dest, src1, src2 } => {
-                src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b0110011
+            Instruction::FcvtDQ { dest, src, rm } => {
+                0b0100001 << 25 | 0b00011 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Slt { dest, src1, src2 } => {
-                src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b0110011
+            Instruction::FcvtQD { dest, src, rm } => {
+                0b0100011 << 25 | 0b00001 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Sltu { dest, src1, src2 } => {
-                src2.rs2() | src1.rs1() | 0b011 << 12 | dest.rd() | 0b0110011
+            Instruction::FeqQ { dest, src1, src2 } => {
+                0b1010011 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Xor { dest, src1, src2 } => {
-                src2.rs2() | src1.rs1() | 0b100 << 12 | dest.rd() | 0b0110011
+            Instruction::FltQ { dest, src1, src2 } => {
+                0b1010011 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Srl { dest, src1, src2 } => {
-                src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b0110011
+            Instruction::FleQ { dest, src1, src2 } => {
+                0b1010011 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Sra { dest, src1, src2 } => {
-                0b0100000 << 25 | src2.rs2() | src1.rs1() | 0b0101 << 12 | dest.rd() | 0b0110011
+            Instruction::FclassQ { dest, src } => {
+                0b1110011 << 25 | 0b00000 << 20 | src.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Or { dest, src1, src2 } => {
-                src2.rs2() | src1.rs1() | 0b110 << 12 | dest.rd() | 0b0110011
+            Instruction::FcvtWQ { dest, src, rm } => {
+                0b1100011 << 25 | 0b00000 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::And { dest, src1, src2 } => {
-                src2.rs2() | src1.rs1() | 0b111 << 12 | dest.rd() | 0b0110011
+            Instruction::FcvtWuQ { dest, src, rm } => {
+                0b1100011 << 25 | 0b00001 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Fence { rd, rs1, ops, fm } => {
-                (*fm as u32) << 28 | (*ops as u32) << 20 | rs1.rs1() | rd.rd() | 0b0001111
+            Instruction::FcvtQW { dest, src, rm } => {
+                0b1101011 << 25 | 0b00000 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Ecall => 0b1110011,
-            Instruction::Ebreak => 0b1 << 20 | 0b1110011,
-            Instruction::Lwu { dest, base, offset } => {
-                offset.to_u32() | base.rs1() | 0b110 << 12 | dest.rd() | 0b0000011
+            Instruction::FcvtQWu { dest, src, rm } => {
+                0b1101011 << 25 | 0b00001 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Ld { dest, base, offset } => {
-                offset.to_u32() | base.rs1() | 0b011 << 12 | dest.rd() | 0b0000011
+            Instruction::FcvtLQ { dest, src, rm } => {
+                0b1100011 << 25 | 0b00010 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Sd { src, base, offset } => {
-                offset.to_u32() | src.rs2() | base.rs1() | 0b011 << 12 | 0b0100011
+            Instruction::FcvtLuQ { dest, src, rm } => {
+                0b1100011 << 25 | 0b00011 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Addiw { dest, src, imm } => {
-                imm.to_u32() | src.rs1() | 0b000 << 12 | dest.rd() | 0b0011011
+            Instruction::FcvtQL { dest, src, rm } => {
+                0b1101011 << 25 | 0b00010 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Slliw { dest, src, shamt } => {
-                shamt.to_u32() | src.rs1() | 0b001 << 12 | dest.rd() | 0b0011011
+            Instruction::FcvtQLu { dest, src, rm } => {
+                0b1101011 << 25 | 0b00011 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Srliw { dest, src, shamt } => {
-                shamt.to_u32() | src.rs1() | 0b101 << 12 | dest.rd() | 0b0011011
+            Instruction::FliS { dest, imm } => {
+                0b1111000 << 25 | 0b00001 << 20 | imm.to_u32() | 0b000 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Sraiw { dest, src, shamt } => {
-                0b0100000 << 25 | shamt.to_u32() | src.rs1() | 0b101 << 12 | dest.rd() | 0b0011011
+            Instruction::FliD { dest, imm } => {
+                0b1111001 << 25 | 0b00001 << 20 | imm.to_u32() | 0b000 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Addw { dest, src1, src2 } => {
-                src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0111011
+            Instruction::FliH { dest, imm } => {
+                0b1111010 << 25 | 0b00001 << 20 | imm.to_u32() | 0b000 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Subw { dest, src1, src2 } => {
-                0b0100000 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0111011
+            Instruction::FliQ { dest, imm } => {
+                0b1111011 << 25 | 0b00001 << 20 | imm.to_u32() | 0b000 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Sllw { dest, src1, src2 } => {
-                src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b0111011
+            Instruction::FminmS { dest, src1, src2 } => {
+                0b0010100 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Srlw { dest, src1, src2 } => {
-                src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b0111011
+            Instruction::FminmD { dest, src1, src2 } => {
+                0b0010101 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Sraw { dest, src1, src2 } => {
-                0b0100000 << 25 | src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b0111011
+            Instruction::FminmH { dest, src1, src2 } => {
+                0b0010110 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Mul { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0110011
+            Instruction::FminmQ { dest, src1, src2 } => {
+                0b0010111 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Mulh { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b0110011
+            Instruction::FmaxmS { dest, src1, src2 } => {
+                0b0010100 << 25 | src2.rs2() | src1.rs1() | 0b011 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Mulhsu { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b0110011
+            Instruction::FmaxmD { dest, src1, src2 } => {
+                0b0010101 << 25 | src2.rs2() | src1.rs1() | 0b011 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Mulhu { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b011 << 12 | dest.rd() | 0b0110011
+            Instruction::FmaxmH { dest, src1, src2 } => {
+                0b0010110 << 25 | src2.rs2() | src1.rs1() | 0b011 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Div { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b100 << 12 | dest.rd() | 0b0110011
+            Instruction::FmaxmQ { dest, src1, src2 } => {
+                0b0010111 << 25 | src2.rs2() | src1.rs1() | 0b011 << 12 | dest.rd() | 0b1010011
             }
-            Instruction::Divu { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b0110011
+            Instruction::FroundS { dest, src, rm } => {
+                0b0100000 << 25 | 0b00100 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Rem { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b110 << 12 | dest.rd() | 0b0110011
+            Instruction::FroundD { dest, src, rm } => {
+                0b0100001 << 25 | 0b00100 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Remu { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b111 << 12 | dest.rd() | 0b0110011
+            Instruction::FroundH { dest, src, rm } => {
+                0b0100010 << 25 | 0b00100 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Mulw { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b0111011
+            Instruction::FroundQ { dest, src, rm } => {
+                0b0100011 << 25 | 0b00100 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Divw { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b100 << 12 | dest.rd() | 0b0111011
+            Instruction::FroundnxS { dest, src, rm } => {
+                0b0100000 << 25 | 0b00101 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Divuw { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b0111011
+            Instruction::FroundnxD { dest, src, rm } => {
+                0b0100001 << 25 | 0b00101 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Remw { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b110 << 12 | dest.rd() | 0b0111011
+            Instruction::FroundnxH { dest, src, rm } => {
+                0b0100010 << 25 | 0b00101 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::Remuw { dest, src1, src2 } => {
-                0b0000001 << 25 | src2.rs2() | src1.rs1() | 0b111 << 12 | dest.rd() | 0b0111011
+            Instruction::FroundnxQ { dest, src, rm } => {
+                0b0100011 << 25 | 0b00101 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
             }
-            Instruction::LrW { dest, addr, aq, rl } => {
-                0b00010 << 27
-                    | aqb(*aq)
-                    | rlb(*rl)
-                    | addr.rs1()
-                    | 0b010 << 12
+            Instruction::FcvtmodWD { dest, src } => {
+                0b1100001 << 25 | 0b01000 << 20 | src.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FleqS { dest, src1, src2 } => {
+                0b1010000 << 25 | src2.rs2() | src1.rs1() | 0b100 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FleqD { dest, src1, src2 } => {
+                0b1010001 << 25 | src2.rs2() | src1.rs1() | 0b100 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FleqH { dest, src1, src2 } => {
+                0b1010010 << 25 | src2.rs2() | src1.rs1() | 0b100 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FleqQ { dest, src1, src2 } => {
+                0b1010011 << 25 | src2.rs2() | src1.rs1() | 0b100 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FltqS { dest, src1, src2 } => {
+                0b1010000 << 25 | src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FltqD { dest, src1, src2 } => {
+                0b1010001 << 25 | src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FltqH { dest, src1, src2 } => {
+                0b1010010 << 25 | src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FltqQ { dest, src1, src2 } => {
+                0b1010011 << 25 | src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FmvhXD { dest, src } => {
+                0b1110001 << 25 | 0b00001 << 20 | src.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FmvpDX { dest, src1, src2 } => {
+                0b1011001 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
+            }
+            Instruction::FcvtBf16S { dest, src, rm } => {
+                0b0100010 << 25 | 0b01000 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::FcvtSBf16 { dest, src, rm } => {
+                0b0100000 << 25 | 0b00110 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            }
+            Instruction::Vfncvtbf16FFW { dest, src, masked } => {
+                0b010010 << 26
+                    | (!masked as u32) << 25
+                    | src.rs2()
+                    | 0b11101 << 15
+                    | 0b001 << 12
                     | dest.rd()
-                    | 0b0101111
+                    | 0b1010111
             }
-            Instruction::ScW {
+            Instruction::Vfwcvtbf16FFV { dest, src, masked } => {
+                0b010010 << 26
+                    | (!masked as u32) << 25
+                    | src.rs2()
+                    | 0b01101 << 15
+                    | 0b001 << 12
+                    | dest.rd()
+                    | 0b1010111
+            }
+            Instruction::Vfwmaccbf16VV {
                 dest,
-                addr,
-                src,
-                aq,
-                rl,
+                src1,
+                src2,
+                masked,
             } => {
-                0b00011 << 27
-                    | aqb(*aq)
-                    | rlb(*rl)
-                    | src.rs2()
-                    | addr.rs1()
-                    | 0b010 << 12
+                0b111011 << 26
+                    | (!masked as u32) << 25
+                    | src2.rs2()
+                    | src1.rs1()
+                    | 0b001 << 12
                     | dest.rd()
-                    | 0b0101111
+                    | 0b1010111
             }
-            Instruction::AmoswapW {
+            Instruction::Vfwmaccbf16VF {
                 dest,
-                addr,
-                src,
-                aq,
-                rl,
+                src1,
+                src2,
+                masked,
             } => {
-                0b00001 << 27
-                    | aqb(*aq)
-                    | rlb(*rl)
-                    | src.rs2()
-                    | addr.rs1()
-                    | 0b010 << 12
+                0b111011 << 26
+                    | (!masked as u32) << 25
+                    | src2.rs2()
+                    | src1.rs1()
+                    | 0b101 << 12
                     | dest.rd()
-                    | 0b0101111
+                    | 0b1010111
             }
-            Instruction::AmoaddW {
+            Instruction::Sret => 0b0001000 << 25 | 0b00010 << 20 | 0b1110011,
+            Instruction::Mret => 0b0011000 << 25 | 0b00010 << 20 | 0b1110011,
+            Instruction::Wfi => 0b0001000 << 25 | 0b00101 << 20 | 0b1110011,
+            Instruction::SfenceVma { vaddr, asid } => {
+                0b0001001 << 25 | asid.rs2() | vaddr.rs1() | 0b1110011
+            }
+            Instruction::SinvalVma { vaddr, asid } => {
+                0b0001011 << 25 | asid.rs2() | vaddr.rs1() | 0b1110011
+            }
+            Instruction::SfenceWInval => 0b0001100 << 25 | 0b00000 << 20 | 0b1110011,
+            Instruction::SfenceInvalIr => 0b0001100 << 25 | 0b00001 << 20 | 0b1110011,
+            Instruction::Mnret => 0b0111000 << 25 | 0b00010 << 20 | 0b1110011,
+            Instruction::Dret => 0b0111101 << 25 | 0b10010 << 20 | 0b1110011,
+            Instruction::HfenceVvma { vaddr, asid } => {
+                0b0010001 << 25 | asid.rs2() | vaddr.rs1() | 0b1110011
+            }
+            Instruction::HfenceGvma { gaddr, vmid } => {
+                0b0110001 << 25 | vmid.rs2() | gaddr.rs1() | 0b1110011
+            }
+            Instruction::HinvalVvma { vaddr, asid } => {
+                0b0010011 << 25 | asid.rs2() | vaddr.rs1() | 0b1110011
+            }
+            Instruction::HinvalGvma { gaddr, vmid } => {
+                0b0110011 << 25 | vmid.rs2() | gaddr.rs1() | 0b1110011
+            }
+            Instruction::HlvB { dest, addr } => {
+                0b0110000 << 25 | 0b00000 << 20 | addr.rs1() | 0b100 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::HlvBu { dest, addr } => {
+                0b0110000 << 25 | 0b00001 << 20 | addr.rs1() | 0b100 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::HlvH { dest, addr } => {
+                0b0110010 << 25 | 0b00000 << 20 | addr.rs1() | 0b100 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::HlvHu { dest, addr } => {
+                0b0110010 << 25 | 0b00001 << 20 | addr.rs1() | 0b100 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::HlvxHu { dest, addr } => {
+                0b0110010 << 25 | 0b00011 << 20 | addr.rs1() | 0b100 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::HlvW { dest, addr } => {
+                0b0110100 << 25 | 0b00000 << 20 | addr.rs1() | 0b100 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::HlvxWu { dest, addr } => {
+                0b0110100 << 25 | 0b00011 << 20 | addr.rs1() | 0b100 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::HlvWu { dest, addr } => {
+                0b0110100 << 25 | 0b00001 << 20 | addr.rs1() | 0b100 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::HlvD { dest, addr } => {
+                0b0110110 << 25 | 0b00000 << 20 | addr.rs1() | 0b100 << 12 | dest.rd() | 0b1110011
+            }
+            Instruction::HsvB { src, addr } => {
+                0b0110001 << 25 | src.rs2() | addr.rs1() | 0b100 << 12 | 0b1110011
+            }
+            Instruction::HsvH { src, addr } => {
+                0b0110011 << 25 | src.rs2() | addr.rs1() | 0b100 << 12 | 0b1110011
+            }
+            Instruction::HsvW { src, addr } => {
+                0b0110101 << 25 | src.rs2() | addr.rs1() | 0b100 << 12 | 0b1110011
+            }
+            Instruction::HsvD { src, addr } => {
+                0b0110111 << 25 | src.rs2() | addr.rs1() | 0b100 << 12 | 0b1110011
+            }
+            Instruction::CboClean { base } => 1 << 20 | base.rs1() | 0b010 << 12 | 0b0001111,
+            Instruction::CboFlush { base } => 2 << 20 | base.rs1() | 0b010 << 12 | 0b0001111,
+            Instruction::CboInval { base } => 0 << 20 | base.rs1() | 0b010 << 12 | 0b0001111,
+            Instruction::CboZero { base } => 4 << 20 | base.rs1() | 0b010 << 12 | 0b0001111,
+            Instruction::PrefetchI { base, offset } => {
+                offset.to_u32() | 0b00000 << 20 | base.rs1() | 0b110 << 12 | 0b0010011
+            }
+            Instruction::PrefetchR { base, offset } => {
+                offset.to_u32() | 0b00001 << 20 | base.rs1() | 0b110 << 12 | 0b0010011
+            }
+            Instruction::PrefetchW { base, offset } => {
+                offset.to_u32() | 0b00011 << 20 | base.rs1() | 0b110 << 12 | 0b0010011
+            }
+            Instruction::AmocasW {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b00000 << 27
+                0b00101 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
@@ -2800,581 +6635,506 @@ impl Instruction {
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmoxorW {
+            Instruction::AmocasD {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b00100 << 27
+                0b00101 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b010 << 12
+                    | 0b011 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmoandW {
+            Instruction::AmocasQ {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b01100 << 27
+                0b00101 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b010 << 12
+                    | 0b100 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmoorW {
+            Instruction::AmoswapB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b01000 << 27
+                0b00001 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b010 << 12
+                    | 0b000 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmominW {
+            Instruction::AmoaddB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b10000 << 27
+                0b00000 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b010 << 12
+                    | 0b000 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmomaxW {
+            Instruction::AmoxorB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b10100 << 27
+                0b00100 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b010 << 12
+                    | 0b000 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmominuW {
+            Instruction::AmoandB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b11000 << 27
+                0b01100 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b010 << 12
+                    | 0b000 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmomaxuW {
+            Instruction::AmoorB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b11100 << 27
+                0b01000 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b010 << 12
-                    | dest.rd()
-                    | 0b0101111
-            }
-            Instruction::LrD { dest, addr, aq, rl } => {
-                0b00010 << 27
-                    | aqb(*aq)
-                    | rlb(*rl)
-                    | addr.rs1()
-                    | 0b011 << 12
+                    | 0b000 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::ScD {
+            Instruction::AmominB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b00011 << 27
+                0b10000 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b011 << 12
+                    | 0b000 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmoswapD {
+            Instruction::AmomaxB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b00001 << 27
+                0b10100 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b011 << 12
+                    | 0b000 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmoaddD {
+            Instruction::AmominuB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b00000 << 27
+                0b11000 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b011 << 12
+                    | 0b000 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmoxorD {
+            Instruction::AmomaxuB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b00100 << 27
+                0b11100 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b011 << 12
+                    | 0b000 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmoandD {
+            Instruction::AmocasB {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b01100 << 27
+                0b00101 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b011 << 12
+                    | 0b000 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmoorD {
+            Instruction::AmoswapH {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b01000 << 27
+                0b00001 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b011 << 12
+                    | 0b001 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmominD {
+            Instruction::AmoaddH {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b10000 << 27
+                0b00000 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b011 << 12
+                    | 0b001 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmomaxD {
+            Instruction::AmoxorH {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b10100 << 27
+                0b00100 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b011 << 12
+                    | 0b001 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmominuD {
+            Instruction::AmoandH {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b11000 << 27
+                0b01100 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b011 << 12
+                    | 0b001 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::AmomaxuD {
+            Instruction::AmoorH {
                 dest,
                 addr,
                 src,
                 aq,
                 rl,
             } => {
-                0b11100 << 27
+                0b01000 << 27
                     | aqb(*aq)
                     | rlb(*rl)
                     | src.rs2()
                     | addr.rs1()
-                    | 0b011 << 12
+                    | 0b001 << 12
                     | dest.rd()
                     | 0b0101111
             }
-            Instruction::Flw { dest, base, offset } => {
-                offset.to_u32() | base.rs1() | 0b010 << 12 | dest.rd() | 0b0000111
-            }
-            Instruction::Fsw { base, src, offset } => {
-                offset.to_u32() | src.rs2() | base.rs1() | 0b010 << 12 | 0b0100111
-            }
-            Instruction::FmaddS {
-                dest,
-                src1,
-                src2,
-                src3,
-                rm,
-            } => src3.rs3() | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1000011,
-            Instruction::FmsubS {
-                dest,
-                src1,
-                src2,
-                src3,
-                rm,
-            } => src3.rs3() | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1000111,
-            Instruction::FnmsubS {
-                dest,
-                src1,
-                src2,
-                src3,
-                rm,
-            } => src3.rs3() | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1001011,
-            Instruction::FnmaddS {
-                dest,
-                src1,
-                src2,
-                src3,
-                rm,
-            } => src3.rs3() | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1001111,
-            Instruction::FaddS {
-                dest,
-                src1,
-                src2,
-                rm,
-            } => 0b0000000 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
-            Instruction::FsubS {
-                dest,
-                src1,
-                src2,
-                rm,
-            } => 0b0000100 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
-            Instruction::FmulS {
-                dest,
-                src1,
-                src2,
-                rm,
-            } => 0b0001000 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
-            Instruction::FdivS {
-                dest,
-                src1,
-                src2,
-                rm,
-            } => 0b0001100 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
-            Instruction::FsqrtS { dest, src, rm } => {
-                0b0101100 << 25 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
-            }
-            Instruction::FsgnjS { dest, src1, src2 } => {
-                0b0010000 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FsgnjnS { dest, src1, src2 } => {
-                0b0010000 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FsgnjxS { dest, src1, src2 } => {
-                0b0010000 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FminS { dest, src1, src2 } => {
-                0b0010100 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FmaxS { dest, src1, src2 } => {
-                0b0010100 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FcvtWS { dest, src, rm } => {
-                0b1100000 << 25 | 0b00000 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
-            }
-            Instruction::FcvtWuS { dest, src, rm } => {
-                0b1100000 << 25 | 0b00001 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
-            }
-            Instruction::FmvXW { dest, src } => 0b1110000 << 25 | src.rs1() | dest.rd() | 0b1010011,
-            Instruction::FeqS { dest, src1, src2 } => {
-                0b1010000 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FltS { dest, src1, src2 } => {
-                0b1010000 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FleS { dest, src1, src2 } => {
-                0b1010000 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FclassS { dest, src } => {
-                0b1110000 << 25 | src.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FcvtSW { dest, src, rm } => {
-                0b1101000 << 25 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
-            }
-            Instruction::FcvtSWu { dest, src, rm } => {
-                0b1101000 << 25 | 0b00001 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
-            }
-            Instruction::FmvWX { dest, src } => 0b1111000 << 25 | src.rs1() | dest.rd() | 0b1010011,
-            Instruction::FcvtLS { dest, src, rm } => {
-                0b1100000 << 25 | 0b00010 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
-            }
-            Instruction::FcvtLuS { dest, src, rm } => {
-                0b1100000 << 25 | 0b00011 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
-            }
-            Instruction::FcvtSL { dest, src, rm } => {
-                0b1101000 << 25 | 0b00010 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
-            }
-            Instruction::FcvtSLu { dest, src, rm } => {
-                0b1101000 << 25 | 0b00011 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
-            }
-            Instruction::Csrrw { dest, src, csr } => {
-                csr.to_u32() | src.rs1() | 0b001 << 12 | dest.rd() | 0b1110011
-            }
-            Instruction::Csrrs { dest, src, csr } => {
-                csr.to_u32() | src.rs1() | 0b010 << 12 | dest.rd() | 0b1110011
-            }
-            Instruction::Csrrc { dest, src, csr } => {
-                csr.to_u32() | src.rs1() | 0b011 << 12 | dest.rd() | 0b1110011
-            }
-            Instruction::Csrrwi { dest, imm, csr } => {
-                csr.to_u32() | imm.to_u32() | 0b101 << 12 | dest.rd() | 0b1110011
-            }
-            Instruction::Csrrsi { dest, imm, csr } => {
-                csr.to_u32() | imm.to_u32() | 0b110 << 12 | dest.rd() | 0b1110011
-            }
-            Instruction::Csrrci { dest, imm, csr } => {
-                csr.to_u32() | imm.to_u32() | 0b111 << 12 | dest.rd() | 0b1110011
-            }
-            Instruction::FenceI => 0b001 << 12 | 0b0001111,
-            Instruction::Fld { dest, base, offset } => {
-                offset.to_u32() | base.rs1() | 0b011 << 12 | dest.rd() | 0b0000111
-            }
-            Instruction::Fsd { src, base, offset } => {
-                offset.to_u32() | base.rs1() | 0b011 << 12 | src.rs2() | 0b0100111
-            }
-            Instruction::FmaddD {
+            Instruction::AmominH {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
+                addr,
+                src,
+                aq,
+                rl,
             } => {
-                src3.rs3()
-                    | 0b01 << 25
-                    | src2.rs2()
-                    | src1.rs1()
-                    | rm.to_u32()
+                0b10000 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b001 << 12
                     | dest.rd()
-                    | 0b1000011
+                    | 0b0101111
             }
-            Instruction::FmsubD {
+            Instruction::AmomaxH {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
+                addr,
+                src,
+                aq,
+                rl,
             } => {
-                src3.rs3()
-                    | 0b01 << 25
-                    | src2.rs2()
-                    | src1.rs1()
-                    | rm.to_u32()
+                0b10100 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b001 << 12
                     | dest.rd()
-                    | 0b1000111
+                    | 0b0101111
             }
-            Instruction::FnmaddD {
+            Instruction::AmominuH {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
+                addr,
+                src,
+                aq,
+                rl,
             } => {
-                src3.rs3()
-                    | 0b01 << 25
-                    | src2.rs2()
-                    | src1.rs1()
-                    | rm.to_u32()
+                0b11000 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b001 << 12
                     | dest.rd()
-                    | 0b1001111
+                    | 0b0101111
             }
-            Instruction::FnmsubD {
+            Instruction::AmomaxuH {
                 dest,
-                src1,
-                src2,
-                src3,
-                rm,
+                addr,
+                src,
+                aq,
+                rl,
             } => {
-                src3.rs3()
-                    | 0b01 << 25
-                    | src2.rs2()
-                    | src1.rs1()
-                    | rm.to_u32()
+                0b11100 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b001 << 12
                     | dest.rd()
-                    | 0b1001011
+                    | 0b0101111
             }
-            Instruction::FaddD {
-                dest,
-                src1,
-                src2,
-                rm,
-            } => 0b0000001 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
-            Instruction::FsubD {
-                dest,
-                src1,
-                src2,
-                rm,
-            } => 0b0000101 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
-            Instruction::FmulD {
-                dest,
-                src1,
-                src2,
-                rm,
-            } => 0b0001001 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
-            Instruction::FdivD {
-                dest,
-                src1,
-                src2,
-                rm,
-            } => 0b0001101 << 25 | src2.rs2() | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
-            Instruction::FsqrtD {
+            Instruction::AmocasH {
                 dest,
-                src: src1,
-                rm,
-            } => 0b0101101 << 25 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
-            Instruction::FsgnjD { dest, src1, src2 } => {
-                0b0010001 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FsgnjnD { dest, src1, src2 } => {
-                0b0010001 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FsgnjxD { dest, src1, src2 } => {
-                0b0010001 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FminD { dest, src1, src2 } => {
-                0b0010101 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FmaxD { dest, src1, src2 } => {
-                0b0010101 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
-            }
-            Instruction::FcvtSD { dest, src, rm } => {
-                0b0100000 << 25 | 0b00001 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b00101 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b001 << 12
+                    | dest.rd()
+                    | 0b0101111
             }
-            Instruction::FcvtDS { dest, src, rm } => {
-                0b0100001 << 25 | 0b00000 << 20 | src.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            Instruction::WrsNto => 0b0000_0000_1101 << 20 | 0b1110011,
+            Instruction::WrsSto => 0b0000_0001_1101 << 20 | 0b1110011,
+            Instruction::LbAq { dest, addr, rl } => {
+                0b00110 << 27
+                    | aqb(true)
+                    | rlb(*rl)
+                    | addr.rs1()
+                    | 0b000 << 12
+                    | dest.rd()
+                    | 0b0101111
             }
-            Instruction::FeqD { dest, src1, src2 } => {
-                0b1010001 << 25 | src2.rs2() | src1.rs1() | 0b010 << 12 | dest.rd() | 0b1010011
+            Instruction::LhAq { dest, addr, rl } => {
+                0b00110 << 27
+                    | aqb(true)
+                    | rlb(*rl)
+                    | addr.rs1()
+                    | 0b001 << 12
+                    | dest.rd()
+                    | 0b0101111
             }
-            Instruction::FltD { dest, src1, src2 } => {
-                0b1010001 << 25 | src2.rs2() | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
+            Instruction::LwAq { dest, addr, rl } => {
+                0b00110 << 27
+                    | aqb(true)
+                    | rlb(*rl)
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
             }
-            Instruction::FleD { dest, src1, src2 } => {
-                0b1010001 << 25 | src2.rs2() | src1.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
+            Instruction::LdAq { dest, addr, rl } => {
+                0b00110 << 27
+                    | aqb(true)
+                    | rlb(*rl)
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
             }
-            Instruction::FclassD { dest, src1 } => {
-                0b1110001 << 25 | 0b00000 << 20 | src1.rs1() | 0b001 << 12 | dest.rd() | 0b1010011
+            Instruction::SbRl { src, addr, aq } => {
+                0b00111 << 27
+                    | aqb(*aq)
+                    | rlb(true)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b000 << 12
+                    | 0b0101111
             }
-            Instruction::FcvtWD { dest, src1, rm } => {
-                0b1100001 << 25 | 0b00000 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            Instruction::ShRl { src, addr, aq } => {
+                0b00111 << 27
+                    | aqb(*aq)
+                    | rlb(true)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b001 << 12
+                    | 0b0101111
             }
-            Instruction::FcvtWuD { dest, src1, rm } => {
-                0b1100001 << 25 | 0b00001 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            Instruction::SwRl { src, addr, aq } => {
+                0b00111 << 27
+                    | aqb(*aq)
+                    | rlb(true)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | 0b0101111
             }
-            Instruction::FcvtDW { dest, src1, rm } => {
-                0b1101001 << 25 | 0b00000 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            Instruction::SdRl { src, addr, aq } => {
+                0b00111 << 27
+                    | aqb(*aq)
+                    | rlb(true)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | 0b0101111
             }
-            Instruction::FcvtDWu { dest, src1, rm } => {
-                0b1101001 << 25 | 0b00001 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            Instruction::CzeroEqz { dest, src1, src2 } => {
+                0b0000111 << 25 | src2.rs2() | src1.rs1() | 0b101 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::CzeroNez { dest, src1, src2 } => {
+                0b0000111 << 25 | src2.rs2() | src1.rs1() | 0b111 << 12 | dest.rd() | 0b0110011
+            }
+            Instruction::Lpad { label } => label.to_u32() | 0b0010111,
+            Instruction::MopR { n, dest, src } => {
+                let n = *n as u32;
+                0b1 << 31
+                    | (n >> 4 & 0b1) << 30
+                    | (n >> 2 & 0b11) << 26
+                    | 0b0111 << 22
+                    | (n & 0b11) << 20
+                    | src.rs1()
+                    | 0b100 << 12
+                    | dest.rd()
+                    | 0b1110011
             }
-            Instruction::FcvtLD { dest, src1, rm } => {
-                0b1100001 << 25 | 0b00010 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            Instruction::MopRr {
+                n,
+                dest,
+                src1,
+                src2,
+            } => {
+                let n = *n as u32;
+                0b1 << 31
+                    | (n >> 2 & 0b1) << 30
+                    | (n & 0b11) << 26
+                    | 0b1 << 25
+                    | src2.rs2()
+                    | src1.rs1()
+                    | 0b100 << 12
+                    | dest.rd()
+                    | 0b1110011
             }
-            Instruction::FcvtLuD { dest, src1, rm } => {
-                0b1100001 << 25 | 0b00011 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011
+            Instruction::Sspush { src } => 0b1100111 << 25 | src.rs2() | 0b100 << 12 | 0b1110011,
+            Instruction::Sspopchk { src } => {
+                0b1100_1101_1100 << 20 | src.rs1() | 0b100 << 12 | 0b1110011
             }
-            Instruction::FmvXD { dest, src } => {
-                0b1110001 << 25 | 0b00000 << 20 | src.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
+            Instruction::Ssrdp { dest } => {
+                0b1100_1101_1100 << 20 | 0b100 << 12 | dest.rd() | 0b1110011
             }
-            Instruction::FcvtDL {
+            Instruction::SsamoswapW {
                 dest,
-                src: src1,
-                rm,
-            } => 0b1101001 << 25 | 0b00010 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
-            Instruction::FcvtDLu {
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b01001 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b010 << 12
+                    | dest.rd()
+                    | 0b0101111
+            }
+            Instruction::SsamoswapD {
                 dest,
-                src: src1,
-                rm,
-            } => 0b1101001 << 25 | 0b00011 << 20 | src1.rs1() | rm.to_u32() | dest.rd() | 0b1010011,
-            Instruction::FmvDX { dest, src } => {
-                0b1111001 << 25 | 0b00000 << 20 | src.rs1() | 0b000 << 12 | dest.rd() | 0b1010011
+                addr,
+                src,
+                aq,
+                rl,
+            } => {
+                0b01001 << 27
+                    | aqb(*aq)
+                    | rlb(*rl)
+                    | src.rs2()
+                    | addr.rs1()
+                    | 0b011 << 12
+                    | dest.rd()
+                    | 0b0101111
             }
         }
     }
@@ -3384,3 +7144,34 @@ impl Instruction {
 pub fn disassemble_instruction(instruction: &Instruction) -> String {
     format!("{}", instruction)
 }
+
+/// Disassembles an instruction for the given ISA configuration.
+///
+/// When float instructions use the integer registers, float register operands are written with integer register names.
+pub fn disassemble_instruction_with(instruction: &Instruction, isa: &IsaConfig) -> String {
+    struct WithIsa<'a>(&'a Instruction, bool);
+
+    impl Display for WithIsa<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), alloc::fmt::Error> {
+            self.0.fmt_with(f, self.1)
+        }
+    }
+
+    format!(
+        "{}",
+        WithIsa(instruction, isa.floats_in_integer_registers())
+    )
+}
+
+/// A float register operand, displayed with the integer register name of the same number if the flag is set
+struct FloatOperand(FRegister, bool);
+
+impl Display for FloatOperand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), alloc::fmt::Error> {
+        if self.1 {
+            write!(f, "{}", IRegister::from_int(self.0.into()))
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
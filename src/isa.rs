@@ -0,0 +1,67 @@
+/// The width of the integer registers
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Xlen {
+    Rv32,
+    #[default]
+    Rv64,
+}
+
+/// Describes the target ISA for the parts of decoding, assembly and disassembly that depend on which extensions are
+/// implemented.
+///
+/// The default configuration is RV64 with float instructions using the float registers, prefetch hints decoded as
+/// prefetches and the compressed double precision loads and stores rather than Zcmp or Zcmt.
+///
+/// When float instructions use the integer registers, decoding and assembling give the float register fields of an
+/// `Instruction` as `FRegister::Integer`, which displays with the integer register name and is reported as an integer
+/// register by `Instruction::registers_read`. The `_with` functions that take a configuration, such as
+/// `disassemble_instruction_with`, `Instruction::operands_with` and `Instruction::registers_read_with`, also treat
+/// float registers built with the float register names as the integer registers of the same number.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct IsaConfig {
+    pub xlen: Xlen,
+    /// Single precision instructions operate on the integer registers
+    pub zfinx: bool,
+    /// Double precision instructions operate on the integer registers (register pairs on RV32)
+    pub zdinx: bool,
+    /// Half precision instructions operate on the integer registers
+    pub zhinx: bool,
+    /// Decode `ori` instructions with rd=x0 that match a prefetch hint as prefetches, rather than as `Ori`
+    pub zicbop: bool,
+    /// Decode the `c.fsdsp` encodings as Zcmp push, pop and register move instructions
+    pub zcmp: bool,
+    /// Decode the `c.fsdsp` encodings as Zcmt table jumps
+    pub zcmt: bool,
+    /// Decode `auipc` instructions with rd=x0 as Zicfilp landing pads
+    pub zicfilp: bool,
+    /// Decode the may-be-operation encodings used by Zicfiss as shadow stack instructions
+    pub zicfiss: bool,
+}
+
+impl Default for IsaConfig {
+    fn default() -> Self {
+        IsaConfig::new()
+    }
+}
+
+impl IsaConfig {
+    /// Creates the default configuration, usable in constants.
+    pub const fn new() -> Self {
+        IsaConfig {
+            xlen: Xlen::Rv64,
+            zfinx: false,
+            zdinx: false,
+            zhinx: false,
+            zicbop: true,
+            zcmp: false,
+            zcmt: false,
+            zicfilp: false,
+            zicfiss: false,
+        }
+    }
+
+    /// Returns true if float instructions use the integer registers instead of a separate float register file.
+    pub fn floats_in_integer_registers(&self) -> bool {
+        self.zfinx || self.zdinx || self.zhinx
+    }
+}
@@ -3,7 +3,19 @@
 extern crate alloc;
 pub mod assembly;
 pub mod cinstruction;
+pub mod control_flow;
+pub mod explain;
+pub mod extension;
+pub mod hart;
 pub mod immediates;
 pub mod instruction;
+pub mod isa;
+pub mod memory;
+pub mod metadata;
 pub mod opcode;
+pub mod operands;
+pub mod raw;
 pub mod register;
+pub mod softfloat;
+pub mod stream;
+pub mod thead;
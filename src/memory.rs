@@ -0,0 +1,660 @@
+use crate::cinstruction::CInstruction;
+use crate::instruction::Instruction;
+use crate::register::{IRegister, Register};
+
+/// The kind of memory operation performed by an instruction.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AccessKind {
+    Load,
+    Store,
+    /// An atomic memory operation, which loads a value and stores the result of combining it with a register
+    Amo,
+    /// `lr.w` and `lr.d`
+    LoadReserved,
+    /// `sc.w` and `sc.d`
+    StoreConditional,
+    /// `fence`, `fence.tso` and `fence.i`, which order memory accesses without performing one
+    Fence,
+    /// The Zicbom and Zicboz cache block operations, where `cbo.zero` writes zeros to the whole block
+    CacheBlock,
+    /// The Zicbop prefetch hints
+    Prefetch,
+}
+
+/// A description of the memory operation performed by an instruction.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MemoryAccess {
+    pub kind: AccessKind,
+    /// The number of bytes accessed, or `None` for fences and cache block operations, which depend on the
+    /// implementation's cache block size
+    pub width: Option<u8>,
+    /// Whether a loaded value narrower than the register is sign-extended rather than zero-extended
+    pub signed: bool,
+    /// The register holding the address, or `None` for fences
+    pub base: Option<IRegister>,
+    /// The offset added to the base register
+    pub offset: i64,
+    /// The register that receives the loaded value, or the result of a store conditional
+    pub dest: Option<Register>,
+    /// The register holding the value that is stored
+    pub src: Option<Register>,
+    /// Whether later memory accesses are ordered after this one
+    pub aq: bool,
+    /// Whether earlier memory accesses are ordered before this one
+    pub rl: bool,
+}
+
+impl MemoryAccess {
+    fn load(width: u8, signed: bool, base: IRegister, offset: i64, dest: Register) -> Self {
+        MemoryAccess {
+            kind: AccessKind::Load,
+            width: Some(width),
+            signed,
+            base: Some(base),
+            offset,
+            dest: Some(dest),
+            src: None,
+            aq: false,
+            rl: false,
+        }
+    }
+
+    fn store(width: u8, base: IRegister, offset: i64, src: Register) -> Self {
+        MemoryAccess {
+            kind: AccessKind::Store,
+            width: Some(width),
+            signed: false,
+            base: Some(base),
+            offset,
+            dest: None,
+            src: Some(src),
+            aq: false,
+            rl: false,
+        }
+    }
+
+    fn atomic(kind: AccessKind, width: u8, addr: IRegister, aq: bool, rl: bool) -> Self {
+        MemoryAccess {
+            kind,
+            width: Some(width),
+            signed: true,
+            base: Some(addr),
+            offset: 0,
+            dest: None,
+            src: None,
+            aq,
+            rl,
+        }
+    }
+
+    fn block(kind: AccessKind, base: IRegister, offset: i64) -> Self {
+        MemoryAccess {
+            kind,
+            width: None,
+            signed: false,
+            base: Some(base),
+            offset,
+            dest: None,
+            src: None,
+            aq: false,
+            rl: false,
+        }
+    }
+}
+
+impl Instruction {
+    /// Describes the memory operation performed by the instruction, if it has one.
+    ///
+    /// The shadow stack instructions from Zicfiss aren't included, since their addresses are held in `ssp` rather than
+    /// an integer register. Address translation fences such as `sfence.vma` don't order ordinary memory accesses, so
+    /// they aren't included either.
+    pub fn memory_access(&self) -> Option<MemoryAccess> {
+        use Register::{F, I};
+        let access = match self {
+            Instruction::Lb { dest, base, offset } => {
+                MemoryAccess::load(1, true, *base, offset.val(), I(*dest))
+            }
+            Instruction::Lh { dest, base, offset } => {
+                MemoryAccess::load(2, true, *base, offset.val(), I(*dest))
+            }
+            Instruction::Lw { dest, base, offset } => {
+                MemoryAccess::load(4, true, *base, offset.val(), I(*dest))
+            }
+            Instruction::Ld { dest, base, offset } => {
+                MemoryAccess::load(8, true, *base, offset.val(), I(*dest))
+            }
+            Instruction::Lbu { dest, base, offset } => {
+                MemoryAccess::load(1, false, *base, offset.val(), I(*dest))
+            }
+            Instruction::Lhu { dest, base, offset } => {
+                MemoryAccess::load(2, false, *base, offset.val(), I(*dest))
+            }
+            Instruction::Lwu { dest, base, offset } => {
+                MemoryAccess::load(4, false, *base, offset.val(), I(*dest))
+            }
+            Instruction::Flw { dest, base, offset } => {
+                MemoryAccess::load(4, false, *base, offset.val(), F(*dest))
+            }
+            Instruction::Fld { dest, base, offset } => {
+                MemoryAccess::load(8, false, *base, offset.val(), F(*dest))
+            }
+            Instruction::Flq { dest, base, offset } => {
+                MemoryAccess::load(16, false, *base, offset.val(), F(*dest))
+            }
+            Instruction::Sb { src, base, offset } => {
+                MemoryAccess::store(1, *base, offset.val(), I(*src))
+            }
+            Instruction::Sh { src, base, offset } => {
+                MemoryAccess::store(2, *base, offset.val(), I(*src))
+            }
+            Instruction::Sw { src, base, offset } => {
+                MemoryAccess::store(4, *base, offset.val(), I(*src))
+            }
+            Instruction::Sd { src, base, offset } => {
+                MemoryAccess::store(8, *base, offset.val(), I(*src))
+            }
+            Instruction::Fsw { src, base, offset } => {
+                MemoryAccess::store(4, *base, offset.val(), F(*src))
+            }
+            Instruction::Fsd { src, base, offset } => {
+                MemoryAccess::store(8, *base, offset.val(), F(*src))
+            }
+            Instruction::Fsq { src, base, offset } => {
+                MemoryAccess::store(16, *base, offset.val(), F(*src))
+            }
+            // the hypervisor virtual machine loads and stores
+            Instruction::HlvB { dest, addr } => MemoryAccess::load(1, true, *addr, 0, I(*dest)),
+            Instruction::HlvH { dest, addr } => MemoryAccess::load(2, true, *addr, 0, I(*dest)),
+            Instruction::HlvW { dest, addr } => MemoryAccess::load(4, true, *addr, 0, I(*dest)),
+            Instruction::HlvD { dest, addr } => MemoryAccess::load(8, true, *addr, 0, I(*dest)),
+            Instruction::HlvBu { dest, addr } => MemoryAccess::load(1, false, *addr, 0, I(*dest)),
+            Instruction::HlvHu { dest, addr } | Instruction::HlvxHu { dest, addr } => {
+                MemoryAccess::load(2, false, *addr, 0, I(*dest))
+            }
+            Instruction::HlvWu { dest, addr } | Instruction::HlvxWu { dest, addr } => {
+                MemoryAccess::load(4, false, *addr, 0, I(*dest))
+            }
+            Instruction::HsvB { src, addr } => MemoryAccess::store(1, *addr, 0, I(*src)),
+            Instruction::HsvH { src, addr } => MemoryAccess::store(2, *addr, 0, I(*src)),
+            Instruction::HsvW { src, addr } => MemoryAccess::store(4, *addr, 0, I(*src)),
+            Instruction::HsvD { src, addr } => MemoryAccess::store(8, *addr, 0, I(*src)),
+            // the load-acquire and store-release instructions from Zalasr
+            Instruction::LbAq { dest, addr, rl } => MemoryAccess {
+                aq: true,
+                rl: *rl,
+                ..MemoryAccess::load(1, true, *addr, 0, I(*dest))
+            },
+            Instruction::LhAq { dest, addr, rl } => MemoryAccess {
+                aq: true,
+                rl: *rl,
+                ..MemoryAccess::load(2, true, *addr, 0, I(*dest))
+            },
+            Instruction::LwAq { dest, addr, rl } => MemoryAccess {
+                aq: true,
+                rl: *rl,
+                ..MemoryAccess::load(4, true, *addr, 0, I(*dest))
+            },
+            Instruction::LdAq { dest, addr, rl } => MemoryAccess {
+                aq: true,
+                rl: *rl,
+                ..MemoryAccess::load(8, true, *addr, 0, I(*dest))
+            },
+            Instruction::SbRl { src, addr, aq } => MemoryAccess {
+                aq: *aq,
+                rl: true,
+                ..MemoryAccess::store(1, *addr, 0, I(*src))
+            },
+            Instruction::ShRl { src, addr, aq } => MemoryAccess {
+                aq: *aq,
+                rl: true,
+                ..MemoryAccess::store(2, *addr, 0, I(*src))
+            },
+            Instruction::SwRl { src, addr, aq } => MemoryAccess {
+                aq: *aq,
+                rl: true,
+                ..MemoryAccess::store(4, *addr, 0, I(*src))
+            },
+            Instruction::SdRl { src, addr, aq } => MemoryAccess {
+                aq: *aq,
+                rl: true,
+                ..MemoryAccess::store(8, *addr, 0, I(*src))
+            },
+            Instruction::LrW { dest, addr, aq, rl } => MemoryAccess {
+                dest: Some(I(*dest)),
+                ..MemoryAccess::atomic(AccessKind::LoadReserved, 4, *addr, *aq, *rl)
+            },
+            Instruction::LrD { dest, addr, aq, rl } => MemoryAccess {
+                dest: Some(I(*dest)),
+                ..MemoryAccess::atomic(AccessKind::LoadReserved, 8, *addr, *aq, *rl)
+            },
+            Instruction::ScW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => MemoryAccess {
+                dest: Some(I(*dest)),
+                src: Some(I(*src)),
+                ..MemoryAccess::atomic(AccessKind::StoreConditional, 4, *addr, *aq, *rl)
+            },
+            Instruction::ScD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => MemoryAccess {
+                dest: Some(I(*dest)),
+                src: Some(I(*src)),
+                ..MemoryAccess::atomic(AccessKind::StoreConditional, 8, *addr, *aq, *rl)
+            },
+            Instruction::AmoswapB {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoaddB {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoxorB {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoandB {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoorB {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmominB {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmomaxB {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmominuB {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmomaxuB {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmocasB {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => MemoryAccess {
+                dest: Some(I(*dest)),
+                src: Some(I(*src)),
+                ..MemoryAccess::atomic(AccessKind::Amo, 1, *addr, *aq, *rl)
+            },
+            Instruction::AmoswapH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoaddH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoxorH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoandH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoorH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmominH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmomaxH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmominuH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmomaxuH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmocasH {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => MemoryAccess {
+                dest: Some(I(*dest)),
+                src: Some(I(*src)),
+                ..MemoryAccess::atomic(AccessKind::Amo, 2, *addr, *aq, *rl)
+            },
+            Instruction::AmoswapW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoaddW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoxorW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoandW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoorW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmominW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmomaxW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmominuW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmomaxuW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmocasW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::SsamoswapW {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => MemoryAccess {
+                dest: Some(I(*dest)),
+                src: Some(I(*src)),
+                ..MemoryAccess::atomic(AccessKind::Amo, 4, *addr, *aq, *rl)
+            },
+            Instruction::AmoswapD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoaddD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoxorD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoandD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmoorD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmominD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmomaxD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmominuD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmomaxuD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::AmocasD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            }
+            | Instruction::SsamoswapD {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => MemoryAccess {
+                dest: Some(I(*dest)),
+                src: Some(I(*src)),
+                ..MemoryAccess::atomic(AccessKind::Amo, 8, *addr, *aq, *rl)
+            },
+            Instruction::AmocasQ {
+                dest,
+                addr,
+                src,
+                aq,
+                rl,
+            } => MemoryAccess {
+                dest: Some(I(*dest)),
+                src: Some(I(*src)),
+                ..MemoryAccess::atomic(AccessKind::Amo, 16, *addr, *aq, *rl)
+            },
+            Instruction::Fence { .. } | Instruction::FenceI => MemoryAccess {
+                kind: AccessKind::Fence,
+                width: None,
+                signed: false,
+                base: None,
+                offset: 0,
+                dest: None,
+                src: None,
+                aq: false,
+                rl: false,
+            },
+            Instruction::CboClean { base }
+            | Instruction::CboFlush { base }
+            | Instruction::CboInval { base }
+            | Instruction::CboZero { base } => {
+                MemoryAccess::block(AccessKind::CacheBlock, *base, 0)
+            }
+            Instruction::PrefetchI { base, offset }
+            | Instruction::PrefetchR { base, offset }
+            | Instruction::PrefetchW { base, offset } => {
+                MemoryAccess::block(AccessKind::Prefetch, *base, offset.val())
+            }
+            _ => return None,
+        };
+        Some(access)
+    }
+}
+
+impl CInstruction {
+    /// Describes the memory operation performed by the instruction, if it has one.
+    ///
+    /// The Zcmp push and pop instructions access several registers' worth of memory, so they aren't included.
+    pub fn memory_access(&self) -> Option<MemoryAccess> {
+        use Register::{F, I};
+        const SP: IRegister = IRegister::StackPointer;
+        let access = match self {
+            CInstruction::LW { dest, base, offset } => {
+                MemoryAccess::load(4, true, base.expand(), offset.val(), I(dest.expand()))
+            }
+            CInstruction::LD { dest, base, offset } => {
+                MemoryAccess::load(8, true, base.expand(), offset.val(), I(dest.expand()))
+            }
+            CInstruction::LH { dest, base, offset } => {
+                MemoryAccess::load(2, true, base.expand(), offset.val(), I(dest.expand()))
+            }
+            CInstruction::LHU { dest, base, offset } => {
+                MemoryAccess::load(2, false, base.expand(), offset.val(), I(dest.expand()))
+            }
+            CInstruction::LBU { dest, base, offset } => {
+                MemoryAccess::load(1, false, base.expand(), offset.val(), I(dest.expand()))
+            }
+            CInstruction::FLD { dest, base, offset } => {
+                MemoryAccess::load(8, false, base.expand(), offset.val(), F(dest.expand()))
+            }
+            CInstruction::SW { src, base, offset } => {
+                MemoryAccess::store(4, base.expand(), offset.val(), I(src.expand()))
+            }
+            CInstruction::SD { src, base, offset } => {
+                MemoryAccess::store(8, base.expand(), offset.val(), I(src.expand()))
+            }
+            CInstruction::SH { src, base, offset } => {
+                MemoryAccess::store(2, base.expand(), offset.val(), I(src.expand()))
+            }
+            CInstruction::SB { src, base, offset } => {
+                MemoryAccess::store(1, base.expand(), offset.val(), I(src.expand()))
+            }
+            CInstruction::FSD { src, base, offset } => {
+                MemoryAccess::store(8, base.expand(), offset.val(), F(src.expand()))
+            }
+            CInstruction::LWSP { dest, offset } => {
+                MemoryAccess::load(4, true, SP, offset.val(), I(*dest))
+            }
+            CInstruction::LDSP { dest, offset } => {
+                MemoryAccess::load(8, true, SP, offset.val(), I(*dest))
+            }
+            CInstruction::FLDSP { dest, offset } => {
+                MemoryAccess::load(8, false, SP, offset.val(), F(*dest))
+            }
+            CInstruction::SWSP { src, offset } => MemoryAccess::store(4, SP, offset.val(), I(*src)),
+            CInstruction::SDSP { src, offset } => MemoryAccess::store(8, SP, offset.val(), I(*src)),
+            CInstruction::FSDSP { src, offset } => {
+                MemoryAccess::store(8, SP, offset.val(), F(*src))
+            }
+            _ => return None,
+        };
+        Some(access)
+    }
+}
@@ -0,0 +1,3991 @@
+use crate::cinstruction::CInstruction;
+use crate::instruction::Instruction;
+use crate::operands::OperandRole;
+use crate::raw::Format;
+
+/// Static information about an instruction that doesn't depend on its operands.
+///
+/// Encodings that have operands baked into the mnemonic, such as `mop.r.3` and `c.mop.5`, get an entry each.
+#[derive(Debug, PartialEq)]
+pub struct InstructionInfo {
+    /// The lowercase mnemonic, without the `.aq` and `.rl` suffixes of atomics
+    pub mnemonic: &'static str,
+    /// The extension that defines the instruction, such as `I`, `Zicsr` or `Zcb`
+    ///
+    /// RV64-only instructions belong to the same extension as their RV32 counterparts. The privileged instructions
+    /// belong to `Sm` or `S`, and `dret` belongs to `Sdext`.
+    pub extension: &'static str,
+    pub format: Format,
+    /// The bits that are fixed in every encoding of the instruction
+    pub mask: u32,
+    /// The values of the fixed bits
+    pub match_bits: u32,
+    /// The operands of the instruction, in the order returned by `operands()`
+    pub operands: &'static [OperandRole],
+}
+
+impl InstructionInfo {
+    /// Returns whether an encoding has the fixed bits of the instruction.
+    ///
+    /// Some patterns overlap, such as `c.lui` and `c.addi16sp`, or the Zimop and Zicfiss instructions, so an encoding
+    /// can match more than one entry. The decoder picks between them based on the operands and the `IsaConfig`.
+    pub fn matches(&self, bits: u32) -> bool {
+        bits & self.mask == self.match_bits
+    }
+
+    /// Returns whether the instruction has a 16-bit encoding
+    pub fn is_compressed(&self) -> bool {
+        self.match_bits & 0b11 != 0b11
+    }
+}
+
+/// The 32-bit instructions, in the order they're declared in `Instruction`
+pub static INSTRUCTIONS: [InstructionInfo; 339] = [
+    InstructionInfo {
+        mnemonic: "lui",
+        extension: "I",
+        format: Format::U,
+        mask: 0x0000007f,
+        match_bits: 0x00000037,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "auipc",
+        extension: "I",
+        format: Format::U,
+        mask: 0x0000007f,
+        match_bits: 0x00000017,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "jal",
+        extension: "I",
+        format: Format::J,
+        mask: 0x0000007f,
+        match_bits: 0x0000006f,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "jalr",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00000067,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "beq",
+        extension: "I",
+        format: Format::B,
+        mask: 0x0000707f,
+        match_bits: 0x00000063,
+        operands: &[OperandRole::Src1, OperandRole::Src2, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "bne",
+        extension: "I",
+        format: Format::B,
+        mask: 0x0000707f,
+        match_bits: 0x00001063,
+        operands: &[OperandRole::Src1, OperandRole::Src2, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "blt",
+        extension: "I",
+        format: Format::B,
+        mask: 0x0000707f,
+        match_bits: 0x00004063,
+        operands: &[OperandRole::Src1, OperandRole::Src2, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "bge",
+        extension: "I",
+        format: Format::B,
+        mask: 0x0000707f,
+        match_bits: 0x00005063,
+        operands: &[OperandRole::Src1, OperandRole::Src2, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "bltu",
+        extension: "I",
+        format: Format::B,
+        mask: 0x0000707f,
+        match_bits: 0x00006063,
+        operands: &[OperandRole::Src1, OperandRole::Src2, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "bgeu",
+        extension: "I",
+        format: Format::B,
+        mask: 0x0000707f,
+        match_bits: 0x00007063,
+        operands: &[OperandRole::Src1, OperandRole::Src2, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "lb",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00000003,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "lh",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00001003,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "lw",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00002003,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "lbu",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00004003,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "lhu",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00005003,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "sb",
+        extension: "I",
+        format: Format::S,
+        mask: 0x0000707f,
+        match_bits: 0x00000023,
+        operands: &[OperandRole::Src1, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "sh",
+        extension: "I",
+        format: Format::S,
+        mask: 0x0000707f,
+        match_bits: 0x00001023,
+        operands: &[OperandRole::Src1, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "sw",
+        extension: "I",
+        format: Format::S,
+        mask: 0x0000707f,
+        match_bits: 0x00002023,
+        operands: &[OperandRole::Src1, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "addi",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00000013,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "slti",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00002013,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "sltiu",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00003013,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "xori",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00004013,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "ori",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00006013,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "andi",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00007013,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "slli",
+        extension: "I",
+        format: Format::I,
+        mask: 0xfc00707f,
+        match_bits: 0x00001013,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "srli",
+        extension: "I",
+        format: Format::I,
+        mask: 0xfc00707f,
+        match_bits: 0x00005013,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "srai",
+        extension: "I",
+        format: Format::I,
+        mask: 0xfc00707f,
+        match_bits: 0x40005013,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "add",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x00000033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "sub",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x40000033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "sll",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x00001033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "slt",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x00002033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "sltu",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x00003033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "xor",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x00004033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "srl",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x00005033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "sra",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x40005033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "or",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x00006033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "and",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x00007033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fence",
+        extension: "I",
+        format: Format::I,
+        mask: 0x700fffff,
+        match_bits: 0x0000000f,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "ecall",
+        extension: "I",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x00000073,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "ebreak",
+        extension: "I",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x00100073,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "lwu",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00006003,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "ld",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00003003,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "sd",
+        extension: "I",
+        format: Format::S,
+        mask: 0x0000707f,
+        match_bits: 0x00003023,
+        operands: &[OperandRole::Src1, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "addiw",
+        extension: "I",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x0000001b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "slliw",
+        extension: "I",
+        format: Format::I,
+        mask: 0xfe00707f,
+        match_bits: 0x0000101b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "srliw",
+        extension: "I",
+        format: Format::I,
+        mask: 0xfe00707f,
+        match_bits: 0x0000501b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "sraiw",
+        extension: "I",
+        format: Format::I,
+        mask: 0xfe00707f,
+        match_bits: 0x4000501b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "addw",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x0000003b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "subw",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x4000003b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "sllw",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x0000103b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "srlw",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x0000503b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "sraw",
+        extension: "I",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x4000503b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mul",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x02000033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mulh",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x02001033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mulhsu",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x02002033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mulhu",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x02003033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "div",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x02004033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "divu",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x02005033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "rem",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x02006033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "remu",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x02007033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mulw",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x0200003b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "divw",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x0200403b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "divuw",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x0200503b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "remw",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x0200603b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "remuw",
+        extension: "M",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x0200703b,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "lr.w",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf9f0707f,
+        match_bits: 0x1000202f,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "sc.w",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x1800202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoswap.w",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x0800202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoadd.w",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x0000202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoxor.w",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x2000202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoand.w",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x6000202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoor.w",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x4000202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomin.w",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x8000202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomax.w",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xa000202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amominu.w",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xc000202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomaxu.w",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xe000202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "lr.d",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf9f0707f,
+        match_bits: 0x1000302f,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "sc.d",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x1800302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoswap.d",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x0800302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoadd.d",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x0000302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoxor.d",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x2000302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoand.d",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x6000302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoor.d",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x4000302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomin.d",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x8000302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomax.d",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xa000302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amominu.d",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xc000302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomaxu.d",
+        extension: "A",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xe000302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "flw",
+        extension: "F",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00002007,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "fsw",
+        extension: "F",
+        format: Format::S,
+        mask: 0x0000707f,
+        match_bits: 0x00002027,
+        operands: &[OperandRole::Base, OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "fmadd.s",
+        extension: "F",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x00000043,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fmsub.s",
+        extension: "F",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x00000047,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fnmsub.s",
+        extension: "F",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x0000004b,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fnmadd.s",
+        extension: "F",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x0000004f,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fadd.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x00000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fsub.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x08000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fmul.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x10000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fdiv.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x18000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fsqrt.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x58000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fsgnj.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x20000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fsgnjn.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x20001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fsgnjx.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x20002053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fmin.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x28000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fmax.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x28001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.w.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc0000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.wu.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc0100053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fmv.x.w",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xe0000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "feq.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa0002053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "flt.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa0001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fle.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa0000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fclass.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xe0001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.s.w",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd0000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.s.wu",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd0100053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fmv.w.x",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xf0000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.l.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc0200053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.lu.s",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc0300053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.s.l",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd0200053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.s.lu",
+        extension: "F",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd0300053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "csrrw",
+        extension: "Zicsr",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00001073,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Csr],
+    },
+    InstructionInfo {
+        mnemonic: "csrrs",
+        extension: "Zicsr",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00002073,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Csr],
+    },
+    InstructionInfo {
+        mnemonic: "csrrc",
+        extension: "Zicsr",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00003073,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Csr],
+    },
+    InstructionInfo {
+        mnemonic: "csrrwi",
+        extension: "Zicsr",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00005073,
+        operands: &[OperandRole::Dest, OperandRole::Immediate, OperandRole::Csr],
+    },
+    InstructionInfo {
+        mnemonic: "csrrsi",
+        extension: "Zicsr",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00006073,
+        operands: &[OperandRole::Dest, OperandRole::Immediate, OperandRole::Csr],
+    },
+    InstructionInfo {
+        mnemonic: "csrrci",
+        extension: "Zicsr",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00007073,
+        operands: &[OperandRole::Dest, OperandRole::Immediate, OperandRole::Csr],
+    },
+    InstructionInfo {
+        mnemonic: "fence.i",
+        extension: "Zifencei",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x0000100f,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "fld",
+        extension: "D",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00003007,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "fsd",
+        extension: "D",
+        format: Format::S,
+        mask: 0x0000707f,
+        match_bits: 0x00003027,
+        operands: &[OperandRole::Src1, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "fmadd.d",
+        extension: "D",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x02000043,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fmsub.d",
+        extension: "D",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x02000047,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fnmadd.d",
+        extension: "D",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x0200004f,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fnmsub.d",
+        extension: "D",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x0200004b,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fadd.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x02000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fsub.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x0a000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fmul.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x12000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fdiv.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x1a000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fsqrt.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x5a000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fsgnj.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x22000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fsgnjn.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x22001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fsgnjx.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x22002053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fmin.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x2a000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fmax.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x2a001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.s.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x40100053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.d.s",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x42000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "feq.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa2002053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "flt.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa2001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fle.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa2000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fclass.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xe2001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.w.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc2000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.wu.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc2100053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.d.w",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd2000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.d.wu",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd2100053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.l.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc2200053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.lu.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc2300053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fmv.x.d",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xe2000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.d.l",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd2200053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.d.lu",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd2300053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fmv.d.x",
+        extension: "D",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xf2000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "flq",
+        extension: "Q",
+        format: Format::I,
+        mask: 0x0000707f,
+        match_bits: 0x00004007,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "fsq",
+        extension: "Q",
+        format: Format::S,
+        mask: 0x0000707f,
+        match_bits: 0x00004027,
+        operands: &[OperandRole::Src1, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "fmadd.q",
+        extension: "Q",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x06000043,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fmsub.q",
+        extension: "Q",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x06000047,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fnmsub.q",
+        extension: "Q",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x0600004b,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fnmadd.q",
+        extension: "Q",
+        format: Format::R4,
+        mask: 0x0600007f,
+        match_bits: 0x0600004f,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::Src3,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fadd.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x06000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fsub.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x0e000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fmul.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x16000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fdiv.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00007f,
+        match_bits: 0x1e000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::Src2,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fsqrt.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x5e000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fsgnj.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x26000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fsgnjn.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x26001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fsgnjx.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x26002053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fmin.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x2e000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fmax.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x2e001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.s.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x40300053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.q.s",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x46000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.d.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x42300053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.q.d",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x46100053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "feq.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa6002053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "flt.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa6001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fle.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa6000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fclass.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xe6001053,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.w.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc6000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.wu.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc6100053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.q.w",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd6000053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.q.wu",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd6100053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.l.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc6200053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.lu.q",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xc6300053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.q.l",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd6200053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.q.lu",
+        extension: "Q",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0xd6300053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fli.s",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xf0100053,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "fli.d",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xf2100053,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "fli.h",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xf4100053,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "fli.q",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xf6100053,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "fminm.s",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x28002053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fminm.d",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x2a002053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fminm.h",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x2c002053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fminm.q",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x2e002053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fmaxm.s",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x28003053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fmaxm.d",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x2a003053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fmaxm.h",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x2c003053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fmaxm.q",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x2e003053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fround.s",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x40400053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fround.d",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x42400053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fround.h",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x44400053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fround.q",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x46400053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "froundnx.s",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x40500053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "froundnx.d",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x42500053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "froundnx.h",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x44500053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "froundnx.q",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x46500053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvtmod.w.d",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xc2801053,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "fleq.s",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa0004053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fleq.d",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa2004053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fleq.h",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa4004053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fleq.q",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa6004053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fltq.s",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa0005053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fltq.d",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa2005053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fltq.h",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa4005053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fltq.q",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xa6005053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fmvh.x.d",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfff0707f,
+        match_bits: 0xe2100053,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "fmvp.d.x",
+        extension: "Zfa",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0xb2000053,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.bf16.s",
+        extension: "Zfbfmin",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x44800053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "fcvt.s.bf16",
+        extension: "Zfbfmin",
+        format: Format::R,
+        mask: 0xfff0007f,
+        match_bits: 0x40600053,
+        operands: &[
+            OperandRole::Dest,
+            OperandRole::Src1,
+            OperandRole::RoundingMode,
+        ],
+    },
+    InstructionInfo {
+        mnemonic: "vfncvtbf16.f.f.w",
+        extension: "Zvfbfmin",
+        format: Format::R,
+        mask: 0xfc0ff07f,
+        match_bits: 0x480e9057,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "vfwcvtbf16.f.f.v",
+        extension: "Zvfbfmin",
+        format: Format::R,
+        mask: 0xfc0ff07f,
+        match_bits: 0x48069057,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "vfwmaccbf16.vv",
+        extension: "Zvfbfwma",
+        format: Format::R,
+        mask: 0xfc00707f,
+        match_bits: 0xec001057,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "vfwmaccbf16.vf",
+        extension: "Zvfbfwma",
+        format: Format::R,
+        mask: 0xfc00707f,
+        match_bits: 0xec005057,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "sret",
+        extension: "S",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x10200073,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "mret",
+        extension: "Sm",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x30200073,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "wfi",
+        extension: "Sm",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x10500073,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "sfence.vma",
+        extension: "S",
+        format: Format::I,
+        mask: 0xfe007fff,
+        match_bits: 0x12000073,
+        operands: &[OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "sinval.vma",
+        extension: "Svinval",
+        format: Format::I,
+        mask: 0xfe007fff,
+        match_bits: 0x16000073,
+        operands: &[OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "sfence.w.inval",
+        extension: "Svinval",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x18000073,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "sfence.inval.ir",
+        extension: "Svinval",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x18100073,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "mnret",
+        extension: "Smrnmi",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x70200073,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "dret",
+        extension: "Sdext",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x7b200073,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "hfence.vvma",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfe007fff,
+        match_bits: 0x22000073,
+        operands: &[OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "hfence.gvma",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfe007fff,
+        match_bits: 0x62000073,
+        operands: &[OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "hinval.vvma",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfe007fff,
+        match_bits: 0x26000073,
+        operands: &[OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "hinval.gvma",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfe007fff,
+        match_bits: 0x66000073,
+        operands: &[OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "hlv.b",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x60004073,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hlv.bu",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x60104073,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hlv.h",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x64004073,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hlv.hu",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x64104073,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hlvx.hu",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x64304073,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hlv.w",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x68004073,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hlvx.wu",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x68304073,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hsv.b",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfe007fff,
+        match_bits: 0x62004073,
+        operands: &[OperandRole::Src1, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hsv.h",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfe007fff,
+        match_bits: 0x66004073,
+        operands: &[OperandRole::Src1, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hsv.w",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfe007fff,
+        match_bits: 0x6a004073,
+        operands: &[OperandRole::Src1, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hlv.wu",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x68104073,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hlv.d",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x6c004073,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "hsv.d",
+        extension: "H",
+        format: Format::I,
+        mask: 0xfe007fff,
+        match_bits: 0x6e004073,
+        operands: &[OperandRole::Src1, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "cbo.clean",
+        extension: "Zicbom",
+        format: Format::I,
+        mask: 0xfff07fff,
+        match_bits: 0x0010200f,
+        operands: &[OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "cbo.flush",
+        extension: "Zicbom",
+        format: Format::I,
+        mask: 0xfff07fff,
+        match_bits: 0x0020200f,
+        operands: &[OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "cbo.inval",
+        extension: "Zicbom",
+        format: Format::I,
+        mask: 0xfff07fff,
+        match_bits: 0x0000200f,
+        operands: &[OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "cbo.zero",
+        extension: "Zicboz",
+        format: Format::I,
+        mask: 0xfff07fff,
+        match_bits: 0x0040200f,
+        operands: &[OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "prefetch.i",
+        extension: "Zicbop",
+        format: Format::I,
+        mask: 0x01f07fff,
+        match_bits: 0x00006013,
+        operands: &[OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "prefetch.r",
+        extension: "Zicbop",
+        format: Format::I,
+        mask: 0x01f07fff,
+        match_bits: 0x00106013,
+        operands: &[OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "prefetch.w",
+        extension: "Zicbop",
+        format: Format::I,
+        mask: 0x01f07fff,
+        match_bits: 0x00306013,
+        operands: &[OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "amocas.w",
+        extension: "Zacas",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x2800202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amocas.d",
+        extension: "Zacas",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x2800302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amocas.q",
+        extension: "Zacas",
+        format: Format::R,
+        mask: 0xf81070ff,
+        match_bits: 0x2800402f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoswap.b",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x0800002f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoadd.b",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x0000002f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoxor.b",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x2000002f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoand.b",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x6000002f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoor.b",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x4000002f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomin.b",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x8000002f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomax.b",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xa000002f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amominu.b",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xc000002f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomaxu.b",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xe000002f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amocas.b",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x2800002f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoswap.h",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x0800102f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoadd.h",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x0000102f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoxor.h",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x2000102f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoand.h",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x6000102f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amoor.h",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x4000102f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomin.h",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x8000102f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomax.h",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xa000102f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amominu.h",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xc000102f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amomaxu.h",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0xe000102f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "amocas.h",
+        extension: "Zabha",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x2800102f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "wrs.nto",
+        extension: "Zawrs",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x00d00073,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "wrs.sto",
+        extension: "Zawrs",
+        format: Format::I,
+        mask: 0xffffffff,
+        match_bits: 0x01d00073,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "lb.aq",
+        extension: "Zalasr",
+        format: Format::R,
+        mask: 0xfdf0707f,
+        match_bits: 0x3400002f,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "lh.aq",
+        extension: "Zalasr",
+        format: Format::R,
+        mask: 0xfdf0707f,
+        match_bits: 0x3400102f,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "lw.aq",
+        extension: "Zalasr",
+        format: Format::R,
+        mask: 0xfdf0707f,
+        match_bits: 0x3400202f,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "sb.rl",
+        extension: "Zalasr",
+        format: Format::R,
+        mask: 0xfa007fff,
+        match_bits: 0x3a00002f,
+        operands: &[OperandRole::Src1, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "sh.rl",
+        extension: "Zalasr",
+        format: Format::R,
+        mask: 0xfa007fff,
+        match_bits: 0x3a00102f,
+        operands: &[OperandRole::Src1, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "sw.rl",
+        extension: "Zalasr",
+        format: Format::R,
+        mask: 0xfa007fff,
+        match_bits: 0x3a00202f,
+        operands: &[OperandRole::Src1, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "ld.aq",
+        extension: "Zalasr",
+        format: Format::R,
+        mask: 0xfdf0707f,
+        match_bits: 0x3400302f,
+        operands: &[OperandRole::Dest, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "sd.rl",
+        extension: "Zalasr",
+        format: Format::R,
+        mask: 0xfa007fff,
+        match_bits: 0x3a00302f,
+        operands: &[OperandRole::Src1, OperandRole::Base],
+    },
+    InstructionInfo {
+        mnemonic: "czero.eqz",
+        extension: "Zicond",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x0e005033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "czero.nez",
+        extension: "Zicond",
+        format: Format::R,
+        mask: 0xfe00707f,
+        match_bits: 0x0e007033,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "lpad",
+        extension: "Zicfilp",
+        format: Format::U,
+        mask: 0x00000fff,
+        match_bits: 0x00000017,
+        operands: &[OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "sspush",
+        extension: "Zicfiss",
+        format: Format::I,
+        mask: 0xffbfffff,
+        match_bits: 0xce104073,
+        operands: &[OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "sspopchk",
+        extension: "Zicfiss",
+        format: Format::I,
+        mask: 0xfffdffff,
+        match_bits: 0xcdc0c073,
+        operands: &[OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "ssrdp",
+        extension: "Zicfiss",
+        format: Format::I,
+        mask: 0xfffff07f,
+        match_bits: 0xcdc04073,
+        operands: &[OperandRole::Dest],
+    },
+    InstructionInfo {
+        mnemonic: "ssamoswap.w",
+        extension: "Zicfiss",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x4800202f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "ssamoswap.d",
+        extension: "Zicfiss",
+        format: Format::R,
+        mask: 0xf800707f,
+        match_bits: 0x4800302f,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.0",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x81c04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.1",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x81d04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.2",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x81e04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.3",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x81f04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.4",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x85c04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.5",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x85d04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.6",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x85e04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.7",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x85f04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.8",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x89c04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.9",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x89d04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.10",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x89e04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.11",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x89f04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.12",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x8dc04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.13",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x8dd04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.14",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x8de04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.15",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0x8df04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.16",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc1c04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.17",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc1d04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.18",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc1e04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.19",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc1f04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.20",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc5c04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.21",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc5d04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.22",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc5e04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.23",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc5f04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.24",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc9c04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.25",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc9d04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.26",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc9e04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.27",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xc9f04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.28",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xcdc04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.29",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xcdd04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.30",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xcde04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.r.31",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfff0707f,
+        match_bits: 0xcdf04073,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "mop.rr.0",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfe00707f,
+        match_bits: 0x82004073,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mop.rr.1",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfe00707f,
+        match_bits: 0x86004073,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mop.rr.2",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfe00707f,
+        match_bits: 0x8a004073,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mop.rr.3",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfe00707f,
+        match_bits: 0x8e004073,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mop.rr.4",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfe00707f,
+        match_bits: 0xc2004073,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mop.rr.5",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfe00707f,
+        match_bits: 0xc6004073,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mop.rr.6",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfe00707f,
+        match_bits: 0xca004073,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "mop.rr.7",
+        extension: "Zimop",
+        format: Format::I,
+        mask: 0xfe00707f,
+        match_bits: 0xce004073,
+        operands: &[OperandRole::Dest, OperandRole::Src1, OperandRole::Src2],
+    },
+];
+
+/// The 16-bit instructions, in the order they're declared in `CInstruction`
+pub static COMPRESSED_INSTRUCTIONS: [InstructionInfo; 66] = [
+    InstructionInfo {
+        mnemonic: "c.addi4spn",
+        extension: "C",
+        format: Format::CIW,
+        mask: 0x0000e003,
+        match_bits: 0x00000000,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.fld",
+        extension: "C",
+        format: Format::CL,
+        mask: 0x0000e003,
+        match_bits: 0x00002000,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.lw",
+        extension: "C",
+        format: Format::CL,
+        mask: 0x0000e003,
+        match_bits: 0x00004000,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.ld",
+        extension: "C",
+        format: Format::CL,
+        mask: 0x0000e003,
+        match_bits: 0x00006000,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.fsd",
+        extension: "C",
+        format: Format::CS,
+        mask: 0x0000e003,
+        match_bits: 0x0000a000,
+        operands: &[OperandRole::Src1, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.sw",
+        extension: "C",
+        format: Format::CS,
+        mask: 0x0000e003,
+        match_bits: 0x0000c000,
+        operands: &[OperandRole::Src1, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.sd",
+        extension: "C",
+        format: Format::CS,
+        mask: 0x0000e003,
+        match_bits: 0x0000e000,
+        operands: &[OperandRole::Src1, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.addi",
+        extension: "C",
+        format: Format::CI,
+        mask: 0x0000e003,
+        match_bits: 0x00000001,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.addiw",
+        extension: "C",
+        format: Format::CI,
+        mask: 0x0000e003,
+        match_bits: 0x00002001,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.li",
+        extension: "C",
+        format: Format::CI,
+        mask: 0x0000e003,
+        match_bits: 0x00004001,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.addi16sp",
+        extension: "C",
+        format: Format::CI,
+        mask: 0x0000ef83,
+        match_bits: 0x00006101,
+        operands: &[OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.lui",
+        extension: "C",
+        format: Format::CI,
+        mask: 0x0000e003,
+        match_bits: 0x00006001,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.srli",
+        extension: "C",
+        format: Format::CB,
+        mask: 0x0000ec03,
+        match_bits: 0x00008001,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.srai",
+        extension: "C",
+        format: Format::CB,
+        mask: 0x0000ec03,
+        match_bits: 0x00008401,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.andi",
+        extension: "C",
+        format: Format::CB,
+        mask: 0x0000ec03,
+        match_bits: 0x00008801,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.sub",
+        extension: "C",
+        format: Format::CA,
+        mask: 0x0000fc63,
+        match_bits: 0x00008c01,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "c.xor",
+        extension: "C",
+        format: Format::CA,
+        mask: 0x0000fc63,
+        match_bits: 0x00008c21,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "c.or",
+        extension: "C",
+        format: Format::CA,
+        mask: 0x0000fc63,
+        match_bits: 0x00008c41,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "c.and",
+        extension: "C",
+        format: Format::CA,
+        mask: 0x0000fc63,
+        match_bits: 0x00008c61,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "c.subw",
+        extension: "C",
+        format: Format::CA,
+        mask: 0x0000fc63,
+        match_bits: 0x00009c01,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "c.addw",
+        extension: "C",
+        format: Format::CA,
+        mask: 0x0000fc63,
+        match_bits: 0x00009c21,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "c.j",
+        extension: "C",
+        format: Format::CJ,
+        mask: 0x0000e003,
+        match_bits: 0x0000a001,
+        operands: &[OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.beqz",
+        extension: "C",
+        format: Format::CB,
+        mask: 0x0000e003,
+        match_bits: 0x0000c001,
+        operands: &[OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.bnez",
+        extension: "C",
+        format: Format::CB,
+        mask: 0x0000e003,
+        match_bits: 0x0000e001,
+        operands: &[OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.slli",
+        extension: "C",
+        format: Format::CI,
+        mask: 0x0000e003,
+        match_bits: 0x00000002,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.fldsp",
+        extension: "C",
+        format: Format::CI,
+        mask: 0x0000e003,
+        match_bits: 0x00002002,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.lwsp",
+        extension: "C",
+        format: Format::CI,
+        mask: 0x0000e003,
+        match_bits: 0x00004002,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.ldsp",
+        extension: "C",
+        format: Format::CI,
+        mask: 0x0000e003,
+        match_bits: 0x00006002,
+        operands: &[OperandRole::Dest, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.jr",
+        extension: "C",
+        format: Format::CR,
+        mask: 0x0000f07f,
+        match_bits: 0x00008002,
+        operands: &[OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "c.mv",
+        extension: "C",
+        format: Format::CR,
+        mask: 0x0000f003,
+        match_bits: 0x00008002,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "c.ebreak",
+        extension: "C",
+        format: Format::CR,
+        mask: 0x0000ffff,
+        match_bits: 0x00009002,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "c.jalr",
+        extension: "C",
+        format: Format::CR,
+        mask: 0x0000f07f,
+        match_bits: 0x00009002,
+        operands: &[OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "c.add",
+        extension: "C",
+        format: Format::CR,
+        mask: 0x0000f003,
+        match_bits: 0x00009002,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "c.fsdsp",
+        extension: "C",
+        format: Format::CSS,
+        mask: 0x0000e003,
+        match_bits: 0x0000a002,
+        operands: &[OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.swsp",
+        extension: "C",
+        format: Format::CSS,
+        mask: 0x0000e003,
+        match_bits: 0x0000c002,
+        operands: &[OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.sdsp",
+        extension: "C",
+        format: Format::CSS,
+        mask: 0x0000e003,
+        match_bits: 0x0000e002,
+        operands: &[OperandRole::Src1, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.lbu",
+        extension: "Zcb",
+        format: Format::CL,
+        mask: 0x0000fc03,
+        match_bits: 0x00008000,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.lhu",
+        extension: "Zcb",
+        format: Format::CL,
+        mask: 0x0000fc43,
+        match_bits: 0x00008400,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.lh",
+        extension: "Zcb",
+        format: Format::CL,
+        mask: 0x0000fc43,
+        match_bits: 0x00008440,
+        operands: &[OperandRole::Dest, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.sb",
+        extension: "Zcb",
+        format: Format::CL,
+        mask: 0x0000fc03,
+        match_bits: 0x00008800,
+        operands: &[OperandRole::Src1, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.sh",
+        extension: "Zcb",
+        format: Format::CL,
+        mask: 0x0000fc43,
+        match_bits: 0x00008c00,
+        operands: &[OperandRole::Src1, OperandRole::Base, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.zext.b",
+        extension: "Zcb",
+        format: Format::CA,
+        mask: 0x0000fc7f,
+        match_bits: 0x00009c61,
+        operands: &[OperandRole::Dest],
+    },
+    InstructionInfo {
+        mnemonic: "c.sext.b",
+        extension: "Zcb",
+        format: Format::CA,
+        mask: 0x0000fc7f,
+        match_bits: 0x00009c65,
+        operands: &[OperandRole::Dest],
+    },
+    InstructionInfo {
+        mnemonic: "c.zext.h",
+        extension: "Zcb",
+        format: Format::CA,
+        mask: 0x0000fc7f,
+        match_bits: 0x00009c69,
+        operands: &[OperandRole::Dest],
+    },
+    InstructionInfo {
+        mnemonic: "c.sext.h",
+        extension: "Zcb",
+        format: Format::CA,
+        mask: 0x0000fc7f,
+        match_bits: 0x00009c6d,
+        operands: &[OperandRole::Dest],
+    },
+    InstructionInfo {
+        mnemonic: "c.zext.w",
+        extension: "Zcb",
+        format: Format::CA,
+        mask: 0x0000fc7f,
+        match_bits: 0x00009c71,
+        operands: &[OperandRole::Dest],
+    },
+    InstructionInfo {
+        mnemonic: "c.not",
+        extension: "Zcb",
+        format: Format::CA,
+        mask: 0x0000fc7f,
+        match_bits: 0x00009c75,
+        operands: &[OperandRole::Dest],
+    },
+    InstructionInfo {
+        mnemonic: "c.mul",
+        extension: "Zcb",
+        format: Format::CA,
+        mask: 0x0000fc63,
+        match_bits: 0x00009c41,
+        operands: &[OperandRole::Dest, OperandRole::Src1],
+    },
+    InstructionInfo {
+        mnemonic: "cm.push",
+        extension: "Zcmp",
+        format: Format::CSS,
+        mask: 0x0000ff03,
+        match_bits: 0x0000b802,
+        operands: &[OperandRole::RegisterList, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "cm.pop",
+        extension: "Zcmp",
+        format: Format::CSS,
+        mask: 0x0000ff03,
+        match_bits: 0x0000ba02,
+        operands: &[OperandRole::RegisterList, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "cm.popretz",
+        extension: "Zcmp",
+        format: Format::CSS,
+        mask: 0x0000ff03,
+        match_bits: 0x0000bc02,
+        operands: &[OperandRole::RegisterList, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "cm.popret",
+        extension: "Zcmp",
+        format: Format::CSS,
+        mask: 0x0000ff03,
+        match_bits: 0x0000be02,
+        operands: &[OperandRole::RegisterList, OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "cm.mvsa01",
+        extension: "Zcmp",
+        format: Format::CSS,
+        mask: 0x0000fc63,
+        match_bits: 0x0000ac22,
+        operands: &[OperandRole::Dest, OperandRole::Dest2],
+    },
+    InstructionInfo {
+        mnemonic: "cm.mva01s",
+        extension: "Zcmp",
+        format: Format::CSS,
+        mask: 0x0000fc63,
+        match_bits: 0x0000ac62,
+        operands: &[OperandRole::Src1, OperandRole::Src2],
+    },
+    InstructionInfo {
+        mnemonic: "cm.jt",
+        extension: "Zcmt",
+        format: Format::CSS,
+        mask: 0x0000ff83,
+        match_bits: 0x0000a002,
+        operands: &[OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "cm.jalt",
+        extension: "Zcmt",
+        format: Format::CSS,
+        mask: 0x0000fc03,
+        match_bits: 0x0000a002,
+        operands: &[OperandRole::Immediate],
+    },
+    InstructionInfo {
+        mnemonic: "c.mop.1",
+        extension: "Zcmop",
+        format: Format::CI,
+        mask: 0x0000ffff,
+        match_bits: 0x00006081,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "c.mop.3",
+        extension: "Zcmop",
+        format: Format::CI,
+        mask: 0x0000ffff,
+        match_bits: 0x00006181,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "c.mop.5",
+        extension: "Zcmop",
+        format: Format::CI,
+        mask: 0x0000ffff,
+        match_bits: 0x00006281,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "c.mop.7",
+        extension: "Zcmop",
+        format: Format::CI,
+        mask: 0x0000ffff,
+        match_bits: 0x00006381,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "c.mop.9",
+        extension: "Zcmop",
+        format: Format::CI,
+        mask: 0x0000ffff,
+        match_bits: 0x00006481,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "c.mop.11",
+        extension: "Zcmop",
+        format: Format::CI,
+        mask: 0x0000ffff,
+        match_bits: 0x00006581,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "c.mop.13",
+        extension: "Zcmop",
+        format: Format::CI,
+        mask: 0x0000ffff,
+        match_bits: 0x00006681,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "c.mop.15",
+        extension: "Zcmop",
+        format: Format::CI,
+        mask: 0x0000ffff,
+        match_bits: 0x00006781,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "c.sspush",
+        extension: "Zicfiss",
+        format: Format::CI,
+        mask: 0x0000ffff,
+        match_bits: 0x00006081,
+        operands: &[],
+    },
+    InstructionInfo {
+        mnemonic: "c.sspopchk",
+        extension: "Zicfiss",
+        format: Format::CI,
+        mask: 0x0000ffff,
+        match_bits: 0x00006281,
+        operands: &[],
+    },
+];
+
+/// Returns an iterator over every instruction, with the 32-bit instructions before the 16-bit ones.
+pub fn instructions() -> impl Iterator<Item = &'static InstructionInfo> {
+    INSTRUCTIONS.iter().chain(COMPRESSED_INSTRUCTIONS.iter())
+}
+
+impl Instruction {
+    /// Returns the static information about the instruction.
+    pub fn info(&self) -> &'static InstructionInfo {
+        &INSTRUCTIONS[self.table_index()]
+    }
+
+    /// Returns the lowercase mnemonic of the instruction, such as `addi` or `amoadd.w`.
+    pub fn mnemonic(&self) -> &'static str {
+        self.info().mnemonic
+    }
+
+    fn table_index(&self) -> usize {
+        match self {
+            Instruction::Lui { .. } => 0,
+            Instruction::Auipc { .. } => 1,
+            Instruction::Jal { .. } => 2,
+            Instruction::Jalr { .. } => 3,
+            Instruction::Beq { .. } => 4,
+            Instruction::Bne { .. } => 5,
+            Instruction::Blt { .. } => 6,
+            Instruction::Bge { .. } => 7,
+            Instruction::Bltu { .. } => 8,
+            Instruction::Bgeu { .. } => 9,
+            Instruction::Lb { .. } => 10,
+            Instruction::Lh { .. } => 11,
+            Instruction::Lw { .. } => 12,
+            Instruction::Lbu { .. } => 13,
+            Instruction::Lhu { .. } => 14,
+            Instruction::Sb { .. } => 15,
+            Instruction::Sh { .. } => 16,
+            Instruction::Sw { .. } => 17,
+            Instruction::Addi { .. } => 18,
+            Instruction::Slti { .. } => 19,
+            Instruction::Sltiu { .. } => 20,
+            Instruction::Xori { .. } => 21,
+            Instruction::Ori { .. } => 22,
+            Instruction::Andi { .. } => 23,
+            Instruction::Slli { .. } => 24,
+            Instruction::Srli { .. } => 25,
+            Instruction::Srai { .. } => 26,
+            Instruction::Add { .. } => 27,
+            Instruction::Sub { .. } => 28,
+            Instruction::Sll { .. } => 29,
+            Instruction::Slt { .. } => 30,
+            Instruction::Sltu { .. } => 31,
+            Instruction::Xor { .. } => 32,
+            Instruction::Srl { .. } => 33,
+            Instruction::Sra { .. } => 34,
+            Instruction::Or { .. } => 35,
+            Instruction::And { .. } => 36,
+            Instruction::Fence { .. } => 37,
+            Instruction::Ecall => 38,
+            Instruction::Ebreak => 39,
+            Instruction::Lwu { .. } => 40,
+            Instruction::Ld { .. } => 41,
+            Instruction::Sd { .. } => 42,
+            Instruction::Addiw { .. } => 43,
+            Instruction::Slliw { .. } => 44,
+            Instruction::Srliw { .. } => 45,
+            Instruction::Sraiw { .. } => 46,
+            Instruction::Addw { .. } => 47,
+            Instruction::Subw { .. } => 48,
+            Instruction::Sllw { .. } => 49,
+            Instruction::Srlw { .. } => 50,
+            Instruction::Sraw { .. } => 51,
+            Instruction::Mul { .. } => 52,
+            Instruction::Mulh { .. } => 53,
+            Instruction::Mulhsu { .. } => 54,
+            Instruction::Mulhu { .. } => 55,
+            Instruction::Div { .. } => 56,
+            Instruction::Divu { .. } => 57,
+            Instruction::Rem { .. } => 58,
+            Instruction::Remu { .. } => 59,
+            Instruction::Mulw { .. } => 60,
+            Instruction::Divw { .. } => 61,
+            Instruction::Divuw { .. } => 62,
+            Instruction::Remw { .. } => 63,
+            Instruction::Remuw { .. } => 64,
+            Instruction::LrW { .. } => 65,
+            Instruction::ScW { .. } => 66,
+            Instruction::AmoswapW { .. } => 67,
+            Instruction::AmoaddW { .. } => 68,
+            Instruction::AmoxorW { .. } => 69,
+            Instruction::AmoandW { .. } => 70,
+            Instruction::AmoorW { .. } => 71,
+            Instruction::AmominW { .. } => 72,
+            Instruction::AmomaxW { .. } => 73,
+            Instruction::AmominuW { .. } => 74,
+            Instruction::AmomaxuW { .. } => 75,
+            Instruction::LrD { .. } => 76,
+            Instruction::ScD { .. } => 77,
+            Instruction::AmoswapD { .. } => 78,
+            Instruction::AmoaddD { .. } => 79,
+            Instruction::AmoxorD { .. } => 80,
+            Instruction::AmoandD { .. } => 81,
+            Instruction::AmoorD { .. } => 82,
+            Instruction::AmominD { .. } => 83,
+            Instruction::AmomaxD { .. } => 84,
+            Instruction::AmominuD { .. } => 85,
+            Instruction::AmomaxuD { .. } => 86,
+            Instruction::Flw { .. } => 87,
+            Instruction::Fsw { .. } => 88,
+            Instruction::FmaddS { .. } => 89,
+            Instruction::FmsubS { .. } => 90,
+            Instruction::FnmsubS { .. } => 91,
+            Instruction::FnmaddS { .. } => 92,
+            Instruction::FaddS { .. } => 93,
+            Instruction::FsubS { .. } => 94,
+            Instruction::FmulS { .. } => 95,
+            Instruction::FdivS { .. } => 96,
+            Instruction::FsqrtS { .. } => 97,
+            Instruction::FsgnjS { .. } => 98,
+            Instruction::FsgnjnS { .. } => 99,
+            Instruction::FsgnjxS { .. } => 100,
+            Instruction::FminS { .. } => 101,
+            Instruction::FmaxS { .. } => 102,
+            Instruction::FcvtWS { .. } => 103,
+            Instruction::FcvtWuS { .. } => 104,
+            Instruction::FmvXW { .. } => 105,
+            Instruction::FeqS { .. } => 106,
+            Instruction::FltS { .. } => 107,
+            Instruction::FleS { .. } => 108,
+            Instruction::FclassS { .. } => 109,
+            Instruction::FcvtSW { .. } => 110,
+            Instruction::FcvtSWu { .. } => 111,
+            Instruction::FmvWX { .. } => 112,
+            Instruction::FcvtLS { .. } => 113,
+            Instruction::FcvtLuS { .. } => 114,
+            Instruction::FcvtSL { .. } => 115,
+            Instruction::FcvtSLu { .. } => 116,
+            Instruction::Csrrw { .. } => 117,
+            Instruction::Csrrs { .. } => 118,
+            Instruction::Csrrc { .. } => 119,
+            Instruction::Csrrwi { .. } => 120,
+            Instruction::Csrrsi { .. } => 121,
+            Instruction::Csrrci { .. } => 122,
+            Instruction::FenceI => 123,
+            Instruction::Fld { .. } => 124,
+            Instruction::Fsd { .. } => 125,
+            Instruction::FmaddD { .. } => 126,
+            Instruction::FmsubD { .. } => 127,
+            Instruction::FnmaddD { .. } => 128,
+            Instruction::FnmsubD { .. } => 129,
+            Instruction::FaddD { .. } => 130,
+            Instruction::FsubD { .. } => 131,
+            Instruction::FmulD { .. } => 132,
+            Instruction::FdivD { .. } => 133,
+            Instruction::FsqrtD { .. } => 134,
+            Instruction::FsgnjD { .. } => 135,
+            Instruction::FsgnjnD { .. } => 136,
+            Instruction::FsgnjxD { .. } => 137,
+            Instruction::FminD { .. } => 138,
+            Instruction::FmaxD { .. } => 139,
+            Instruction::FcvtSD { .. } => 140,
+            Instruction::FcvtDS { .. } => 141,
+            Instruction::FeqD { .. } => 142,
+            Instruction::FltD { .. } => 143,
+            Instruction::FleD { .. } => 144,
+            Instruction::FclassD { .. } => 145,
+            Instruction::FcvtWD { .. } => 146,
+            Instruction::FcvtWuD { .. } => 147,
+            Instruction::FcvtDW { .. } => 148,
+            Instruction::FcvtDWu { .. } => 149,
+            Instruction::FcvtLD { .. } => 150,
+            Instruction::FcvtLuD { .. } => 151,
+            Instruction::FmvXD { .. } => 152,
+            Instruction::FcvtDL { .. } => 153,
+            Instruction::FcvtDLu { .. } => 154,
+            Instruction::FmvDX { .. } => 155,
+            Instruction::Flq { .. } => 156,
+            Instruction::Fsq { .. } => 157,
+            Instruction::FmaddQ { .. } => 158,
+            Instruction::FmsubQ { .. } => 159,
+            Instruction::FnmsubQ { .. } => 160,
+            Instruction::FnmaddQ { .. } => 161,
+            Instruction::FaddQ { .. } => 162,
+            Instruction::FsubQ { .. } => 163,
+            Instruction::FmulQ { .. } => 164,
+            Instruction::FdivQ { .. } => 165,
+            Instruction::FsqrtQ { .. } => 166,
+            Instruction::FsgnjQ { .. } => 167,
+            Instruction::FsgnjnQ { .. } => 168,
+            Instruction::FsgnjxQ { .. } => 169,
+            Instruction::FminQ { .. } => 170,
+            Instruction::FmaxQ { .. } => 171,
+            Instruction::FcvtSQ { .. } => 172,
+            Instruction::FcvtQS { .. } => 173,
+            Instruction::FcvtDQ { .. } => 174,
+            Instruction::FcvtQD { .. } => 175,
+            Instruction::FeqQ { .. } => 176,
+            Instruction::FltQ { .. } => 177,
+            Instruction::FleQ { .. } => 178,
+            Instruction::FclassQ { .. } => 179,
+            Instruction::FcvtWQ { .. } => 180,
+            Instruction::FcvtWuQ { .. } => 181,
+            Instruction::FcvtQW { .. } => 182,
+            Instruction::FcvtQWu { .. } => 183,
+            Instruction::FcvtLQ { .. } => 184,
+            Instruction::FcvtLuQ { .. } => 185,
+            Instruction::FcvtQL { .. } => 186,
+            Instruction::FcvtQLu { .. } => 187,
+            Instruction::FliS { .. } => 188,
+            Instruction::FliD { .. } => 189,
+            Instruction::FliH { .. } => 190,
+            Instruction::FliQ { .. } => 191,
+            Instruction::FminmS { .. } => 192,
+            Instruction::FminmD { .. } => 193,
+            Instruction::FminmH { .. } => 194,
+            Instruction::FminmQ { .. } => 195,
+            Instruction::FmaxmS { .. } => 196,
+            Instruction::FmaxmD { .. } => 197,
+            Instruction::FmaxmH { .. } => 198,
+            Instruction::FmaxmQ { .. } => 199,
+            Instruction::FroundS { .. } => 200,
+            Instruction::FroundD { .. } => 201,
+            Instruction::FroundH { .. } => 202,
+            Instruction::FroundQ { .. } => 203,
+            Instruction::FroundnxS { .. } => 204,
+            Instruction::FroundnxD { .. } => 205,
+            Instruction::FroundnxH { .. } => 206,
+            Instruction::FroundnxQ { .. } => 207,
+            Instruction::FcvtmodWD { .. } => 208,
+            Instruction::FleqS { .. } => 209,
+            Instruction::FleqD { .. } => 210,
+            Instruction::FleqH { .. } => 211,
+            Instruction::FleqQ { .. } => 212,
+            Instruction::FltqS { .. } => 213,
+            Instruction::FltqD { .. } => 214,
+            Instruction::FltqH { .. } => 215,
+            Instruction::FltqQ { .. } => 216,
+            Instruction::FmvhXD { .. } => 217,
+            Instruction::FmvpDX { .. } => 218,
+            Instruction::FcvtBf16S { .. } => 219,
+            Instruction::FcvtSBf16 { .. } => 220,
+            Instruction::Vfncvtbf16FFW { .. } => 221,
+            Instruction::Vfwcvtbf16FFV { .. } => 222,
+            Instruction::Vfwmaccbf16VV { .. } => 223,
+            Instruction::Vfwmaccbf16VF { .. } => 224,
+            Instruction::Sret => 225,
+            Instruction::Mret => 226,
+            Instruction::Wfi => 227,
+            Instruction::SfenceVma { .. } => 228,
+            Instruction::SinvalVma { .. } => 229,
+            Instruction::SfenceWInval => 230,
+            Instruction::SfenceInvalIr => 231,
+            Instruction::Mnret => 232,
+            Instruction::Dret => 233,
+            Instruction::HfenceVvma { .. } => 234,
+            Instruction::HfenceGvma { .. } => 235,
+            Instruction::HinvalVvma { .. } => 236,
+            Instruction::HinvalGvma { .. } => 237,
+            Instruction::HlvB { .. } => 238,
+            Instruction::HlvBu { .. } => 239,
+            Instruction::HlvH { .. } => 240,
+            Instruction::HlvHu { .. } => 241,
+            Instruction::HlvxHu { .. } => 242,
+            Instruction::HlvW { .. } => 243,
+            Instruction::HlvxWu { .. } => 244,
+            Instruction::HsvB { .. } => 245,
+            Instruction::HsvH { .. } => 246,
+            Instruction::HsvW { .. } => 247,
+            Instruction::HlvWu { .. } => 248,
+            Instruction::HlvD { .. } => 249,
+            Instruction::HsvD { .. } => 250,
+            Instruction::CboClean { .. } => 251,
+            Instruction::CboFlush { .. } => 252,
+            Instruction::CboInval { .. } => 253,
+            Instruction::CboZero { .. } => 254,
+            Instruction::PrefetchI { .. } => 255,
+            Instruction::PrefetchR { .. } => 256,
+            Instruction::PrefetchW { .. } => 257,
+            Instruction::AmocasW { .. } => 258,
+            Instruction::AmocasD { .. } => 259,
+            Instruction::AmocasQ { .. } => 260,
+            Instruction::AmoswapB { .. } => 261,
+            Instruction::AmoaddB { .. } => 262,
+            Instruction::AmoxorB { .. } => 263,
+            Instruction::AmoandB { .. } => 264,
+            Instruction::AmoorB { .. } => 265,
+            Instruction::AmominB { .. } => 266,
+            Instruction::AmomaxB { .. } => 267,
+            Instruction::AmominuB { .. } => 268,
+            Instruction::AmomaxuB { .. } => 269,
+            Instruction::AmocasB { .. } => 270,
+            Instruction::AmoswapH { .. } => 271,
+            Instruction::AmoaddH { .. } => 272,
+            Instruction::AmoxorH { .. } => 273,
+            Instruction::AmoandH { .. } => 274,
+            Instruction::AmoorH { .. } => 275,
+            Instruction::AmominH { .. } => 276,
+            Instruction::AmomaxH { .. } => 277,
+            Instruction::AmominuH { .. } => 278,
+            Instruction::AmomaxuH { .. } => 279,
+            Instruction::AmocasH { .. } => 280,
+            Instruction::WrsNto => 281,
+            Instruction::WrsSto => 282,
+            Instruction::LbAq { .. } => 283,
+            Instruction::LhAq { .. } => 284,
+            Instruction::LwAq { .. } => 285,
+            Instruction::SbRl { .. } => 286,
+            Instruction::ShRl { .. } => 287,
+            Instruction::SwRl { .. } => 288,
+            Instruction::LdAq { .. } => 289,
+            Instruction::SdRl { .. } => 290,
+            Instruction::CzeroEqz { .. } => 291,
+            Instruction::CzeroNez { .. } => 292,
+            Instruction::Lpad { .. } => 293,
+            Instruction::Sspush { .. } => 294,
+            Instruction::Sspopchk { .. } => 295,
+            Instruction::Ssrdp { .. } => 296,
+            Instruction::SsamoswapW { .. } => 297,
+            Instruction::SsamoswapD { .. } => 298,
+            Instruction::MopR { n, .. } => 299 + *n as usize,
+            Instruction::MopRr { n, .. } => 331 + *n as usize,
+        }
+    }
+}
+
+impl CInstruction {
+    /// Returns the static information about the instruction.
+    pub fn info(&self) -> &'static InstructionInfo {
+        &COMPRESSED_INSTRUCTIONS[self.table_index()]
+    }
+
+    /// Returns the lowercase mnemonic of the instruction, such as `c.addi` or `cm.push`.
+    pub fn mnemonic(&self) -> &'static str {
+        self.info().mnemonic
+    }
+
+    fn table_index(&self) -> usize {
+        match self {
+            CInstruction::ADDI4SPN { .. } => 0,
+            CInstruction::FLD { .. } => 1,
+            CInstruction::LW { .. } => 2,
+            CInstruction::LD { .. } => 3,
+            CInstruction::FSD { .. } => 4,
+            CInstruction::SW { .. } => 5,
+            CInstruction::SD { .. } => 6,
+            CInstruction::ADDI { .. } => 7,
+            CInstruction::ADDIW { .. } => 8,
+            CInstruction::LI { .. } => 9,
+            CInstruction::ADDI16SP { .. } => 10,
+            CInstruction::LUI { .. } => 11,
+            CInstruction::SRLI { .. } => 12,
+            CInstruction::SRAI { .. } => 13,
+            CInstruction::ANDI { .. } => 14,
+            CInstruction::SUB { .. } => 15,
+            CInstruction::XOR { .. } => 16,
+            CInstruction::OR { .. } => 17,
+            CInstruction::AND { .. } => 18,
+            CInstruction::SUBW { .. } => 19,
+            CInstruction::ADDW { .. } => 20,
+            CInstruction::J { .. } => 21,
+            CInstruction::BEQZ { .. } => 22,
+            CInstruction::BNEZ { .. } => 23,
+            CInstruction::SLLI { .. } => 24,
+            CInstruction::FLDSP { .. } => 25,
+            CInstruction::LWSP { .. } => 26,
+            CInstruction::LDSP { .. } => 27,
+            CInstruction::JR { .. } => 28,
+            CInstruction::MV { .. } => 29,
+            CInstruction::EBREAK => 30,
+            CInstruction::JALR { .. } => 31,
+            CInstruction::ADD { .. } => 32,
+            CInstruction::FSDSP { .. } => 33,
+            CInstruction::SWSP { .. } => 34,
+            CInstruction::SDSP { .. } => 35,
+            CInstruction::LBU { .. } => 36,
+            CInstruction::LHU { .. } => 37,
+            CInstruction::LH { .. } => 38,
+            CInstruction::SB { .. } => 39,
+            CInstruction::SH { .. } => 40,
+            CInstruction::ZEXTB { .. } => 41,
+            CInstruction::SEXTB { .. } => 42,
+            CInstruction::ZEXTH { .. } => 43,
+            CInstruction::SEXTH { .. } => 44,
+            CInstruction::ZEXTW { .. } => 45,
+            CInstruction::NOT { .. } => 46,
+            CInstruction::MUL { .. } => 47,
+            CInstruction::CMPUSH { .. } => 48,
+            CInstruction::CMPOP { .. } => 49,
+            CInstruction::CMPOPRETZ { .. } => 50,
+            CInstruction::CMPOPRET { .. } => 51,
+            CInstruction::CMMVSA01 { .. } => 52,
+            CInstruction::CMMVA01S { .. } => 53,
+            CInstruction::CMJT { .. } => 54,
+            CInstruction::CMJALT { .. } => 55,
+            CInstruction::MOP { n, .. } => 56 + *n as usize / 2,
+            CInstruction::SSPUSH => 64,
+            CInstruction::SSPOPCHK => 65,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::raw::Format;
+
 // the unusual bit groupings are used to match the ISA manual table
 #[allow(clippy::unusual_byte_groupings)]
 // Table 70, page 553 of the Unprivileged ISA Manual
@@ -24,6 +26,12 @@ pub enum Opcode {
     Nmsub = 0b10_010_11,
     Nmadd = 0b10_011_11,
     System = 0b11_100_11,
+    OpV = 0b10_101_11,
+    // reserved for custom and vendor extensions
+    Custom0 = 0b00_010_11,
+    Custom1 = 0b01_010_11,
+    Custom2 = 0b10_110_11,
+    Custom3 = 0b11_110_11,
     Reserved = 0,
 }
 
@@ -55,7 +63,42 @@ impl Opcode {
             0b00_001_11 => Self::LoadFp,
             0b01_001_11 => Self::StoreFp,
             0b11_100_11 => Self::System,
+            0b10_101_11 => Self::OpV,
+            0b00_010_11 => Self::Custom0,
+            0b01_010_11 => Self::Custom1,
+            0b10_110_11 => Self::Custom2,
+            0b11_110_11 => Self::Custom3,
             _ => Self::Reserved,
         }
     }
 }
+
+impl Opcode {
+    /// Returns the format used by the instructions with this opcode, custom and reserved opcodes use the R format.
+    pub fn format(&self) -> Format {
+        match self {
+            Opcode::Load
+            | Opcode::LoadFp
+            | Opcode::OpImm
+            | Opcode::OpImm32
+            | Opcode::Jalr
+            | Opcode::MiscMem
+            | Opcode::System => Format::I,
+            Opcode::Store | Opcode::StoreFp => Format::S,
+            Opcode::Branch => Format::B,
+            Opcode::Lui | Opcode::Auipc => Format::U,
+            Opcode::Jal => Format::J,
+            Opcode::Madd | Opcode::Msub | Opcode::Nmsub | Opcode::Nmadd => Format::R4,
+            Opcode::Op
+            | Opcode::Op32
+            | Opcode::AMO
+            | Opcode::OpFp
+            | Opcode::OpV
+            | Opcode::Custom0
+            | Opcode::Custom1
+            | Opcode::Custom2
+            | Opcode::Custom3
+            | Opcode::Reserved => Format::R,
+        }
+    }
+}
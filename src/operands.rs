@@ -0,0 +1,3639 @@
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::cinstruction::CInstruction;
+use crate::immediates::{
+    BImmediate, C16SPImmediate, CBImmediate, CByteImmediate, CDImmediate, CDSPImmediate,
+    CHalfImmediate, CIImmediate, CJImmediate, CSDSPImmediate, CSR, CSRImmediate, CSWSPImmediate,
+    CShamt, CStackAdjImmediate, CWImmediate, CWSPImmediate, CWideImmediate, IImmediate, JImmediate,
+    LpadLabel, PrefetchImmediate, SImmediate, Shamt, ShamtW, UImmediate,
+};
+use crate::instruction::{FliConstant, Instruction, RoundingMode};
+use crate::isa::{IsaConfig, Xlen};
+use crate::register::{
+    CFRegister, CIRegister, CSRegister, FRegister, IRegister, Register, RegisterList, VRegister,
+};
+
+/// The role of an operand in an instruction.
+///
+/// Registers are named by their position in the assembly syntax rather than the encoding, so the single source of
+/// instructions like `addi` and `fsqrt.s` is `Src1`, and the data register of stores and AMOs is also `Src1`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OperandRole {
+    Dest,
+    /// The second destination of `cm.mvsa01`
+    Dest2,
+    Src1,
+    Src2,
+    Src3,
+    /// The address register of loads, stores and atomics
+    Base,
+    /// An immediate, offset, shift amount or index
+    Immediate,
+    Csr,
+    RoundingMode,
+    /// The saved registers of the Zcmp push and pop instructions
+    RegisterList,
+}
+
+/// The value of an operand.
+///
+/// Registers in compressed instructions are expanded to the full registers, and immediates hold the value that is
+/// written in assembly, such as the byte offset of a branch. The `fli` constants are given by their index in the
+/// table of constants.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Operand {
+    I(IRegister),
+    F(FRegister),
+    V(VRegister),
+    Immediate(i64),
+    Csr(u16),
+    RoundingMode(RoundingMode),
+    RegisterList(RegisterList),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Operand::I(r) => write!(f, "{r}"),
+            Operand::F(r) => write!(f, "{r}"),
+            Operand::V(r) => write!(f, "{r}"),
+            Operand::Immediate(x) => write!(f, "{x}"),
+            Operand::Csr(csr) => write!(f, "{}", Register::Csr(*csr)),
+            Operand::RoundingMode(rm) => write!(f, "{rm}"),
+            Operand::RegisterList(rlist) => write!(f, "{rlist}"),
+        }
+    }
+}
+
+/// The registers read and written by an instruction, as `(read, written)`
+type RegisterEffects = (Vec<Register>, Vec<Register>);
+
+impl Instruction {
+    /// Returns the registers read by the instruction, including implicit operands such as the dynamic rounding mode
+    /// and the shadow stack pointer. `zero` always reads as zero, so it isn't included, and accesses to `fcsr` are
+    /// reported as accesses to `frm` and `fflags`. Float operands given as `FRegister::Integer` are reported as the
+    /// integer registers they name.
+    ///
+    /// The second register of the pairs used by `amocas.d` on RV32 isn't included, since it depends on the ISA
+    /// configuration. Use `registers_read_with` to include it.
+    pub fn registers_read(&self) -> Vec<Register> {
+        without_zero(integer_floats(self.register_effects().0, false))
+    }
+
+    /// Returns the registers written by the instruction, including implicit operands such as `fflags`. Writes to
+    /// `zero` are discarded, so they aren't included.
+    pub fn registers_written(&self) -> Vec<Register> {
+        without_zero(integer_floats(self.register_effects().1, false))
+    }
+
+    /// Returns the registers read by the instruction for the given ISA configuration.
+    ///
+    /// When float instructions use the integer registers (Zfinx, Zdinx, Zhinx), their float operands are reported as
+    /// the integer registers that hold them. On RV32, operands that are held in an even/odd register pair (the double
+    /// precision operands with Zdinx and the `amocas.d` operands) are reported as both registers of the pair.
+    pub fn registers_read_with(&self, isa: &IsaConfig) -> Vec<Register> {
+        without_zero(self.register_effects_with(isa).0)
+    }
+
+    /// Returns the registers written by the instruction for the given ISA configuration, reporting floats held in the
+    /// integer registers and register pairs like `registers_read_with`.
+    pub fn registers_written_with(&self, isa: &IsaConfig) -> Vec<Register> {
+        without_zero(self.register_effects_with(isa).1)
+    }
+
+    fn register_effects_with(&self, isa: &IsaConfig) -> RegisterEffects {
+        let (reads, writes) = self.register_effects();
+        let all = isa.floats_in_integer_registers();
+        let (reads, writes) = (integer_floats(reads, all), integer_floats(writes, all));
+        if isa.xlen != Xlen::Rv32 {
+            return (reads, writes);
+        }
+        let (paired_writes, paired_reads) = match self {
+            Instruction::AmocasD { dest, src, .. } => (vec![*dest], vec![*dest, *src]),
+            _ if isa.zdinx => {
+                let integer = |r: Vec<FRegister>| {
+                    r.into_iter()
+                        .map(|r| IRegister::from_int(r.into()))
+                        .collect()
+                };
+                let (dests, srcs) = self.double_operands();
+                (integer(dests), integer(srcs))
+            }
+            _ => (vec![], vec![]),
+        };
+        (
+            with_pairs(reads, &paired_reads),
+            with_pairs(writes, &paired_writes),
+        )
+    }
+
+    fn register_effects(&self) -> RegisterEffects {
+        use Register::{F, I, V};
+        match self {
+            Instruction::Fence { .. } => (vec![], vec![]),
+            // csrrw and csrrwi don't read the CSR when the destination is zero, and the other CSR instructions don't
+            // write it when the source is zero
+            Instruction::Csrrw { dest, src, csr } => {
+                let csr = csr_registers(csr);
+                let mut reads = vec![I(*src)];
+                if *dest != IRegister::Zero {
+                    reads.extend(&csr);
+                }
+                let mut writes = vec![I(*dest)];
+                writes.extend(csr);
+                (reads, writes)
+            }
+            Instruction::Csrrs { dest, src, csr } | Instruction::Csrrc { dest, src, csr } => {
+                let csr = csr_registers(csr);
+                let mut reads = vec![I(*src)];
+                reads.extend(&csr);
+                let mut writes = vec![I(*dest)];
+                if *src != IRegister::Zero {
+                    writes.extend(csr);
+                }
+                (reads, writes)
+            }
+            Instruction::Csrrwi { dest, csr, .. } => {
+                let csr = csr_registers(csr);
+                let mut writes = vec![I(*dest)];
+                writes.extend(&csr);
+                if *dest != IRegister::Zero {
+                    (csr, writes)
+                } else {
+                    (vec![], writes)
+                }
+            }
+            Instruction::Csrrsi { dest, imm, csr } | Instruction::Csrrci { dest, imm, csr } => {
+                let csr = csr_registers(csr);
+                let mut writes = vec![I(*dest)];
+                if imm.val() != 0 {
+                    writes.extend(&csr);
+                }
+                (csr, writes)
+            }
+            Instruction::Lpad { .. } => (vec![I(IRegister::T2)], vec![]),
+            Instruction::Sspush { src } | Instruction::Sspopchk { src } => {
+                (vec![I(*src), Register::SSP], vec![Register::SSP])
+            }
+            Instruction::Ssrdp { dest } => (vec![Register::SSP], vec![I(*dest)]),
+            // the may-be-operations only write their destination until they are redefined
+            Instruction::MopR { dest, .. } | Instruction::MopRr { dest, .. } => {
+                (vec![], vec![I(*dest)])
+            }
+            Instruction::AmocasW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmocasD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmocasB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmocasH {
+                dest, addr, src, ..
+            } => (vec![I(*addr), I(*src), I(*dest)], vec![I(*dest)]),
+            Instruction::AmocasQ {
+                dest, addr, src, ..
+            } => (
+                vec![
+                    I(*addr),
+                    I(*src),
+                    I(pair_register(*src)),
+                    I(*dest),
+                    I(pair_register(*dest)),
+                ],
+                vec![I(*dest), I(pair_register(*dest))],
+            ),
+            // the widening conversion is exact, so it doesn't depend on the rounding mode, but a signaling NaN still
+            // raises the invalid flag
+            Instruction::Vfwcvtbf16FFV { dest, src, masked } => {
+                let mut reads = vec![V(*src), Register::VL, Register::VTYPE];
+                if *masked {
+                    reads.push(V(VRegister::V0));
+                }
+                (reads, vec![V(*dest), Register::FFLAGS])
+            }
+            Instruction::Vfncvtbf16FFW { dest, src, masked } => {
+                let mut reads = vec![V(*src), Register::VL, Register::VTYPE, Register::FRM];
+                if *masked {
+                    reads.push(V(VRegister::V0));
+                }
+                (reads, vec![V(*dest), Register::FFLAGS])
+            }
+            Instruction::Vfwmaccbf16VV {
+                dest,
+                src1,
+                src2,
+                masked,
+            } => {
+                let mut reads = vec![
+                    V(*src1),
+                    V(*src2),
+                    V(*dest),
+                    Register::VL,
+                    Register::VTYPE,
+                    Register::FRM,
+                ];
+                if *masked {
+                    reads.push(V(VRegister::V0));
+                }
+                (reads, vec![V(*dest), Register::FFLAGS])
+            }
+            Instruction::Vfwmaccbf16VF {
+                dest,
+                src1,
+                src2,
+                masked,
+            } => {
+                let mut reads = vec![
+                    F(*src1),
+                    V(*src2),
+                    V(*dest),
+                    Register::VL,
+                    Register::VTYPE,
+                    Register::FRM,
+                ];
+                if *masked {
+                    reads.push(V(VRegister::V0));
+                }
+                (reads, vec![V(*dest), Register::FFLAGS])
+            }
+            Instruction::Lui { dest, .. }
+            | Instruction::Auipc { dest, .. }
+            | Instruction::Jal { dest, .. } => (vec![], vec![I(*dest)]),
+            Instruction::Jalr { dest, base, .. }
+            | Instruction::Lb { dest, base, .. }
+            | Instruction::Lh { dest, base, .. }
+            | Instruction::Lw { dest, base, .. }
+            | Instruction::Lbu { dest, base, .. }
+            | Instruction::Lhu { dest, base, .. }
+            | Instruction::Lwu { dest, base, .. }
+            | Instruction::Ld { dest, base, .. } => (vec![I(*base)], vec![I(*dest)]),
+            Instruction::Beq { src1, src2, .. }
+            | Instruction::Bne { src1, src2, .. }
+            | Instruction::Blt { src1, src2, .. }
+            | Instruction::Bge { src1, src2, .. }
+            | Instruction::Bltu { src1, src2, .. }
+            | Instruction::Bgeu { src1, src2, .. } => (vec![I(*src1), I(*src2)], vec![]),
+            Instruction::Sb { src, base, .. }
+            | Instruction::Sh { src, base, .. }
+            | Instruction::Sw { src, base, .. }
+            | Instruction::Sd { src, base, .. } => (vec![I(*src), I(*base)], vec![]),
+            Instruction::Addi { dest, src, .. }
+            | Instruction::Slti { dest, src, .. }
+            | Instruction::Sltiu { dest, src, .. }
+            | Instruction::Xori { dest, src, .. }
+            | Instruction::Ori { dest, src, .. }
+            | Instruction::Andi { dest, src, .. }
+            | Instruction::Slli { dest, src, .. }
+            | Instruction::Srli { dest, src, .. }
+            | Instruction::Srai { dest, src, .. }
+            | Instruction::Addiw { dest, src, .. }
+            | Instruction::Slliw { dest, src, .. }
+            | Instruction::Srliw { dest, src, .. }
+            | Instruction::Sraiw { dest, src, .. } => (vec![I(*src)], vec![I(*dest)]),
+            Instruction::Add {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sub {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sll {
+                dest, src1, src2, ..
+            }
+            | Instruction::Slt {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sltu {
+                dest, src1, src2, ..
+            }
+            | Instruction::Xor {
+                dest, src1, src2, ..
+            }
+            | Instruction::Srl {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sra {
+                dest, src1, src2, ..
+            }
+            | Instruction::Or {
+                dest, src1, src2, ..
+            }
+            | Instruction::And {
+                dest, src1, src2, ..
+            }
+            | Instruction::Addw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Subw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sllw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Srlw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sraw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Mul {
+                dest, src1, src2, ..
+            }
+            | Instruction::Mulh {
+                dest, src1, src2, ..
+            }
+            | Instruction::Mulhsu {
+                dest, src1, src2, ..
+            }
+            | Instruction::Mulhu {
+                dest, src1, src2, ..
+            }
+            | Instruction::Div {
+                dest, src1, src2, ..
+            }
+            | Instruction::Divu {
+                dest, src1, src2, ..
+            }
+            | Instruction::Rem {
+                dest, src1, src2, ..
+            }
+            | Instruction::Remu {
+                dest, src1, src2, ..
+            }
+            | Instruction::Mulw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Divw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Divuw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Remw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Remuw {
+                dest, src1, src2, ..
+            }
+            | Instruction::CzeroEqz {
+                dest, src1, src2, ..
+            }
+            | Instruction::CzeroNez {
+                dest, src1, src2, ..
+            } => (vec![I(*src1), I(*src2)], vec![I(*dest)]),
+            Instruction::Ecall
+            | Instruction::Ebreak
+            | Instruction::FenceI
+            | Instruction::Sret
+            | Instruction::Mret
+            | Instruction::Wfi
+            | Instruction::SfenceWInval
+            | Instruction::SfenceInvalIr
+            | Instruction::Mnret
+            | Instruction::Dret
+            | Instruction::WrsNto
+            | Instruction::WrsSto => (vec![], vec![]),
+            Instruction::LrW { dest, addr, .. }
+            | Instruction::LrD { dest, addr, .. }
+            | Instruction::HlvB { dest, addr, .. }
+            | Instruction::HlvBu { dest, addr, .. }
+            | Instruction::HlvH { dest, addr, .. }
+            | Instruction::HlvHu { dest, addr, .. }
+            | Instruction::HlvxHu { dest, addr, .. }
+            | Instruction::HlvW { dest, addr, .. }
+            | Instruction::HlvxWu { dest, addr, .. }
+            | Instruction::HlvWu { dest, addr, .. }
+            | Instruction::HlvD { dest, addr, .. }
+            | Instruction::LbAq { dest, addr, .. }
+            | Instruction::LhAq { dest, addr, .. }
+            | Instruction::LwAq { dest, addr, .. }
+            | Instruction::LdAq { dest, addr, .. } => (vec![I(*addr)], vec![I(*dest)]),
+            Instruction::ScW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoswapW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoaddW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoxorW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoandW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoorW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominuW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxuW {
+                dest, addr, src, ..
+            }
+            | Instruction::ScD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoswapD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoaddD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoxorD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoandD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoorD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominuD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxuD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoswapB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoaddB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoxorB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoandB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoorB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominuB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxuB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoswapH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoaddH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoxorH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoandH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoorH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominuH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxuH {
+                dest, addr, src, ..
+            }
+            | Instruction::SsamoswapW {
+                dest, addr, src, ..
+            }
+            | Instruction::SsamoswapD {
+                dest, addr, src, ..
+            } => (vec![I(*addr), I(*src)], vec![I(*dest)]),
+            Instruction::Flw { dest, base, .. }
+            | Instruction::Fld { dest, base, .. }
+            | Instruction::Flq { dest, base, .. } => (vec![I(*base)], vec![F(*dest)]),
+            Instruction::Fsw { base, src, .. } => (vec![I(*base), F(*src)], vec![]),
+            Instruction::FmaddS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FmsubS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmsubS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmaddS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FmaddQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FmsubQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmsubQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmaddQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            } => {
+                let mut reads = vec![F(*src1), F(*src2), F(*src3)];
+                if *rm == RoundingMode::DYN {
+                    reads.push(Register::FRM);
+                }
+                (reads, vec![F(*dest), Register::FFLAGS])
+            }
+            Instruction::FaddS {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FsubS {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FmulS {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FdivS {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FaddD {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FsubD {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FmulD {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FdivD {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FaddQ {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FsubQ {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FmulQ {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FdivQ {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            } => {
+                let mut reads = vec![F(*src1), F(*src2)];
+                if *rm == RoundingMode::DYN {
+                    reads.push(Register::FRM);
+                }
+                (reads, vec![F(*dest), Register::FFLAGS])
+            }
+            Instruction::FsqrtS { dest, src, rm, .. }
+            | Instruction::FsqrtD { dest, src, rm, .. }
+            | Instruction::FcvtSD { dest, src, rm, .. }
+            | Instruction::FsqrtQ { dest, src, rm, .. }
+            | Instruction::FcvtSQ { dest, src, rm, .. }
+            | Instruction::FcvtDQ { dest, src, rm, .. }
+            | Instruction::FroundS { dest, src, rm, .. }
+            | Instruction::FroundD { dest, src, rm, .. }
+            | Instruction::FroundH { dest, src, rm, .. }
+            | Instruction::FroundQ { dest, src, rm, .. }
+            | Instruction::FroundnxS { dest, src, rm, .. }
+            | Instruction::FroundnxD { dest, src, rm, .. }
+            | Instruction::FroundnxH { dest, src, rm, .. }
+            | Instruction::FroundnxQ { dest, src, rm, .. }
+            | Instruction::FcvtBf16S { dest, src, rm, .. } => {
+                let mut reads = vec![F(*src)];
+                if *rm == RoundingMode::DYN {
+                    reads.push(Register::FRM);
+                }
+                (reads, vec![F(*dest), Register::FFLAGS])
+            }
+            // widening conversions are exact, so they don't depend on the rounding mode, but a signaling NaN still
+            // raises the invalid flag
+            Instruction::FcvtDS { dest, src, .. }
+            | Instruction::FcvtQS { dest, src, .. }
+            | Instruction::FcvtQD { dest, src, .. }
+            | Instruction::FcvtSBf16 { dest, src, .. } => {
+                (vec![F(*src)], vec![F(*dest), Register::FFLAGS])
+            }
+            Instruction::FsgnjS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjnS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjxS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjnD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjxD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjnQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjxQ {
+                dest, src1, src2, ..
+            } => (vec![F(*src1), F(*src2)], vec![F(*dest)]),
+            Instruction::FminS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminmS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminmD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminmH {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminmQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxmS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxmD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxmH {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxmQ {
+                dest, src1, src2, ..
+            } => (vec![F(*src1), F(*src2)], vec![F(*dest), Register::FFLAGS]),
+            Instruction::FcvtWS { dest, src, rm, .. }
+            | Instruction::FcvtWuS { dest, src, rm, .. }
+            | Instruction::FcvtLS { dest, src, rm, .. }
+            | Instruction::FcvtLuS { dest, src, rm, .. }
+            | Instruction::FcvtWQ { dest, src, rm, .. }
+            | Instruction::FcvtWuQ { dest, src, rm, .. }
+            | Instruction::FcvtLQ { dest, src, rm, .. }
+            | Instruction::FcvtLuQ { dest, src, rm, .. } => {
+                let mut reads = vec![F(*src)];
+                if *rm == RoundingMode::DYN {
+                    reads.push(Register::FRM);
+                }
+                (reads, vec![I(*dest), Register::FFLAGS])
+            }
+            Instruction::FmvXW { dest, src, .. }
+            | Instruction::FclassS { dest, src, .. }
+            | Instruction::FmvXD { dest, src, .. }
+            | Instruction::FclassQ { dest, src, .. }
+            | Instruction::FmvhXD { dest, src, .. } => (vec![F(*src)], vec![I(*dest)]),
+            Instruction::FeqS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FeqD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FeqQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleqS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleqD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleqH {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleqQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltqS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltqD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltqH {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltqQ {
+                dest, src1, src2, ..
+            } => (vec![F(*src1), F(*src2)], vec![I(*dest), Register::FFLAGS]),
+            Instruction::FcvtSW { dest, src, rm, .. }
+            | Instruction::FcvtSWu { dest, src, rm, .. }
+            | Instruction::FcvtSL { dest, src, rm, .. }
+            | Instruction::FcvtSLu { dest, src, rm, .. }
+            | Instruction::FcvtDL { dest, src, rm, .. }
+            | Instruction::FcvtDLu { dest, src, rm, .. } => {
+                let mut reads = vec![I(*src)];
+                if *rm == RoundingMode::DYN {
+                    reads.push(Register::FRM);
+                }
+                (reads, vec![F(*dest), Register::FFLAGS])
+            }
+            // every integer fits in the wider format, so the conversion is exact and can't raise any flags
+            Instruction::FcvtQW { dest, src, .. }
+            | Instruction::FcvtQWu { dest, src, .. }
+            | Instruction::FcvtQL { dest, src, .. }
+            | Instruction::FcvtQLu { dest, src, .. } => (vec![I(*src)], vec![F(*dest)]),
+            Instruction::FmvWX { dest, src, .. } | Instruction::FmvDX { dest, src, .. } => {
+                (vec![I(*src)], vec![F(*dest)])
+            }
+            Instruction::Fsd { src, base, .. } | Instruction::Fsq { src, base, .. } => {
+                (vec![F(*src), I(*base)], vec![])
+            }
+            Instruction::FclassD { dest, src1, .. } => (vec![F(*src1)], vec![I(*dest)]),
+            Instruction::FcvtWD { dest, src1, rm, .. }
+            | Instruction::FcvtWuD { dest, src1, rm, .. }
+            | Instruction::FcvtLD { dest, src1, rm, .. }
+            | Instruction::FcvtLuD { dest, src1, rm, .. } => {
+                let mut reads = vec![F(*src1)];
+                if *rm == RoundingMode::DYN {
+                    reads.push(Register::FRM);
+                }
+                (reads, vec![I(*dest), Register::FFLAGS])
+            }
+            // as are the conversions from 32-bit integers to doubles
+            Instruction::FcvtDW { dest, src1, .. } | Instruction::FcvtDWu { dest, src1, .. } => {
+                (vec![I(*src1)], vec![F(*dest)])
+            }
+            Instruction::FliS { dest, .. }
+            | Instruction::FliD { dest, .. }
+            | Instruction::FliH { dest, .. }
+            | Instruction::FliQ { dest, .. } => (vec![], vec![F(*dest)]),
+            Instruction::FcvtmodWD { dest, src, .. } => {
+                (vec![F(*src)], vec![I(*dest), Register::FFLAGS])
+            }
+            Instruction::FmvpDX {
+                dest, src1, src2, ..
+            } => (vec![I(*src1), I(*src2)], vec![F(*dest)]),
+            Instruction::SfenceVma { vaddr, asid, .. }
+            | Instruction::SinvalVma { vaddr, asid, .. }
+            | Instruction::HfenceVvma { vaddr, asid, .. }
+            | Instruction::HinvalVvma { vaddr, asid, .. } => (vec![I(*vaddr), I(*asid)], vec![]),
+            Instruction::HfenceGvma { gaddr, vmid, .. }
+            | Instruction::HinvalGvma { gaddr, vmid, .. } => (vec![I(*gaddr), I(*vmid)], vec![]),
+            Instruction::HsvB { src, addr, .. }
+            | Instruction::HsvH { src, addr, .. }
+            | Instruction::HsvW { src, addr, .. }
+            | Instruction::HsvD { src, addr, .. }
+            | Instruction::SbRl { src, addr, .. }
+            | Instruction::ShRl { src, addr, .. }
+            | Instruction::SwRl { src, addr, .. }
+            | Instruction::SdRl { src, addr, .. } => (vec![I(*src), I(*addr)], vec![]),
+            Instruction::CboClean { base, .. }
+            | Instruction::CboFlush { base, .. }
+            | Instruction::CboInval { base, .. }
+            | Instruction::CboZero { base, .. }
+            | Instruction::PrefetchI { base, .. }
+            | Instruction::PrefetchR { base, .. }
+            | Instruction::PrefetchW { base, .. } => (vec![I(*base)], vec![]),
+        }
+    }
+}
+
+impl CInstruction {
+    /// Returns the registers read by the instruction, including implicit operands such as `sp` in `c.lwsp` and the
+    /// registers saved by `cm.push`. `zero` always reads as zero, so it isn't included.
+    pub fn registers_read(&self) -> Vec<Register> {
+        without_zero(self.register_effects().0)
+    }
+
+    /// Returns the registers written by the instruction, including implicit operands such as `ra` in `c.jalr`. Writes
+    /// to `zero` are discarded, so they aren't included.
+    pub fn registers_written(&self) -> Vec<Register> {
+        without_zero(self.register_effects().1)
+    }
+
+    fn register_effects(&self) -> RegisterEffects {
+        use Register::{F, I};
+        const SP: Register = I(IRegister::StackPointer);
+        const RA: Register = I(IRegister::ReturnAddress);
+        match self {
+            CInstruction::ADDI4SPN { dest, .. } => (vec![SP], vec![I(dest.expand())]),
+            CInstruction::FLD { dest, base, .. } => {
+                (vec![I(base.expand())], vec![F(dest.expand())])
+            }
+            CInstruction::LW { dest, base, .. }
+            | CInstruction::LD { dest, base, .. }
+            | CInstruction::LBU { dest, base, .. }
+            | CInstruction::LHU { dest, base, .. }
+            | CInstruction::LH { dest, base, .. } => {
+                (vec![I(base.expand())], vec![I(dest.expand())])
+            }
+            CInstruction::FSD { src, base, .. } => {
+                (vec![F(src.expand()), I(base.expand())], vec![])
+            }
+            CInstruction::SW { src, base, .. }
+            | CInstruction::SD { src, base, .. }
+            | CInstruction::SB { src, base, .. }
+            | CInstruction::SH { src, base, .. } => {
+                (vec![I(src.expand()), I(base.expand())], vec![])
+            }
+            CInstruction::ADDI { dest, .. }
+            | CInstruction::ADDIW { dest, .. }
+            | CInstruction::SLLI { dest, .. } => (vec![I(*dest)], vec![I(*dest)]),
+            CInstruction::LI { dest, .. } | CInstruction::LUI { dest, .. } => {
+                (vec![], vec![I(*dest)])
+            }
+            CInstruction::ADDI16SP { .. } => (vec![SP], vec![SP]),
+            CInstruction::SRLI { dest, .. }
+            | CInstruction::SRAI { dest, .. }
+            | CInstruction::ANDI { dest, .. }
+            | CInstruction::ZEXTB { dest }
+            | CInstruction::SEXTB { dest }
+            | CInstruction::ZEXTH { dest }
+            | CInstruction::SEXTH { dest }
+            | CInstruction::ZEXTW { dest }
+            | CInstruction::NOT { dest } => (vec![I(dest.expand())], vec![I(dest.expand())]),
+            CInstruction::SUB { dest, src }
+            | CInstruction::XOR { dest, src }
+            | CInstruction::OR { dest, src }
+            | CInstruction::AND { dest, src }
+            | CInstruction::SUBW { dest, src }
+            | CInstruction::ADDW { dest, src }
+            | CInstruction::MUL { dest, src } => (
+                vec![I(dest.expand()), I(src.expand())],
+                vec![I(dest.expand())],
+            ),
+            CInstruction::J { .. } | CInstruction::EBREAK | CInstruction::MOP { .. } => {
+                (vec![], vec![])
+            }
+            CInstruction::BEQZ { src, .. } | CInstruction::BNEZ { src, .. } => {
+                (vec![I(src.expand())], vec![])
+            }
+            CInstruction::FLDSP { dest, .. } => (vec![SP], vec![F(*dest)]),
+            CInstruction::LWSP { dest, .. } | CInstruction::LDSP { dest, .. } => {
+                (vec![SP], vec![I(*dest)])
+            }
+            CInstruction::JR { src } => (vec![I(*src)], vec![]),
+            CInstruction::JALR { src } => (vec![I(*src)], vec![RA]),
+            CInstruction::MV { dest, src } => (vec![I(*src)], vec![I(*dest)]),
+            CInstruction::ADD { dest, src } => (vec![I(*dest), I(*src)], vec![I(*dest)]),
+            CInstruction::FSDSP { src, .. } => (vec![F(*src), SP], vec![]),
+            CInstruction::SWSP { src, .. } | CInstruction::SDSP { src, .. } => {
+                (vec![I(*src), SP], vec![])
+            }
+            CInstruction::CMPUSH { rlist, .. } => {
+                let mut reads: Vec<Register> = rlist.registers().into_iter().map(I).collect();
+                reads.push(SP);
+                (reads, vec![SP])
+            }
+            // ra is popped before the return jumps through it, so it is written but never read
+            CInstruction::CMPOPRETZ { rlist, .. } => {
+                let mut writes: Vec<Register> = rlist.registers().into_iter().map(I).collect();
+                writes.push(SP);
+                writes.push(I(IRegister::A0));
+                (vec![SP], writes)
+            }
+            CInstruction::CMPOP { rlist, .. } | CInstruction::CMPOPRET { rlist, .. } => {
+                let mut writes: Vec<Register> = rlist.registers().into_iter().map(I).collect();
+                writes.push(SP);
+                (vec![SP], writes)
+            }
+            CInstruction::CMMVSA01 { dest1, dest2 } => (
+                vec![I(IRegister::A0), I(IRegister::A1)],
+                vec![I(dest1.expand()), I(dest2.expand())],
+            ),
+            CInstruction::CMMVA01S { src1, src2 } => (
+                vec![I(src1.expand()), I(src2.expand())],
+                vec![I(IRegister::A0), I(IRegister::A1)],
+            ),
+            CInstruction::CMJT { .. } => (vec![Register::JVT], vec![]),
+            CInstruction::CMJALT { .. } => (vec![Register::JVT], vec![RA]),
+            CInstruction::SSPUSH => (vec![RA, Register::SSP], vec![Register::SSP]),
+            CInstruction::SSPOPCHK => (vec![I(IRegister::T0), Register::SSP], vec![Register::SSP]),
+        }
+    }
+}
+
+/// Returns the registers accessed through a CSR, `fcsr` is accessed as the `frm` and `fflags` fields that make it up
+fn csr_registers(csr: &CSR) -> Vec<Register> {
+    match Register::Csr(csr.val() as u16) {
+        Register::FCSR => vec![Register::FFLAGS, Register::FRM],
+        csr => vec![csr],
+    }
+}
+
+/// Replaces float registers given as `FRegister::Integer` with the integer registers they name, and every other float
+/// register with the integer register of the same number if `all` is set
+fn integer_floats(registers: Vec<Register>, all: bool) -> Vec<Register> {
+    registers
+        .into_iter()
+        .map(|r| match r {
+            Register::F(FRegister::Integer(r)) => Register::I(r),
+            Register::F(r) if all => Register::I(IRegister::from_int(r.into())),
+            r => r,
+        })
+        .collect()
+}
+
+/// Returns the odd register of the even/odd pair starting at `r`, a pair starting at `zero` is all zero
+fn pair_register(r: IRegister) -> IRegister {
+    match r {
+        IRegister::Zero => IRegister::Zero,
+        r => IRegister::from_int(u32::from(r) + 1),
+    }
+}
+
+/// Adds the odd register of a pair after each of the given integer registers that hold the start of a pair
+fn with_pairs(registers: Vec<Register>, pairs: &[IRegister]) -> Vec<Register> {
+    let mut expanded = Vec::with_capacity(registers.len() * 2);
+    for r in registers {
+        expanded.push(r);
+        if let Register::I(r) = r
+            && pairs.contains(&r)
+        {
+            expanded.push(Register::I(pair_register(r)));
+        }
+    }
+    expanded
+}
+
+/// Removes repeated registers, keeping the first occurrence
+fn dedup(registers: Vec<Register>) -> Vec<Register> {
+    let mut unique = Vec::with_capacity(registers.len());
+    for r in registers {
+        if !unique.contains(&r) {
+            unique.push(r);
+        }
+    }
+    unique
+}
+
+/// Removes repeated registers and `zero`, since it always reads as zero and writes to it are discarded
+fn without_zero(registers: Vec<Register>) -> Vec<Register> {
+    let mut registers = dedup(registers);
+    registers.retain(|r| *r != Register::I(IRegister::Zero));
+    registers
+}
+
+impl Instruction {
+    /// Returns the operands of the instruction with their roles, in the order they are stored.
+    ///
+    /// Parts of the encoding that select the operation rather than its operands, such as the `aq` and `rl` bits or the
+    /// ordering of a `fence`, aren't included. Float operands held in the integer registers are given as
+    /// `Operand::F(FRegister::Integer(..))`.
+    pub fn operands(&self) -> Vec<(OperandRole, Operand)> {
+        match self {
+            Instruction::Lui { dest, imm, .. } | Instruction::Auipc { dest, imm, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Immediate, Operand::Immediate(imm.val())),
+            ],
+            Instruction::Jal { dest, offset, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            Instruction::Jalr {
+                dest, base, offset, ..
+            }
+            | Instruction::Lb {
+                dest, base, offset, ..
+            }
+            | Instruction::Lh {
+                dest, base, offset, ..
+            }
+            | Instruction::Lw {
+                dest, base, offset, ..
+            }
+            | Instruction::Lbu {
+                dest, base, offset, ..
+            }
+            | Instruction::Lhu {
+                dest, base, offset, ..
+            }
+            | Instruction::Lwu {
+                dest, base, offset, ..
+            }
+            | Instruction::Ld {
+                dest, base, offset, ..
+            } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Base, Operand::I(*base)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            Instruction::Beq {
+                src1, src2, offset, ..
+            }
+            | Instruction::Bne {
+                src1, src2, offset, ..
+            }
+            | Instruction::Blt {
+                src1, src2, offset, ..
+            }
+            | Instruction::Bge {
+                src1, src2, offset, ..
+            }
+            | Instruction::Bltu {
+                src1, src2, offset, ..
+            }
+            | Instruction::Bgeu {
+                src1, src2, offset, ..
+            } => vec![
+                (OperandRole::Src1, Operand::I(*src1)),
+                (OperandRole::Src2, Operand::I(*src2)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            Instruction::Sb {
+                src, base, offset, ..
+            }
+            | Instruction::Sh {
+                src, base, offset, ..
+            }
+            | Instruction::Sw {
+                src, base, offset, ..
+            }
+            | Instruction::Sd {
+                src, base, offset, ..
+            } => vec![
+                (OperandRole::Src1, Operand::I(*src)),
+                (OperandRole::Base, Operand::I(*base)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            Instruction::Addi { dest, src, imm, .. }
+            | Instruction::Slti { dest, src, imm, .. }
+            | Instruction::Sltiu { dest, src, imm, .. }
+            | Instruction::Xori { dest, src, imm, .. }
+            | Instruction::Ori { dest, src, imm, .. }
+            | Instruction::Andi { dest, src, imm, .. }
+            | Instruction::Addiw { dest, src, imm, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::I(*src)),
+                (OperandRole::Immediate, Operand::Immediate(imm.val())),
+            ],
+            Instruction::Slli {
+                dest, src, shamt, ..
+            }
+            | Instruction::Srli {
+                dest, src, shamt, ..
+            }
+            | Instruction::Srai {
+                dest, src, shamt, ..
+            } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::I(*src)),
+                (OperandRole::Immediate, Operand::Immediate(shamt.val())),
+            ],
+            Instruction::Add {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sub {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sll {
+                dest, src1, src2, ..
+            }
+            | Instruction::Slt {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sltu {
+                dest, src1, src2, ..
+            }
+            | Instruction::Xor {
+                dest, src1, src2, ..
+            }
+            | Instruction::Srl {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sra {
+                dest, src1, src2, ..
+            }
+            | Instruction::Or {
+                dest, src1, src2, ..
+            }
+            | Instruction::And {
+                dest, src1, src2, ..
+            }
+            | Instruction::Addw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Subw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sllw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Srlw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Sraw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Mul {
+                dest, src1, src2, ..
+            }
+            | Instruction::Mulh {
+                dest, src1, src2, ..
+            }
+            | Instruction::Mulhsu {
+                dest, src1, src2, ..
+            }
+            | Instruction::Mulhu {
+                dest, src1, src2, ..
+            }
+            | Instruction::Div {
+                dest, src1, src2, ..
+            }
+            | Instruction::Divu {
+                dest, src1, src2, ..
+            }
+            | Instruction::Rem {
+                dest, src1, src2, ..
+            }
+            | Instruction::Remu {
+                dest, src1, src2, ..
+            }
+            | Instruction::Mulw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Divw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Divuw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Remw {
+                dest, src1, src2, ..
+            }
+            | Instruction::Remuw {
+                dest, src1, src2, ..
+            }
+            | Instruction::CzeroEqz {
+                dest, src1, src2, ..
+            }
+            | Instruction::CzeroNez {
+                dest, src1, src2, ..
+            }
+            | Instruction::MopRr {
+                dest, src1, src2, ..
+            } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::I(*src1)),
+                (OperandRole::Src2, Operand::I(*src2)),
+            ],
+            Instruction::Fence { .. }
+            | Instruction::Ecall
+            | Instruction::Ebreak
+            | Instruction::FenceI
+            | Instruction::Sret
+            | Instruction::Mret
+            | Instruction::Wfi
+            | Instruction::SfenceWInval
+            | Instruction::SfenceInvalIr
+            | Instruction::Mnret
+            | Instruction::Dret
+            | Instruction::WrsNto
+            | Instruction::WrsSto => vec![],
+            Instruction::Slliw {
+                dest, src, shamt, ..
+            }
+            | Instruction::Srliw {
+                dest, src, shamt, ..
+            }
+            | Instruction::Sraiw {
+                dest, src, shamt, ..
+            } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::I(*src)),
+                (OperandRole::Immediate, Operand::Immediate(shamt.val())),
+            ],
+            Instruction::LrW { dest, addr, .. }
+            | Instruction::LrD { dest, addr, .. }
+            | Instruction::HlvB { dest, addr, .. }
+            | Instruction::HlvBu { dest, addr, .. }
+            | Instruction::HlvH { dest, addr, .. }
+            | Instruction::HlvHu { dest, addr, .. }
+            | Instruction::HlvxHu { dest, addr, .. }
+            | Instruction::HlvW { dest, addr, .. }
+            | Instruction::HlvxWu { dest, addr, .. }
+            | Instruction::HlvWu { dest, addr, .. }
+            | Instruction::HlvD { dest, addr, .. }
+            | Instruction::LbAq { dest, addr, .. }
+            | Instruction::LhAq { dest, addr, .. }
+            | Instruction::LwAq { dest, addr, .. }
+            | Instruction::LdAq { dest, addr, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Base, Operand::I(*addr)),
+            ],
+            Instruction::ScW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoswapW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoaddW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoxorW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoandW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoorW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominuW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxuW {
+                dest, addr, src, ..
+            }
+            | Instruction::ScD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoswapD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoaddD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoxorD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoandD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoorD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominuD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxuD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmocasW {
+                dest, addr, src, ..
+            }
+            | Instruction::AmocasD {
+                dest, addr, src, ..
+            }
+            | Instruction::AmocasQ {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoswapB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoaddB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoxorB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoandB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoorB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominuB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxuB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmocasB {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoswapH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoaddH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoxorH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoandH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmoorH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmominuH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmomaxuH {
+                dest, addr, src, ..
+            }
+            | Instruction::AmocasH {
+                dest, addr, src, ..
+            }
+            | Instruction::SsamoswapW {
+                dest, addr, src, ..
+            }
+            | Instruction::SsamoswapD {
+                dest, addr, src, ..
+            } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Base, Operand::I(*addr)),
+                (OperandRole::Src1, Operand::I(*src)),
+            ],
+            Instruction::Flw {
+                dest, base, offset, ..
+            }
+            | Instruction::Fld {
+                dest, base, offset, ..
+            }
+            | Instruction::Flq {
+                dest, base, offset, ..
+            } => vec![
+                (OperandRole::Dest, Operand::F(*dest)),
+                (OperandRole::Base, Operand::I(*base)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            Instruction::Fsw {
+                base, src, offset, ..
+            } => vec![
+                (OperandRole::Base, Operand::I(*base)),
+                (OperandRole::Src1, Operand::F(*src)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            Instruction::FmaddS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FmsubS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmsubS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmaddS {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmaddD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmsubD {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FmaddQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FmsubQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmsubQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            }
+            | Instruction::FnmaddQ {
+                dest,
+                src1,
+                src2,
+                src3,
+                rm,
+                ..
+            } => vec![
+                (OperandRole::Dest, Operand::F(*dest)),
+                (OperandRole::Src1, Operand::F(*src1)),
+                (OperandRole::Src2, Operand::F(*src2)),
+                (OperandRole::Src3, Operand::F(*src3)),
+                (OperandRole::RoundingMode, Operand::RoundingMode(*rm)),
+            ],
+            Instruction::FaddS {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FsubS {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FmulS {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FdivS {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FaddD {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FsubD {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FmulD {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FdivD {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FaddQ {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FsubQ {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FmulQ {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            }
+            | Instruction::FdivQ {
+                dest,
+                src1,
+                src2,
+                rm,
+                ..
+            } => vec![
+                (OperandRole::Dest, Operand::F(*dest)),
+                (OperandRole::Src1, Operand::F(*src1)),
+                (OperandRole::Src2, Operand::F(*src2)),
+                (OperandRole::RoundingMode, Operand::RoundingMode(*rm)),
+            ],
+            Instruction::FsqrtS { dest, src, rm, .. }
+            | Instruction::FsqrtD { dest, src, rm, .. }
+            | Instruction::FcvtSD { dest, src, rm, .. }
+            | Instruction::FcvtDS { dest, src, rm, .. }
+            | Instruction::FsqrtQ { dest, src, rm, .. }
+            | Instruction::FcvtSQ { dest, src, rm, .. }
+            | Instruction::FcvtQS { dest, src, rm, .. }
+            | Instruction::FcvtDQ { dest, src, rm, .. }
+            | Instruction::FcvtQD { dest, src, rm, .. }
+            | Instruction::FroundS { dest, src, rm, .. }
+            | Instruction::FroundD { dest, src, rm, .. }
+            | Instruction::FroundH { dest, src, rm, .. }
+            | Instruction::FroundQ { dest, src, rm, .. }
+            | Instruction::FroundnxS { dest, src, rm, .. }
+            | Instruction::FroundnxD { dest, src, rm, .. }
+            | Instruction::FroundnxH { dest, src, rm, .. }
+            | Instruction::FroundnxQ { dest, src, rm, .. }
+            | Instruction::FcvtBf16S { dest, src, rm, .. }
+            | Instruction::FcvtSBf16 { dest, src, rm, .. } => vec![
+                (OperandRole::Dest, Operand::F(*dest)),
+                (OperandRole::Src1, Operand::F(*src)),
+                (OperandRole::RoundingMode, Operand::RoundingMode(*rm)),
+            ],
+            Instruction::FsgnjS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjnS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjxS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjnD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjxD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjnQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FsgnjxQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminmS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminmD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminmH {
+                dest, src1, src2, ..
+            }
+            | Instruction::FminmQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxmS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxmD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxmH {
+                dest, src1, src2, ..
+            }
+            | Instruction::FmaxmQ {
+                dest, src1, src2, ..
+            } => vec![
+                (OperandRole::Dest, Operand::F(*dest)),
+                (OperandRole::Src1, Operand::F(*src1)),
+                (OperandRole::Src2, Operand::F(*src2)),
+            ],
+            Instruction::FcvtWS { dest, src, rm, .. }
+            | Instruction::FcvtWuS { dest, src, rm, .. }
+            | Instruction::FcvtLS { dest, src, rm, .. }
+            | Instruction::FcvtLuS { dest, src, rm, .. }
+            | Instruction::FcvtWQ { dest, src, rm, .. }
+            | Instruction::FcvtWuQ { dest, src, rm, .. }
+            | Instruction::FcvtLQ { dest, src, rm, .. }
+            | Instruction::FcvtLuQ { dest, src, rm, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::F(*src)),
+                (OperandRole::RoundingMode, Operand::RoundingMode(*rm)),
+            ],
+            Instruction::FmvXW { dest, src, .. }
+            | Instruction::FclassS { dest, src, .. }
+            | Instruction::FmvXD { dest, src, .. }
+            | Instruction::FclassQ { dest, src, .. }
+            | Instruction::FcvtmodWD { dest, src, .. }
+            | Instruction::FmvhXD { dest, src, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::F(*src)),
+            ],
+            Instruction::FeqS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FeqD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FeqQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleqS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleqD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleqH {
+                dest, src1, src2, ..
+            }
+            | Instruction::FleqQ {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltqS {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltqD {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltqH {
+                dest, src1, src2, ..
+            }
+            | Instruction::FltqQ {
+                dest, src1, src2, ..
+            } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::F(*src1)),
+                (OperandRole::Src2, Operand::F(*src2)),
+            ],
+            Instruction::FcvtSW { dest, src, rm, .. }
+            | Instruction::FcvtSWu { dest, src, rm, .. }
+            | Instruction::FcvtSL { dest, src, rm, .. }
+            | Instruction::FcvtSLu { dest, src, rm, .. }
+            | Instruction::FcvtDL { dest, src, rm, .. }
+            | Instruction::FcvtDLu { dest, src, rm, .. }
+            | Instruction::FcvtQW { dest, src, rm, .. }
+            | Instruction::FcvtQWu { dest, src, rm, .. }
+            | Instruction::FcvtQL { dest, src, rm, .. }
+            | Instruction::FcvtQLu { dest, src, rm, .. } => vec![
+                (OperandRole::Dest, Operand::F(*dest)),
+                (OperandRole::Src1, Operand::I(*src)),
+                (OperandRole::RoundingMode, Operand::RoundingMode(*rm)),
+            ],
+            Instruction::FmvWX { dest, src, .. } | Instruction::FmvDX { dest, src, .. } => vec![
+                (OperandRole::Dest, Operand::F(*dest)),
+                (OperandRole::Src1, Operand::I(*src)),
+            ],
+            Instruction::Csrrw { dest, src, csr, .. }
+            | Instruction::Csrrs { dest, src, csr, .. }
+            | Instruction::Csrrc { dest, src, csr, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::I(*src)),
+                (OperandRole::Csr, Operand::Csr(csr.val() as u16)),
+            ],
+            Instruction::Csrrwi { dest, imm, csr, .. }
+            | Instruction::Csrrsi { dest, imm, csr, .. }
+            | Instruction::Csrrci { dest, imm, csr, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Immediate, Operand::Immediate(imm.val())),
+                (OperandRole::Csr, Operand::Csr(csr.val() as u16)),
+            ],
+            Instruction::Fsd {
+                src, base, offset, ..
+            }
+            | Instruction::Fsq {
+                src, base, offset, ..
+            } => vec![
+                (OperandRole::Src1, Operand::F(*src)),
+                (OperandRole::Base, Operand::I(*base)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            Instruction::FclassD { dest, src1, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::F(*src1)),
+            ],
+            Instruction::FcvtWD { dest, src1, rm, .. }
+            | Instruction::FcvtWuD { dest, src1, rm, .. }
+            | Instruction::FcvtLD { dest, src1, rm, .. }
+            | Instruction::FcvtLuD { dest, src1, rm, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::F(*src1)),
+                (OperandRole::RoundingMode, Operand::RoundingMode(*rm)),
+            ],
+            Instruction::FcvtDW { dest, src1, rm, .. }
+            | Instruction::FcvtDWu { dest, src1, rm, .. } => vec![
+                (OperandRole::Dest, Operand::F(*dest)),
+                (OperandRole::Src1, Operand::I(*src1)),
+                (OperandRole::RoundingMode, Operand::RoundingMode(*rm)),
+            ],
+            Instruction::FliS { dest, imm, .. }
+            | Instruction::FliD { dest, imm, .. }
+            | Instruction::FliH { dest, imm, .. }
+            | Instruction::FliQ { dest, imm, .. } => vec![
+                (OperandRole::Dest, Operand::F(*dest)),
+                (
+                    OperandRole::Immediate,
+                    Operand::Immediate(imm.index() as i64),
+                ),
+            ],
+            Instruction::FmvpDX {
+                dest, src1, src2, ..
+            } => vec![
+                (OperandRole::Dest, Operand::F(*dest)),
+                (OperandRole::Src1, Operand::I(*src1)),
+                (OperandRole::Src2, Operand::I(*src2)),
+            ],
+            Instruction::Vfncvtbf16FFW { dest, src, .. }
+            | Instruction::Vfwcvtbf16FFV { dest, src, .. } => vec![
+                (OperandRole::Dest, Operand::V(*dest)),
+                (OperandRole::Src1, Operand::V(*src)),
+            ],
+            Instruction::Vfwmaccbf16VV {
+                dest, src1, src2, ..
+            } => vec![
+                (OperandRole::Dest, Operand::V(*dest)),
+                (OperandRole::Src1, Operand::V(*src1)),
+                (OperandRole::Src2, Operand::V(*src2)),
+            ],
+            Instruction::Vfwmaccbf16VF {
+                dest, src1, src2, ..
+            } => vec![
+                (OperandRole::Dest, Operand::V(*dest)),
+                (OperandRole::Src1, Operand::F(*src1)),
+                (OperandRole::Src2, Operand::V(*src2)),
+            ],
+            Instruction::SfenceVma { vaddr, asid, .. }
+            | Instruction::SinvalVma { vaddr, asid, .. }
+            | Instruction::HfenceVvma { vaddr, asid, .. }
+            | Instruction::HinvalVvma { vaddr, asid, .. } => vec![
+                (OperandRole::Src1, Operand::I(*vaddr)),
+                (OperandRole::Src2, Operand::I(*asid)),
+            ],
+            Instruction::HfenceGvma { gaddr, vmid, .. }
+            | Instruction::HinvalGvma { gaddr, vmid, .. } => vec![
+                (OperandRole::Src1, Operand::I(*gaddr)),
+                (OperandRole::Src2, Operand::I(*vmid)),
+            ],
+            Instruction::HsvB { src, addr, .. }
+            | Instruction::HsvH { src, addr, .. }
+            | Instruction::HsvW { src, addr, .. }
+            | Instruction::HsvD { src, addr, .. }
+            | Instruction::SbRl { src, addr, .. }
+            | Instruction::ShRl { src, addr, .. }
+            | Instruction::SwRl { src, addr, .. }
+            | Instruction::SdRl { src, addr, .. } => vec![
+                (OperandRole::Src1, Operand::I(*src)),
+                (OperandRole::Base, Operand::I(*addr)),
+            ],
+            Instruction::CboClean { base, .. }
+            | Instruction::CboFlush { base, .. }
+            | Instruction::CboInval { base, .. }
+            | Instruction::CboZero { base, .. } => vec![(OperandRole::Base, Operand::I(*base))],
+            Instruction::PrefetchI { base, offset, .. }
+            | Instruction::PrefetchR { base, offset, .. }
+            | Instruction::PrefetchW { base, offset, .. } => vec![
+                (OperandRole::Base, Operand::I(*base)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            Instruction::Lpad { label, .. } => {
+                vec![(OperandRole::Immediate, Operand::Immediate(label.val()))]
+            }
+            Instruction::Sspush { src, .. } | Instruction::Sspopchk { src, .. } => {
+                vec![(OperandRole::Src1, Operand::I(*src))]
+            }
+            Instruction::Ssrdp { dest, .. } => vec![(OperandRole::Dest, Operand::I(*dest))],
+            Instruction::MopR { dest, src, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::I(*src)),
+            ],
+        }
+    }
+
+    /// Returns the operands of the instruction for the given ISA configuration.
+    ///
+    /// When float instructions use the integer registers, their float operands are given as integer registers, which
+    /// is also how [`Instruction::set_operand_with`] and [`Instruction::map_operands_with`] expect them.
+    pub fn operands_with(&self, isa: &IsaConfig) -> Vec<(OperandRole, Operand)> {
+        operands_with(self, isa)
+    }
+
+    /// Replaces an operand of the instruction, validating it for the default ISA configuration.
+    ///
+    /// The instruction is left unchanged if it has no operand with the role, the value doesn't fit the operand, or
+    /// the result isn't a valid instruction, such as a reserved encoding or one that decodes as another instruction.
+    pub fn set_operand(&mut self, role: OperandRole, value: Operand) -> Result<(), String> {
+        self.set_operand_with(role, value, &IsaConfig::default())
+    }
+
+    /// Replaces an operand of the instruction, validating it for the given ISA configuration.
+    pub fn set_operand_with(
+        &mut self,
+        role: OperandRole,
+        value: Operand,
+        isa: &IsaConfig,
+    ) -> Result<(), String> {
+        set_operand_with(self, role, value, isa)
+    }
+
+    /// Replaces every operand of the instruction with the result of `f`, which is given the role and current value.
+    ///
+    /// The operands are validated together for the default ISA configuration after all of them are replaced, and the
+    /// instruction is left unchanged if the result isn't valid.
+    pub fn map_operands(
+        &mut self,
+        f: impl FnMut(OperandRole, Operand) -> Operand,
+    ) -> Result<(), String> {
+        self.map_operands_with(&IsaConfig::default(), f)
+    }
+
+    /// Replaces every operand of the instruction with the result of `f`, validating the result for the given ISA
+    /// configuration.
+    pub fn map_operands_with(
+        &mut self,
+        isa: &IsaConfig,
+        f: impl FnMut(OperandRole, Operand) -> Operand,
+    ) -> Result<(), String> {
+        map_operands_with(self, isa, f)
+    }
+
+    /// Gives every float operand as the integer register of the same number, for when floats are held in the integer
+    /// registers
+    pub(crate) fn with_integer_floats(mut self) -> Instruction {
+        for (role, value) in self.operands() {
+            if let Operand::F(r) = value {
+                let r = FRegister::Integer(IRegister::from_int(r.into()));
+                self.replace_operand(role, Operand::F(r)).unwrap();
+            }
+        }
+        self
+    }
+
+    fn replace_operand(&mut self, role: OperandRole, value: Operand) -> Result<(), String> {
+        match (self, role) {
+            (
+                Instruction::Lui { dest, .. }
+                | Instruction::Auipc { dest, .. }
+                | Instruction::Jal { dest, .. }
+                | Instruction::Jalr { dest, .. }
+                | Instruction::Lb { dest, .. }
+                | Instruction::Lh { dest, .. }
+                | Instruction::Lw { dest, .. }
+                | Instruction::Lbu { dest, .. }
+                | Instruction::Lhu { dest, .. }
+                | Instruction::Addi { dest, .. }
+                | Instruction::Slti { dest, .. }
+                | Instruction::Sltiu { dest, .. }
+                | Instruction::Xori { dest, .. }
+                | Instruction::Ori { dest, .. }
+                | Instruction::Andi { dest, .. }
+                | Instruction::Slli { dest, .. }
+                | Instruction::Srli { dest, .. }
+                | Instruction::Srai { dest, .. }
+                | Instruction::Add { dest, .. }
+                | Instruction::Sub { dest, .. }
+                | Instruction::Sll { dest, .. }
+                | Instruction::Slt { dest, .. }
+                | Instruction::Sltu { dest, .. }
+                | Instruction::Xor { dest, .. }
+                | Instruction::Srl { dest, .. }
+                | Instruction::Sra { dest, .. }
+                | Instruction::Or { dest, .. }
+                | Instruction::And { dest, .. }
+                | Instruction::Lwu { dest, .. }
+                | Instruction::Ld { dest, .. }
+                | Instruction::Addiw { dest, .. }
+                | Instruction::Slliw { dest, .. }
+                | Instruction::Srliw { dest, .. }
+                | Instruction::Sraiw { dest, .. }
+                | Instruction::Addw { dest, .. }
+                | Instruction::Subw { dest, .. }
+                | Instruction::Sllw { dest, .. }
+                | Instruction::Srlw { dest, .. }
+                | Instruction::Sraw { dest, .. }
+                | Instruction::Mul { dest, .. }
+                | Instruction::Mulh { dest, .. }
+                | Instruction::Mulhsu { dest, .. }
+                | Instruction::Mulhu { dest, .. }
+                | Instruction::Div { dest, .. }
+                | Instruction::Divu { dest, .. }
+                | Instruction::Rem { dest, .. }
+                | Instruction::Remu { dest, .. }
+                | Instruction::Mulw { dest, .. }
+                | Instruction::Divw { dest, .. }
+                | Instruction::Divuw { dest, .. }
+                | Instruction::Remw { dest, .. }
+                | Instruction::Remuw { dest, .. }
+                | Instruction::LrW { dest, .. }
+                | Instruction::ScW { dest, .. }
+                | Instruction::AmoswapW { dest, .. }
+                | Instruction::AmoaddW { dest, .. }
+                | Instruction::AmoxorW { dest, .. }
+                | Instruction::AmoandW { dest, .. }
+                | Instruction::AmoorW { dest, .. }
+                | Instruction::AmominW { dest, .. }
+                | Instruction::AmomaxW { dest, .. }
+                | Instruction::AmominuW { dest, .. }
+                | Instruction::AmomaxuW { dest, .. }
+                | Instruction::LrD { dest, .. }
+                | Instruction::ScD { dest, .. }
+                | Instruction::AmoswapD { dest, .. }
+                | Instruction::AmoaddD { dest, .. }
+                | Instruction::AmoxorD { dest, .. }
+                | Instruction::AmoandD { dest, .. }
+                | Instruction::AmoorD { dest, .. }
+                | Instruction::AmominD { dest, .. }
+                | Instruction::AmomaxD { dest, .. }
+                | Instruction::AmominuD { dest, .. }
+                | Instruction::AmomaxuD { dest, .. }
+                | Instruction::FcvtWS { dest, .. }
+                | Instruction::FcvtWuS { dest, .. }
+                | Instruction::FmvXW { dest, .. }
+                | Instruction::FeqS { dest, .. }
+                | Instruction::FltS { dest, .. }
+                | Instruction::FleS { dest, .. }
+                | Instruction::FclassS { dest, .. }
+                | Instruction::FcvtLS { dest, .. }
+                | Instruction::FcvtLuS { dest, .. }
+                | Instruction::Csrrw { dest, .. }
+                | Instruction::Csrrs { dest, .. }
+                | Instruction::Csrrc { dest, .. }
+                | Instruction::Csrrwi { dest, .. }
+                | Instruction::Csrrsi { dest, .. }
+                | Instruction::Csrrci { dest, .. }
+                | Instruction::FeqD { dest, .. }
+                | Instruction::FltD { dest, .. }
+                | Instruction::FleD { dest, .. }
+                | Instruction::FclassD { dest, .. }
+                | Instruction::FcvtWD { dest, .. }
+                | Instruction::FcvtWuD { dest, .. }
+                | Instruction::FcvtLD { dest, .. }
+                | Instruction::FcvtLuD { dest, .. }
+                | Instruction::FmvXD { dest, .. }
+                | Instruction::FeqQ { dest, .. }
+                | Instruction::FltQ { dest, .. }
+                | Instruction::FleQ { dest, .. }
+                | Instruction::FclassQ { dest, .. }
+                | Instruction::FcvtWQ { dest, .. }
+                | Instruction::FcvtWuQ { dest, .. }
+                | Instruction::FcvtLQ { dest, .. }
+                | Instruction::FcvtLuQ { dest, .. }
+                | Instruction::FcvtmodWD { dest, .. }
+                | Instruction::FleqS { dest, .. }
+                | Instruction::FleqD { dest, .. }
+                | Instruction::FleqH { dest, .. }
+                | Instruction::FleqQ { dest, .. }
+                | Instruction::FltqS { dest, .. }
+                | Instruction::FltqD { dest, .. }
+                | Instruction::FltqH { dest, .. }
+                | Instruction::FltqQ { dest, .. }
+                | Instruction::FmvhXD { dest, .. }
+                | Instruction::HlvB { dest, .. }
+                | Instruction::HlvBu { dest, .. }
+                | Instruction::HlvH { dest, .. }
+                | Instruction::HlvHu { dest, .. }
+                | Instruction::HlvxHu { dest, .. }
+                | Instruction::HlvW { dest, .. }
+                | Instruction::HlvxWu { dest, .. }
+                | Instruction::HlvWu { dest, .. }
+                | Instruction::HlvD { dest, .. }
+                | Instruction::AmocasW { dest, .. }
+                | Instruction::AmocasD { dest, .. }
+                | Instruction::AmocasQ { dest, .. }
+                | Instruction::AmoswapB { dest, .. }
+                | Instruction::AmoaddB { dest, .. }
+                | Instruction::AmoxorB { dest, .. }
+                | Instruction::AmoandB { dest, .. }
+                | Instruction::AmoorB { dest, .. }
+                | Instruction::AmominB { dest, .. }
+                | Instruction::AmomaxB { dest, .. }
+                | Instruction::AmominuB { dest, .. }
+                | Instruction::AmomaxuB { dest, .. }
+                | Instruction::AmocasB { dest, .. }
+                | Instruction::AmoswapH { dest, .. }
+                | Instruction::AmoaddH { dest, .. }
+                | Instruction::AmoxorH { dest, .. }
+                | Instruction::AmoandH { dest, .. }
+                | Instruction::AmoorH { dest, .. }
+                | Instruction::AmominH { dest, .. }
+                | Instruction::AmomaxH { dest, .. }
+                | Instruction::AmominuH { dest, .. }
+                | Instruction::AmomaxuH { dest, .. }
+                | Instruction::AmocasH { dest, .. }
+                | Instruction::LbAq { dest, .. }
+                | Instruction::LhAq { dest, .. }
+                | Instruction::LwAq { dest, .. }
+                | Instruction::LdAq { dest, .. }
+                | Instruction::CzeroEqz { dest, .. }
+                | Instruction::CzeroNez { dest, .. }
+                | Instruction::Ssrdp { dest, .. }
+                | Instruction::SsamoswapW { dest, .. }
+                | Instruction::SsamoswapD { dest, .. }
+                | Instruction::MopR { dest, .. }
+                | Instruction::MopRr { dest, .. },
+                OperandRole::Dest,
+            ) => *dest = integer(value)?,
+            (
+                Instruction::Lui { imm, .. } | Instruction::Auipc { imm, .. },
+                OperandRole::Immediate,
+            ) => *imm = UImmediate::try_from(immediate(value)?)?,
+            (Instruction::Jal { offset, .. }, OperandRole::Immediate) => {
+                *offset = JImmediate::try_from(immediate(value)?)?
+            }
+            (
+                Instruction::Jalr { base, .. }
+                | Instruction::Lb { base, .. }
+                | Instruction::Lh { base, .. }
+                | Instruction::Lw { base, .. }
+                | Instruction::Lbu { base, .. }
+                | Instruction::Lhu { base, .. }
+                | Instruction::Sb { base, .. }
+                | Instruction::Sh { base, .. }
+                | Instruction::Sw { base, .. }
+                | Instruction::Lwu { base, .. }
+                | Instruction::Ld { base, .. }
+                | Instruction::Sd { base, .. }
+                | Instruction::Flw { base, .. }
+                | Instruction::Fsw { base, .. }
+                | Instruction::Fld { base, .. }
+                | Instruction::Fsd { base, .. }
+                | Instruction::Flq { base, .. }
+                | Instruction::Fsq { base, .. }
+                | Instruction::CboClean { base, .. }
+                | Instruction::CboFlush { base, .. }
+                | Instruction::CboInval { base, .. }
+                | Instruction::CboZero { base, .. }
+                | Instruction::PrefetchI { base, .. }
+                | Instruction::PrefetchR { base, .. }
+                | Instruction::PrefetchW { base, .. },
+                OperandRole::Base,
+            ) => *base = integer(value)?,
+            (
+                Instruction::Jalr { offset, .. }
+                | Instruction::Lb { offset, .. }
+                | Instruction::Lh { offset, .. }
+                | Instruction::Lw { offset, .. }
+                | Instruction::Lbu { offset, .. }
+                | Instruction::Lhu { offset, .. }
+                | Instruction::Lwu { offset, .. }
+                | Instruction::Ld { offset, .. }
+                | Instruction::Flw { offset, .. }
+                | Instruction::Fld { offset, .. }
+                | Instruction::Flq { offset, .. },
+                OperandRole::Immediate,
+            ) => *offset = IImmediate::try_from(immediate(value)?)?,
+            (
+                Instruction::Beq { src1, .. }
+                | Instruction::Bne { src1, .. }
+                | Instruction::Blt { src1, .. }
+                | Instruction::Bge { src1, .. }
+                | Instruction::Bltu { src1, .. }
+                | Instruction::Bgeu { src1, .. }
+                | Instruction::Add { src1, .. }
+                | Instruction::Sub { src1, .. }
+                | Instruction::Sll { src1, .. }
+                | Instruction::Slt { src1, .. }
+                | Instruction::Sltu { src1, .. }
+                | Instruction::Xor { src1, .. }
+                | Instruction::Srl { src1, .. }
+                | Instruction::Sra { src1, .. }
+                | Instruction::Or { src1, .. }
+                | Instruction::And { src1, .. }
+                | Instruction::Addw { src1, .. }
+                | Instruction::Subw { src1, .. }
+                | Instruction::Sllw { src1, .. }
+                | Instruction::Srlw { src1, .. }
+                | Instruction::Sraw { src1, .. }
+                | Instruction::Mul { src1, .. }
+                | Instruction::Mulh { src1, .. }
+                | Instruction::Mulhsu { src1, .. }
+                | Instruction::Mulhu { src1, .. }
+                | Instruction::Div { src1, .. }
+                | Instruction::Divu { src1, .. }
+                | Instruction::Rem { src1, .. }
+                | Instruction::Remu { src1, .. }
+                | Instruction::Mulw { src1, .. }
+                | Instruction::Divw { src1, .. }
+                | Instruction::Divuw { src1, .. }
+                | Instruction::Remw { src1, .. }
+                | Instruction::Remuw { src1, .. }
+                | Instruction::FcvtDW { src1, .. }
+                | Instruction::FcvtDWu { src1, .. }
+                | Instruction::FmvpDX { src1, .. }
+                | Instruction::CzeroEqz { src1, .. }
+                | Instruction::CzeroNez { src1, .. }
+                | Instruction::MopRr { src1, .. },
+                OperandRole::Src1,
+            ) => *src1 = integer(value)?,
+            (
+                Instruction::Beq { src2, .. }
+                | Instruction::Bne { src2, .. }
+                | Instruction::Blt { src2, .. }
+                | Instruction::Bge { src2, .. }
+                | Instruction::Bltu { src2, .. }
+                | Instruction::Bgeu { src2, .. }
+                | Instruction::Add { src2, .. }
+                | Instruction::Sub { src2, .. }
+                | Instruction::Sll { src2, .. }
+                | Instruction::Slt { src2, .. }
+                | Instruction::Sltu { src2, .. }
+                | Instruction::Xor { src2, .. }
+                | Instruction::Srl { src2, .. }
+                | Instruction::Sra { src2, .. }
+                | Instruction::Or { src2, .. }
+                | Instruction::And { src2, .. }
+                | Instruction::Addw { src2, .. }
+                | Instruction::Subw { src2, .. }
+                | Instruction::Sllw { src2, .. }
+                | Instruction::Srlw { src2, .. }
+                | Instruction::Sraw { src2, .. }
+                | Instruction::Mul { src2, .. }
+                | Instruction::Mulh { src2, .. }
+                | Instruction::Mulhsu { src2, .. }
+                | Instruction::Mulhu { src2, .. }
+                | Instruction::Div { src2, .. }
+                | Instruction::Divu { src2, .. }
+                | Instruction::Rem { src2, .. }
+                | Instruction::Remu { src2, .. }
+                | Instruction::Mulw { src2, .. }
+                | Instruction::Divw { src2, .. }
+                | Instruction::Divuw { src2, .. }
+                | Instruction::Remw { src2, .. }
+                | Instruction::Remuw { src2, .. }
+                | Instruction::FmvpDX { src2, .. }
+                | Instruction::CzeroEqz { src2, .. }
+                | Instruction::CzeroNez { src2, .. }
+                | Instruction::MopRr { src2, .. },
+                OperandRole::Src2,
+            ) => *src2 = integer(value)?,
+            (
+                Instruction::Beq { offset, .. }
+                | Instruction::Bne { offset, .. }
+                | Instruction::Blt { offset, .. }
+                | Instruction::Bge { offset, .. }
+                | Instruction::Bltu { offset, .. }
+                | Instruction::Bgeu { offset, .. },
+                OperandRole::Immediate,
+            ) => *offset = BImmediate::try_from(immediate(value)?)?,
+            (
+                Instruction::Sb { src, .. }
+                | Instruction::Sh { src, .. }
+                | Instruction::Sw { src, .. }
+                | Instruction::Addi { src, .. }
+                | Instruction::Slti { src, .. }
+                | Instruction::Sltiu { src, .. }
+                | Instruction::Xori { src, .. }
+                | Instruction::Ori { src, .. }
+                | Instruction::Andi { src, .. }
+                | Instruction::Slli { src, .. }
+                | Instruction::Srli { src, .. }
+                | Instruction::Srai { src, .. }
+                | Instruction::Sd { src, .. }
+                | Instruction::Addiw { src, .. }
+                | Instruction::Slliw { src, .. }
+                | Instruction::Srliw { src, .. }
+                | Instruction::Sraiw { src, .. }
+                | Instruction::ScW { src, .. }
+                | Instruction::AmoswapW { src, .. }
+                | Instruction::AmoaddW { src, .. }
+                | Instruction::AmoxorW { src, .. }
+                | Instruction::AmoandW { src, .. }
+                | Instruction::AmoorW { src, .. }
+                | Instruction::AmominW { src, .. }
+                | Instruction::AmomaxW { src, .. }
+                | Instruction::AmominuW { src, .. }
+                | Instruction::AmomaxuW { src, .. }
+                | Instruction::ScD { src, .. }
+                | Instruction::AmoswapD { src, .. }
+                | Instruction::AmoaddD { src, .. }
+                | Instruction::AmoxorD { src, .. }
+                | Instruction::AmoandD { src, .. }
+                | Instruction::AmoorD { src, .. }
+                | Instruction::AmominD { src, .. }
+                | Instruction::AmomaxD { src, .. }
+                | Instruction::AmominuD { src, .. }
+                | Instruction::AmomaxuD { src, .. }
+                | Instruction::FcvtSW { src, .. }
+                | Instruction::FcvtSWu { src, .. }
+                | Instruction::FmvWX { src, .. }
+                | Instruction::FcvtSL { src, .. }
+                | Instruction::FcvtSLu { src, .. }
+                | Instruction::Csrrw { src, .. }
+                | Instruction::Csrrs { src, .. }
+                | Instruction::Csrrc { src, .. }
+                | Instruction::FcvtDL { src, .. }
+                | Instruction::FcvtDLu { src, .. }
+                | Instruction::FmvDX { src, .. }
+                | Instruction::FcvtQW { src, .. }
+                | Instruction::FcvtQWu { src, .. }
+                | Instruction::FcvtQL { src, .. }
+                | Instruction::FcvtQLu { src, .. }
+                | Instruction::HsvB { src, .. }
+                | Instruction::HsvH { src, .. }
+                | Instruction::HsvW { src, .. }
+                | Instruction::HsvD { src, .. }
+                | Instruction::AmocasW { src, .. }
+                | Instruction::AmocasD { src, .. }
+                | Instruction::AmocasQ { src, .. }
+                | Instruction::AmoswapB { src, .. }
+                | Instruction::AmoaddB { src, .. }
+                | Instruction::AmoxorB { src, .. }
+                | Instruction::AmoandB { src, .. }
+                | Instruction::AmoorB { src, .. }
+                | Instruction::AmominB { src, .. }
+                | Instruction::AmomaxB { src, .. }
+                | Instruction::AmominuB { src, .. }
+                | Instruction::AmomaxuB { src, .. }
+                | Instruction::AmocasB { src, .. }
+                | Instruction::AmoswapH { src, .. }
+                | Instruction::AmoaddH { src, .. }
+                | Instruction::AmoxorH { src, .. }
+                | Instruction::AmoandH { src, .. }
+                | Instruction::AmoorH { src, .. }
+                | Instruction::AmominH { src, .. }
+                | Instruction::AmomaxH { src, .. }
+                | Instruction::AmominuH { src, .. }
+                | Instruction::AmomaxuH { src, .. }
+                | Instruction::AmocasH { src, .. }
+                | Instruction::SbRl { src, .. }
+                | Instruction::ShRl { src, .. }
+                | Instruction::SwRl { src, .. }
+                | Instruction::SdRl { src, .. }
+                | Instruction::Sspush { src, .. }
+                | Instruction::Sspopchk { src, .. }
+                | Instruction::SsamoswapW { src, .. }
+                | Instruction::SsamoswapD { src, .. }
+                | Instruction::MopR { src, .. },
+                OperandRole::Src1,
+            ) => *src = integer(value)?,
+            (
+                Instruction::Sb { offset, .. }
+                | Instruction::Sh { offset, .. }
+                | Instruction::Sw { offset, .. }
+                | Instruction::Sd { offset, .. }
+                | Instruction::Fsw { offset, .. }
+                | Instruction::Fsd { offset, .. }
+                | Instruction::Fsq { offset, .. },
+                OperandRole::Immediate,
+            ) => *offset = SImmediate::try_from(immediate(value)?)?,
+            (
+                Instruction::Addi { imm, .. }
+                | Instruction::Slti { imm, .. }
+                | Instruction::Sltiu { imm, .. }
+                | Instruction::Xori { imm, .. }
+                | Instruction::Ori { imm, .. }
+                | Instruction::Andi { imm, .. }
+                | Instruction::Addiw { imm, .. },
+                OperandRole::Immediate,
+            ) => *imm = IImmediate::try_from(immediate(value)?)?,
+            (
+                Instruction::Slli { shamt, .. }
+                | Instruction::Srli { shamt, .. }
+                | Instruction::Srai { shamt, .. },
+                OperandRole::Immediate,
+            ) => *shamt = Shamt::try_from(immediate(value)?)?,
+            (
+                Instruction::Slliw { shamt, .. }
+                | Instruction::Srliw { shamt, .. }
+                | Instruction::Sraiw { shamt, .. },
+                OperandRole::Immediate,
+            ) => *shamt = ShamtW::try_from(immediate(value)?)?,
+            (
+                Instruction::LrW { addr, .. }
+                | Instruction::ScW { addr, .. }
+                | Instruction::AmoswapW { addr, .. }
+                | Instruction::AmoaddW { addr, .. }
+                | Instruction::AmoxorW { addr, .. }
+                | Instruction::AmoandW { addr, .. }
+                | Instruction::AmoorW { addr, .. }
+                | Instruction::AmominW { addr, .. }
+                | Instruction::AmomaxW { addr, .. }
+                | Instruction::AmominuW { addr, .. }
+                | Instruction::AmomaxuW { addr, .. }
+                | Instruction::LrD { addr, .. }
+                | Instruction::ScD { addr, .. }
+                | Instruction::AmoswapD { addr, .. }
+                | Instruction::AmoaddD { addr, .. }
+                | Instruction::AmoxorD { addr, .. }
+                | Instruction::AmoandD { addr, .. }
+                | Instruction::AmoorD { addr, .. }
+                | Instruction::AmominD { addr, .. }
+                | Instruction::AmomaxD { addr, .. }
+                | Instruction::AmominuD { addr, .. }
+                | Instruction::AmomaxuD { addr, .. }
+                | Instruction::HlvB { addr, .. }
+                | Instruction::HlvBu { addr, .. }
+                | Instruction::HlvH { addr, .. }
+                | Instruction::HlvHu { addr, .. }
+                | Instruction::HlvxHu { addr, .. }
+                | Instruction::HlvW { addr, .. }
+                | Instruction::HlvxWu { addr, .. }
+                | Instruction::HsvB { addr, .. }
+                | Instruction::HsvH { addr, .. }
+                | Instruction::HsvW { addr, .. }
+                | Instruction::HlvWu { addr, .. }
+                | Instruction::HlvD { addr, .. }
+                | Instruction::HsvD { addr, .. }
+                | Instruction::AmocasW { addr, .. }
+                | Instruction::AmocasD { addr, .. }
+                | Instruction::AmocasQ { addr, .. }
+                | Instruction::AmoswapB { addr, .. }
+                | Instruction::AmoaddB { addr, .. }
+                | Instruction::AmoxorB { addr, .. }
+                | Instruction::AmoandB { addr, .. }
+                | Instruction::AmoorB { addr, .. }
+                | Instruction::AmominB { addr, .. }
+                | Instruction::AmomaxB { addr, .. }
+                | Instruction::AmominuB { addr, .. }
+                | Instruction::AmomaxuB { addr, .. }
+                | Instruction::AmocasB { addr, .. }
+                | Instruction::AmoswapH { addr, .. }
+                | Instruction::AmoaddH { addr, .. }
+                | Instruction::AmoxorH { addr, .. }
+                | Instruction::AmoandH { addr, .. }
+                | Instruction::AmoorH { addr, .. }
+                | Instruction::AmominH { addr, .. }
+                | Instruction::AmomaxH { addr, .. }
+                | Instruction::AmominuH { addr, .. }
+                | Instruction::AmomaxuH { addr, .. }
+                | Instruction::AmocasH { addr, .. }
+                | Instruction::LbAq { addr, .. }
+                | Instruction::LhAq { addr, .. }
+                | Instruction::LwAq { addr, .. }
+                | Instruction::SbRl { addr, .. }
+                | Instruction::ShRl { addr, .. }
+                | Instruction::SwRl { addr, .. }
+                | Instruction::LdAq { addr, .. }
+                | Instruction::SdRl { addr, .. }
+                | Instruction::SsamoswapW { addr, .. }
+                | Instruction::SsamoswapD { addr, .. },
+                OperandRole::Base,
+            ) => *addr = integer(value)?,
+            (
+                Instruction::Flw { dest, .. }
+                | Instruction::FmaddS { dest, .. }
+                | Instruction::FmsubS { dest, .. }
+                | Instruction::FnmsubS { dest, .. }
+                | Instruction::FnmaddS { dest, .. }
+                | Instruction::FaddS { dest, .. }
+                | Instruction::FsubS { dest, .. }
+                | Instruction::FmulS { dest, .. }
+                | Instruction::FdivS { dest, .. }
+                | Instruction::FsqrtS { dest, .. }
+                | Instruction::FsgnjS { dest, .. }
+                | Instruction::FsgnjnS { dest, .. }
+                | Instruction::FsgnjxS { dest, .. }
+                | Instruction::FminS { dest, .. }
+                | Instruction::FmaxS { dest, .. }
+                | Instruction::FcvtSW { dest, .. }
+                | Instruction::FcvtSWu { dest, .. }
+                | Instruction::FmvWX { dest, .. }
+                | Instruction::FcvtSL { dest, .. }
+                | Instruction::FcvtSLu { dest, .. }
+                | Instruction::Fld { dest, .. }
+                | Instruction::FmaddD { dest, .. }
+                | Instruction::FmsubD { dest, .. }
+                | Instruction::FnmaddD { dest, .. }
+                | Instruction::FnmsubD { dest, .. }
+                | Instruction::FaddD { dest, .. }
+                | Instruction::FsubD { dest, .. }
+                | Instruction::FmulD { dest, .. }
+                | Instruction::FdivD { dest, .. }
+                | Instruction::FsqrtD { dest, .. }
+                | Instruction::FsgnjD { dest, .. }
+                | Instruction::FsgnjnD { dest, .. }
+                | Instruction::FsgnjxD { dest, .. }
+                | Instruction::FminD { dest, .. }
+                | Instruction::FmaxD { dest, .. }
+                | Instruction::FcvtSD { dest, .. }
+                | Instruction::FcvtDS { dest, .. }
+                | Instruction::FcvtDW { dest, .. }
+                | Instruction::FcvtDWu { dest, .. }
+                | Instruction::FcvtDL { dest, .. }
+                | Instruction::FcvtDLu { dest, .. }
+                | Instruction::FmvDX { dest, .. }
+                | Instruction::Flq { dest, .. }
+                | Instruction::FmaddQ { dest, .. }
+                | Instruction::FmsubQ { dest, .. }
+                | Instruction::FnmsubQ { dest, .. }
+                | Instruction::FnmaddQ { dest, .. }
+                | Instruction::FaddQ { dest, .. }
+                | Instruction::FsubQ { dest, .. }
+                | Instruction::FmulQ { dest, .. }
+                | Instruction::FdivQ { dest, .. }
+                | Instruction::FsqrtQ { dest, .. }
+                | Instruction::FsgnjQ { dest, .. }
+                | Instruction::FsgnjnQ { dest, .. }
+                | Instruction::FsgnjxQ { dest, .. }
+                | Instruction::FminQ { dest, .. }
+                | Instruction::FmaxQ { dest, .. }
+                | Instruction::FcvtSQ { dest, .. }
+                | Instruction::FcvtQS { dest, .. }
+                | Instruction::FcvtDQ { dest, .. }
+                | Instruction::FcvtQD { dest, .. }
+                | Instruction::FcvtQW { dest, .. }
+                | Instruction::FcvtQWu { dest, .. }
+                | Instruction::FcvtQL { dest, .. }
+                | Instruction::FcvtQLu { dest, .. }
+                | Instruction::FliS { dest, .. }
+                | Instruction::FliD { dest, .. }
+                | Instruction::FliH { dest, .. }
+                | Instruction::FliQ { dest, .. }
+                | Instruction::FminmS { dest, .. }
+                | Instruction::FminmD { dest, .. }
+                | Instruction::FminmH { dest, .. }
+                | Instruction::FminmQ { dest, .. }
+                | Instruction::FmaxmS { dest, .. }
+                | Instruction::FmaxmD { dest, .. }
+                | Instruction::FmaxmH { dest, .. }
+                | Instruction::FmaxmQ { dest, .. }
+                | Instruction::FroundS { dest, .. }
+                | Instruction::FroundD { dest, .. }
+                | Instruction::FroundH { dest, .. }
+                | Instruction::FroundQ { dest, .. }
+                | Instruction::FroundnxS { dest, .. }
+                | Instruction::FroundnxD { dest, .. }
+                | Instruction::FroundnxH { dest, .. }
+                | Instruction::FroundnxQ { dest, .. }
+                | Instruction::FmvpDX { dest, .. }
+                | Instruction::FcvtBf16S { dest, .. }
+                | Instruction::FcvtSBf16 { dest, .. },
+                OperandRole::Dest,
+            ) => *dest = float(value)?,
+            (
+                Instruction::Fsw { src, .. }
+                | Instruction::FsqrtS { src, .. }
+                | Instruction::FcvtWS { src, .. }
+                | Instruction::FcvtWuS { src, .. }
+                | Instruction::FmvXW { src, .. }
+                | Instruction::FclassS { src, .. }
+                | Instruction::FcvtLS { src, .. }
+                | Instruction::FcvtLuS { src, .. }
+                | Instruction::Fsd { src, .. }
+                | Instruction::FsqrtD { src, .. }
+                | Instruction::FcvtSD { src, .. }
+                | Instruction::FcvtDS { src, .. }
+                | Instruction::FmvXD { src, .. }
+                | Instruction::Fsq { src, .. }
+                | Instruction::FsqrtQ { src, .. }
+                | Instruction::FcvtSQ { src, .. }
+                | Instruction::FcvtQS { src, .. }
+                | Instruction::FcvtDQ { src, .. }
+                | Instruction::FcvtQD { src, .. }
+                | Instruction::FclassQ { src, .. }
+                | Instruction::FcvtWQ { src, .. }
+                | Instruction::FcvtWuQ { src, .. }
+                | Instruction::FcvtLQ { src, .. }
+                | Instruction::FcvtLuQ { src, .. }
+                | Instruction::FroundS { src, .. }
+                | Instruction::FroundD { src, .. }
+                | Instruction::FroundH { src, .. }
+                | Instruction::FroundQ { src, .. }
+                | Instruction::FroundnxS { src, .. }
+                | Instruction::FroundnxD { src, .. }
+                | Instruction::FroundnxH { src, .. }
+                | Instruction::FroundnxQ { src, .. }
+                | Instruction::FcvtmodWD { src, .. }
+                | Instruction::FmvhXD { src, .. }
+                | Instruction::FcvtBf16S { src, .. }
+                | Instruction::FcvtSBf16 { src, .. },
+                OperandRole::Src1,
+            ) => *src = float(value)?,
+            (
+                Instruction::FmaddS { src1, .. }
+                | Instruction::FmsubS { src1, .. }
+                | Instruction::FnmsubS { src1, .. }
+                | Instruction::FnmaddS { src1, .. }
+                | Instruction::FaddS { src1, .. }
+                | Instruction::FsubS { src1, .. }
+                | Instruction::FmulS { src1, .. }
+                | Instruction::FdivS { src1, .. }
+                | Instruction::FsgnjS { src1, .. }
+                | Instruction::FsgnjnS { src1, .. }
+                | Instruction::FsgnjxS { src1, .. }
+                | Instruction::FminS { src1, .. }
+                | Instruction::FmaxS { src1, .. }
+                | Instruction::FeqS { src1, .. }
+                | Instruction::FltS { src1, .. }
+                | Instruction::FleS { src1, .. }
+                | Instruction::FmaddD { src1, .. }
+                | Instruction::FmsubD { src1, .. }
+                | Instruction::FnmaddD { src1, .. }
+                | Instruction::FnmsubD { src1, .. }
+                | Instruction::FaddD { src1, .. }
+                | Instruction::FsubD { src1, .. }
+                | Instruction::FmulD { src1, .. }
+                | Instruction::FdivD { src1, .. }
+                | Instruction::FsgnjD { src1, .. }
+                | Instruction::FsgnjnD { src1, .. }
+                | Instruction::FsgnjxD { src1, .. }
+                | Instruction::FminD { src1, .. }
+                | Instruction::FmaxD { src1, .. }
+                | Instruction::FeqD { src1, .. }
+                | Instruction::FltD { src1, .. }
+                | Instruction::FleD { src1, .. }
+                | Instruction::FclassD { src1, .. }
+                | Instruction::FcvtWD { src1, .. }
+                | Instruction::FcvtWuD { src1, .. }
+                | Instruction::FcvtLD { src1, .. }
+                | Instruction::FcvtLuD { src1, .. }
+                | Instruction::FmaddQ { src1, .. }
+                | Instruction::FmsubQ { src1, .. }
+                | Instruction::FnmsubQ { src1, .. }
+                | Instruction::FnmaddQ { src1, .. }
+                | Instruction::FaddQ { src1, .. }
+                | Instruction::FsubQ { src1, .. }
+                | Instruction::FmulQ { src1, .. }
+                | Instruction::FdivQ { src1, .. }
+                | Instruction::FsgnjQ { src1, .. }
+                | Instruction::FsgnjnQ { src1, .. }
+                | Instruction::FsgnjxQ { src1, .. }
+                | Instruction::FminQ { src1, .. }
+                | Instruction::FmaxQ { src1, .. }
+                | Instruction::FeqQ { src1, .. }
+                | Instruction::FltQ { src1, .. }
+                | Instruction::FleQ { src1, .. }
+                | Instruction::FminmS { src1, .. }
+                | Instruction::FminmD { src1, .. }
+                | Instruction::FminmH { src1, .. }
+                | Instruction::FminmQ { src1, .. }
+                | Instruction::FmaxmS { src1, .. }
+                | Instruction::FmaxmD { src1, .. }
+                | Instruction::FmaxmH { src1, .. }
+                | Instruction::FmaxmQ { src1, .. }
+                | Instruction::FleqS { src1, .. }
+                | Instruction::FleqD { src1, .. }
+                | Instruction::FleqH { src1, .. }
+                | Instruction::FleqQ { src1, .. }
+                | Instruction::FltqS { src1, .. }
+                | Instruction::FltqD { src1, .. }
+                | Instruction::FltqH { src1, .. }
+                | Instruction::FltqQ { src1, .. }
+                | Instruction::Vfwmaccbf16VF { src1, .. },
+                OperandRole::Src1,
+            ) => *src1 = float(value)?,
+            (
+                Instruction::FmaddS { src2, .. }
+                | Instruction::FmsubS { src2, .. }
+                | Instruction::FnmsubS { src2, .. }
+                | Instruction::FnmaddS { src2, .. }
+                | Instruction::FaddS { src2, .. }
+                | Instruction::FsubS { src2, .. }
+                | Instruction::FmulS { src2, .. }
+                | Instruction::FdivS { src2, .. }
+                | Instruction::FsgnjS { src2, .. }
+                | Instruction::FsgnjnS { src2, .. }
+                | Instruction::FsgnjxS { src2, .. }
+                | Instruction::FminS { src2, .. }
+                | Instruction::FmaxS { src2, .. }
+                | Instruction::FeqS { src2, .. }
+                | Instruction::FltS { src2, .. }
+                | Instruction::FleS { src2, .. }
+                | Instruction::FmaddD { src2, .. }
+                | Instruction::FmsubD { src2, .. }
+                | Instruction::FnmaddD { src2, .. }
+                | Instruction::FnmsubD { src2, .. }
+                | Instruction::FaddD { src2, .. }
+                | Instruction::FsubD { src2, .. }
+                | Instruction::FmulD { src2, .. }
+                | Instruction::FdivD { src2, .. }
+                | Instruction::FsgnjD { src2, .. }
+                | Instruction::FsgnjnD { src2, .. }
+                | Instruction::FsgnjxD { src2, .. }
+                | Instruction::FminD { src2, .. }
+                | Instruction::FmaxD { src2, .. }
+                | Instruction::FeqD { src2, .. }
+                | Instruction::FltD { src2, .. }
+                | Instruction::FleD { src2, .. }
+                | Instruction::FmaddQ { src2, .. }
+                | Instruction::FmsubQ { src2, .. }
+                | Instruction::FnmsubQ { src2, .. }
+                | Instruction::FnmaddQ { src2, .. }
+                | Instruction::FaddQ { src2, .. }
+                | Instruction::FsubQ { src2, .. }
+                | Instruction::FmulQ { src2, .. }
+                | Instruction::FdivQ { src2, .. }
+                | Instruction::FsgnjQ { src2, .. }
+                | Instruction::FsgnjnQ { src2, .. }
+                | Instruction::FsgnjxQ { src2, .. }
+                | Instruction::FminQ { src2, .. }
+                | Instruction::FmaxQ { src2, .. }
+                | Instruction::FeqQ { src2, .. }
+                | Instruction::FltQ { src2, .. }
+                | Instruction::FleQ { src2, .. }
+                | Instruction::FminmS { src2, .. }
+                | Instruction::FminmD { src2, .. }
+                | Instruction::FminmH { src2, .. }
+                | Instruction::FminmQ { src2, .. }
+                | Instruction::FmaxmS { src2, .. }
+                | Instruction::FmaxmD { src2, .. }
+                | Instruction::FmaxmH { src2, .. }
+                | Instruction::FmaxmQ { src2, .. }
+                | Instruction::FleqS { src2, .. }
+                | Instruction::FleqD { src2, .. }
+                | Instruction::FleqH { src2, .. }
+                | Instruction::FleqQ { src2, .. }
+                | Instruction::FltqS { src2, .. }
+                | Instruction::FltqD { src2, .. }
+                | Instruction::FltqH { src2, .. }
+                | Instruction::FltqQ { src2, .. },
+                OperandRole::Src2,
+            ) => *src2 = float(value)?,
+            (
+                Instruction::FmaddS { src3, .. }
+                | Instruction::FmsubS { src3, .. }
+                | Instruction::FnmsubS { src3, .. }
+                | Instruction::FnmaddS { src3, .. }
+                | Instruction::FmaddD { src3, .. }
+                | Instruction::FmsubD { src3, .. }
+                | Instruction::FnmaddD { src3, .. }
+                | Instruction::FnmsubD { src3, .. }
+                | Instruction::FmaddQ { src3, .. }
+                | Instruction::FmsubQ { src3, .. }
+                | Instruction::FnmsubQ { src3, .. }
+                | Instruction::FnmaddQ { src3, .. },
+                OperandRole::Src3,
+            ) => *src3 = float(value)?,
+            (
+                Instruction::FmaddS { rm, .. }
+                | Instruction::FmsubS { rm, .. }
+                | Instruction::FnmsubS { rm, .. }
+                | Instruction::FnmaddS { rm, .. }
+                | Instruction::FaddS { rm, .. }
+                | Instruction::FsubS { rm, .. }
+                | Instruction::FmulS { rm, .. }
+                | Instruction::FdivS { rm, .. }
+                | Instruction::FsqrtS { rm, .. }
+                | Instruction::FcvtWS { rm, .. }
+                | Instruction::FcvtWuS { rm, .. }
+                | Instruction::FcvtSW { rm, .. }
+                | Instruction::FcvtSWu { rm, .. }
+                | Instruction::FcvtLS { rm, .. }
+                | Instruction::FcvtLuS { rm, .. }
+                | Instruction::FcvtSL { rm, .. }
+                | Instruction::FcvtSLu { rm, .. }
+                | Instruction::FmaddD { rm, .. }
+                | Instruction::FmsubD { rm, .. }
+                | Instruction::FnmaddD { rm, .. }
+                | Instruction::FnmsubD { rm, .. }
+                | Instruction::FaddD { rm, .. }
+                | Instruction::FsubD { rm, .. }
+                | Instruction::FmulD { rm, .. }
+                | Instruction::FdivD { rm, .. }
+                | Instruction::FsqrtD { rm, .. }
+                | Instruction::FcvtSD { rm, .. }
+                | Instruction::FcvtDS { rm, .. }
+                | Instruction::FcvtWD { rm, .. }
+                | Instruction::FcvtWuD { rm, .. }
+                | Instruction::FcvtDW { rm, .. }
+                | Instruction::FcvtDWu { rm, .. }
+                | Instruction::FcvtLD { rm, .. }
+                | Instruction::FcvtLuD { rm, .. }
+                | Instruction::FcvtDL { rm, .. }
+                | Instruction::FcvtDLu { rm, .. }
+                | Instruction::FmaddQ { rm, .. }
+                | Instruction::FmsubQ { rm, .. }
+                | Instruction::FnmsubQ { rm, .. }
+                | Instruction::FnmaddQ { rm, .. }
+                | Instruction::FaddQ { rm, .. }
+                | Instruction::FsubQ { rm, .. }
+                | Instruction::FmulQ { rm, .. }
+                | Instruction::FdivQ { rm, .. }
+                | Instruction::FsqrtQ { rm, .. }
+                | Instruction::FcvtSQ { rm, .. }
+                | Instruction::FcvtQS { rm, .. }
+                | Instruction::FcvtDQ { rm, .. }
+                | Instruction::FcvtQD { rm, .. }
+                | Instruction::FcvtWQ { rm, .. }
+                | Instruction::FcvtWuQ { rm, .. }
+                | Instruction::FcvtQW { rm, .. }
+                | Instruction::FcvtQWu { rm, .. }
+                | Instruction::FcvtLQ { rm, .. }
+                | Instruction::FcvtLuQ { rm, .. }
+                | Instruction::FcvtQL { rm, .. }
+                | Instruction::FcvtQLu { rm, .. }
+                | Instruction::FroundS { rm, .. }
+                | Instruction::FroundD { rm, .. }
+                | Instruction::FroundH { rm, .. }
+                | Instruction::FroundQ { rm, .. }
+                | Instruction::FroundnxS { rm, .. }
+                | Instruction::FroundnxD { rm, .. }
+                | Instruction::FroundnxH { rm, .. }
+                | Instruction::FroundnxQ { rm, .. }
+                | Instruction::FcvtBf16S { rm, .. }
+                | Instruction::FcvtSBf16 { rm, .. },
+                OperandRole::RoundingMode,
+            ) => *rm = rounding_mode(value)?,
+            (
+                Instruction::Csrrw { csr, .. }
+                | Instruction::Csrrs { csr, .. }
+                | Instruction::Csrrc { csr, .. }
+                | Instruction::Csrrwi { csr, .. }
+                | Instruction::Csrrsi { csr, .. }
+                | Instruction::Csrrci { csr, .. },
+                OperandRole::Csr,
+            ) => *csr = CSR::try_from(csr_address(value)? as i64)?,
+            (
+                Instruction::Csrrwi { imm, .. }
+                | Instruction::Csrrsi { imm, .. }
+                | Instruction::Csrrci { imm, .. },
+                OperandRole::Immediate,
+            ) => *imm = CSRImmediate::try_from(immediate(value)?)?,
+            (
+                Instruction::FliS { imm, .. }
+                | Instruction::FliD { imm, .. }
+                | Instruction::FliH { imm, .. }
+                | Instruction::FliQ { imm, .. },
+                OperandRole::Immediate,
+            ) => *imm = fli_constant(value)?,
+            (
+                Instruction::Vfncvtbf16FFW { dest, .. }
+                | Instruction::Vfwcvtbf16FFV { dest, .. }
+                | Instruction::Vfwmaccbf16VV { dest, .. }
+                | Instruction::Vfwmaccbf16VF { dest, .. },
+                OperandRole::Dest,
+            ) => *dest = vector(value)?,
+            (
+                Instruction::Vfncvtbf16FFW { src, .. } | Instruction::Vfwcvtbf16FFV { src, .. },
+                OperandRole::Src1,
+            ) => *src = vector(value)?,
+            (Instruction::Vfwmaccbf16VV { src1, .. }, OperandRole::Src1) => *src1 = vector(value)?,
+            (
+                Instruction::Vfwmaccbf16VV { src2, .. } | Instruction::Vfwmaccbf16VF { src2, .. },
+                OperandRole::Src2,
+            ) => *src2 = vector(value)?,
+            (
+                Instruction::SfenceVma { vaddr, .. }
+                | Instruction::SinvalVma { vaddr, .. }
+                | Instruction::HfenceVvma { vaddr, .. }
+                | Instruction::HinvalVvma { vaddr, .. },
+                OperandRole::Src1,
+            ) => *vaddr = integer(value)?,
+            (
+                Instruction::SfenceVma { asid, .. }
+                | Instruction::SinvalVma { asid, .. }
+                | Instruction::HfenceVvma { asid, .. }
+                | Instruction::HinvalVvma { asid, .. },
+                OperandRole::Src2,
+            ) => *asid = integer(value)?,
+            (
+                Instruction::HfenceGvma { gaddr, .. } | Instruction::HinvalGvma { gaddr, .. },
+                OperandRole::Src1,
+            ) => *gaddr = integer(value)?,
+            (
+                Instruction::HfenceGvma { vmid, .. } | Instruction::HinvalGvma { vmid, .. },
+                OperandRole::Src2,
+            ) => *vmid = integer(value)?,
+            (
+                Instruction::PrefetchI { offset, .. }
+                | Instruction::PrefetchR { offset, .. }
+                | Instruction::PrefetchW { offset, .. },
+                OperandRole::Immediate,
+            ) => *offset = PrefetchImmediate::try_from(immediate(value)?)?,
+            (Instruction::Lpad { label, .. }, OperandRole::Immediate) => {
+                *label = LpadLabel::try_from(immediate(value)?)?
+            }
+            (i, role) => return Err(format!("{i} has no {role:?} operand")),
+        }
+        Ok(())
+    }
+}
+
+impl EditOperands for Instruction {
+    fn all_operands(&self) -> Vec<(OperandRole, Operand)> {
+        self.operands()
+    }
+
+    fn replace(&mut self, role: OperandRole, value: Operand) -> Result<(), String> {
+        self.replace_operand(role, value)
+    }
+
+    fn round_trip(&self, isa: &IsaConfig) -> Result<Self, String> {
+        Instruction::decode_with(Instruction::encode(self), isa)
+    }
+}
+
+impl CInstruction {
+    /// Returns the operands of the instruction with their roles, in the order they are stored.
+    ///
+    /// Registers that are implicit in the encoding, such as `sp` in `c.lwsp`, aren't included.
+    pub fn operands(&self) -> Vec<(OperandRole, Operand)> {
+        match self {
+            CInstruction::ADDI4SPN { dest, imm, .. } => vec![
+                (OperandRole::Dest, Operand::I(dest.expand())),
+                (OperandRole::Immediate, Operand::Immediate(imm.val())),
+            ],
+            CInstruction::FLD {
+                dest, base, offset, ..
+            } => vec![
+                (OperandRole::Dest, Operand::F(dest.expand())),
+                (OperandRole::Base, Operand::I(base.expand())),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::LW {
+                dest, base, offset, ..
+            } => vec![
+                (OperandRole::Dest, Operand::I(dest.expand())),
+                (OperandRole::Base, Operand::I(base.expand())),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::LD {
+                dest, base, offset, ..
+            } => vec![
+                (OperandRole::Dest, Operand::I(dest.expand())),
+                (OperandRole::Base, Operand::I(base.expand())),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::FSD {
+                src, base, offset, ..
+            } => vec![
+                (OperandRole::Src1, Operand::F(src.expand())),
+                (OperandRole::Base, Operand::I(base.expand())),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::SW {
+                src, base, offset, ..
+            } => vec![
+                (OperandRole::Src1, Operand::I(src.expand())),
+                (OperandRole::Base, Operand::I(base.expand())),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::SD {
+                src, base, offset, ..
+            } => vec![
+                (OperandRole::Src1, Operand::I(src.expand())),
+                (OperandRole::Base, Operand::I(base.expand())),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::ADDI { dest, imm, .. }
+            | CInstruction::ADDIW { dest, imm, .. }
+            | CInstruction::LI { dest, imm, .. }
+            | CInstruction::LUI { dest, imm, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Immediate, Operand::Immediate(imm.val())),
+            ],
+            CInstruction::ADDI16SP { imm, .. } => {
+                vec![(OperandRole::Immediate, Operand::Immediate(imm.val()))]
+            }
+            CInstruction::SRLI { dest, shamt, .. } | CInstruction::SRAI { dest, shamt, .. } => {
+                vec![
+                    (OperandRole::Dest, Operand::I(dest.expand())),
+                    (OperandRole::Immediate, Operand::Immediate(shamt.val())),
+                ]
+            }
+            CInstruction::ANDI { dest, imm, .. } => vec![
+                (OperandRole::Dest, Operand::I(dest.expand())),
+                (OperandRole::Immediate, Operand::Immediate(imm.val())),
+            ],
+            CInstruction::SUB { dest, src, .. }
+            | CInstruction::XOR { dest, src, .. }
+            | CInstruction::OR { dest, src, .. }
+            | CInstruction::AND { dest, src, .. }
+            | CInstruction::SUBW { dest, src, .. }
+            | CInstruction::ADDW { dest, src, .. }
+            | CInstruction::MUL { dest, src, .. } => vec![
+                (OperandRole::Dest, Operand::I(dest.expand())),
+                (OperandRole::Src1, Operand::I(src.expand())),
+            ],
+            CInstruction::J { offset, .. } => {
+                vec![(OperandRole::Immediate, Operand::Immediate(offset.val()))]
+            }
+            CInstruction::BEQZ { src, offset, .. } | CInstruction::BNEZ { src, offset, .. } => {
+                vec![
+                    (OperandRole::Src1, Operand::I(src.expand())),
+                    (OperandRole::Immediate, Operand::Immediate(offset.val())),
+                ]
+            }
+            CInstruction::SLLI { dest, shamt, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Immediate, Operand::Immediate(shamt.val())),
+            ],
+            CInstruction::FLDSP { dest, offset, .. } => vec![
+                (OperandRole::Dest, Operand::F(*dest)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::LWSP { dest, offset, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::LDSP { dest, offset, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::JR { src, .. } | CInstruction::JALR { src, .. } => {
+                vec![(OperandRole::Src1, Operand::I(*src))]
+            }
+            CInstruction::MV { dest, src, .. } | CInstruction::ADD { dest, src, .. } => vec![
+                (OperandRole::Dest, Operand::I(*dest)),
+                (OperandRole::Src1, Operand::I(*src)),
+            ],
+            CInstruction::EBREAK
+            | CInstruction::MOP { .. }
+            | CInstruction::SSPUSH
+            | CInstruction::SSPOPCHK => vec![],
+            CInstruction::FSDSP { src, offset, .. } => vec![
+                (OperandRole::Src1, Operand::F(*src)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::SWSP { src, offset, .. } => vec![
+                (OperandRole::Src1, Operand::I(*src)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::SDSP { src, offset, .. } => vec![
+                (OperandRole::Src1, Operand::I(*src)),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::LBU {
+                dest, base, offset, ..
+            } => vec![
+                (OperandRole::Dest, Operand::I(dest.expand())),
+                (OperandRole::Base, Operand::I(base.expand())),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::LHU {
+                dest, base, offset, ..
+            }
+            | CInstruction::LH {
+                dest, base, offset, ..
+            } => vec![
+                (OperandRole::Dest, Operand::I(dest.expand())),
+                (OperandRole::Base, Operand::I(base.expand())),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::SB {
+                src, base, offset, ..
+            } => vec![
+                (OperandRole::Src1, Operand::I(src.expand())),
+                (OperandRole::Base, Operand::I(base.expand())),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::SH {
+                src, base, offset, ..
+            } => vec![
+                (OperandRole::Src1, Operand::I(src.expand())),
+                (OperandRole::Base, Operand::I(base.expand())),
+                (OperandRole::Immediate, Operand::Immediate(offset.val())),
+            ],
+            CInstruction::ZEXTB { dest, .. }
+            | CInstruction::SEXTB { dest, .. }
+            | CInstruction::ZEXTH { dest, .. }
+            | CInstruction::SEXTH { dest, .. }
+            | CInstruction::ZEXTW { dest, .. }
+            | CInstruction::NOT { dest, .. } => {
+                vec![(OperandRole::Dest, Operand::I(dest.expand()))]
+            }
+            CInstruction::CMPUSH {
+                rlist, stack_adj, ..
+            }
+            | CInstruction::CMPOP {
+                rlist, stack_adj, ..
+            }
+            | CInstruction::CMPOPRETZ {
+                rlist, stack_adj, ..
+            }
+            | CInstruction::CMPOPRET {
+                rlist, stack_adj, ..
+            } => vec![
+                (OperandRole::RegisterList, Operand::RegisterList(*rlist)),
+                (OperandRole::Immediate, Operand::Immediate(stack_adj.val())),
+            ],
+            CInstruction::CMMVSA01 { dest1, dest2, .. } => vec![
+                (OperandRole::Dest, Operand::I(dest1.expand())),
+                (OperandRole::Dest2, Operand::I(dest2.expand())),
+            ],
+            CInstruction::CMMVA01S { src1, src2, .. } => vec![
+                (OperandRole::Src1, Operand::I(src1.expand())),
+                (OperandRole::Src2, Operand::I(src2.expand())),
+            ],
+            CInstruction::CMJT { index, .. } | CInstruction::CMJALT { index, .. } => {
+                vec![(OperandRole::Immediate, Operand::Immediate(*index as i64))]
+            }
+        }
+    }
+
+    /// Replaces an operand of the instruction, validating it for the default ISA configuration.
+    ///
+    /// The instruction is left unchanged if it has no operand with the role, the value doesn't fit the operand, such
+    /// as a register that isn't available in the compressed encoding, or the result isn't a valid instruction.
+    pub fn set_operand(&mut self, role: OperandRole, value: Operand) -> Result<(), String> {
+        self.set_operand_with(role, value, &IsaConfig::default())
+    }
+
+    /// Replaces an operand of the instruction, validating it for the given ISA configuration.
+    pub fn set_operand_with(
+        &mut self,
+        role: OperandRole,
+        value: Operand,
+        isa: &IsaConfig,
+    ) -> Result<(), String> {
+        set_operand_with(self, role, value, isa)
+    }
+
+    /// Replaces every operand of the instruction with the result of `f`, which is given the role and current value.
+    ///
+    /// The operands are validated together for the default ISA configuration after all of them are replaced, and the
+    /// instruction is left unchanged if the result isn't valid.
+    pub fn map_operands(
+        &mut self,
+        f: impl FnMut(OperandRole, Operand) -> Operand,
+    ) -> Result<(), String> {
+        self.map_operands_with(&IsaConfig::default(), f)
+    }
+
+    /// Replaces every operand of the instruction with the result of `f`, validating the result for the given ISA
+    /// configuration.
+    pub fn map_operands_with(
+        &mut self,
+        isa: &IsaConfig,
+        f: impl FnMut(OperandRole, Operand) -> Operand,
+    ) -> Result<(), String> {
+        map_operands_with(self, isa, f)
+    }
+
+    fn replace_operand(&mut self, role: OperandRole, value: Operand) -> Result<(), String> {
+        match (self, role) {
+            (
+                CInstruction::ADDI4SPN { dest, .. }
+                | CInstruction::LW { dest, .. }
+                | CInstruction::LD { dest, .. }
+                | CInstruction::SRLI { dest, .. }
+                | CInstruction::SRAI { dest, .. }
+                | CInstruction::ANDI { dest, .. }
+                | CInstruction::SUB { dest, .. }
+                | CInstruction::XOR { dest, .. }
+                | CInstruction::OR { dest, .. }
+                | CInstruction::AND { dest, .. }
+                | CInstruction::SUBW { dest, .. }
+                | CInstruction::ADDW { dest, .. }
+                | CInstruction::LBU { dest, .. }
+                | CInstruction::LHU { dest, .. }
+                | CInstruction::LH { dest, .. }
+                | CInstruction::ZEXTB { dest, .. }
+                | CInstruction::SEXTB { dest, .. }
+                | CInstruction::ZEXTH { dest, .. }
+                | CInstruction::SEXTH { dest, .. }
+                | CInstruction::ZEXTW { dest, .. }
+                | CInstruction::NOT { dest, .. }
+                | CInstruction::MUL { dest, .. },
+                OperandRole::Dest,
+            ) => *dest = CIRegister::try_from(integer(value)?)?,
+            (CInstruction::ADDI4SPN { imm, .. }, OperandRole::Immediate) => {
+                *imm = CWideImmediate::try_from(immediate(value)?)?
+            }
+            (CInstruction::FLD { dest, .. }, OperandRole::Dest) => {
+                *dest = CFRegister::try_from(float(value)?)?
+            }
+            (
+                CInstruction::FLD { base, .. }
+                | CInstruction::LW { base, .. }
+                | CInstruction::LD { base, .. }
+                | CInstruction::FSD { base, .. }
+                | CInstruction::SW { base, .. }
+                | CInstruction::SD { base, .. }
+                | CInstruction::LBU { base, .. }
+                | CInstruction::LHU { base, .. }
+                | CInstruction::LH { base, .. }
+                | CInstruction::SB { base, .. }
+                | CInstruction::SH { base, .. },
+                OperandRole::Base,
+            ) => *base = CIRegister::try_from(integer(value)?)?,
+            (
+                CInstruction::FLD { offset, .. }
+                | CInstruction::LD { offset, .. }
+                | CInstruction::FSD { offset, .. }
+                | CInstruction::SD { offset, .. },
+                OperandRole::Immediate,
+            ) => *offset = CDImmediate::try_from(immediate(value)?)?,
+            (
+                CInstruction::LW { offset, .. } | CInstruction::SW { offset, .. },
+                OperandRole::Immediate,
+            ) => *offset = CWImmediate::try_from(immediate(value)?)?,
+            (CInstruction::FSD { src, .. }, OperandRole::Src1) => {
+                *src = CFRegister::try_from(float(value)?)?
+            }
+            (
+                CInstruction::SW { src, .. }
+                | CInstruction::SD { src, .. }
+                | CInstruction::SUB { src, .. }
+                | CInstruction::XOR { src, .. }
+                | CInstruction::OR { src, .. }
+                | CInstruction::AND { src, .. }
+                | CInstruction::SUBW { src, .. }
+                | CInstruction::ADDW { src, .. }
+                | CInstruction::BEQZ { src, .. }
+                | CInstruction::BNEZ { src, .. }
+                | CInstruction::SB { src, .. }
+                | CInstruction::SH { src, .. }
+                | CInstruction::MUL { src, .. },
+                OperandRole::Src1,
+            ) => *src = CIRegister::try_from(integer(value)?)?,
+            (
+                CInstruction::ADDI { dest, .. }
+                | CInstruction::ADDIW { dest, .. }
+                | CInstruction::LI { dest, .. }
+                | CInstruction::LUI { dest, .. }
+                | CInstruction::SLLI { dest, .. }
+                | CInstruction::LWSP { dest, .. }
+                | CInstruction::LDSP { dest, .. }
+                | CInstruction::MV { dest, .. }
+                | CInstruction::ADD { dest, .. },
+                OperandRole::Dest,
+            ) => *dest = integer(value)?,
+            (
+                CInstruction::ADDI { imm, .. }
+                | CInstruction::ADDIW { imm, .. }
+                | CInstruction::LI { imm, .. }
+                | CInstruction::LUI { imm, .. }
+                | CInstruction::ANDI { imm, .. },
+                OperandRole::Immediate,
+            ) => *imm = CIImmediate::try_from(immediate(value)?)?,
+            (CInstruction::ADDI16SP { imm, .. }, OperandRole::Immediate) => {
+                *imm = C16SPImmediate::try_from(immediate(value)?)?
+            }
+            (
+                CInstruction::SRLI { shamt, .. }
+                | CInstruction::SRAI { shamt, .. }
+                | CInstruction::SLLI { shamt, .. },
+                OperandRole::Immediate,
+            ) => *shamt = CShamt::try_from(immediate(value)?)?,
+            (CInstruction::J { offset, .. }, OperandRole::Immediate) => {
+                *offset = CJImmediate::try_from(immediate(value)?)?
+            }
+            (
+                CInstruction::BEQZ { offset, .. } | CInstruction::BNEZ { offset, .. },
+                OperandRole::Immediate,
+            ) => *offset = CBImmediate::try_from(immediate(value)?)?,
+            (CInstruction::FLDSP { dest, .. }, OperandRole::Dest) => *dest = float(value)?,
+            (
+                CInstruction::FLDSP { offset, .. } | CInstruction::LDSP { offset, .. },
+                OperandRole::Immediate,
+            ) => *offset = CDSPImmediate::try_from(immediate(value)?)?,
+            (CInstruction::LWSP { offset, .. }, OperandRole::Immediate) => {
+                *offset = CWSPImmediate::try_from(immediate(value)?)?
+            }
+            (
+                CInstruction::JR { src, .. }
+                | CInstruction::MV { src, .. }
+                | CInstruction::JALR { src, .. }
+                | CInstruction::ADD { src, .. }
+                | CInstruction::SWSP { src, .. }
+                | CInstruction::SDSP { src, .. },
+                OperandRole::Src1,
+            ) => *src = integer(value)?,
+            (CInstruction::FSDSP { src, .. }, OperandRole::Src1) => *src = float(value)?,
+            (
+                CInstruction::FSDSP { offset, .. } | CInstruction::SDSP { offset, .. },
+                OperandRole::Immediate,
+            ) => *offset = CSDSPImmediate::try_from(immediate(value)?)?,
+            (CInstruction::SWSP { offset, .. }, OperandRole::Immediate) => {
+                *offset = CSWSPImmediate::try_from(immediate(value)?)?
+            }
+            (
+                CInstruction::LBU { offset, .. } | CInstruction::SB { offset, .. },
+                OperandRole::Immediate,
+            ) => *offset = CByteImmediate::try_from(immediate(value)?)?,
+            (
+                CInstruction::LHU { offset, .. }
+                | CInstruction::LH { offset, .. }
+                | CInstruction::SH { offset, .. },
+                OperandRole::Immediate,
+            ) => *offset = CHalfImmediate::try_from(immediate(value)?)?,
+            (
+                CInstruction::CMPUSH { rlist, .. }
+                | CInstruction::CMPOP { rlist, .. }
+                | CInstruction::CMPOPRETZ { rlist, .. }
+                | CInstruction::CMPOPRET { rlist, .. },
+                OperandRole::RegisterList,
+            ) => *rlist = register_list(value)?,
+            (
+                CInstruction::CMPUSH { stack_adj, .. }
+                | CInstruction::CMPOP { stack_adj, .. }
+                | CInstruction::CMPOPRETZ { stack_adj, .. }
+                | CInstruction::CMPOPRET { stack_adj, .. },
+                OperandRole::Immediate,
+            ) => *stack_adj = CStackAdjImmediate::try_from(immediate(value)?)?,
+            (CInstruction::CMMVSA01 { dest1, .. }, OperandRole::Dest) => {
+                *dest1 = CSRegister::try_from(integer(value)?)?
+            }
+            (CInstruction::CMMVSA01 { dest2, .. }, OperandRole::Dest2) => {
+                *dest2 = CSRegister::try_from(integer(value)?)?
+            }
+            (CInstruction::CMMVA01S { src1, .. }, OperandRole::Src1) => {
+                *src1 = CSRegister::try_from(integer(value)?)?
+            }
+            (CInstruction::CMMVA01S { src2, .. }, OperandRole::Src2) => {
+                *src2 = CSRegister::try_from(integer(value)?)?
+            }
+            (
+                CInstruction::CMJT { index, .. } | CInstruction::CMJALT { index, .. },
+                OperandRole::Immediate,
+            ) => *index = table_index(value)?,
+            (c, role) => return Err(format!("{c} has no {role:?} operand")),
+        }
+        Ok(())
+    }
+}
+
+impl EditOperands for CInstruction {
+    fn all_operands(&self) -> Vec<(OperandRole, Operand)> {
+        self.operands()
+    }
+
+    fn replace(&mut self, role: OperandRole, value: Operand) -> Result<(), String> {
+        self.replace_operand(role, value)
+    }
+
+    fn round_trip(&self, isa: &IsaConfig) -> Result<Self, String> {
+        CInstruction::decode_with(CInstruction::encode(self), isa)
+    }
+}
+
+/// The operations shared by the operand mutators of `Instruction` and `CInstruction`
+trait EditOperands: Copy + PartialEq + Display {
+    fn all_operands(&self) -> Vec<(OperandRole, Operand)>;
+
+    /// Replaces an operand without checking that the result is a valid instruction
+    fn replace(&mut self, role: OperandRole, value: Operand) -> Result<(), String>;
+
+    /// Encodes and decodes the instruction
+    fn round_trip(&self, isa: &IsaConfig) -> Result<Self, String>;
+
+    /// Checks that the instruction survives encoding, which rejects reserved encodings and aliases
+    fn validate(&self, isa: &IsaConfig) -> Result<(), String> {
+        match self.round_trip(isa) {
+            Ok(i) if i == *self => Ok(()),
+            Ok(i) => Err(format!("{self} would be encoded as {i}")),
+            Err(e) => Err(format!("{self} is not a valid instruction: {e}")),
+        }
+    }
+}
+
+fn operands_with<T: EditOperands>(instruction: &T, isa: &IsaConfig) -> Vec<(OperandRole, Operand)> {
+    instruction
+        .all_operands()
+        .into_iter()
+        .map(|(role, value)| match value {
+            Operand::F(r) if isa.floats_in_integer_registers() => {
+                (role, Operand::I(IRegister::from_int(r.into())))
+            }
+            value => (role, value),
+        })
+        .collect()
+}
+
+/// Converts an integer register given for a float operand back to the float register field that holds it, when
+/// floats are held in the integer registers
+fn float_operand<T: EditOperands>(
+    instruction: &T,
+    role: OperandRole,
+    value: Operand,
+    isa: &IsaConfig,
+) -> Operand {
+    let float = instruction
+        .all_operands()
+        .iter()
+        .any(|(r, v)| *r == role && matches!(v, Operand::F(_)));
+    match value {
+        Operand::I(r) if float && isa.floats_in_integer_registers() => {
+            Operand::F(FRegister::Integer(r))
+        }
+        value => value,
+    }
+}
+
+fn set_operand_with<T: EditOperands>(
+    instruction: &mut T,
+    role: OperandRole,
+    value: Operand,
+    isa: &IsaConfig,
+) -> Result<(), String> {
+    let mut new = *instruction;
+    new.replace(role, float_operand(instruction, role, value, isa))?;
+    new.validate(isa)?;
+    *instruction = new;
+    Ok(())
+}
+
+fn map_operands_with<T: EditOperands>(
+    instruction: &mut T,
+    isa: &IsaConfig,
+    mut f: impl FnMut(OperandRole, Operand) -> Operand,
+) -> Result<(), String> {
+    let mut new = *instruction;
+    for (role, value) in operands_with(instruction, isa) {
+        new.replace(role, float_operand(instruction, role, f(role, value), isa))?;
+    }
+    new.validate(isa)?;
+    *instruction = new;
+    Ok(())
+}
+
+fn integer(value: Operand) -> Result<IRegister, String> {
+    match value {
+        Operand::I(r) => Ok(r),
+        x => Err(format!("expected an integer register, found {x}")),
+    }
+}
+
+fn float(value: Operand) -> Result<FRegister, String> {
+    match value {
+        Operand::F(r) => Ok(r),
+        x => Err(format!("expected a float register, found {x}")),
+    }
+}
+
+fn vector(value: Operand) -> Result<VRegister, String> {
+    match value {
+        Operand::V(r) => Ok(r),
+        x => Err(format!("expected a vector register, found {x}")),
+    }
+}
+
+fn immediate(value: Operand) -> Result<i64, String> {
+    match value {
+        Operand::Immediate(x) => Ok(x),
+        x => Err(format!("expected an immediate, found {x}")),
+    }
+}
+
+fn csr_address(value: Operand) -> Result<u16, String> {
+    match value {
+        Operand::Csr(x) => Ok(x),
+        x => Err(format!("expected a CSR, found {x}")),
+    }
+}
+
+fn rounding_mode(value: Operand) -> Result<RoundingMode, String> {
+    match value {
+        Operand::RoundingMode(rm) => Ok(rm),
+        x => Err(format!("expected a rounding mode, found {x}")),
+    }
+}
+
+fn register_list(value: Operand) -> Result<RegisterList, String> {
+    match value {
+        Operand::RegisterList(rlist) => Ok(rlist),
+        x => Err(format!("expected a register list, found {x}")),
+    }
+}
+
+fn fli_constant(value: Operand) -> Result<FliConstant, String> {
+    let x = immediate(value)?;
+    FliConstant::from_int(
+        u32::try_from(x).map_err(|_| "attempted to create invalid fli constant".to_owned())?,
+    )
+}
+
+fn table_index(value: Operand) -> Result<u8, String> {
+    let x = immediate(value)?;
+    u8::try_from(x).map_err(|_| format!("jump table index out of range: {x}"))
+}
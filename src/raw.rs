@@ -0,0 +1,763 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+use crate::immediates::{
+    BImmediate, CBImmediate, CIImmediate, CJImmediate, IImmediate, JImmediate, SImmediate,
+    UImmediate,
+};
+use crate::opcode::Opcode;
+use crate::register::IRegister;
+
+/// The layout of the fields in an instruction encoding
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Format {
+    R,
+    R4,
+    I,
+    S,
+    B,
+    U,
+    J,
+    CR,
+    CI,
+    CSS,
+    CIW,
+    CL,
+    CS,
+    CA,
+    CB,
+    CJ,
+}
+
+/// The fields of a 32-bit instruction, split according to its format without interpreting what the instruction does.
+///
+/// Registers are the raw 5-bit register numbers, which may select integer or float registers depending on the
+/// instruction.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RawInstruction {
+    R {
+        opcode: u8,
+        rd: u8,
+        funct3: u8,
+        rs1: u8,
+        rs2: u8,
+        funct7: u8,
+    },
+    R4 {
+        opcode: u8,
+        rd: u8,
+        funct3: u8,
+        rs1: u8,
+        rs2: u8,
+        fmt: u8,
+        rs3: u8,
+    },
+    I {
+        opcode: u8,
+        rd: u8,
+        funct3: u8,
+        rs1: u8,
+        imm: IImmediate,
+    },
+    S {
+        opcode: u8,
+        funct3: u8,
+        rs1: u8,
+        rs2: u8,
+        imm: SImmediate,
+    },
+    B {
+        opcode: u8,
+        funct3: u8,
+        rs1: u8,
+        rs2: u8,
+        imm: BImmediate,
+    },
+    U {
+        opcode: u8,
+        rd: u8,
+        imm: UImmediate,
+    },
+    J {
+        opcode: u8,
+        rd: u8,
+        imm: JImmediate,
+    },
+}
+
+/// The fields of a 16-bit instruction, split according to its format without interpreting what the instruction does.
+///
+/// The registers in the CIW, CL, CS, CA and CB formats are 3-bit fields that select x8-x15 or f8-f15. The CIW, CSS,
+/// CL and CS immediates are the raw bits of the field, since their scaling and bit order depends on the instruction.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RawCInstruction {
+    CR {
+        opcode: u8,
+        funct4: u8,
+        rd: u8,
+        rs2: u8,
+    },
+    CI {
+        opcode: u8,
+        funct3: u8,
+        rd: u8,
+        imm: CIImmediate,
+    },
+    CSS {
+        opcode: u8,
+        funct3: u8,
+        rs2: u8,
+        imm: u8,
+    },
+    CIW {
+        opcode: u8,
+        funct3: u8,
+        rd: u8,
+        imm: u8,
+    },
+    CL {
+        opcode: u8,
+        funct3: u8,
+        rd: u8,
+        rs1: u8,
+        imm: u8,
+    },
+    CS {
+        opcode: u8,
+        funct3: u8,
+        rs1: u8,
+        rs2: u8,
+        imm: u8,
+    },
+    CA {
+        opcode: u8,
+        funct6: u8,
+        rd: u8,
+        funct2: u8,
+        rs2: u8,
+    },
+    CB {
+        opcode: u8,
+        funct3: u8,
+        rs1: u8,
+        imm: CBImmediate,
+    },
+    CJ {
+        opcode: u8,
+        funct3: u8,
+        imm: CJImmediate,
+    },
+}
+
+fn field(instruction: u32, start: u32, size: u32) -> u8 {
+    ((instruction >> start) & ((1 << size) - 1)) as u8
+}
+
+impl RawInstruction {
+    /// Splits a 32-bit instruction into the fields of the format used by its opcode.
+    pub fn decode(instruction: u32) -> Result<Self, String> {
+        if instruction & 0b11 != 0b11 {
+            return Err("compressed instruction can't be split into 32-bit fields".to_owned());
+        }
+        Ok(Self::decode_as(
+            instruction,
+            Opcode::from_int(instruction & 0b111_1111).format(),
+        ))
+    }
+
+    /// Splits a 32-bit instruction into the fields of the given format, which is useful for custom opcodes.
+    ///
+    /// Panics if the format is compressed.
+    pub fn decode_as(instruction: u32, format: Format) -> Self {
+        let opcode = field(instruction, 0, 7);
+        let rd = field(instruction, 7, 5);
+        let funct3 = field(instruction, 12, 3);
+        let rs1 = field(instruction, 15, 5);
+        let rs2 = field(instruction, 20, 5);
+        match format {
+            Format::R => RawInstruction::R {
+                opcode,
+                rd,
+                funct3,
+                rs1,
+                rs2,
+                funct7: field(instruction, 25, 7),
+            },
+            Format::R4 => RawInstruction::R4 {
+                opcode,
+                rd,
+                funct3,
+                rs1,
+                rs2,
+                fmt: field(instruction, 25, 2),
+                rs3: field(instruction, 27, 5),
+            },
+            Format::I => RawInstruction::I {
+                opcode,
+                rd,
+                funct3,
+                rs1,
+                imm: IImmediate::from_u32(instruction),
+            },
+            Format::S => RawInstruction::S {
+                opcode,
+                funct3,
+                rs1,
+                rs2,
+                imm: SImmediate::from_u32(instruction),
+            },
+            Format::B => RawInstruction::B {
+                opcode,
+                funct3,
+                rs1,
+                rs2,
+                imm: BImmediate::from_u32(instruction),
+            },
+            Format::U => RawInstruction::U {
+                opcode,
+                rd,
+                imm: UImmediate::from_u32(instruction),
+            },
+            Format::J => RawInstruction::J {
+                opcode,
+                rd,
+                imm: JImmediate::from_u32(instruction),
+            },
+            _ => panic!("{format:?} is not the format of a 32-bit instruction"),
+        }
+    }
+
+    /// Builds the instruction back into a `u32`.
+    pub fn encode(&self) -> u32 {
+        let base = |opcode: &u8, rd: &u8, funct3: &u8, rs1: &u8, rs2: &u8| -> u32 {
+            (*rs2 as u32) << 20
+                | (*rs1 as u32) << 15
+                | (*funct3 as u32) << 12
+                | (*rd as u32) << 7
+                | *opcode as u32
+        };
+        match self {
+            RawInstruction::R {
+                opcode,
+                rd,
+                funct3,
+                rs1,
+                rs2,
+                funct7,
+            } => (*funct7 as u32) << 25 | base(opcode, rd, funct3, rs1, rs2),
+            RawInstruction::R4 {
+                opcode,
+                rd,
+                funct3,
+                rs1,
+                rs2,
+                fmt,
+                rs3,
+            } => (*rs3 as u32) << 27 | (*fmt as u32) << 25 | base(opcode, rd, funct3, rs1, rs2),
+            RawInstruction::I {
+                opcode,
+                rd,
+                funct3,
+                rs1,
+                imm,
+            } => imm.to_u32() | base(opcode, rd, funct3, rs1, &0),
+            RawInstruction::S {
+                opcode,
+                funct3,
+                rs1,
+                rs2,
+                imm,
+            } => imm.to_u32() | base(opcode, &0, funct3, rs1, rs2),
+            RawInstruction::B {
+                opcode,
+                funct3,
+                rs1,
+                rs2,
+                imm,
+            } => imm.to_u32() | base(opcode, &0, funct3, rs1, rs2),
+            RawInstruction::U { opcode, rd, imm } => imm.to_u32() | base(opcode, rd, &0, &0, &0),
+            RawInstruction::J { opcode, rd, imm } => imm.to_u32() | base(opcode, rd, &0, &0, &0),
+        }
+    }
+
+    pub fn format(&self) -> Format {
+        match self {
+            RawInstruction::R { .. } => Format::R,
+            RawInstruction::R4 { .. } => Format::R4,
+            RawInstruction::I { .. } => Format::I,
+            RawInstruction::S { .. } => Format::S,
+            RawInstruction::B { .. } => Format::B,
+            RawInstruction::U { .. } => Format::U,
+            RawInstruction::J { .. } => Format::J,
+        }
+    }
+
+    pub fn opcode(&self) -> u8 {
+        match self {
+            RawInstruction::R { opcode, .. }
+            | RawInstruction::R4 { opcode, .. }
+            | RawInstruction::I { opcode, .. }
+            | RawInstruction::S { opcode, .. }
+            | RawInstruction::B { opcode, .. }
+            | RawInstruction::U { opcode, .. }
+            | RawInstruction::J { opcode, .. } => *opcode,
+        }
+    }
+
+    pub fn rd(&self) -> Option<u8> {
+        match self {
+            RawInstruction::R { rd, .. }
+            | RawInstruction::R4 { rd, .. }
+            | RawInstruction::I { rd, .. }
+            | RawInstruction::U { rd, .. }
+            | RawInstruction::J { rd, .. } => Some(*rd),
+            _ => None,
+        }
+    }
+
+    pub fn funct3(&self) -> Option<u8> {
+        match self {
+            RawInstruction::R { funct3, .. }
+            | RawInstruction::R4 { funct3, .. }
+            | RawInstruction::I { funct3, .. }
+            | RawInstruction::S { funct3, .. }
+            | RawInstruction::B { funct3, .. } => Some(*funct3),
+            _ => None,
+        }
+    }
+
+    pub fn rs1(&self) -> Option<u8> {
+        match self {
+            RawInstruction::R { rs1, .. }
+            | RawInstruction::R4 { rs1, .. }
+            | RawInstruction::I { rs1, .. }
+            | RawInstruction::S { rs1, .. }
+            | RawInstruction::B { rs1, .. } => Some(*rs1),
+            _ => None,
+        }
+    }
+
+    pub fn rs2(&self) -> Option<u8> {
+        match self {
+            RawInstruction::R { rs2, .. }
+            | RawInstruction::R4 { rs2, .. }
+            | RawInstruction::S { rs2, .. }
+            | RawInstruction::B { rs2, .. } => Some(*rs2),
+            _ => None,
+        }
+    }
+
+    pub fn rs3(&self) -> Option<u8> {
+        match self {
+            RawInstruction::R4 { rs3, .. } => Some(*rs3),
+            _ => None,
+        }
+    }
+
+    pub fn funct7(&self) -> Option<u8> {
+        match self {
+            RawInstruction::R { funct7, .. } => Some(*funct7),
+            _ => None,
+        }
+    }
+
+    /// Returns the floating point format field, which is the lowest two bits of `funct7` for `OP-FP` instructions.
+    pub fn fmt(&self) -> Option<u8> {
+        match self {
+            RawInstruction::R4 { fmt, .. } => Some(*fmt),
+            RawInstruction::R { opcode, funct7, .. } if *opcode == Opcode::OpFp as u8 => {
+                Some(funct7 & 0b11)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the immediate, with its bits reassembled and sign extended.
+    pub fn imm(&self) -> Option<i64> {
+        match self {
+            RawInstruction::I { imm, .. } => Some(imm.val()),
+            RawInstruction::S { imm, .. } => Some(imm.val()),
+            RawInstruction::B { imm, .. } => Some(imm.val()),
+            RawInstruction::U { imm, .. } => Some(imm.val()),
+            RawInstruction::J { imm, .. } => Some(imm.val()),
+            _ => None,
+        }
+    }
+}
+
+impl RawCInstruction {
+    /// Splits a 16-bit instruction into the fields of the format used by its quadrant and `funct3`.
+    pub fn decode(instruction: u16) -> Result<Self, String> {
+        let quadrant = instruction & 0b11;
+        let funct3 = instruction >> 13;
+        let format = match (quadrant, funct3) {
+            (0b00, 0b000) => Format::CIW,
+            (0b00, 0b001..=0b100) => Format::CL,
+            (0b00, _) => Format::CS,
+            (0b01, 0b000..=0b011) => Format::CI,
+            (0b01, 0b100) if instruction >> 10 & 0b11 == 0b11 => Format::CA,
+            (0b01, 0b101) => Format::CJ,
+            (0b01, _) => Format::CB,
+            (0b10, 0b000..=0b011) => Format::CI,
+            (0b10, 0b100) => Format::CR,
+            (0b10, _) => Format::CSS,
+            _ => return Err("32-bit instruction can't be split into compressed fields".to_owned()),
+        };
+        Ok(Self::decode_as(instruction, format))
+    }
+
+    /// Splits a 16-bit instruction into the fields of the given format.
+    ///
+    /// Panics if the format isn't compressed.
+    pub fn decode_as(instruction: u16, format: Format) -> Self {
+        let x = instruction as u32;
+        let opcode = field(x, 0, 2);
+        let funct3 = field(x, 13, 3);
+        match format {
+            Format::CR => RawCInstruction::CR {
+                opcode,
+                funct4: field(x, 12, 4),
+                rd: field(x, 7, 5),
+                rs2: field(x, 2, 5),
+            },
+            Format::CI => RawCInstruction::CI {
+                opcode,
+                funct3,
+                rd: field(x, 7, 5),
+                imm: CIImmediate::from_u16(instruction),
+            },
+            Format::CSS => RawCInstruction::CSS {
+                opcode,
+                funct3,
+                rs2: field(x, 2, 5),
+                imm: field(x, 7, 6),
+            },
+            Format::CIW => RawCInstruction::CIW {
+                opcode,
+                funct3,
+                rd: field(x, 2, 3),
+                imm: field(x, 5, 8),
+            },
+            Format::CL => RawCInstruction::CL {
+                opcode,
+                funct3,
+                rd: field(x, 2, 3),
+                rs1: field(x, 7, 3),
+                imm: field(x, 10, 3) << 2 | field(x, 5, 2),
+            },
+            Format::CS => RawCInstruction::CS {
+                opcode,
+                funct3,
+                rs1: field(x, 7, 3),
+                rs2: field(x, 2, 3),
+                imm: field(x, 10, 3) << 2 | field(x, 5, 2),
+            },
+            Format::CA => RawCInstruction::CA {
+                opcode,
+                funct6: field(x, 10, 6),
+                rd: field(x, 7, 3),
+                funct2: field(x, 5, 2),
+                rs2: field(x, 2, 3),
+            },
+            Format::CB => RawCInstruction::CB {
+                opcode,
+                funct3,
+                rs1: field(x, 7, 3),
+                imm: CBImmediate::from_u16(instruction),
+            },
+            Format::CJ => RawCInstruction::CJ {
+                opcode,
+                funct3,
+                imm: CJImmediate::from_u16(instruction),
+            },
+            _ => panic!("{format:?} is not the format of a compressed instruction"),
+        }
+    }
+
+    /// Builds the instruction back into a `u16`.
+    pub fn encode(&self) -> u16 {
+        let x: u32 = match self {
+            RawCInstruction::CR {
+                opcode,
+                funct4,
+                rd,
+                rs2,
+            } => (*funct4 as u32) << 12 | (*rd as u32) << 7 | (*rs2 as u32) << 2 | *opcode as u32,
+            RawCInstruction::CI {
+                opcode,
+                funct3,
+                rd,
+                imm,
+            } => (*funct3 as u32) << 13 | imm.to_u16() as u32 | (*rd as u32) << 7 | *opcode as u32,
+            RawCInstruction::CSS {
+                opcode,
+                funct3,
+                rs2,
+                imm,
+            } => (*funct3 as u32) << 13 | (*imm as u32) << 7 | (*rs2 as u32) << 2 | *opcode as u32,
+            RawCInstruction::CIW {
+                opcode,
+                funct3,
+                rd,
+                imm,
+            } => (*funct3 as u32) << 13 | (*imm as u32) << 5 | (*rd as u32) << 2 | *opcode as u32,
+            RawCInstruction::CL {
+                opcode,
+                funct3,
+                rd: r2,
+                rs1,
+                imm,
+            }
+            | RawCInstruction::CS {
+                opcode,
+                funct3,
+                rs1,
+                rs2: r2,
+                imm,
+            } => {
+                (*funct3 as u32) << 13
+                    | (*imm as u32 >> 2) << 10
+                    | (*rs1 as u32) << 7
+                    | (*imm as u32 & 0b11) << 5
+                    | (*r2 as u32) << 2
+                    | *opcode as u32
+            }
+            RawCInstruction::CA {
+                opcode,
+                funct6,
+                rd,
+                funct2,
+                rs2,
+            } => {
+                (*funct6 as u32) << 10
+                    | (*rd as u32) << 7
+                    | (*funct2 as u32) << 5
+                    | (*rs2 as u32) << 2
+                    | *opcode as u32
+            }
+            RawCInstruction::CB {
+                opcode,
+                funct3,
+                rs1,
+                imm,
+            } => (*funct3 as u32) << 13 | imm.to_u16() as u32 | (*rs1 as u32) << 7 | *opcode as u32,
+            RawCInstruction::CJ {
+                opcode,
+                funct3,
+                imm,
+            } => (*funct3 as u32) << 13 | imm.to_u16() as u32 | *opcode as u32,
+        };
+        x as u16
+    }
+
+    pub fn format(&self) -> Format {
+        match self {
+            RawCInstruction::CR { .. } => Format::CR,
+            RawCInstruction::CI { .. } => Format::CI,
+            RawCInstruction::CSS { .. } => Format::CSS,
+            RawCInstruction::CIW { .. } => Format::CIW,
+            RawCInstruction::CL { .. } => Format::CL,
+            RawCInstruction::CS { .. } => Format::CS,
+            RawCInstruction::CA { .. } => Format::CA,
+            RawCInstruction::CB { .. } => Format::CB,
+            RawCInstruction::CJ { .. } => Format::CJ,
+        }
+    }
+
+    pub fn opcode(&self) -> u8 {
+        match self {
+            RawCInstruction::CR { opcode, .. }
+            | RawCInstruction::CI { opcode, .. }
+            | RawCInstruction::CSS { opcode, .. }
+            | RawCInstruction::CIW { opcode, .. }
+            | RawCInstruction::CL { opcode, .. }
+            | RawCInstruction::CS { opcode, .. }
+            | RawCInstruction::CA { opcode, .. }
+            | RawCInstruction::CB { opcode, .. }
+            | RawCInstruction::CJ { opcode, .. } => *opcode,
+        }
+    }
+
+    /// Returns the value of the immediate, with its bits reassembled and sign extended where the format defines how.
+    pub fn imm(&self) -> Option<i64> {
+        match self {
+            RawCInstruction::CI { imm, .. } => Some(imm.val()),
+            RawCInstruction::CB { imm, .. } => Some(imm.val()),
+            RawCInstruction::CJ { imm, .. } => Some(imm.val()),
+            RawCInstruction::CSS { imm, .. }
+            | RawCInstruction::CIW { imm, .. }
+            | RawCInstruction::CL { imm, .. }
+            | RawCInstruction::CS { imm, .. } => Some(*imm as i64),
+            RawCInstruction::CR { .. } | RawCInstruction::CA { .. } => None,
+        }
+    }
+}
+
+fn reg(r: &u8) -> IRegister {
+    IRegister::from_int(*r as u32)
+}
+
+/// Expands a 3-bit compressed register field into a register name
+fn creg(r: &u8) -> IRegister {
+    IRegister::from_int(*r as u32 + 8)
+}
+
+/// Writes the fields using the `.insn` directive
+impl Display for RawInstruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RawInstruction::R {
+                opcode,
+                rd,
+                funct3,
+                rs1,
+                rs2,
+                funct7,
+            } => write!(
+                f,
+                ".insn r 0x{opcode:02x},{funct3},0x{funct7:02x},{},{},{}",
+                reg(rd),
+                reg(rs1),
+                reg(rs2)
+            ),
+            RawInstruction::R4 {
+                opcode,
+                rd,
+                funct3,
+                rs1,
+                rs2,
+                fmt,
+                rs3,
+            } => write!(
+                f,
+                ".insn r4 0x{opcode:02x},{funct3},{fmt},{},{},{},{}",
+                reg(rd),
+                reg(rs1),
+                reg(rs2),
+                reg(rs3)
+            ),
+            RawInstruction::I {
+                opcode,
+                rd,
+                funct3,
+                rs1,
+                imm,
+            } => write!(
+                f,
+                ".insn i 0x{opcode:02x},{funct3},{},{},{imm}",
+                reg(rd),
+                reg(rs1)
+            ),
+            RawInstruction::S {
+                opcode,
+                funct3,
+                rs1,
+                rs2,
+                imm,
+            } => write!(
+                f,
+                ".insn s 0x{opcode:02x},{funct3},{},{imm}({})",
+                reg(rs2),
+                reg(rs1)
+            ),
+            RawInstruction::B {
+                opcode,
+                funct3,
+                rs1,
+                rs2,
+                imm,
+            } => write!(
+                f,
+                ".insn b 0x{opcode:02x},{funct3},{},{},{imm}",
+                reg(rs1),
+                reg(rs2)
+            ),
+            RawInstruction::U { opcode, rd, imm } => {
+                write!(f, ".insn u 0x{opcode:02x},{},{imm}", reg(rd))
+            }
+            RawInstruction::J { opcode, rd, imm } => {
+                write!(f, ".insn j 0x{opcode:02x},{},{imm}", reg(rd))
+            }
+        }
+    }
+}
+
+/// Writes the fields using the `.insn` directive
+impl Display for RawCInstruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RawCInstruction::CR {
+                opcode,
+                funct4,
+                rd,
+                rs2,
+            } => write!(f, ".insn cr {opcode},{funct4},{},{}", reg(rd), reg(rs2)),
+            RawCInstruction::CI {
+                opcode,
+                funct3,
+                rd,
+                imm,
+            } => write!(f, ".insn ci {opcode},{funct3},{},{imm}", reg(rd)),
+            RawCInstruction::CSS {
+                opcode,
+                funct3,
+                rs2,
+                imm,
+            } => write!(f, ".insn css {opcode},{funct3},{},{imm}", reg(rs2)),
+            RawCInstruction::CIW {
+                opcode,
+                funct3,
+                rd,
+                imm,
+            } => write!(f, ".insn ciw {opcode},{funct3},{},{imm}", creg(rd)),
+            RawCInstruction::CL {
+                opcode,
+                funct3,
+                rd,
+                rs1,
+                imm,
+            } => write!(
+                f,
+                ".insn cl {opcode},{funct3},{},{imm}({})",
+                creg(rd),
+                creg(rs1)
+            ),
+            RawCInstruction::CS {
+                opcode,
+                funct3,
+                rs1,
+                rs2,
+                imm,
+            } => write!(
+                f,
+                ".insn cs {opcode},{funct3},{},{imm}({})",
+                creg(rs2),
+                creg(rs1)
+            ),
+            RawCInstruction::CA {
+                opcode,
+                funct6,
+                rd,
+                funct2,
+                rs2,
+            } => write!(
+                f,
+                ".insn ca {opcode},{funct6},{funct2},{},{}",
+                creg(rd),
+                creg(rs2)
+            ),
+            RawCInstruction::CB {
+                opcode,
+                funct3,
+                rs1,
+                imm,
+            } => write!(f, ".insn cb {opcode},{funct3},{},{imm}", creg(rs1)),
+            RawCInstruction::CJ {
+                opcode,
+                funct3,
+                imm,
+            } => write!(f, ".insn cj {opcode},{funct3},{imm}"),
+        }
+    }
+}
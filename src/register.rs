@@ -1,9 +1,14 @@
 use alloc::{
+    borrow::ToOwned,
     fmt::{Display, Formatter},
     format,
     string::String,
+    vec,
+    vec::Vec,
 };
 
+use crate::isa::Xlen;
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum IRegister {
     Zero = 0,
@@ -84,41 +89,41 @@ impl Display for IRegister {
     }
 }
 
-impl Into<u32> for IRegister {
-    fn into(self) -> u32 {
-        match self {
-            Self::Zero => 0,
-            Self::ReturnAddress => 1,
-            Self::StackPointer => 2,
-            Self::GlobalPointer => 3,
-            Self::ThreadPointer => 4,
-            Self::T0 => 5,
-            Self::T1 => 6,
-            Self::T2 => 7,
-            Self::FramePointer => 8,
-            Self::S1 => 9,
-            Self::A0 => 10,
-            Self::A1 => 11,
-            Self::A2 => 12,
-            Self::A3 => 13,
-            Self::A4 => 14,
-            Self::A5 => 15,
-            Self::A6 => 16,
-            Self::A7 => 17,
-            Self::S2 => 18,
-            Self::S3 => 19,
-            Self::S4 => 20,
-            Self::S5 => 21,
-            Self::S6 => 22,
-            Self::S7 => 23,
-            Self::S8 => 24,
-            Self::S9 => 25,
-            Self::S10 => 26,
-            Self::S11 => 27,
-            Self::T3 => 28,
-            Self::T4 => 29,
-            Self::T5 => 30,
-            Self::T6 => 31,
+impl From<IRegister> for u32 {
+    fn from(value: IRegister) -> Self {
+        match value {
+            IRegister::Zero => 0,
+            IRegister::ReturnAddress => 1,
+            IRegister::StackPointer => 2,
+            IRegister::GlobalPointer => 3,
+            IRegister::ThreadPointer => 4,
+            IRegister::T0 => 5,
+            IRegister::T1 => 6,
+            IRegister::T2 => 7,
+            IRegister::FramePointer => 8,
+            IRegister::S1 => 9,
+            IRegister::A0 => 10,
+            IRegister::A1 => 11,
+            IRegister::A2 => 12,
+            IRegister::A3 => 13,
+            IRegister::A4 => 14,
+            IRegister::A5 => 15,
+            IRegister::A6 => 16,
+            IRegister::A7 => 17,
+            IRegister::S2 => 18,
+            IRegister::S3 => 19,
+            IRegister::S4 => 20,
+            IRegister::S5 => 21,
+            IRegister::S6 => 22,
+            IRegister::S7 => 23,
+            IRegister::S8 => 24,
+            IRegister::S9 => 25,
+            IRegister::S10 => 26,
+            IRegister::S11 => 27,
+            IRegister::T3 => 28,
+            IRegister::T4 => 29,
+            IRegister::T5 => 30,
+            IRegister::T6 => 31,
         }
     }
 }
@@ -207,52 +212,59 @@ impl IRegister {
 
     pub fn rd(self) -> u32 {
         let v: u32 = self.into();
-        return v << 7;
+        v << 7
     }
     pub fn rs1(self) -> u32 {
         let v: u32 = self.into();
-        return v << 15;
+        v << 15
     }
     pub fn rs2(self) -> u32 {
         let v: u32 = self.into();
-        return v << 20;
+        v << 20
     }
 }
 
+/// A float register.
+///
+/// When float instructions use the integer registers (Zfinx, Zdinx, Zhinx), their register fields name integer
+/// registers instead. Decoding and assembling for such a configuration gives those fields as `Integer`, which displays
+/// with the integer register name.
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum FRegister {
-    FT0 = 0,
-    FT1 = 1,
-    FT2 = 2,
-    FT3 = 3,
-    FT4 = 4,
-    FT5 = 5,
-    FT6 = 6,
-    FT7 = 7,
-    FS0 = 8,
-    FS1 = 9,
-    FA0 = 10,
-    FA1 = 11,
-    FA2 = 12,
-    FA3 = 13,
-    FA4 = 14,
-    FA5 = 15,
-    FA6 = 16,
-    FA7 = 17,
-    FS2 = 18,
-    FS3 = 19,
-    FS4 = 20,
-    FS5 = 21,
-    FS6 = 22,
-    FS7 = 23,
-    FS8 = 24,
-    FS9 = 25,
-    FS10 = 26,
-    FS11 = 27,
-    FT8 = 28,
-    FT9 = 29,
-    FT10 = 30,
-    FT11 = 31,
+    FT0,
+    FT1,
+    FT2,
+    FT3,
+    FT4,
+    FT5,
+    FT6,
+    FT7,
+    FS0,
+    FS1,
+    FA0,
+    FA1,
+    FA2,
+    FA3,
+    FA4,
+    FA5,
+    FA6,
+    FA7,
+    FS2,
+    FS3,
+    FS4,
+    FS5,
+    FS6,
+    FS7,
+    FS8,
+    FS9,
+    FS10,
+    FS11,
+    FT8,
+    FT9,
+    FT10,
+    FT11,
+    /// An integer register used for a float operand
+    Integer(IRegister),
 }
 
 impl Display for FRegister {
@@ -293,6 +305,7 @@ impl Display for FRegister {
                 FRegister::FT9 => "ft9",
                 FRegister::FT10 => "ft10",
                 FRegister::FT11 => "ft11",
+                FRegister::Integer(r) => return write!(f, "{r}"),
             }
         )
     }
@@ -382,9 +395,9 @@ impl TryFrom<&str> for FRegister {
     }
 }
 
-impl Into<u32> for FRegister {
-    fn into(self) -> u32 {
-        match self {
+impl From<FRegister> for u32 {
+    fn from(value: FRegister) -> Self {
+        match value {
             FRegister::FT0 => 0,
             FRegister::FT1 => 1,
             FRegister::FT2 => 2,
@@ -417,6 +430,7 @@ impl Into<u32> for FRegister {
             FRegister::FT9 => 29,
             FRegister::FT10 => 30,
             FRegister::FT11 => 31,
+            FRegister::Integer(r) => r.into(),
         }
     }
 }
@@ -424,19 +438,168 @@ impl Into<u32> for FRegister {
 impl FRegister {
     pub fn rd(self) -> u32 {
         let v: u32 = self.into();
-        return v << 7;
+        v << 7
     }
     pub fn rs1(self) -> u32 {
         let v: u32 = self.into();
-        return v << 15;
+        v << 15
     }
     pub fn rs2(self) -> u32 {
         let v: u32 = self.into();
-        return v << 20;
+        v << 20
     }
     pub fn rs3(self) -> u32 {
         let v: u32 = self.into();
-        return v << 27;
+        v << 27
+    }
+}
+
+/// A vector register
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum VRegister {
+    V0 = 0,
+    V1 = 1,
+    V2 = 2,
+    V3 = 3,
+    V4 = 4,
+    V5 = 5,
+    V6 = 6,
+    V7 = 7,
+    V8 = 8,
+    V9 = 9,
+    V10 = 10,
+    V11 = 11,
+    V12 = 12,
+    V13 = 13,
+    V14 = 14,
+    V15 = 15,
+    V16 = 16,
+    V17 = 17,
+    V18 = 18,
+    V19 = 19,
+    V20 = 20,
+    V21 = 21,
+    V22 = 22,
+    V23 = 23,
+    V24 = 24,
+    V25 = 25,
+    V26 = 26,
+    V27 = 27,
+    V28 = 28,
+    V29 = 29,
+    V30 = 30,
+    V31 = 31,
+}
+
+impl Display for VRegister {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), alloc::fmt::Error> {
+        let v: u32 = (*self).into();
+        write!(f, "v{v}")
+    }
+}
+
+impl TryFrom<u32> for VRegister {
+    type Error = String;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::V0),
+            1 => Ok(Self::V1),
+            2 => Ok(Self::V2),
+            3 => Ok(Self::V3),
+            4 => Ok(Self::V4),
+            5 => Ok(Self::V5),
+            6 => Ok(Self::V6),
+            7 => Ok(Self::V7),
+            8 => Ok(Self::V8),
+            9 => Ok(Self::V9),
+            10 => Ok(Self::V10),
+            11 => Ok(Self::V11),
+            12 => Ok(Self::V12),
+            13 => Ok(Self::V13),
+            14 => Ok(Self::V14),
+            15 => Ok(Self::V15),
+            16 => Ok(Self::V16),
+            17 => Ok(Self::V17),
+            18 => Ok(Self::V18),
+            19 => Ok(Self::V19),
+            20 => Ok(Self::V20),
+            21 => Ok(Self::V21),
+            22 => Ok(Self::V22),
+            23 => Ok(Self::V23),
+            24 => Ok(Self::V24),
+            25 => Ok(Self::V25),
+            26 => Ok(Self::V26),
+            27 => Ok(Self::V27),
+            28 => Ok(Self::V28),
+            29 => Ok(Self::V29),
+            30 => Ok(Self::V30),
+            31 => Ok(Self::V31),
+            x => Err(format!("converted invalid integer to vector register {}", x)),
+        }
+    }
+}
+
+impl TryFrom<&str> for VRegister {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "v0" => Ok(Self::V0),
+            "v1" => Ok(Self::V1),
+            "v2" => Ok(Self::V2),
+            "v3" => Ok(Self::V3),
+            "v4" => Ok(Self::V4),
+            "v5" => Ok(Self::V5),
+            "v6" => Ok(Self::V6),
+            "v7" => Ok(Self::V7),
+            "v8" => Ok(Self::V8),
+            "v9" => Ok(Self::V9),
+            "v10" => Ok(Self::V10),
+            "v11" => Ok(Self::V11),
+            "v12" => Ok(Self::V12),
+            "v13" => Ok(Self::V13),
+            "v14" => Ok(Self::V14),
+            "v15" => Ok(Self::V15),
+            "v16" => Ok(Self::V16),
+            "v17" => Ok(Self::V17),
+            "v18" => Ok(Self::V18),
+            "v19" => Ok(Self::V19),
+            "v20" => Ok(Self::V20),
+            "v21" => Ok(Self::V21),
+            "v22" => Ok(Self::V22),
+            "v23" => Ok(Self::V23),
+            "v24" => Ok(Self::V24),
+            "v25" => Ok(Self::V25),
+            "v26" => Ok(Self::V26),
+            "v27" => Ok(Self::V27),
+            "v28" => Ok(Self::V28),
+            "v29" => Ok(Self::V29),
+            "v30" => Ok(Self::V30),
+            "v31" => Ok(Self::V31),
+            x => Err(format!("converted invalid str to vector register {}", x)),
+        }
+    }
+}
+
+impl From<VRegister> for u32 {
+    fn from(value: VRegister) -> Self {
+        value as u32
+    }
+}
+
+impl VRegister {
+    pub fn rd(self) -> u32 {
+        let v: u32 = self.into();
+        v << 7
+    }
+    pub fn rs1(self) -> u32 {
+        let v: u32 = self.into();
+        v << 15
+    }
+    pub fn rs2(self) -> u32 {
+        let v: u32 = self.into();
+        v << 20
     }
 }
 
@@ -494,6 +657,24 @@ impl TryFrom<&str> for CIRegister {
     }
 }
 
+impl TryFrom<IRegister> for CIRegister {
+    type Error = String;
+
+    fn try_from(value: IRegister) -> Result<Self, Self::Error> {
+        match value {
+            IRegister::FramePointer => Ok(CIRegister::FramePointer),
+            IRegister::S1 => Ok(CIRegister::S1),
+            IRegister::A0 => Ok(CIRegister::A0),
+            IRegister::A1 => Ok(CIRegister::A1),
+            IRegister::A2 => Ok(CIRegister::A2),
+            IRegister::A3 => Ok(CIRegister::A3),
+            IRegister::A4 => Ok(CIRegister::A4),
+            IRegister::A5 => Ok(CIRegister::A5),
+            x => Err(format!("{x} is not available in compressed instructions")),
+        }
+    }
+}
+
 impl CIRegister {
     pub fn expand(&self) -> IRegister {
         match self {
@@ -509,11 +690,11 @@ impl CIRegister {
     }
 
     pub fn rs2(&self) -> u16 {
-        return (*self as u16) << 2;
+        (*self as u16) << 2
     }
 
     pub fn rs1(&self) -> u16 {
-        return (*self as u16) << 7;
+        (*self as u16) << 7
     }
 }
 
@@ -590,6 +771,24 @@ impl TryFrom<&str> for CFRegister {
     }
 }
 
+impl TryFrom<FRegister> for CFRegister {
+    type Error = String;
+
+    fn try_from(value: FRegister) -> Result<Self, Self::Error> {
+        match value {
+            FRegister::FS0 => Ok(CFRegister::FS0),
+            FRegister::FS1 => Ok(CFRegister::FS1),
+            FRegister::FA0 => Ok(CFRegister::FA0),
+            FRegister::FA1 => Ok(CFRegister::FA1),
+            FRegister::FA2 => Ok(CFRegister::FA2),
+            FRegister::FA3 => Ok(CFRegister::FA3),
+            FRegister::FA4 => Ok(CFRegister::FA4),
+            FRegister::FA5 => Ok(CFRegister::FA5),
+            x => Err(format!("{x} is not available in compressed instructions")),
+        }
+    }
+}
+
 impl CFRegister {
     pub fn expand(&self) -> FRegister {
         match self {
@@ -605,11 +804,11 @@ impl CFRegister {
     }
 
     pub fn rs2(&self) -> u16 {
-        return (*self as u16) << 2;
+        (*self as u16) << 2
     }
 
     pub fn rs1(&self) -> u16 {
-        return (*self as u16) << 7;
+        (*self as u16) << 7
     }
 }
 
@@ -631,3 +830,282 @@ impl Display for CFRegister {
         )
     }
 }
+
+/// One of the saved registers available in the Zcmp register move instructions
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CSRegister {
+    FramePointer,
+    S1,
+    S2,
+    S3,
+    S4,
+    S5,
+    S6,
+    S7,
+}
+
+impl From<u16> for CSRegister {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => Self::FramePointer,
+            1 => Self::S1,
+            2 => Self::S2,
+            3 => Self::S3,
+            4 => Self::S4,
+            5 => Self::S5,
+            6 => Self::S6,
+            7 => Self::S7,
+            x => panic!(
+                "converted invalid integer to saved register in compressed instruction: {}",
+                x
+            ),
+        }
+    }
+}
+
+impl TryFrom<&str> for CSRegister {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "fp" => Ok(Self::FramePointer),
+            "s0" => Ok(Self::FramePointer),
+            "s1" => Ok(Self::S1),
+            "s2" => Ok(Self::S2),
+            "s3" => Ok(Self::S3),
+            "s4" => Ok(Self::S4),
+            "s5" => Ok(Self::S5),
+            "s6" => Ok(Self::S6),
+            "s7" => Ok(Self::S7),
+            x => Err(format!(
+                "converted invalid str to saved register in compressed instruction: {}",
+                x
+            )),
+        }
+    }
+}
+
+impl TryFrom<IRegister> for CSRegister {
+    type Error = String;
+
+    fn try_from(value: IRegister) -> Result<Self, Self::Error> {
+        match value {
+            IRegister::FramePointer => Ok(CSRegister::FramePointer),
+            IRegister::S1 => Ok(CSRegister::S1),
+            IRegister::S2 => Ok(CSRegister::S2),
+            IRegister::S3 => Ok(CSRegister::S3),
+            IRegister::S4 => Ok(CSRegister::S4),
+            IRegister::S5 => Ok(CSRegister::S5),
+            IRegister::S6 => Ok(CSRegister::S6),
+            IRegister::S7 => Ok(CSRegister::S7),
+            x => Err(format!(
+                "{x} is not available in the Zcmp register move instructions"
+            )),
+        }
+    }
+}
+
+impl CSRegister {
+    pub fn expand(&self) -> IRegister {
+        match self {
+            CSRegister::FramePointer => IRegister::FramePointer,
+            CSRegister::S1 => IRegister::S1,
+            CSRegister::S2 => IRegister::S2,
+            CSRegister::S3 => IRegister::S3,
+            CSRegister::S4 => IRegister::S4,
+            CSRegister::S5 => IRegister::S5,
+            CSRegister::S6 => IRegister::S6,
+            CSRegister::S7 => IRegister::S7,
+        }
+    }
+
+    pub fn rs2(&self) -> u16 {
+        (*self as u16) << 2
+    }
+
+    pub fn rs1(&self) -> u16 {
+        (*self as u16) << 7
+    }
+}
+
+impl Display for CSRegister {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), alloc::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            match self {
+                CSRegister::FramePointer => "s0",
+                CSRegister::S1 => "s1",
+                CSRegister::S2 => "s2",
+                CSRegister::S3 => "s3",
+                CSRegister::S4 => "s4",
+                CSRegister::S5 => "s5",
+                CSRegister::S6 => "s6",
+                CSRegister::S7 => "s7",
+            }
+        )
+    }
+}
+
+/// The `{ra, s0-sN}` register list of the Zcmp push and pop instructions, stored as its `rlist` encoding.
+///
+/// The encodings 0-3 are reserved, 4 is `{ra}`, 5 is `{ra, s0}` and 6-14 are `{ra, s0-s1}` to `{ra, s0-s9}`. The list
+/// `{ra, s0-s10}` can't be encoded, so 15 is `{ra, s0-s11}`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RegisterList(u8);
+
+impl RegisterList {
+    /// Creates a register list from its `rlist` encoding.
+    pub fn from_rlist(rlist: u8) -> Result<Self, String> {
+        if (4..=15).contains(&rlist) {
+            Ok(RegisterList(rlist))
+        } else {
+            Err(format!("reserved register list encoding: {rlist}"))
+        }
+    }
+
+    /// Extracts the register list from a Zcmp push or pop instruction.
+    pub fn from_u16(x: u16) -> Result<Self, String> {
+        RegisterList::from_rlist(((x >> 4) & 0b1111) as u8)
+    }
+
+    /// Returns the register list in the position used by the push and pop instructions.
+    pub fn to_u16(&self) -> u16 {
+        (self.0 as u16) << 4
+    }
+
+    /// Returns the number of registers in the list.
+    pub fn count(&self) -> u32 {
+        match self.0 {
+            15 => 13,
+            x => x as u32 - 3,
+        }
+    }
+
+    /// Returns the registers in the list, in the order they are saved below the stack pointer.
+    pub fn registers(&self) -> Vec<IRegister> {
+        let saved = [
+            IRegister::FramePointer,
+            IRegister::S1,
+            IRegister::S2,
+            IRegister::S3,
+            IRegister::S4,
+            IRegister::S5,
+            IRegister::S6,
+            IRegister::S7,
+            IRegister::S8,
+            IRegister::S9,
+            IRegister::S10,
+            IRegister::S11,
+        ];
+        let mut registers = vec![IRegister::ReturnAddress];
+        registers.extend_from_slice(&saved[..self.count() as usize - 1]);
+        registers
+    }
+
+    /// Returns the smallest stack adjustment for this list, which is the space needed to save the registers rounded up
+    /// to keep the stack pointer 16 byte aligned.
+    pub fn base_stack_adjustment(&self, xlen: Xlen) -> u32 {
+        let bytes = match xlen {
+            Xlen::Rv32 => 4,
+            Xlen::Rv64 => 8,
+        };
+        (self.count() * bytes).div_ceil(16) * 16
+    }
+}
+
+impl Display for RegisterList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), alloc::fmt::Error> {
+        match self.0 {
+            4 => write!(f, "{{ra}}"),
+            5 => write!(f, "{{ra,s0}}"),
+            15 => write!(f, "{{ra,s0-s11}}"),
+            x => write!(f, "{{ra,s0-s{}}}", x - 5),
+        }
+    }
+}
+
+impl TryFrom<&str> for RegisterList {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let inner = value
+            .trim()
+            .strip_prefix('{')
+            .and_then(|v| v.strip_suffix('}'))
+            .ok_or_else(|| format!("register list must be surrounded by braces: {value}"))?;
+        let parts: Vec<&str> = inner.split(',').map(|p| p.trim()).collect();
+        if IRegister::try_from(parts[0])? != IRegister::ReturnAddress {
+            return Err(format!("register list must start with ra: {value}"));
+        }
+        match parts[1..] {
+            [] => RegisterList::from_rlist(4),
+            [saved] => {
+                let (first, last) = saved.split_once('-').unwrap_or((saved, saved));
+                if IRegister::try_from(first.trim())? != IRegister::FramePointer {
+                    return Err(format!("saved registers must start at s0: {value}"));
+                }
+                match IRegister::try_from(last.trim())? {
+                    IRegister::FramePointer => RegisterList::from_rlist(5),
+                    IRegister::S1 => RegisterList::from_rlist(6),
+                    IRegister::S11 => RegisterList::from_rlist(15),
+                    IRegister::S10 => Err("register list can't end at s10".to_owned()),
+                    // s2 to s9 are x18 to x25
+                    r if (18..=25).contains(&u32::from(r)) => {
+                        RegisterList::from_rlist((u32::from(r) - 11) as u8)
+                    }
+                    _ => Err(format!(
+                        "saved registers must end at a saved register: {value}"
+                    )),
+                }
+            }
+            _ => Err(format!("invalid register list: {value}")),
+        }
+    }
+}
+
+/// Any register that an instruction can read or write, including the control and status registers that are accessed
+/// implicitly.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Register {
+    I(IRegister),
+    F(FRegister),
+    V(VRegister),
+    /// A control and status register, by its 12-bit address
+    Csr(u16),
+}
+
+impl Register {
+    /// The accrued floating point exception flags, also written through `fcsr`
+    pub const FFLAGS: Register = Register::Csr(0x001);
+    /// The dynamic floating point rounding mode, also read through `fcsr`
+    pub const FRM: Register = Register::Csr(0x002);
+    /// The floating point control and status register, made up of `frm` and `fflags`
+    pub const FCSR: Register = Register::Csr(0x003);
+    /// The shadow stack pointer from Zicfiss
+    pub const SSP: Register = Register::Csr(0x011);
+    /// The jump vector table base from Zcmt
+    pub const JVT: Register = Register::Csr(0x017);
+    /// The vector length
+    pub const VL: Register = Register::Csr(0xc20);
+    /// The vector type
+    pub const VTYPE: Register = Register::Csr(0xc21);
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), alloc::fmt::Error> {
+        match self {
+            Register::I(r) => write!(f, "{r}"),
+            Register::F(r) => write!(f, "{r}"),
+            Register::V(r) => write!(f, "{r}"),
+            Register::Csr(0x001) => write!(f, "fflags"),
+            Register::Csr(0x002) => write!(f, "frm"),
+            Register::Csr(0x003) => write!(f, "fcsr"),
+            Register::Csr(0x011) => write!(f, "ssp"),
+            Register::Csr(0x017) => write!(f, "jvt"),
+            Register::Csr(0xc20) => write!(f, "vl"),
+            Register::Csr(0xc21) => write!(f, "vtype"),
+            Register::Csr(csr) => write!(f, "{csr:#05x}"),
+        }
+    }
+}
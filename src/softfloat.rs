@@ -0,0 +1,685 @@
+use core::cmp::Ordering;
+
+use crate::instruction::RoundingMode;
+
+/// Invalid operation
+pub const NV: u8 = 0b10000;
+/// Divide by zero
+pub const DZ: u8 = 0b01000;
+/// Overflow
+pub const OF: u8 = 0b00100;
+/// Underflow
+pub const UF: u8 = 0b00010;
+/// Inexact
+pub const NX: u8 = 0b00001;
+
+/// Resolves the rounding mode field of an instruction, replacing `DYN` with the mode held in `frm`.
+///
+/// Returns `None` if `frm` holds one of the reserved modes or `DYN`, which makes the instruction illegal.
+pub fn resolve_rounding_mode(rm: RoundingMode, frm: u8) -> Option<RoundingMode> {
+    match rm {
+        RoundingMode::DYN => RoundingMode::from_int(frm as u32)
+            .ok()
+            .filter(|rm| *rm != RoundingMode::DYN),
+        rm => Some(rm),
+    }
+}
+
+/// The widths of the fields of an IEEE-754 binary format
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Format {
+    exp_bits: u32,
+    frac_bits: u32,
+}
+
+/// Single precision, used by the F extension
+pub const F32: Format = Format {
+    exp_bits: 8,
+    frac_bits: 23,
+};
+
+/// Double precision, used by the D extension
+pub const F64: Format = Format {
+    exp_bits: 11,
+    frac_bits: 52,
+};
+
+impl Format {
+    fn bias(self) -> i32 {
+        (1 << (self.exp_bits - 1)) - 1
+    }
+
+    /// The exponent of the smallest normal number
+    fn emin(self) -> i32 {
+        1 - self.bias()
+    }
+
+    fn max_exp(self) -> u64 {
+        (1 << self.exp_bits) - 1
+    }
+
+    fn sign_bit(self) -> u64 {
+        1 << (self.exp_bits + self.frac_bits)
+    }
+
+    fn frac_mask(self) -> u64 {
+        (1 << self.frac_bits) - 1
+    }
+
+    /// The quiet NaN that RISC-V returns from every operation that produces a NaN
+    pub fn canonical_nan(self) -> u64 {
+        self.max_exp() << self.frac_bits | 1 << (self.frac_bits - 1)
+    }
+
+    fn zero(self, sign: bool) -> u64 {
+        if sign { self.sign_bit() } else { 0 }
+    }
+
+    fn infinity(self, sign: bool) -> u64 {
+        self.zero(sign) | self.max_exp() << self.frac_bits
+    }
+
+    fn max_finite(self, sign: bool) -> u64 {
+        self.infinity(sign) - 1
+    }
+
+    fn negate(self, bits: u64) -> u64 {
+        bits ^ self.sign_bit()
+    }
+
+    fn unpack(self, bits: u64) -> Value {
+        let sign = bits & self.sign_bit() != 0;
+        let exp = (bits >> self.frac_bits) & self.max_exp();
+        let frac = bits & self.frac_mask();
+        if exp == self.max_exp() {
+            if frac == 0 {
+                Value::Infinite(sign)
+            } else {
+                Value::Nan {
+                    signaling: frac >> (self.frac_bits - 1) == 0,
+                }
+            }
+        } else if exp == 0 {
+            if frac == 0 {
+                Value::Zero(sign)
+            } else {
+                Value::Finite {
+                    sign,
+                    exp: self.emin() - self.frac_bits as i32,
+                    sig: frac as u128,
+                }
+            }
+        } else {
+            Value::Finite {
+                sign,
+                exp: exp as i32 - self.bias() - self.frac_bits as i32,
+                sig: (frac | 1 << self.frac_bits) as u128,
+            }
+        }
+    }
+
+    /// Rounds `(-1)^sign * sig * 2^exp` to the nearest value in the format according to `rm`.
+    ///
+    /// Underflow is detected after rounding, as RISC-V requires.
+    fn round(self, sign: bool, exp: i32, sig: u128, rm: RoundingMode, flags: &mut u8) -> u64 {
+        if sig == 0 {
+            return self.zero(sign);
+        }
+        let frac_bits = self.frac_bits as i32;
+        // the exponent of the leading bit
+        let e = exp + msb(sig);
+        // the exponent of the lowest bit of the result, which is fixed for subnormals
+        let mut lsb_exp = e.max(self.emin()) - frac_bits;
+        let (mut q, inexact) = shift_round(sig, lsb_exp - exp, sign, rm);
+        if q >> (frac_bits + 1) != 0 {
+            q >>= 1;
+            lsb_exp += 1;
+        }
+
+        let biased = (lsb_exp + frac_bits + self.bias()) as i64;
+        if biased >= self.max_exp() as i64 {
+            *flags |= OF | NX;
+            let infinite = match rm {
+                RoundingMode::RTZ => false,
+                RoundingMode::RDN => sign,
+                RoundingMode::RUP => !sign,
+                _ => true,
+            };
+            return if infinite {
+                self.infinity(sign)
+            } else {
+                self.max_finite(sign)
+            };
+        }
+
+        if inexact {
+            *flags |= NX;
+            // the result is tiny unless rounding with an unbounded exponent would have reached the smallest normal
+            if e < self.emin() {
+                let (unbounded, _) = shift_round(sig, e - frac_bits - exp, sign, rm);
+                if e != self.emin() - 1 || unbounded >> (frac_bits + 1) == 0 {
+                    *flags |= UF;
+                }
+            }
+        }
+        // the hidden bit of a normal q carries into the exponent field
+        self.zero(sign) + (((biased - 1) as u64) << self.frac_bits) + q as u64
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Value {
+    Nan { signaling: bool },
+    Infinite(bool),
+    Zero(bool),
+    Finite { sign: bool, exp: i32, sig: u128 },
+}
+
+fn msb(x: u128) -> i32 {
+    127 - x.leading_zeros() as i32
+}
+
+/// Shifts `sig` right by `shift` bits, rounding according to `rm`, and returns whether any bits were lost.
+fn shift_round(sig: u128, shift: i32, sign: bool, rm: RoundingMode) -> (u128, bool) {
+    if shift <= 0 {
+        return (sig << -shift, false);
+    }
+    let (q, half) = if shift > 128 {
+        (0, Ordering::Less)
+    } else if shift == 128 {
+        (0, sig.cmp(&(1 << 127)))
+    } else {
+        let rem = sig & ((1 << shift) - 1);
+        (sig >> shift, rem.cmp(&(1 << (shift - 1))))
+    };
+    let inexact = shift >= 128 && sig != 0 || shift < 128 && sig & ((1 << shift) - 1) != 0;
+    let increment = match rm {
+        RoundingMode::RNE => half == Ordering::Greater || half == Ordering::Equal && q & 1 == 1,
+        RoundingMode::RTZ => false,
+        RoundingMode::RDN => inexact && sign,
+        RoundingMode::RUP => inexact && !sign,
+        RoundingMode::RMM => half != Ordering::Less,
+        RoundingMode::DYN => {
+            unreachable!("the dynamic rounding mode must be resolved by the caller")
+        }
+    };
+    (q + increment as u128, inexact)
+}
+
+/// Shifts right, keeping a set bit at the bottom if any of the shifted out bits were set
+fn shift_sticky(x: u128, shift: i32) -> u128 {
+    if shift >= 128 {
+        (x != 0) as u128
+    } else {
+        x >> shift | (x & ((1 << shift) - 1) != 0) as u128
+    }
+}
+
+/// Adds two signed values exactly, apart from a sticky bit when their exponents are far apart.
+///
+/// The significands may have up to 106 bits, which is enough for a double precision product.
+fn add_exact(a: (bool, i32, u128), b: (bool, i32, u128)) -> (bool, i32, u128) {
+    let normalize = |(sign, exp, sig): (bool, i32, u128)| {
+        let shift = 110 - msb(sig);
+        (sign, exp - shift, sig << shift)
+    };
+    let (mut a, mut b) = (normalize(a), normalize(b));
+    if a.1 < b.1 {
+        core::mem::swap(&mut a, &mut b);
+    }
+    let diff = a.1 - b.1;
+    let exp = if diff <= 16 {
+        a.2 <<= diff;
+        b.1
+    } else {
+        b.2 = shift_sticky(b.2, diff);
+        a.1
+    };
+    if a.0 == b.0 {
+        (a.0, exp, a.2 + b.2)
+    } else if a.2 >= b.2 {
+        (a.0, exp, a.2 - b.2)
+    } else {
+        (b.0, exp, b.2 - a.2)
+    }
+}
+
+/// Returns the canonical NaN, raising the invalid flag if `invalid` is set
+fn nan(fmt: Format, invalid: bool, flags: &mut u8) -> u64 {
+    if invalid {
+        *flags |= NV;
+    }
+    fmt.canonical_nan()
+}
+
+fn is_signaling(v: Value) -> bool {
+    v == Value::Nan { signaling: true }
+}
+
+fn is_nan(v: Value) -> bool {
+    matches!(v, Value::Nan { .. })
+}
+
+/// Computes `a + b`. Adding infinities of opposite signs is invalid.
+pub fn add(fmt: Format, a: u64, b: u64, rm: RoundingMode, flags: &mut u8) -> u64 {
+    let (ua, ub) = (fmt.unpack(a), fmt.unpack(b));
+    match (ua, ub) {
+        _ if is_nan(ua) || is_nan(ub) => nan(fmt, is_signaling(ua) || is_signaling(ub), flags),
+        (Value::Infinite(sa), Value::Infinite(sb)) if sa != sb => nan(fmt, true, flags),
+        (Value::Infinite(sign), _) | (_, Value::Infinite(sign)) => fmt.infinity(sign),
+        (Value::Zero(sa), Value::Zero(sb)) => fmt.zero(if sa == sb {
+            sa
+        } else {
+            rm == RoundingMode::RDN
+        }),
+        (Value::Zero(_), _) => b,
+        (_, Value::Zero(_)) => a,
+        (
+            Value::Finite {
+                sign: sa,
+                exp: ea,
+                sig: siga,
+            },
+            Value::Finite {
+                sign: sb,
+                exp: eb,
+                sig: sigb,
+            },
+        ) => {
+            let (sign, exp, sig) = add_exact((sa, ea, siga), (sb, eb, sigb));
+            if sig == 0 {
+                fmt.zero(rm == RoundingMode::RDN)
+            } else {
+                fmt.round(sign, exp, sig, rm, flags)
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Computes `a - b`.
+pub fn sub(fmt: Format, a: u64, b: u64, rm: RoundingMode, flags: &mut u8) -> u64 {
+    add(fmt, a, fmt.negate(b), rm, flags)
+}
+
+/// Computes `a * b`. Multiplying zero by infinity is invalid.
+pub fn mul(fmt: Format, a: u64, b: u64, rm: RoundingMode, flags: &mut u8) -> u64 {
+    let (ua, ub) = (fmt.unpack(a), fmt.unpack(b));
+    match (ua, ub) {
+        _ if is_nan(ua) || is_nan(ub) => nan(fmt, is_signaling(ua) || is_signaling(ub), flags),
+        (Value::Infinite(_), Value::Zero(_)) | (Value::Zero(_), Value::Infinite(_)) => {
+            nan(fmt, true, flags)
+        }
+        (Value::Infinite(sa), Value::Infinite(sb))
+        | (Value::Infinite(sa), Value::Finite { sign: sb, .. })
+        | (Value::Finite { sign: sa, .. }, Value::Infinite(sb)) => fmt.infinity(sa != sb),
+        (Value::Zero(sa), Value::Zero(sb))
+        | (Value::Zero(sa), Value::Finite { sign: sb, .. })
+        | (Value::Finite { sign: sa, .. }, Value::Zero(sb)) => fmt.zero(sa != sb),
+        (
+            Value::Finite {
+                sign: sa,
+                exp: ea,
+                sig: siga,
+            },
+            Value::Finite {
+                sign: sb,
+                exp: eb,
+                sig: sigb,
+            },
+        ) => fmt.round(sa != sb, ea + eb, siga * sigb, rm, flags),
+        _ => unreachable!(),
+    }
+}
+
+/// Computes `a / b`. Dividing a finite non-zero value by zero raises the divide by zero flag.
+pub fn div(fmt: Format, a: u64, b: u64, rm: RoundingMode, flags: &mut u8) -> u64 {
+    let (ua, ub) = (fmt.unpack(a), fmt.unpack(b));
+    match (ua, ub) {
+        _ if is_nan(ua) || is_nan(ub) => nan(fmt, is_signaling(ua) || is_signaling(ub), flags),
+        (Value::Infinite(_), Value::Infinite(_)) | (Value::Zero(_), Value::Zero(_)) => {
+            nan(fmt, true, flags)
+        }
+        (Value::Infinite(sa), Value::Zero(sb))
+        | (Value::Infinite(sa), Value::Finite { sign: sb, .. }) => fmt.infinity(sa != sb),
+        (Value::Finite { sign: sa, .. }, Value::Zero(sb)) => {
+            *flags |= DZ;
+            fmt.infinity(sa != sb)
+        }
+        (Value::Zero(sa), Value::Infinite(sb))
+        | (Value::Zero(sa), Value::Finite { sign: sb, .. })
+        | (Value::Finite { sign: sa, .. }, Value::Infinite(sb)) => fmt.zero(sa != sb),
+        (
+            Value::Finite {
+                sign: sa,
+                exp: ea,
+                sig: siga,
+            },
+            Value::Finite {
+                sign: sb,
+                exp: eb,
+                sig: sigb,
+            },
+        ) => {
+            // leaves at least 72 bits in the quotient, so the remainder can be folded into a sticky bit
+            let shift = 125 - msb(siga);
+            let n = siga << shift;
+            let sig = (n / sigb) | !n.is_multiple_of(sigb) as u128;
+            fmt.round(sa != sb, ea - shift - eb, sig, rm, flags)
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Computes the square root. The square root of `-0.0` is `-0.0`, and of any other negative value is invalid.
+pub fn sqrt(fmt: Format, a: u64, rm: RoundingMode, flags: &mut u8) -> u64 {
+    match fmt.unpack(a) {
+        Value::Nan { signaling } => nan(fmt, signaling, flags),
+        Value::Zero(_) | Value::Infinite(false) => a,
+        Value::Infinite(true) | Value::Finite { sign: true, .. } => nan(fmt, true, flags),
+        Value::Finite {
+            sign: false,
+            exp,
+            sig,
+        } => {
+            // make the exponent even so it can be halved, leaving at least 61 bits in the root
+            let mut shift = 124 - msb(sig);
+            if (exp - shift) & 1 != 0 {
+                shift -= 1;
+            }
+            let (root, rem) = isqrt(sig << shift);
+            fmt.round(
+                false,
+                (exp - shift) / 2,
+                root | (rem != 0) as u128,
+                rm,
+                flags,
+            )
+        }
+    }
+}
+
+/// Returns the integer square root and the remainder
+fn isqrt(n: u128) -> (u128, u128) {
+    let mut rem = n;
+    let mut root = 0;
+    let mut bit = 1 << 126;
+    while bit > n {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    (root, rem)
+}
+
+/// Computes `a * b + c` with a single rounding.
+pub fn fma(fmt: Format, a: u64, b: u64, c: u64, rm: RoundingMode, flags: &mut u8) -> u64 {
+    let (ua, ub, uc) = (fmt.unpack(a), fmt.unpack(b), fmt.unpack(c));
+    let infinite_times_zero = matches!(
+        (ua, ub),
+        (Value::Infinite(_), Value::Zero(_)) | (Value::Zero(_), Value::Infinite(_))
+    );
+    // RISC-V raises the invalid flag for infinity times zero even when the addend is a quiet NaN
+    if is_nan(ua) || is_nan(ub) || is_nan(uc) || infinite_times_zero {
+        return nan(
+            fmt,
+            is_signaling(ua) || is_signaling(ub) || is_signaling(uc) || infinite_times_zero,
+            flags,
+        );
+    }
+    let sign = |v: Value| match v {
+        Value::Infinite(sign) | Value::Zero(sign) | Value::Finite { sign, .. } => sign,
+        Value::Nan { .. } => false,
+    };
+    let product_sign = sign(ua) != sign(ub);
+    if matches!(ua, Value::Infinite(_)) || matches!(ub, Value::Infinite(_)) {
+        return match uc {
+            Value::Infinite(sc) if sc != product_sign => nan(fmt, true, flags),
+            _ => fmt.infinity(product_sign),
+        };
+    }
+    match (ua, ub, uc) {
+        (_, _, Value::Infinite(sc)) => fmt.infinity(sc),
+        (Value::Zero(_), _, Value::Zero(sc)) | (_, Value::Zero(_), Value::Zero(sc)) => {
+            fmt.zero(if product_sign == sc {
+                sc
+            } else {
+                rm == RoundingMode::RDN
+            })
+        }
+        (Value::Zero(_), _, _) | (_, Value::Zero(_), _) => c,
+        (
+            Value::Finite {
+                exp: ea, sig: siga, ..
+            },
+            Value::Finite {
+                exp: eb, sig: sigb, ..
+            },
+            uc,
+        ) => {
+            let product = (product_sign, ea + eb, siga * sigb);
+            let (sign, exp, sig) = match uc {
+                Value::Finite { sign, exp, sig } => add_exact(product, (sign, exp, sig)),
+                _ => product,
+            };
+            if sig == 0 {
+                fmt.zero(rm == RoundingMode::RDN)
+            } else {
+                fmt.round(sign, exp, sig, rm, flags)
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Converts between formats, rounding if the destination is narrower.
+pub fn convert(from: Format, to: Format, a: u64, rm: RoundingMode, flags: &mut u8) -> u64 {
+    match from.unpack(a) {
+        Value::Nan { signaling } => nan(to, signaling, flags),
+        Value::Infinite(sign) => to.infinity(sign),
+        Value::Zero(sign) => to.zero(sign),
+        Value::Finite { sign, exp, sig } => to.round(sign, exp, sig, rm, flags),
+    }
+}
+
+/// Converts an integer held in the low `width` bits of `value` to a float.
+pub fn from_int(
+    fmt: Format,
+    value: u64,
+    signed: bool,
+    width: u32,
+    rm: RoundingMode,
+    flags: &mut u8,
+) -> u64 {
+    let value = if width == 32 {
+        if signed {
+            value as i32 as i64 as u64
+        } else {
+            value as u32 as u64
+        }
+    } else {
+        value
+    };
+    let sign = signed && (value as i64) < 0;
+    let magnitude = if sign {
+        (value as i64).unsigned_abs()
+    } else {
+        value
+    };
+    fmt.round(sign, 0, magnitude as u128, rm, flags)
+}
+
+/// Converts a float to a `width`-bit integer, saturating if it's out of range.
+///
+/// NaNs convert to the largest value. The result is sign-extended to 64 bits, even for unsigned conversions, which
+/// is how RV64 writes 32-bit results.
+pub fn to_int(
+    fmt: Format,
+    a: u64,
+    signed: bool,
+    width: u32,
+    rm: RoundingMode,
+    flags: &mut u8,
+) -> u64 {
+    let max = if signed {
+        (1u128 << (width - 1)) - 1
+    } else {
+        (1u128 << width) - 1
+    };
+    let saturate = |sign: bool, flags: &mut u8| {
+        *flags |= NV;
+        match (sign, signed) {
+            (true, true) => (max + 1).wrapping_neg() as u64,
+            (true, false) => 0,
+            (false, _) => max as u64,
+        }
+    };
+    let result = match fmt.unpack(a) {
+        Value::Nan { .. } => saturate(false, flags),
+        Value::Infinite(sign) => saturate(sign, flags),
+        Value::Zero(_) => 0,
+        Value::Finite { sign, exp, sig } => {
+            let (q, inexact) = if exp > 64 {
+                (u128::MAX, false)
+            } else {
+                shift_round(sig, -exp, sign, rm)
+            };
+            let limit = match (sign, signed) {
+                (true, true) => max + 1,
+                (true, false) => 0,
+                (false, _) => max,
+            };
+            if q > limit {
+                saturate(sign, flags)
+            } else {
+                if inexact {
+                    *flags |= NX;
+                }
+                if sign {
+                    (q as u64).wrapping_neg()
+                } else {
+                    q as u64
+                }
+            }
+        }
+    };
+    if width == 32 {
+        result as i32 as i64 as u64
+    } else {
+        result
+    }
+}
+
+/// Orders two values that aren't NaNs, treating zeros of either sign as equal
+fn compare(fmt: Format, a: u64, b: u64) -> Ordering {
+    let key = |x: u64| {
+        let magnitude = (x & !fmt.sign_bit()) as i128;
+        if x & fmt.sign_bit() != 0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    };
+    key(a).cmp(&key(b))
+}
+
+/// Quiet equality, which only raises the invalid flag for signaling NaNs
+pub fn eq(fmt: Format, a: u64, b: u64, flags: &mut u8) -> bool {
+    let (ua, ub) = (fmt.unpack(a), fmt.unpack(b));
+    if is_nan(ua) || is_nan(ub) {
+        if is_signaling(ua) || is_signaling(ub) {
+            *flags |= NV;
+        }
+        return false;
+    }
+    compare(fmt, a, b) == Ordering::Equal
+}
+
+/// Signaling less than, which raises the invalid flag for any NaN
+pub fn lt(fmt: Format, a: u64, b: u64, flags: &mut u8) -> bool {
+    if is_nan(fmt.unpack(a)) || is_nan(fmt.unpack(b)) {
+        *flags |= NV;
+        return false;
+    }
+    compare(fmt, a, b) == Ordering::Less
+}
+
+/// Signaling less than or equal, which raises the invalid flag for any NaN
+pub fn le(fmt: Format, a: u64, b: u64, flags: &mut u8) -> bool {
+    if is_nan(fmt.unpack(a)) || is_nan(fmt.unpack(b)) {
+        *flags |= NV;
+        return false;
+    }
+    compare(fmt, a, b) != Ordering::Greater
+}
+
+/// The IEEE 754-2019 `minimumNumber` and `maximumNumber` operations used by `fmin` and `fmax`.
+///
+/// A NaN operand is ignored unless both are NaNs, signaling NaNs raise the invalid flag, and `-0.0` is less than
+/// `+0.0`.
+pub fn min_max(fmt: Format, a: u64, b: u64, max: bool, flags: &mut u8) -> u64 {
+    let (ua, ub) = (fmt.unpack(a), fmt.unpack(b));
+    if is_signaling(ua) || is_signaling(ub) {
+        *flags |= NV;
+    }
+    match (is_nan(ua), is_nan(ub)) {
+        (true, true) => fmt.canonical_nan(),
+        (true, false) => b,
+        (false, true) => a,
+        (false, false) => {
+            let a_first = match compare(fmt, a, b) {
+                Ordering::Less => !max,
+                Ordering::Greater => max,
+                // only zeros of opposite signs compare equal without being identical
+                Ordering::Equal => (a & fmt.sign_bit() != 0) != max,
+            };
+            if a_first { a } else { b }
+        }
+    }
+}
+
+/// Returns the `fclass` mask with exactly one bit set.
+pub fn classify(fmt: Format, a: u64) -> u64 {
+    let subnormal = (a >> fmt.frac_bits) & fmt.max_exp() == 0;
+    let bit = match fmt.unpack(a) {
+        Value::Infinite(true) => 0,
+        Value::Finite { sign: true, .. } if !subnormal => 1,
+        Value::Finite { sign: true, .. } => 2,
+        Value::Zero(true) => 3,
+        Value::Zero(false) => 4,
+        Value::Finite { sign: false, .. } if subnormal => 5,
+        Value::Finite { sign: false, .. } => 6,
+        Value::Infinite(false) => 7,
+        Value::Nan { signaling: true } => 8,
+        Value::Nan { signaling: false } => 9,
+    };
+    1 << bit
+}
+
+/// Implements `fsgnj`, `fsgnjn` and `fsgnjx`, which copy the magnitude of `a` and compute the sign from both.
+pub fn sign_inject(fmt: Format, a: u64, b: u64, op: SignInjection) -> u64 {
+    let sign = match op {
+        SignInjection::Copy => b,
+        SignInjection::Negate => !b,
+        SignInjection::Xor => a ^ b,
+    } & fmt.sign_bit();
+    a & !fmt.sign_bit() | sign
+}
+
+/// The sign computed by [`sign_inject`]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SignInjection {
+    /// The sign of `b` (`fsgnj`)
+    Copy,
+    /// The opposite of the sign of `b` (`fsgnjn`)
+    Negate,
+    /// The exclusive or of both signs (`fsgnjx`)
+    Xor,
+}
@@ -0,0 +1,171 @@
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+
+use crate::assembly::AssemblyResult;
+use crate::cinstruction::CInstruction;
+use crate::extension::Extensions;
+use crate::instruction::{Instruction, disassemble_instruction_with};
+use crate::isa::IsaConfig;
+
+/// An instruction decoded from a stream.
+#[derive(Debug, PartialEq)]
+pub struct DecodedInstruction {
+    /// The offset of the instruction from the start of the stream in bytes
+    pub offset: usize,
+    /// The length of the instruction in bytes
+    pub length: usize,
+    /// The bits of the instruction, the upper half is zero for compressed instructions
+    pub bits: u32,
+    pub result: Result<AssemblyResult, String>,
+}
+
+/// Decodes a little-endian stream of 16 and 32-bit instructions, such as the text section of a binary.
+///
+/// Encodings that aren't part of the standard extensions are passed to the registered extensions before an error is
+/// reported. Instructions that fail to decode are skipped using the length encoded in their lowest bits.
+pub struct StreamDecoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    isa: IsaConfig,
+    extensions: Option<&'a Extensions>,
+}
+
+impl<'a> StreamDecoder<'a> {
+    /// Creates a decoder for the given ISA configuration, without any custom extensions.
+    pub fn new(bytes: &'a [u8], isa: IsaConfig) -> Self {
+        StreamDecoder {
+            bytes,
+            offset: 0,
+            isa,
+            extensions: None,
+        }
+    }
+
+    /// Consults the given extensions for encodings that aren't part of the standard extensions.
+    pub fn with_extensions(mut self, extensions: &'a Extensions) -> Self {
+        self.extensions = Some(extensions);
+        self
+    }
+
+    /// Decodes a single 32-bit instruction.
+    pub fn decode_word(&self, word: u32) -> Result<AssemblyResult, String> {
+        match Instruction::decode_with(word, &self.isa) {
+            Ok(i) => Ok(AssemblyResult::I(i)),
+            Err(e) => match self.extensions.and_then(|x| x.decode(word, &self.isa)) {
+                Some(result) => Ok(AssemblyResult::Custom(result?)),
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Disassembles an instruction for this decoder's ISA configuration.
+    pub fn disassemble(&self, instruction: &AssemblyResult) -> String {
+        match instruction {
+            AssemblyResult::I(i) => disassemble_instruction_with(i, &self.isa),
+            AssemblyResult::C(c) => CInstruction::disassemble_with(c, &self.isa),
+            AssemblyResult::Custom(x) => format!("{x}"),
+            AssemblyResult::Raw(bits) => disassemble_raw(*bits),
+        }
+    }
+
+    /// Disassembles an instruction from the stream, writing instructions that failed to decode with the `.insn`
+    /// directive so that the output can be assembled again.
+    ///
+    /// Instructions longer than 32 bits, truncated instructions and halfwords with a reserved length encoding are
+    /// written as a comment containing their lowest bits and the error, since `.insn` would assemble them with a
+    /// different length.
+    pub fn disassemble_decoded(&self, decoded: &DecodedInstruction) -> String {
+        let raw_length = if decoded.bits & 0b11 == 0b11 { 4 } else { 2 };
+        match &decoded.result {
+            Ok(x) => self.disassemble(x),
+            Err(_) if decoded.length == raw_length => disassemble_raw(decoded.bits),
+            Err(e) => {
+                // only the lowest halfword is kept for instructions that aren't decoded
+                let width = decoded.length.min(2) * 2;
+                format!("# 0x{:0width$x}: {e}", decoded.bits)
+            }
+        }
+    }
+}
+
+/// Disassembles an instruction with the `.insn` directive, compressed if the lowest two bits aren't `0b11`.
+pub fn disassemble_raw(bits: u32) -> String {
+    if bits & 0b11 == 0b11 {
+        format!(".insn 0x{bits:08x}")
+    } else {
+        format!(".insn 0x{bits:04x}")
+    }
+}
+
+/// Returns the length of an instruction in bytes from its lowest 16 bits.
+///
+/// Instructions longer than 32 bits aren't supported by the decoder, but their lengths are used to skip over them.
+pub fn instruction_length(low: u16) -> usize {
+    if low & 0b11 != 0b11 {
+        2
+    } else if low & 0b1_1100 != 0b1_1100 {
+        4
+    } else if low & 0b11_1111 == 0b01_1111 {
+        6
+    } else if low & 0b111_1111 == 0b011_1111 {
+        8
+    } else {
+        // the longer formats are reserved, so there isn't a reliable length to skip
+        2
+    }
+}
+
+impl Iterator for StreamDecoder<'_> {
+    type Item = DecodedInstruction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = &self.bytes[self.offset..];
+        if remaining.is_empty() {
+            return None;
+        }
+        let offset = self.offset;
+        if remaining.len() < 2 {
+            self.offset = self.bytes.len();
+            return Some(DecodedInstruction {
+                offset,
+                length: remaining.len(),
+                bits: remaining[0] as u32,
+                result: Err("truncated instruction at the end of the stream".to_owned()),
+            });
+        }
+        let low = u16::from_le_bytes([remaining[0], remaining[1]]);
+        let length = instruction_length(low);
+        if remaining.len() < length {
+            self.offset = self.bytes.len();
+            return Some(DecodedInstruction {
+                offset,
+                length: remaining.len(),
+                bits: low as u32,
+                result: Err("truncated instruction at the end of the stream".to_owned()),
+            });
+        }
+        self.offset += length;
+        let (bits, result) = match length {
+            2 => (
+                low as u32,
+                CInstruction::decode_with(low, &self.isa).map(AssemblyResult::C),
+            ),
+            4 => {
+                let word =
+                    u32::from_le_bytes([remaining[0], remaining[1], remaining[2], remaining[3]]);
+                (word, self.decode_word(word))
+            }
+            _ => (
+                low as u32,
+                Err(format!("{}-bit instructions are not supported", length * 8)),
+            ),
+        };
+        Some(DecodedInstruction {
+            offset,
+            length,
+            bits,
+            result,
+        })
+    }
+}
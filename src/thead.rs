@@ -0,0 +1,3062 @@
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use core::any::Any;
+use core::fmt::{Display, Formatter};
+
+use crate::assembly::{parse_int, parse_register_address};
+use crate::extension::{CustomInstruction, Extension};
+use crate::immediates::{IncrementImmediate, Shamt, ShamtW};
+use crate::isa::{IsaConfig, Xlen};
+use crate::register::{FRegister, IRegister};
+
+/// The T-Head vendor extensions implemented by the C906 and C910 cores: XTheadBa, XTheadBb, XTheadBs, XTheadCondMov,
+/// XTheadMemIdx, XTheadMemPair, XTheadMac, XTheadFMemIdx, XTheadCmo and XTheadSync.
+///
+/// These aren't decoded by default, register them with `Extensions::new().with(XThead)`.
+pub struct XThead;
+
+impl Extension for XThead {
+    fn name(&self) -> &str {
+        "xthead"
+    }
+
+    fn decode(
+        &self,
+        instruction: u32,
+        isa: &IsaConfig,
+    ) -> Option<Result<Box<dyn CustomInstruction>, String>> {
+        THeadInstruction::decode(instruction, isa)
+            .map(|x| x.map(|i| Box::new(i) as Box<dyn CustomInstruction>))
+    }
+
+    fn assemble(
+        &self,
+        mnemonics: &[&str],
+        operands: &[&str],
+        isa: &IsaConfig,
+    ) -> Option<Result<Box<dyn CustomInstruction>, String>> {
+        match mnemonics {
+            ["th", rest @ ..] => Some(
+                THeadInstruction::assemble(&rest.join("."), operands, isa)
+                    .map(|i| Box::new(i) as Box<dyn CustomInstruction>),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// An instruction from the T-Head vendor extensions, all of which use the custom-0 opcode.
+///
+/// The indexed loads and stores access `base + (index << shift)`, the increment forms access `base` (after) or
+/// `base + (offset << shift)` (before) and then write that sum back to `base`. The paired loads and stores access
+/// `base + (offset << 3)` for words and `base + (offset << 4)` for doublewords.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum THeadInstruction {
+    // XTheadBa
+    Addsl {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+        shift: u8,
+    },
+    // XTheadBb
+    Srri {
+        dest: IRegister,
+        src: IRegister,
+        shamt: Shamt,
+    },
+    Srriw {
+        dest: IRegister,
+        src: IRegister,
+        shamt: ShamtW,
+    },
+    Ext {
+        dest: IRegister,
+        src: IRegister,
+        msb: u8,
+        lsb: u8,
+    },
+    Extu {
+        dest: IRegister,
+        src: IRegister,
+        msb: u8,
+        lsb: u8,
+    },
+    Ff0 {
+        dest: IRegister,
+        src: IRegister,
+    },
+    Ff1 {
+        dest: IRegister,
+        src: IRegister,
+    },
+    Rev {
+        dest: IRegister,
+        src: IRegister,
+    },
+    Revw {
+        dest: IRegister,
+        src: IRegister,
+    },
+    Tstnbz {
+        dest: IRegister,
+        src: IRegister,
+    },
+    // XTheadBs
+    Tst {
+        dest: IRegister,
+        src: IRegister,
+        shamt: Shamt,
+    },
+    // XTheadCondMov
+    Mveqz {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+    Mvnez {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+    // XTheadMac
+    Mula {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+    Muls {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+    Mulaw {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+    Mulsw {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+    Mulah {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+    Mulsh {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+    // XTheadMemIdx
+    Lbia {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lbib {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lbuia {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lbuib {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lhia {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lhib {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lhuia {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lhuib {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lwia {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lwib {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lwuia {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lwuib {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Ldia {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Ldib {
+        dest: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Sbia {
+        src: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Sbib {
+        src: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Shia {
+        src: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Shib {
+        src: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Swia {
+        src: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Swib {
+        src: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Sdia {
+        src: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Sdib {
+        src: IRegister,
+        base: IRegister,
+        offset: IncrementImmediate,
+        shift: u8,
+    },
+    Lrb {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lurb {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lrbu {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lurbu {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lrh {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lurh {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lrhu {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lurhu {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lrw {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lurw {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lrwu {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lurwu {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lrd {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Lurd {
+        dest: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Srb {
+        src: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Surb {
+        src: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Srh {
+        src: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Surh {
+        src: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Srw {
+        src: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Surw {
+        src: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Srd {
+        src: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Surd {
+        src: IRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    // XTheadMemPair
+    Lwd {
+        dest1: IRegister,
+        dest2: IRegister,
+        base: IRegister,
+        offset: u8,
+    },
+    Lwud {
+        dest1: IRegister,
+        dest2: IRegister,
+        base: IRegister,
+        offset: u8,
+    },
+    Ldd {
+        dest1: IRegister,
+        dest2: IRegister,
+        base: IRegister,
+        offset: u8,
+    },
+    Swd {
+        src1: IRegister,
+        src2: IRegister,
+        base: IRegister,
+        offset: u8,
+    },
+    Sdd {
+        src1: IRegister,
+        src2: IRegister,
+        base: IRegister,
+        offset: u8,
+    },
+    // XTheadFMemIdx
+    Flrw {
+        dest: FRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Flrd {
+        dest: FRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Flurw {
+        dest: FRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Flurd {
+        dest: FRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Fsrw {
+        src: FRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Fsrd {
+        src: FRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Fsurw {
+        src: FRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    Fsurd {
+        src: FRegister,
+        base: IRegister,
+        index: IRegister,
+        shift: u8,
+    },
+    // XTheadCmo
+    DcacheCall,
+    DcacheIall,
+    DcacheCiall,
+    DcacheCsw {
+        src: IRegister,
+    },
+    DcacheIsw {
+        src: IRegister,
+    },
+    DcacheCisw {
+        src: IRegister,
+    },
+    DcacheCval1 {
+        src: IRegister,
+    },
+    DcacheCva {
+        src: IRegister,
+    },
+    DcacheIva {
+        src: IRegister,
+    },
+    DcacheCiva {
+        src: IRegister,
+    },
+    DcacheCpal1 {
+        src: IRegister,
+    },
+    DcacheCpa {
+        src: IRegister,
+    },
+    DcacheIpa {
+        src: IRegister,
+    },
+    DcacheCipa {
+        src: IRegister,
+    },
+    IcacheIall,
+    IcacheIalls,
+    IcacheIva {
+        src: IRegister,
+    },
+    IcacheIpa {
+        src: IRegister,
+    },
+    L2cacheCall,
+    L2cacheIall,
+    L2cacheCiall,
+    // XTheadSync
+    SfenceVmas {
+        vaddr: IRegister,
+        asid: IRegister,
+    },
+    Sync,
+    SyncS,
+    SyncI,
+    SyncIs,
+}
+
+fn r_type(funct7: u32, src2: u32, src1: u32, funct3: u32, dest: u32) -> u32 {
+    funct7 << 25 | src2 << 20 | src1 << 15 | funct3 << 12 | dest << 7 | 0b000_1011
+}
+
+impl THeadInstruction {
+    pub fn encode(&self) -> u32 {
+        match self {
+            THeadInstruction::Addsl {
+                dest,
+                src1,
+                src2,
+                shift,
+            } => r_type(
+                *shift as u32,
+                u32::from(*src2),
+                u32::from(*src1),
+                0b001,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Srri { dest, src, shamt } => {
+                r_type(0b0001000, 0, u32::from(*src), 0b001, u32::from(*dest)) | shamt.to_u32()
+            }
+            THeadInstruction::Srriw { dest, src, shamt } => {
+                r_type(0b0001010, 0, u32::from(*src), 0b001, u32::from(*dest)) | shamt.to_u32()
+            }
+            THeadInstruction::Ext {
+                dest,
+                src,
+                msb,
+                lsb,
+            } => {
+                (*msb as u32) << 26
+                    | (*lsb as u32) << 20
+                    | r_type(0, 0, u32::from(*src), 0b010, u32::from(*dest))
+            }
+            THeadInstruction::Extu {
+                dest,
+                src,
+                msb,
+                lsb,
+            } => {
+                (*msb as u32) << 26
+                    | (*lsb as u32) << 20
+                    | r_type(0, 0, u32::from(*src), 0b011, u32::from(*dest))
+            }
+            THeadInstruction::Ff0 { dest, src } => {
+                r_type(0b1000010, 0, u32::from(*src), 0b001, u32::from(*dest))
+            }
+            THeadInstruction::Ff1 { dest, src } => {
+                r_type(0b1000011, 0, u32::from(*src), 0b001, u32::from(*dest))
+            }
+            THeadInstruction::Rev { dest, src } => {
+                r_type(0b1000001, 0, u32::from(*src), 0b001, u32::from(*dest))
+            }
+            THeadInstruction::Revw { dest, src } => {
+                r_type(0b1001000, 0, u32::from(*src), 0b001, u32::from(*dest))
+            }
+            THeadInstruction::Tstnbz { dest, src } => {
+                r_type(0b1000000, 0, u32::from(*src), 0b001, u32::from(*dest))
+            }
+            THeadInstruction::Tst { dest, src, shamt } => {
+                r_type(0b1000100, 0, u32::from(*src), 0b001, u32::from(*dest)) | shamt.to_u32()
+            }
+            THeadInstruction::Mveqz { dest, src1, src2 } => r_type(
+                0b0100000,
+                u32::from(*src2),
+                u32::from(*src1),
+                0b001,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Mvnez { dest, src1, src2 } => r_type(
+                0b0100001,
+                u32::from(*src2),
+                u32::from(*src1),
+                0b001,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Mula { dest, src1, src2 } => r_type(
+                0b0010000,
+                u32::from(*src2),
+                u32::from(*src1),
+                0b001,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Muls { dest, src1, src2 } => r_type(
+                0b0010001,
+                u32::from(*src2),
+                u32::from(*src1),
+                0b001,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Mulaw { dest, src1, src2 } => r_type(
+                0b0010010,
+                u32::from(*src2),
+                u32::from(*src1),
+                0b001,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Mulsw { dest, src1, src2 } => r_type(
+                0b0010011,
+                u32::from(*src2),
+                u32::from(*src1),
+                0b001,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Mulah { dest, src1, src2 } => r_type(
+                0b0010100,
+                u32::from(*src2),
+                u32::from(*src1),
+                0b001,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Mulsh { dest, src1, src2 } => r_type(
+                0b0010101,
+                u32::from(*src2),
+                u32::from(*src1),
+                0b001,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lbia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b00011 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lbib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b00001 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lbuia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b10011 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lbuib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b10001 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lhia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b00111 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lhib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b00101 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lhuia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b10111 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lhuib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b10101 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lwia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b01011 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lwib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b01001 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lwuia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b11011 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lwuib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b11001 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Ldia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b01111 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Ldib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b01101 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b100,
+                    u32::from(*dest),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Sbia {
+                src,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b00011 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b101,
+                    u32::from(*src),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Sbib {
+                src,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b00001 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b101,
+                    u32::from(*src),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Shia {
+                src,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b00111 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b101,
+                    u32::from(*src),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Shib {
+                src,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b00101 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b101,
+                    u32::from(*src),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Swia {
+                src,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b01011 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b101,
+                    u32::from(*src),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Swib {
+                src,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b01001 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b101,
+                    u32::from(*src),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Sdia {
+                src,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b01111 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b101,
+                    u32::from(*src),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Sdib {
+                src,
+                base,
+                offset,
+                shift,
+            } => {
+                r_type(
+                    0b01101 << 2 | *shift as u32,
+                    0,
+                    u32::from(*base),
+                    0b101,
+                    u32::from(*src),
+                ) | offset.to_u32()
+            }
+            THeadInstruction::Lrb {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lurb {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b00010 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lrbu {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b10000 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lurbu {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b10010 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lrh {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b00100 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lurh {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b00110 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lrhu {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b10100 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lurhu {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b10110 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lrw {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01000 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lurw {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01010 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lrwu {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b11000 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lurwu {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b11010 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lrd {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01100 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Lurd {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01110 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Srb {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b101,
+                u32::from(*src),
+            ),
+            THeadInstruction::Surb {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b00010 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b101,
+                u32::from(*src),
+            ),
+            THeadInstruction::Srh {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b00100 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b101,
+                u32::from(*src),
+            ),
+            THeadInstruction::Surh {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b00110 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b101,
+                u32::from(*src),
+            ),
+            THeadInstruction::Srw {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01000 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b101,
+                u32::from(*src),
+            ),
+            THeadInstruction::Surw {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01010 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b101,
+                u32::from(*src),
+            ),
+            THeadInstruction::Srd {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01100 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b101,
+                u32::from(*src),
+            ),
+            THeadInstruction::Surd {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01110 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b101,
+                u32::from(*src),
+            ),
+            THeadInstruction::Lwd {
+                dest1,
+                dest2,
+                base,
+                offset,
+            } => r_type(
+                0b11100 << 2 | *offset as u32,
+                u32::from(*dest2),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest1),
+            ),
+            THeadInstruction::Lwud {
+                dest1,
+                dest2,
+                base,
+                offset,
+            } => r_type(
+                0b11110 << 2 | *offset as u32,
+                u32::from(*dest2),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest1),
+            ),
+            THeadInstruction::Ldd {
+                dest1,
+                dest2,
+                base,
+                offset,
+            } => r_type(
+                0b11111 << 2 | *offset as u32,
+                u32::from(*dest2),
+                u32::from(*base),
+                0b100,
+                u32::from(*dest1),
+            ),
+            THeadInstruction::Swd {
+                src1,
+                src2,
+                base,
+                offset,
+            } => r_type(
+                0b11100 << 2 | *offset as u32,
+                u32::from(*src2),
+                u32::from(*base),
+                0b101,
+                u32::from(*src1),
+            ),
+            THeadInstruction::Sdd {
+                src1,
+                src2,
+                base,
+                offset,
+            } => r_type(
+                0b11111 << 2 | *offset as u32,
+                u32::from(*src2),
+                u32::from(*base),
+                0b101,
+                u32::from(*src1),
+            ),
+            THeadInstruction::Flrw {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01000 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b110,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Flrd {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01100 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b110,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Flurw {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01010 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b110,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Flurd {
+                dest,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01110 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b110,
+                u32::from(*dest),
+            ),
+            THeadInstruction::Fsrw {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01000 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b111,
+                u32::from(*src),
+            ),
+            THeadInstruction::Fsrd {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01100 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b111,
+                u32::from(*src),
+            ),
+            THeadInstruction::Fsurw {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01010 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b111,
+                u32::from(*src),
+            ),
+            THeadInstruction::Fsurd {
+                src,
+                base,
+                index,
+                shift,
+            } => r_type(
+                0b01110 << 2 | *shift as u32,
+                u32::from(*index),
+                u32::from(*base),
+                0b111,
+                u32::from(*src),
+            ),
+            THeadInstruction::DcacheCall => 0x001 << 20 | 0b000_1011,
+            THeadInstruction::DcacheIall => 0x002 << 20 | 0b000_1011,
+            THeadInstruction::DcacheCiall => 0x003 << 20 | 0b000_1011,
+            THeadInstruction::DcacheCsw { src } => 0x021 << 20 | u32::from(*src) << 15 | 0b000_1011,
+            THeadInstruction::DcacheIsw { src } => 0x022 << 20 | u32::from(*src) << 15 | 0b000_1011,
+            THeadInstruction::DcacheCisw { src } => {
+                0x023 << 20 | u32::from(*src) << 15 | 0b000_1011
+            }
+            THeadInstruction::DcacheCval1 { src } => {
+                0x024 << 20 | u32::from(*src) << 15 | 0b000_1011
+            }
+            THeadInstruction::DcacheCva { src } => 0x025 << 20 | u32::from(*src) << 15 | 0b000_1011,
+            THeadInstruction::DcacheIva { src } => 0x026 << 20 | u32::from(*src) << 15 | 0b000_1011,
+            THeadInstruction::DcacheCiva { src } => {
+                0x027 << 20 | u32::from(*src) << 15 | 0b000_1011
+            }
+            THeadInstruction::DcacheCpal1 { src } => {
+                0x028 << 20 | u32::from(*src) << 15 | 0b000_1011
+            }
+            THeadInstruction::DcacheCpa { src } => 0x029 << 20 | u32::from(*src) << 15 | 0b000_1011,
+            THeadInstruction::DcacheIpa { src } => 0x02a << 20 | u32::from(*src) << 15 | 0b000_1011,
+            THeadInstruction::DcacheCipa { src } => {
+                0x02b << 20 | u32::from(*src) << 15 | 0b000_1011
+            }
+            THeadInstruction::IcacheIall => 0x010 << 20 | 0b000_1011,
+            THeadInstruction::IcacheIalls => 0x011 << 20 | 0b000_1011,
+            THeadInstruction::IcacheIva { src } => 0x030 << 20 | u32::from(*src) << 15 | 0b000_1011,
+            THeadInstruction::IcacheIpa { src } => 0x038 << 20 | u32::from(*src) << 15 | 0b000_1011,
+            THeadInstruction::L2cacheCall => 0x015 << 20 | 0b000_1011,
+            THeadInstruction::L2cacheIall => 0x016 << 20 | 0b000_1011,
+            THeadInstruction::L2cacheCiall => 0x017 << 20 | 0b000_1011,
+            THeadInstruction::SfenceVmas { vaddr, asid } => {
+                r_type(0b0000010, u32::from(*asid), u32::from(*vaddr), 0b000, 0)
+            }
+            THeadInstruction::Sync => 0x018 << 20 | 0b000_1011,
+            THeadInstruction::SyncS => 0x019 << 20 | 0b000_1011,
+            THeadInstruction::SyncI => 0x01a << 20 | 0b000_1011,
+            THeadInstruction::SyncIs => 0x01b << 20 | 0b000_1011,
+        }
+    }
+
+    /// Decodes a T-Head instruction, returning `None` if it isn't one.
+    pub fn decode(instruction: u32, isa: &IsaConfig) -> Option<Result<Self, String>> {
+        if instruction & 0b111_1111 != 0b000_1011 {
+            return None;
+        }
+        let rd = instruction >> 7 & 0b1_1111;
+        let funct3 = instruction >> 12 & 0b111;
+        let rs1 = instruction >> 15 & 0b1_1111;
+        let rs2 = instruction >> 20 & 0b1_1111;
+        let shift = (instruction >> 25 & 0b11) as u8;
+        let funct5 = instruction >> 27;
+        let funct7 = instruction >> 25;
+        let funct12 = instruction >> 20;
+
+        let dest = IRegister::from_int(rd);
+        let src1 = IRegister::from_int(rs1);
+        let src2 = IRegister::from_int(rs2);
+        let fdest = FRegister::try_from(rd).unwrap();
+
+        let i = match funct3 {
+            0b000 if rd != 0 => return None,
+            0b000 if funct7 == 0b000_0010 => THeadInstruction::SfenceVmas {
+                vaddr: src1,
+                asid: src2,
+            },
+            0b000 => match funct12 {
+                0x001 if rs1 == 0 => THeadInstruction::DcacheCall,
+                0x002 if rs1 == 0 => THeadInstruction::DcacheIall,
+                0x003 if rs1 == 0 => THeadInstruction::DcacheCiall,
+                0x021 => THeadInstruction::DcacheCsw { src: src1 },
+                0x022 => THeadInstruction::DcacheIsw { src: src1 },
+                0x023 => THeadInstruction::DcacheCisw { src: src1 },
+                0x024 => THeadInstruction::DcacheCval1 { src: src1 },
+                0x025 => THeadInstruction::DcacheCva { src: src1 },
+                0x026 => THeadInstruction::DcacheIva { src: src1 },
+                0x027 => THeadInstruction::DcacheCiva { src: src1 },
+                0x028 => THeadInstruction::DcacheCpal1 { src: src1 },
+                0x029 => THeadInstruction::DcacheCpa { src: src1 },
+                0x02a => THeadInstruction::DcacheIpa { src: src1 },
+                0x02b => THeadInstruction::DcacheCipa { src: src1 },
+                0x010 if rs1 == 0 => THeadInstruction::IcacheIall,
+                0x011 if rs1 == 0 => THeadInstruction::IcacheIalls,
+                0x030 => THeadInstruction::IcacheIva { src: src1 },
+                0x038 => THeadInstruction::IcacheIpa { src: src1 },
+                0x015 if rs1 == 0 => THeadInstruction::L2cacheCall,
+                0x016 if rs1 == 0 => THeadInstruction::L2cacheIall,
+                0x017 if rs1 == 0 => THeadInstruction::L2cacheCiall,
+                0x018 if rs1 == 0 => THeadInstruction::Sync,
+                0x019 if rs1 == 0 => THeadInstruction::SyncS,
+                0x01a if rs1 == 0 => THeadInstruction::SyncI,
+                0x01b if rs1 == 0 => THeadInstruction::SyncIs,
+                _ => return None,
+            },
+            0b001 => match funct7 {
+                0b000_0000..=0b000_0011 => THeadInstruction::Addsl {
+                    dest,
+                    src1,
+                    src2,
+                    shift,
+                },
+                0b0001000 | 0b0001001 => THeadInstruction::Srri {
+                    dest,
+                    src: src1,
+                    shamt: Shamt::from_u32(instruction),
+                },
+                0b0001010 => THeadInstruction::Srriw {
+                    dest,
+                    src: src1,
+                    shamt: ShamtW::from_u32(instruction),
+                },
+                0b1000010 if rs2 == 0 => THeadInstruction::Ff0 { dest, src: src1 },
+                0b1000011 if rs2 == 0 => THeadInstruction::Ff1 { dest, src: src1 },
+                0b1000001 if rs2 == 0 => THeadInstruction::Rev { dest, src: src1 },
+                0b1001000 if rs2 == 0 => THeadInstruction::Revw { dest, src: src1 },
+                0b1000000 if rs2 == 0 => THeadInstruction::Tstnbz { dest, src: src1 },
+                0b1000100 | 0b1000101 => THeadInstruction::Tst {
+                    dest,
+                    src: src1,
+                    shamt: Shamt::from_u32(instruction),
+                },
+                0b0100000 => THeadInstruction::Mveqz { dest, src1, src2 },
+                0b0100001 => THeadInstruction::Mvnez { dest, src1, src2 },
+                0b0010000 => THeadInstruction::Mula { dest, src1, src2 },
+                0b0010001 => THeadInstruction::Muls { dest, src1, src2 },
+                0b0010010 => THeadInstruction::Mulaw { dest, src1, src2 },
+                0b0010011 => THeadInstruction::Mulsw { dest, src1, src2 },
+                0b0010100 => THeadInstruction::Mulah { dest, src1, src2 },
+                0b0010101 => THeadInstruction::Mulsh { dest, src1, src2 },
+                _ => return None,
+            },
+            0b010 => THeadInstruction::Ext {
+                dest,
+                src: src1,
+                msb: (instruction >> 26) as u8,
+                lsb: (instruction >> 20 & 0b11_1111) as u8,
+            },
+            0b011 => THeadInstruction::Extu {
+                dest,
+                src: src1,
+                msb: (instruction >> 26) as u8,
+                lsb: (instruction >> 20 & 0b11_1111) as u8,
+            },
+            0b100 => match funct5 {
+                0b00011 => THeadInstruction::Lbia {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b00001 => THeadInstruction::Lbib {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b10011 => THeadInstruction::Lbuia {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b10001 => THeadInstruction::Lbuib {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b00111 => THeadInstruction::Lhia {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b00101 => THeadInstruction::Lhib {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b10111 => THeadInstruction::Lhuia {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b10101 => THeadInstruction::Lhuib {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b01011 => THeadInstruction::Lwia {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b01001 => THeadInstruction::Lwib {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b11011 => THeadInstruction::Lwuia {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b11001 => THeadInstruction::Lwuib {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b01111 => THeadInstruction::Ldia {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b01101 => THeadInstruction::Ldib {
+                    dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b00000 => THeadInstruction::Lrb {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b00010 => THeadInstruction::Lurb {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b10000 => THeadInstruction::Lrbu {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b10010 => THeadInstruction::Lurbu {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b00100 => THeadInstruction::Lrh {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b00110 => THeadInstruction::Lurh {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b10100 => THeadInstruction::Lrhu {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b10110 => THeadInstruction::Lurhu {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01000 => THeadInstruction::Lrw {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01010 => THeadInstruction::Lurw {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b11000 => THeadInstruction::Lrwu {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b11010 => THeadInstruction::Lurwu {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01100 => THeadInstruction::Lrd {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01110 => THeadInstruction::Lurd {
+                    dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b11100 => THeadInstruction::Lwd {
+                    dest1: dest,
+                    dest2: src2,
+                    base: src1,
+                    offset: shift,
+                },
+                0b11110 => THeadInstruction::Lwud {
+                    dest1: dest,
+                    dest2: src2,
+                    base: src1,
+                    offset: shift,
+                },
+                0b11111 => THeadInstruction::Ldd {
+                    dest1: dest,
+                    dest2: src2,
+                    base: src1,
+                    offset: shift,
+                },
+                _ => return None,
+            },
+            0b101 => match funct5 {
+                0b00011 => THeadInstruction::Sbia {
+                    src: dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b00001 => THeadInstruction::Sbib {
+                    src: dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b00111 => THeadInstruction::Shia {
+                    src: dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b00101 => THeadInstruction::Shib {
+                    src: dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b01011 => THeadInstruction::Swia {
+                    src: dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b01001 => THeadInstruction::Swib {
+                    src: dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b01111 => THeadInstruction::Sdia {
+                    src: dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b01101 => THeadInstruction::Sdib {
+                    src: dest,
+                    base: src1,
+                    offset: IncrementImmediate::from_u32(instruction),
+                    shift,
+                },
+                0b00000 => THeadInstruction::Srb {
+                    src: dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b00010 => THeadInstruction::Surb {
+                    src: dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b00100 => THeadInstruction::Srh {
+                    src: dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b00110 => THeadInstruction::Surh {
+                    src: dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01000 => THeadInstruction::Srw {
+                    src: dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01010 => THeadInstruction::Surw {
+                    src: dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01100 => THeadInstruction::Srd {
+                    src: dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01110 => THeadInstruction::Surd {
+                    src: dest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b11100 => THeadInstruction::Swd {
+                    src1: dest,
+                    src2,
+                    base: src1,
+                    offset: shift,
+                },
+                0b11111 => THeadInstruction::Sdd {
+                    src1: dest,
+                    src2,
+                    base: src1,
+                    offset: shift,
+                },
+                _ => return None,
+            },
+            0b110 => match funct5 {
+                0b01000 => THeadInstruction::Flrw {
+                    dest: fdest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01100 => THeadInstruction::Flrd {
+                    dest: fdest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01010 => THeadInstruction::Flurw {
+                    dest: fdest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01110 => THeadInstruction::Flurd {
+                    dest: fdest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                _ => return None,
+            },
+            0b111 => match funct5 {
+                0b01000 => THeadInstruction::Fsrw {
+                    src: fdest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01100 => THeadInstruction::Fsrd {
+                    src: fdest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01010 => THeadInstruction::Fsurw {
+                    src: fdest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                0b01110 => THeadInstruction::Fsurd {
+                    src: fdest,
+                    base: src1,
+                    index: src2,
+                    shift,
+                },
+                _ => return None,
+            },
+            _ => unreachable!(),
+        };
+        Some(i.check_isa(isa).map(|()| i))
+    }
+
+    /// Checks that the instruction is valid for the given ISA configuration, and that its registers don't overlap
+    /// where the extensions reserve those encodings.
+    pub fn check_isa(&self, isa: &IsaConfig) -> Result<(), String> {
+        if isa.xlen == Xlen::Rv32 {
+            match self {
+                THeadInstruction::Srriw { .. }
+                | THeadInstruction::Revw { .. }
+                | THeadInstruction::Mulaw { .. }
+                | THeadInstruction::Mulsw { .. }
+                | THeadInstruction::Lwuia { .. }
+                | THeadInstruction::Lwuib { .. }
+                | THeadInstruction::Ldia { .. }
+                | THeadInstruction::Ldib { .. }
+                | THeadInstruction::Sdia { .. }
+                | THeadInstruction::Sdib { .. }
+                | THeadInstruction::Lurb { .. }
+                | THeadInstruction::Lurbu { .. }
+                | THeadInstruction::Lurh { .. }
+                | THeadInstruction::Lurhu { .. }
+                | THeadInstruction::Lurw { .. }
+                | THeadInstruction::Lrwu { .. }
+                | THeadInstruction::Lurwu { .. }
+                | THeadInstruction::Lrd { .. }
+                | THeadInstruction::Lurd { .. }
+                | THeadInstruction::Surb { .. }
+                | THeadInstruction::Surh { .. }
+                | THeadInstruction::Surw { .. }
+                | THeadInstruction::Srd { .. }
+                | THeadInstruction::Surd { .. }
+                | THeadInstruction::Lwud { .. }
+                | THeadInstruction::Ldd { .. }
+                | THeadInstruction::Sdd { .. }
+                | THeadInstruction::Flurw { .. }
+                | THeadInstruction::Flurd { .. }
+                | THeadInstruction::Fsurw { .. }
+                | THeadInstruction::Fsurd { .. } => {
+                    return Err(format!("{self} is only available on RV64"));
+                }
+                THeadInstruction::Srri { shamt, .. } | THeadInstruction::Tst { shamt, .. }
+                    if shamt.val() >= 32 =>
+                {
+                    return Err(format!("{self} has a shift amount out of range for RV32"));
+                }
+                THeadInstruction::Ext { msb, lsb, .. }
+                | THeadInstruction::Extu { msb, lsb, .. }
+                    if *msb >= 32 || *lsb >= 32 =>
+                {
+                    return Err(format!("{self} has a bit index out of range for RV32"));
+                }
+                _ => {}
+            }
+        }
+        match self {
+            THeadInstruction::Lbia { dest, base, .. }
+            | THeadInstruction::Lbib { dest, base, .. }
+            | THeadInstruction::Lbuia { dest, base, .. }
+            | THeadInstruction::Lbuib { dest, base, .. }
+            | THeadInstruction::Lhia { dest, base, .. }
+            | THeadInstruction::Lhib { dest, base, .. }
+            | THeadInstruction::Lhuia { dest, base, .. }
+            | THeadInstruction::Lhuib { dest, base, .. }
+            | THeadInstruction::Lwia { dest, base, .. }
+            | THeadInstruction::Lwib { dest, base, .. }
+            | THeadInstruction::Lwuia { dest, base, .. }
+            | THeadInstruction::Lwuib { dest, base, .. }
+            | THeadInstruction::Ldia { dest, base, .. }
+            | THeadInstruction::Ldib { dest, base, .. }
+                if dest == base =>
+            {
+                Err(format!(
+                    "{self} uses the same register for the destination and base"
+                ))
+            }
+            THeadInstruction::Lwd {
+                dest1, dest2, base, ..
+            }
+            | THeadInstruction::Lwud {
+                dest1, dest2, base, ..
+            }
+            | THeadInstruction::Ldd {
+                dest1, dest2, base, ..
+            } if dest1 == dest2 || dest1 == base || dest2 == base => Err(format!(
+                "{self} uses overlapping destination and base registers"
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Assembles a T-Head instruction from its mnemonic, without the `th.` prefix.
+    pub fn assemble(mnemonic: &str, operands: &[&str], isa: &IsaConfig) -> Result<Self, String> {
+        let i = match mnemonic {
+            "addsl" => {
+                add_shifted(operands).map(|(dest, src1, src2, shift)| THeadInstruction::Addsl {
+                    dest,
+                    src1,
+                    src2,
+                    shift,
+                })
+            }
+            "srri" => shift(operands).map(|(dest, src, shamt)| THeadInstruction::Srri {
+                dest,
+                src,
+                shamt,
+            }),
+            "srriw" => shift_word(operands).map(|(dest, src, shamt)| THeadInstruction::Srriw {
+                dest,
+                src,
+                shamt,
+            }),
+            "ext" => bit_field(operands).map(|(dest, src, msb, lsb)| THeadInstruction::Ext {
+                dest,
+                src,
+                msb,
+                lsb,
+            }),
+            "extu" => bit_field(operands).map(|(dest, src, msb, lsb)| THeadInstruction::Extu {
+                dest,
+                src,
+                msb,
+                lsb,
+            }),
+            "ff0" => two_registers(operands).map(|(dest, src)| THeadInstruction::Ff0 { dest, src }),
+            "ff1" => two_registers(operands).map(|(dest, src)| THeadInstruction::Ff1 { dest, src }),
+            "rev" => two_registers(operands).map(|(dest, src)| THeadInstruction::Rev { dest, src }),
+            "revw" => {
+                two_registers(operands).map(|(dest, src)| THeadInstruction::Revw { dest, src })
+            }
+            "tstnbz" => {
+                two_registers(operands).map(|(dest, src)| THeadInstruction::Tstnbz { dest, src })
+            }
+            "tst" => {
+                shift(operands).map(|(dest, src, shamt)| THeadInstruction::Tst { dest, src, shamt })
+            }
+            "mveqz" => three_registers(operands)
+                .map(|(dest, src1, src2)| THeadInstruction::Mveqz { dest, src1, src2 }),
+            "mvnez" => three_registers(operands)
+                .map(|(dest, src1, src2)| THeadInstruction::Mvnez { dest, src1, src2 }),
+            "mula" => three_registers(operands).map(|(dest, src1, src2)| THeadInstruction::Mula {
+                dest,
+                src1,
+                src2,
+            }),
+            "muls" => three_registers(operands).map(|(dest, src1, src2)| THeadInstruction::Muls {
+                dest,
+                src1,
+                src2,
+            }),
+            "mulaw" => three_registers(operands)
+                .map(|(dest, src1, src2)| THeadInstruction::Mulaw { dest, src1, src2 }),
+            "mulsw" => three_registers(operands)
+                .map(|(dest, src1, src2)| THeadInstruction::Mulsw { dest, src1, src2 }),
+            "mulah" => three_registers(operands)
+                .map(|(dest, src1, src2)| THeadInstruction::Mulah { dest, src1, src2 }),
+            "mulsh" => three_registers(operands)
+                .map(|(dest, src1, src2)| THeadInstruction::Mulsh { dest, src1, src2 }),
+            "lbia" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lbia {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lbib" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lbib {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lbuia" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lbuia {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lbuib" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lbuib {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lhia" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lhia {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lhib" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lhib {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lhuia" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lhuia {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lhuib" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lhuib {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lwia" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lwia {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lwib" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lwib {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lwuia" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lwuia {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lwuib" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Lwuib {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "ldia" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Ldia {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "ldib" => {
+                increment(operands).map(|(dest, base, offset, shift)| THeadInstruction::Ldib {
+                    dest,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "sbia" => {
+                increment(operands).map(|(src, base, offset, shift)| THeadInstruction::Sbia {
+                    src,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "sbib" => {
+                increment(operands).map(|(src, base, offset, shift)| THeadInstruction::Sbib {
+                    src,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "shia" => {
+                increment(operands).map(|(src, base, offset, shift)| THeadInstruction::Shia {
+                    src,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "shib" => {
+                increment(operands).map(|(src, base, offset, shift)| THeadInstruction::Shib {
+                    src,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "swia" => {
+                increment(operands).map(|(src, base, offset, shift)| THeadInstruction::Swia {
+                    src,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "swib" => {
+                increment(operands).map(|(src, base, offset, shift)| THeadInstruction::Swib {
+                    src,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "sdia" => {
+                increment(operands).map(|(src, base, offset, shift)| THeadInstruction::Sdia {
+                    src,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "sdib" => {
+                increment(operands).map(|(src, base, offset, shift)| THeadInstruction::Sdib {
+                    src,
+                    base,
+                    offset,
+                    shift,
+                })
+            }
+            "lrb" => indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lrb {
+                dest,
+                base,
+                index,
+                shift,
+            }),
+            "lurb" => indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lurb {
+                dest,
+                base,
+                index,
+                shift,
+            }),
+            "lrbu" => indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lrbu {
+                dest,
+                base,
+                index,
+                shift,
+            }),
+            "lurbu" => {
+                indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lurbu {
+                    dest,
+                    base,
+                    index,
+                    shift,
+                })
+            }
+            "lrh" => indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lrh {
+                dest,
+                base,
+                index,
+                shift,
+            }),
+            "lurh" => indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lurh {
+                dest,
+                base,
+                index,
+                shift,
+            }),
+            "lrhu" => indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lrhu {
+                dest,
+                base,
+                index,
+                shift,
+            }),
+            "lurhu" => {
+                indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lurhu {
+                    dest,
+                    base,
+                    index,
+                    shift,
+                })
+            }
+            "lrw" => indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lrw {
+                dest,
+                base,
+                index,
+                shift,
+            }),
+            "lurw" => indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lurw {
+                dest,
+                base,
+                index,
+                shift,
+            }),
+            "lrwu" => indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lrwu {
+                dest,
+                base,
+                index,
+                shift,
+            }),
+            "lurwu" => {
+                indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lurwu {
+                    dest,
+                    base,
+                    index,
+                    shift,
+                })
+            }
+            "lrd" => indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lrd {
+                dest,
+                base,
+                index,
+                shift,
+            }),
+            "lurd" => indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Lurd {
+                dest,
+                base,
+                index,
+                shift,
+            }),
+            "srb" => indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Srb {
+                src,
+                base,
+                index,
+                shift,
+            }),
+            "surb" => indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Surb {
+                src,
+                base,
+                index,
+                shift,
+            }),
+            "srh" => indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Srh {
+                src,
+                base,
+                index,
+                shift,
+            }),
+            "surh" => indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Surh {
+                src,
+                base,
+                index,
+                shift,
+            }),
+            "srw" => indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Srw {
+                src,
+                base,
+                index,
+                shift,
+            }),
+            "surw" => indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Surw {
+                src,
+                base,
+                index,
+                shift,
+            }),
+            "srd" => indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Srd {
+                src,
+                base,
+                index,
+                shift,
+            }),
+            "surd" => indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Surd {
+                src,
+                base,
+                index,
+                shift,
+            }),
+            "lwd" => pair(operands, 3).map(|(dest1, dest2, base, offset)| THeadInstruction::Lwd {
+                dest1,
+                dest2,
+                base,
+                offset,
+            }),
+            "lwud" => {
+                pair(operands, 3).map(|(dest1, dest2, base, offset)| THeadInstruction::Lwud {
+                    dest1,
+                    dest2,
+                    base,
+                    offset,
+                })
+            }
+            "ldd" => pair(operands, 4).map(|(dest1, dest2, base, offset)| THeadInstruction::Ldd {
+                dest1,
+                dest2,
+                base,
+                offset,
+            }),
+            "swd" => pair(operands, 3).map(|(src1, src2, base, offset)| THeadInstruction::Swd {
+                src1,
+                src2,
+                base,
+                offset,
+            }),
+            "sdd" => pair(operands, 4).map(|(src1, src2, base, offset)| THeadInstruction::Sdd {
+                src1,
+                src2,
+                base,
+                offset,
+            }),
+            "flrw" => {
+                float_indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Flrw {
+                    dest,
+                    base,
+                    index,
+                    shift,
+                })
+            }
+            "flrd" => {
+                float_indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Flrd {
+                    dest,
+                    base,
+                    index,
+                    shift,
+                })
+            }
+            "flurw" => {
+                float_indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Flurw {
+                    dest,
+                    base,
+                    index,
+                    shift,
+                })
+            }
+            "flurd" => {
+                float_indexed(operands).map(|(dest, base, index, shift)| THeadInstruction::Flurd {
+                    dest,
+                    base,
+                    index,
+                    shift,
+                })
+            }
+            "fsrw" => {
+                float_indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Fsrw {
+                    src,
+                    base,
+                    index,
+                    shift,
+                })
+            }
+            "fsrd" => {
+                float_indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Fsrd {
+                    src,
+                    base,
+                    index,
+                    shift,
+                })
+            }
+            "fsurw" => {
+                float_indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Fsurw {
+                    src,
+                    base,
+                    index,
+                    shift,
+                })
+            }
+            "fsurd" => {
+                float_indexed(operands).map(|(src, base, index, shift)| THeadInstruction::Fsurd {
+                    src,
+                    base,
+                    index,
+                    shift,
+                })
+            }
+            "dcache.call" => none(operands).map(|()| THeadInstruction::DcacheCall),
+            "dcache.iall" => none(operands).map(|()| THeadInstruction::DcacheIall),
+            "dcache.ciall" => none(operands).map(|()| THeadInstruction::DcacheCiall),
+            "dcache.csw" => register(operands).map(|src| THeadInstruction::DcacheCsw { src }),
+            "dcache.isw" => register(operands).map(|src| THeadInstruction::DcacheIsw { src }),
+            "dcache.cisw" => register(operands).map(|src| THeadInstruction::DcacheCisw { src }),
+            "dcache.cval1" => register(operands).map(|src| THeadInstruction::DcacheCval1 { src }),
+            "dcache.cva" => register(operands).map(|src| THeadInstruction::DcacheCva { src }),
+            "dcache.iva" => register(operands).map(|src| THeadInstruction::DcacheIva { src }),
+            "dcache.civa" => register(operands).map(|src| THeadInstruction::DcacheCiva { src }),
+            "dcache.cpal1" => register(operands).map(|src| THeadInstruction::DcacheCpal1 { src }),
+            "dcache.cpa" => register(operands).map(|src| THeadInstruction::DcacheCpa { src }),
+            "dcache.ipa" => register(operands).map(|src| THeadInstruction::DcacheIpa { src }),
+            "dcache.cipa" => register(operands).map(|src| THeadInstruction::DcacheCipa { src }),
+            "icache.iall" => none(operands).map(|()| THeadInstruction::IcacheIall),
+            "icache.ialls" => none(operands).map(|()| THeadInstruction::IcacheIalls),
+            "icache.iva" => register(operands).map(|src| THeadInstruction::IcacheIva { src }),
+            "icache.ipa" => register(operands).map(|src| THeadInstruction::IcacheIpa { src }),
+            "l2cache.call" => none(operands).map(|()| THeadInstruction::L2cacheCall),
+            "l2cache.iall" => none(operands).map(|()| THeadInstruction::L2cacheIall),
+            "l2cache.ciall" => none(operands).map(|()| THeadInstruction::L2cacheCiall),
+            "sfence.vmas" => {
+                sfence(operands).map(|(vaddr, asid)| THeadInstruction::SfenceVmas { vaddr, asid })
+            }
+            "sync" => none(operands).map(|()| THeadInstruction::Sync),
+            "sync.s" => none(operands).map(|()| THeadInstruction::SyncS),
+            "sync.i" => none(operands).map(|()| THeadInstruction::SyncI),
+            "sync.is" => none(operands).map(|()| THeadInstruction::SyncIs),
+            _ => Err(format!("unknown mnemonic: th.{mnemonic}")),
+        }?;
+        i.check_isa(isa)?;
+        Ok(i)
+    }
+}
+
+impl CustomInstruction for THeadInstruction {
+    fn encode(&self) -> u32 {
+        THeadInstruction::encode(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl Display for THeadInstruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            THeadInstruction::Addsl {
+                dest,
+                src1,
+                src2,
+                shift,
+            } => write!(f, "th.addsl {dest},{src1},{src2},{shift}"),
+            THeadInstruction::Srri { dest, src, shamt } => {
+                write!(f, "th.srri {dest},{src},{shamt}")
+            }
+            THeadInstruction::Srriw { dest, src, shamt } => {
+                write!(f, "th.srriw {dest},{src},{shamt}")
+            }
+            THeadInstruction::Ext {
+                dest,
+                src,
+                msb,
+                lsb,
+            } => write!(f, "th.ext {dest},{src},{msb},{lsb}"),
+            THeadInstruction::Extu {
+                dest,
+                src,
+                msb,
+                lsb,
+            } => write!(f, "th.extu {dest},{src},{msb},{lsb}"),
+            THeadInstruction::Ff0 { dest, src } => write!(f, "th.ff0 {dest},{src}"),
+            THeadInstruction::Ff1 { dest, src } => write!(f, "th.ff1 {dest},{src}"),
+            THeadInstruction::Rev { dest, src } => write!(f, "th.rev {dest},{src}"),
+            THeadInstruction::Revw { dest, src } => write!(f, "th.revw {dest},{src}"),
+            THeadInstruction::Tstnbz { dest, src } => write!(f, "th.tstnbz {dest},{src}"),
+            THeadInstruction::Tst { dest, src, shamt } => write!(f, "th.tst {dest},{src},{shamt}"),
+            THeadInstruction::Mveqz { dest, src1, src2 } => {
+                write!(f, "th.mveqz {dest},{src1},{src2}")
+            }
+            THeadInstruction::Mvnez { dest, src1, src2 } => {
+                write!(f, "th.mvnez {dest},{src1},{src2}")
+            }
+            THeadInstruction::Mula { dest, src1, src2 } => {
+                write!(f, "th.mula {dest},{src1},{src2}")
+            }
+            THeadInstruction::Muls { dest, src1, src2 } => {
+                write!(f, "th.muls {dest},{src1},{src2}")
+            }
+            THeadInstruction::Mulaw { dest, src1, src2 } => {
+                write!(f, "th.mulaw {dest},{src1},{src2}")
+            }
+            THeadInstruction::Mulsw { dest, src1, src2 } => {
+                write!(f, "th.mulsw {dest},{src1},{src2}")
+            }
+            THeadInstruction::Mulah { dest, src1, src2 } => {
+                write!(f, "th.mulah {dest},{src1},{src2}")
+            }
+            THeadInstruction::Mulsh { dest, src1, src2 } => {
+                write!(f, "th.mulsh {dest},{src1},{src2}")
+            }
+            THeadInstruction::Lbia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lbia {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Lbib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lbib {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Lbuia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lbuia {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Lbuib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lbuib {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Lhia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lhia {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Lhib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lhib {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Lhuia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lhuia {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Lhuib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lhuib {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Lwia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lwia {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Lwib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lwib {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Lwuia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lwuia {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Lwuib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.lwuib {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Ldia {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.ldia {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Ldib {
+                dest,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.ldib {dest},({base}),{offset},{shift}"),
+            THeadInstruction::Sbia {
+                src,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.sbia {src},({base}),{offset},{shift}"),
+            THeadInstruction::Sbib {
+                src,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.sbib {src},({base}),{offset},{shift}"),
+            THeadInstruction::Shia {
+                src,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.shia {src},({base}),{offset},{shift}"),
+            THeadInstruction::Shib {
+                src,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.shib {src},({base}),{offset},{shift}"),
+            THeadInstruction::Swia {
+                src,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.swia {src},({base}),{offset},{shift}"),
+            THeadInstruction::Swib {
+                src,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.swib {src},({base}),{offset},{shift}"),
+            THeadInstruction::Sdia {
+                src,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.sdia {src},({base}),{offset},{shift}"),
+            THeadInstruction::Sdib {
+                src,
+                base,
+                offset,
+                shift,
+            } => write!(f, "th.sdib {src},({base}),{offset},{shift}"),
+            THeadInstruction::Lrb {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lrb {dest},{base},{index},{shift}"),
+            THeadInstruction::Lurb {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lurb {dest},{base},{index},{shift}"),
+            THeadInstruction::Lrbu {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lrbu {dest},{base},{index},{shift}"),
+            THeadInstruction::Lurbu {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lurbu {dest},{base},{index},{shift}"),
+            THeadInstruction::Lrh {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lrh {dest},{base},{index},{shift}"),
+            THeadInstruction::Lurh {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lurh {dest},{base},{index},{shift}"),
+            THeadInstruction::Lrhu {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lrhu {dest},{base},{index},{shift}"),
+            THeadInstruction::Lurhu {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lurhu {dest},{base},{index},{shift}"),
+            THeadInstruction::Lrw {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lrw {dest},{base},{index},{shift}"),
+            THeadInstruction::Lurw {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lurw {dest},{base},{index},{shift}"),
+            THeadInstruction::Lrwu {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lrwu {dest},{base},{index},{shift}"),
+            THeadInstruction::Lurwu {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lurwu {dest},{base},{index},{shift}"),
+            THeadInstruction::Lrd {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lrd {dest},{base},{index},{shift}"),
+            THeadInstruction::Lurd {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.lurd {dest},{base},{index},{shift}"),
+            THeadInstruction::Srb {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.srb {src},{base},{index},{shift}"),
+            THeadInstruction::Surb {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.surb {src},{base},{index},{shift}"),
+            THeadInstruction::Srh {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.srh {src},{base},{index},{shift}"),
+            THeadInstruction::Surh {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.surh {src},{base},{index},{shift}"),
+            THeadInstruction::Srw {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.srw {src},{base},{index},{shift}"),
+            THeadInstruction::Surw {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.surw {src},{base},{index},{shift}"),
+            THeadInstruction::Srd {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.srd {src},{base},{index},{shift}"),
+            THeadInstruction::Surd {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.surd {src},{base},{index},{shift}"),
+            THeadInstruction::Lwd {
+                dest1,
+                dest2,
+                base,
+                offset,
+            } => write!(f, "th.lwd {dest1},{dest2},({base}),{offset},3"),
+            THeadInstruction::Lwud {
+                dest1,
+                dest2,
+                base,
+                offset,
+            } => write!(f, "th.lwud {dest1},{dest2},({base}),{offset},3"),
+            THeadInstruction::Ldd {
+                dest1,
+                dest2,
+                base,
+                offset,
+            } => write!(f, "th.ldd {dest1},{dest2},({base}),{offset},4"),
+            THeadInstruction::Swd {
+                src1,
+                src2,
+                base,
+                offset,
+            } => write!(f, "th.swd {src1},{src2},({base}),{offset},3"),
+            THeadInstruction::Sdd {
+                src1,
+                src2,
+                base,
+                offset,
+            } => write!(f, "th.sdd {src1},{src2},({base}),{offset},4"),
+            THeadInstruction::Flrw {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.flrw {dest},{base},{index},{shift}"),
+            THeadInstruction::Flrd {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.flrd {dest},{base},{index},{shift}"),
+            THeadInstruction::Flurw {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.flurw {dest},{base},{index},{shift}"),
+            THeadInstruction::Flurd {
+                dest,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.flurd {dest},{base},{index},{shift}"),
+            THeadInstruction::Fsrw {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.fsrw {src},{base},{index},{shift}"),
+            THeadInstruction::Fsrd {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.fsrd {src},{base},{index},{shift}"),
+            THeadInstruction::Fsurw {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.fsurw {src},{base},{index},{shift}"),
+            THeadInstruction::Fsurd {
+                src,
+                base,
+                index,
+                shift,
+            } => write!(f, "th.fsurd {src},{base},{index},{shift}"),
+            THeadInstruction::DcacheCall => write!(f, "th.dcache.call"),
+            THeadInstruction::DcacheIall => write!(f, "th.dcache.iall"),
+            THeadInstruction::DcacheCiall => write!(f, "th.dcache.ciall"),
+            THeadInstruction::DcacheCsw { src } => write!(f, "th.dcache.csw {src}"),
+            THeadInstruction::DcacheIsw { src } => write!(f, "th.dcache.isw {src}"),
+            THeadInstruction::DcacheCisw { src } => write!(f, "th.dcache.cisw {src}"),
+            THeadInstruction::DcacheCval1 { src } => write!(f, "th.dcache.cval1 {src}"),
+            THeadInstruction::DcacheCva { src } => write!(f, "th.dcache.cva {src}"),
+            THeadInstruction::DcacheIva { src } => write!(f, "th.dcache.iva {src}"),
+            THeadInstruction::DcacheCiva { src } => write!(f, "th.dcache.civa {src}"),
+            THeadInstruction::DcacheCpal1 { src } => write!(f, "th.dcache.cpal1 {src}"),
+            THeadInstruction::DcacheCpa { src } => write!(f, "th.dcache.cpa {src}"),
+            THeadInstruction::DcacheIpa { src } => write!(f, "th.dcache.ipa {src}"),
+            THeadInstruction::DcacheCipa { src } => write!(f, "th.dcache.cipa {src}"),
+            THeadInstruction::IcacheIall => write!(f, "th.icache.iall"),
+            THeadInstruction::IcacheIalls => write!(f, "th.icache.ialls"),
+            THeadInstruction::IcacheIva { src } => write!(f, "th.icache.iva {src}"),
+            THeadInstruction::IcacheIpa { src } => write!(f, "th.icache.ipa {src}"),
+            THeadInstruction::L2cacheCall => write!(f, "th.l2cache.call"),
+            THeadInstruction::L2cacheIall => write!(f, "th.l2cache.iall"),
+            THeadInstruction::L2cacheCiall => write!(f, "th.l2cache.ciall"),
+            THeadInstruction::SfenceVmas { vaddr, asid } => {
+                write!(f, "th.sfence.vmas {vaddr},{asid}")
+            }
+            THeadInstruction::Sync => write!(f, "th.sync"),
+            THeadInstruction::SyncS => write!(f, "th.sync.s"),
+            THeadInstruction::SyncI => write!(f, "th.sync.i"),
+            THeadInstruction::SyncIs => write!(f, "th.sync.is"),
+        }
+    }
+}
+
+fn expect_operands(operands: &[&str], count: usize) -> Result<(), String> {
+    if operands.len() == count {
+        Ok(())
+    } else {
+        Err(format!(
+            "instruction requires {count} operands, found {}",
+            operands.len()
+        ))
+    }
+}
+
+fn parse_shift(str: &str) -> Result<u8, String> {
+    match parse_int(str)? {
+        x @ 0..=3 => Ok(x as u8),
+        _ => Err(format!("shift amount must be between 0 and 3: {str}")),
+    }
+}
+
+fn none(operands: &[&str]) -> Result<(), String> {
+    expect_operands(operands, 0)
+}
+
+fn register(operands: &[&str]) -> Result<IRegister, String> {
+    expect_operands(operands, 1)?;
+    IRegister::try_from(operands[0])
+}
+
+fn two_registers(operands: &[&str]) -> Result<(IRegister, IRegister), String> {
+    expect_operands(operands, 2)?;
+    Ok((
+        IRegister::try_from(operands[0])?,
+        IRegister::try_from(operands[1])?,
+    ))
+}
+
+fn three_registers(operands: &[&str]) -> Result<(IRegister, IRegister, IRegister), String> {
+    expect_operands(operands, 3)?;
+    Ok((
+        IRegister::try_from(operands[0])?,
+        IRegister::try_from(operands[1])?,
+        IRegister::try_from(operands[2])?,
+    ))
+}
+
+fn sfence(operands: &[&str]) -> Result<(IRegister, IRegister), String> {
+    two_registers(operands)
+}
+
+fn add_shifted(operands: &[&str]) -> Result<(IRegister, IRegister, IRegister, u8), String> {
+    expect_operands(operands, 4)?;
+    let (dest, src1, src2) = three_registers(&operands[..3])?;
+    Ok((dest, src1, src2, parse_shift(operands[3])?))
+}
+
+fn shift(operands: &[&str]) -> Result<(IRegister, IRegister, Shamt), String> {
+    expect_operands(operands, 3)?;
+    let (dest, src) = two_registers(&operands[..2])?;
+    Ok((dest, src, Shamt::try_from(parse_int(operands[2])?)?))
+}
+
+fn shift_word(operands: &[&str]) -> Result<(IRegister, IRegister, ShamtW), String> {
+    expect_operands(operands, 3)?;
+    let (dest, src) = two_registers(&operands[..2])?;
+    Ok((dest, src, ShamtW::try_from(parse_int(operands[2])?)?))
+}
+
+fn bit_field(operands: &[&str]) -> Result<(IRegister, IRegister, u8, u8), String> {
+    expect_operands(operands, 4)?;
+    let (dest, src) = two_registers(&operands[..2])?;
+    let index = |str: &str| match parse_int(str)? {
+        x @ 0..=63 => Ok(x as u8),
+        _ => Err(format!("bit index must be between 0 and 63: {str}")),
+    };
+    Ok((dest, src, index(operands[2])?, index(operands[3])?))
+}
+
+fn increment(operands: &[&str]) -> Result<(IRegister, IRegister, IncrementImmediate, u8), String> {
+    expect_operands(operands, 4)?;
+    Ok((
+        IRegister::try_from(operands[0])?,
+        parse_register_address(operands[1])?,
+        IncrementImmediate::try_from(parse_int(operands[2])?)?,
+        parse_shift(operands[3])?,
+    ))
+}
+
+fn indexed(operands: &[&str]) -> Result<(IRegister, IRegister, IRegister, u8), String> {
+    expect_operands(operands, 4)?;
+    let (reg, base, index) = three_registers(&operands[..3])?;
+    Ok((reg, base, index, parse_shift(operands[3])?))
+}
+
+fn float_indexed(operands: &[&str]) -> Result<(FRegister, IRegister, IRegister, u8), String> {
+    expect_operands(operands, 4)?;
+    Ok((
+        FRegister::try_from(operands[0])?,
+        IRegister::try_from(operands[1])?,
+        IRegister::try_from(operands[2])?,
+        parse_shift(operands[3])?,
+    ))
+}
+
+/// Parses the operands of a paired load or store, which end with the constant shift applied to the offset
+fn pair(operands: &[&str], shift: i64) -> Result<(IRegister, IRegister, IRegister, u8), String> {
+    expect_operands(operands, 5)?;
+    if parse_int(operands[4])? != shift {
+        return Err(format!("paired access requires a shift of {shift}"));
+    }
+    Ok((
+        IRegister::try_from(operands[0])?,
+        IRegister::try_from(operands[1])?,
+        parse_register_address(operands[2])?,
+        parse_shift(operands[3])?,
+    ))
+}
@@ -0,0 +1,179 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::instruction::{Instruction, RoundingMode, disassemble_instruction};
+use riscv_codec::register::{FRegister, VRegister};
+
+#[test]
+fn float_convert_bfloat16_from_single() {
+    let expected = Instruction::FcvtBf16S { dest: FRegister::FA0, src: FRegister::FA1, rm: RoundingMode::DYN };
+    let bin = 0x4485f553;
+
+    // check assembler
+    let i = assemble_line("fcvt.bf16.s fa0, fa1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_single_from_bfloat16() {
+    let expected = Instruction::FcvtSBf16 { dest: FRegister::FT0, src: FRegister::FS1, rm: RoundingMode::RNE };
+    let bin = 0x40648053;
+
+    // check assembler
+    let i = assemble_line("fcvt.s.bf16 ft0,fs1,rne").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn vector_narrowing_convert_bfloat16() {
+    let expected = Instruction::Vfncvtbf16FFW { dest: VRegister::V1, src: VRegister::V2, masked: false };
+    let bin = 0x4a2e90d7;
+
+    // check assembler
+    let i = assemble_line("vfncvtbf16.f.f.w v1, v2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn vector_narrowing_convert_bfloat16_masked() {
+    let expected = Instruction::Vfncvtbf16FFW { dest: VRegister::V3, src: VRegister::V30, masked: true };
+    let bin = 0x49ee91d7;
+
+    // check assembler
+    let i = assemble_line("vfncvtbf16.f.f.w v3, v30, v0.t").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn vector_widening_convert_bfloat16() {
+    let expected = Instruction::Vfwcvtbf16FFV { dest: VRegister::V4, src: VRegister::V8, masked: false };
+    let bin = 0x4a869257;
+
+    // check assembler
+    let i = assemble_line("vfwcvtbf16.f.f.v v4, v8").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn vector_widening_convert_bfloat16_masked() {
+    let expected = Instruction::Vfwcvtbf16FFV { dest: VRegister::V4, src: VRegister::V8, masked: true };
+    let bin = 0x48869257;
+
+    // check assembler
+    let i = assemble_line("vfwcvtbf16.f.f.v v4,v8,v0.t").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn vector_widening_multiply_accumulate_bfloat16_vector() {
+    let expected = Instruction::Vfwmaccbf16VV { dest: VRegister::V8, src1: VRegister::V1, src2: VRegister::V2, masked: false };
+    let bin = 0xee209457;
+
+    // check assembler
+    let i = assemble_line("vfwmaccbf16.vv v8, v1, v2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn vector_widening_multiply_accumulate_bfloat16_scalar() {
+    let expected = Instruction::Vfwmaccbf16VF { dest: VRegister::V16, src1: FRegister::FA0, src2: VRegister::V24, masked: true };
+    let bin = 0xed855857;
+
+    // check assembler
+    let i = assemble_line("vfwmaccbf16.vf v16, fa0, v24, v0.t").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
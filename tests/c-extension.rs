@@ -322,6 +322,33 @@ fn load_upper_immediate() {
     assert_eq!(i, i3);
 }
 
+#[test]
+fn load_upper_immediate_a0() {
+    // the low bits of a0 match sp, which must not be decoded as c.addi16sp
+    let expected = CInstruction::LUI {
+        dest: IRegister::A0,
+        imm: CIImmediate::try_from(1).unwrap(),
+    };
+    let bin = 0x6505;
+
+    // check assembler
+    let i = assemble_line("c.lui a0,1").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    println!("{}", CInstruction::disassemble(&i));
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
 #[test]
 fn shift_right_logical_immediate() {
     let expected = CInstruction::SRLI {
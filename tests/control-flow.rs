@@ -0,0 +1,124 @@
+use riscv_codec::assembly::{assemble_line, assemble_line_with};
+use riscv_codec::control_flow::ControlFlow;
+use riscv_codec::isa::IsaConfig;
+
+const ZCMP: IsaConfig = IsaConfig {
+    zcmp: true,
+    ..IsaConfig::new()
+};
+
+const ZCMT: IsaConfig = IsaConfig {
+    zcmt: true,
+    ..IsaConfig::new()
+};
+
+fn flow(line: &str) -> ControlFlow {
+    assemble_line(line).unwrap().i().control_flow()
+}
+
+fn compressed_flow(line: &str, isa: &IsaConfig) -> ControlFlow {
+    assemble_line_with(line, isa).unwrap().c().control_flow()
+}
+
+#[test]
+fn sequential() {
+    assert_eq!(flow("add a0,a1,a2"), ControlFlow::Sequential);
+    assert_eq!(flow("lw a0,0(sp)"), ControlFlow::Sequential);
+    assert_eq!(flow("fence.i"), ControlFlow::Sequential);
+    assert_eq!(flow("wfi"), ControlFlow::Sequential);
+}
+
+#[test]
+fn branches() {
+    assert_eq!(flow("beq a0,a1,16"), ControlFlow::Branch);
+    assert_eq!(flow("bgeu a0,a1,-16"), ControlFlow::Branch);
+}
+
+#[test]
+fn jal() {
+    assert_eq!(flow("jal zero,16"), ControlFlow::Jump);
+    assert_eq!(flow("jal a0,16"), ControlFlow::Jump);
+    assert_eq!(flow("jal ra,16"), ControlFlow::Call);
+    assert_eq!(flow("jal t0,16"), ControlFlow::Call);
+}
+
+#[test]
+fn jalr() {
+    assert_eq!(flow("jalr zero,0(a0)"), ControlFlow::IndirectJump);
+    assert_eq!(flow("jalr ra,0(a0)"), ControlFlow::Call);
+    assert_eq!(flow("jalr zero,0(ra)"), ControlFlow::Return);
+    assert_eq!(flow("jalr zero,0(t0)"), ControlFlow::Return);
+    // pops and then pushes the return address stack
+    assert_eq!(flow("jalr ra,0(t0)"), ControlFlow::Call);
+    assert_eq!(flow("jalr ra,0(ra)"), ControlFlow::Call);
+}
+
+#[test]
+fn traps() {
+    assert_eq!(flow("ecall"), ControlFlow::Trap);
+    assert_eq!(flow("ebreak"), ControlFlow::Trap);
+    assert_eq!(flow("mret"), ControlFlow::TrapReturn);
+    assert_eq!(flow("sret"), ControlFlow::TrapReturn);
+}
+
+#[test]
+fn compressed() {
+    let isa = IsaConfig::default();
+    assert_eq!(
+        compressed_flow("c.addi a0,1", &isa),
+        ControlFlow::Sequential
+    );
+    assert_eq!(compressed_flow("c.j 72", &isa), ControlFlow::Jump);
+    assert_eq!(compressed_flow("c.beqz a5,72", &isa), ControlFlow::Branch);
+    assert_eq!(compressed_flow("c.jr a0", &isa), ControlFlow::IndirectJump);
+    assert_eq!(compressed_flow("c.jr ra", &isa), ControlFlow::Return);
+    assert_eq!(compressed_flow("c.jalr a0", &isa), ControlFlow::Call);
+    assert_eq!(compressed_flow("c.ebreak", &isa), ControlFlow::Trap);
+}
+
+#[test]
+fn compressed_push_pop_and_table_jumps() {
+    assert_eq!(
+        compressed_flow("cm.popret {ra},16", &ZCMP),
+        ControlFlow::Return
+    );
+    assert_eq!(
+        compressed_flow("cm.pop {ra},16", &ZCMP),
+        ControlFlow::Sequential
+    );
+    assert_eq!(compressed_flow("cm.jt 3", &ZCMT), ControlFlow::IndirectJump);
+    assert_eq!(compressed_flow("cm.jalt 32", &ZCMT), ControlFlow::Call);
+}
+
+#[test]
+fn targets() {
+    let i = assemble_line("jal ra,-2048").unwrap().i();
+    assert_eq!(i.target(0x1000), Some(0x800));
+
+    let i = assemble_line("bne a0,a1,16").unwrap().i();
+    assert_eq!(i.target(0x1000), Some(0x1010));
+
+    let i = assemble_line("jalr ra,16(a0)").unwrap().i();
+    assert_eq!(i.target(0x1000), None);
+
+    let i = assemble_line("add a0,a1,a2").unwrap().i();
+    assert_eq!(i.target(0x1000), None);
+}
+
+#[test]
+fn target_wraps() {
+    let i = assemble_line("beq a0,a1,-8").unwrap().i();
+    assert_eq!(i.target(4), Some(u64::MAX - 3));
+}
+
+#[test]
+fn compressed_targets() {
+    let c = assemble_line("c.j -216").unwrap().c();
+    assert_eq!(c.target(0x1000), Some(0x1000 - 216));
+
+    let c = assemble_line("c.bnez a0,72").unwrap().c();
+    assert_eq!(c.target(0x1000), Some(0x1048));
+
+    let c = assemble_line("c.jr a0").unwrap().c();
+    assert_eq!(c.target(0x1000), None);
+}
@@ -0,0 +1,160 @@
+use riscv_codec::explain::{BitField, Explanation, explain};
+use riscv_codec::raw::Format;
+
+fn field(high: u8, low: u8, name: &str, value: u32, immediate: Option<(u8, u8)>) -> BitField {
+    BitField {
+        high,
+        low,
+        name: name.to_owned(),
+        value,
+        immediate,
+    }
+}
+
+#[test]
+fn r_format() {
+    let e = explain(0x00c58533).unwrap();
+    assert_eq!(e.format, Format::R);
+    assert_eq!(
+        e.fields,
+        [
+            field(31, 25, "funct7", 0, None),
+            field(24, 20, "rs2", 12, None),
+            field(19, 15, "rs1", 11, None),
+            field(14, 12, "funct3", 0, None),
+            field(11, 7, "rd", 10, None),
+            field(6, 0, "opcode", 0b011_0011, None),
+        ]
+    );
+    assert_eq!(e.immediate, None);
+    assert_eq!(e.disassembly, Some("add a0,a1,a2".to_owned()));
+    assert_eq!(
+        e.to_string(),
+        " 31     25 24   20 19   15 14    12 11    7 6       0
++---------+-------+-------+--------+-------+---------+
+| funct7  |  rs2  |  rs1  | funct3 |  rd   | opcode  |
+| 0000000 | 01100 | 01011 |  000   | 01010 | 0110011 |
++---------+-------+-------+--------+-------+---------+
+R-type: add a0,a1,a2"
+    );
+}
+
+#[test]
+fn b_format() {
+    let e = explain(0x80b51063).unwrap();
+    assert_eq!(e.format, Format::B);
+    assert_eq!(
+        e.fields,
+        [
+            field(31, 31, "imm[12]", 1, Some((12, 12))),
+            field(30, 25, "imm[10:5]", 0, Some((10, 5))),
+            field(24, 20, "rs2", 11, None),
+            field(19, 15, "rs1", 10, None),
+            field(14, 12, "funct3", 1, None),
+            field(11, 8, "imm[4:1]", 0, Some((4, 1))),
+            field(7, 7, "imm[11]", 0, Some((11, 11))),
+            field(6, 0, "opcode", 0b110_0011, None),
+        ]
+    );
+    assert_eq!(e.immediate, Some(-4096));
+    assert_eq!(e.disassembly, Some("bne a0,a1,-4096".to_owned()));
+}
+
+#[test]
+fn cj_format() {
+    let e = explain(0xbf25).unwrap();
+    assert_eq!(e.format, Format::CJ);
+    let names: Vec<_> = e.fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "funct3", "imm[11]", "imm[4]", "imm[9:8]", "imm[10]", "imm[6]", "imm[7]", "imm[3:1]",
+            "imm[5]", "op"
+        ]
+    );
+    // reassembling the immediate from its parts gives the decoded value
+    let imm = e
+        .fields
+        .iter()
+        .filter_map(|f| f.immediate.map(|(_, low)| f.value << low))
+        .fold(0, |x, part| x | part);
+    assert_eq!(((imm as i32) << 20) >> 20, -200);
+    assert_eq!(e.immediate, Some(-200));
+    assert_eq!(e.disassembly, Some("c.j -200".to_owned()));
+}
+
+/// Reassembles the immediate from the fields that hold its parts
+fn reassemble(e: &Explanation) -> u32 {
+    e.fields
+        .iter()
+        .filter_map(|f| f.immediate.map(|(_, low)| f.value << low))
+        .fold(0, |x, part| x | part)
+}
+
+#[test]
+fn c_lw_layout() {
+    let e = explain(0x41a8).unwrap();
+    assert_eq!(e.format, Format::CL);
+    assert_eq!(
+        e.fields,
+        [
+            field(15, 13, "funct3", 0b010, None),
+            field(12, 10, "imm[5:3]", 0, Some((5, 3))),
+            field(9, 7, "rs1'", 3, None),
+            field(6, 6, "imm[2]", 0, Some((2, 2))),
+            field(5, 5, "imm[6]", 1, Some((6, 6))),
+            field(4, 2, "rd'", 2, None),
+            field(1, 0, "op", 0, None),
+        ]
+    );
+    assert_eq!(reassemble(&e), 64);
+    assert_eq!(e.immediate, Some(64));
+    assert_eq!(e.disassembly, Some("c.lw a0,64(a1)".to_owned()));
+}
+
+#[test]
+fn c_addi16sp_layout() {
+    let e = explain(0x7139).unwrap();
+    let names: Vec<_> = e.fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "funct3", "imm[9]", "rd/rs1", "imm[4]", "imm[6]", "imm[8:7]", "imm[5]", "op"
+        ]
+    );
+    assert_eq!(((reassemble(&e) as i32) << 22) >> 22, -64);
+    assert_eq!(e.immediate, Some(-64));
+    assert_eq!(e.disassembly, Some("c.addi16sp -64".to_owned()));
+}
+
+#[test]
+fn c_lwsp_layout() {
+    let e = explain(0x557e).unwrap();
+    let names: Vec<_> = e.fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["funct3", "imm[5]", "rd/rs1", "imm[4:2]", "imm[7:6]", "op"]
+    );
+    assert_eq!(reassemble(&e), 252);
+    assert_eq!(e.immediate, Some(252));
+    assert_eq!(e.disassembly, Some("c.lwsp a0,252".to_owned()));
+}
+
+#[test]
+fn c_lui_layout() {
+    let e = explain(0x757d).unwrap();
+    let names: Vec<_> = e.fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, ["funct3", "imm[17]", "rd/rs1", "imm[16:12]", "op"]);
+    assert_eq!(((reassemble(&e) as i32) << 14) >> 14, -4096);
+    assert_eq!(e.immediate, Some(-4096));
+}
+
+#[test]
+fn undecodable() {
+    let e = explain(0xfff5850b).unwrap();
+    assert_eq!(e.format, Format::R);
+    assert_eq!(e.disassembly, None);
+    assert!(e.to_string().ends_with("R-type"));
+
+    assert!(explain(0x1852e).is_err());
+}
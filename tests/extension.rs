@@ -0,0 +1,196 @@
+use core::any::Any;
+use core::fmt::{Display, Formatter};
+
+use riscv_codec::assembly::{AssemblyResult, assemble_line_with_extensions};
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::extension::{CustomInstruction, Extension, Extensions};
+use riscv_codec::instruction::Instruction;
+use riscv_codec::isa::IsaConfig;
+use riscv_codec::register::IRegister;
+use riscv_codec::stream::StreamDecoder;
+
+/// A multiply-accumulate accelerator in the custom-0 opcode space
+#[derive(Debug, PartialEq)]
+enum Accelerator {
+    Mac {
+        dest: IRegister,
+        src1: IRegister,
+        src2: IRegister,
+    },
+}
+
+impl Display for Accelerator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Accelerator::Mac { dest, src1, src2 } => write!(f, "acc.mac {dest},{src1},{src2}"),
+        }
+    }
+}
+
+impl CustomInstruction for Accelerator {
+    fn encode(&self) -> u32 {
+        match self {
+            Accelerator::Mac { dest, src1, src2 } => {
+                u32::from(*src2) << 20 | u32::from(*src1) << 15 | u32::from(*dest) << 7 | 0b000_1011
+            }
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+struct AcceleratorExtension;
+
+impl Extension for AcceleratorExtension {
+    fn name(&self) -> &str {
+        "xacc"
+    }
+
+    fn decode(
+        &self,
+        instruction: u32,
+        _isa: &IsaConfig,
+    ) -> Option<Result<Box<dyn CustomInstruction>, String>> {
+        if instruction & 0x7f != 0b000_1011 {
+            return None;
+        }
+        if instruction >> 25 != 0 || (instruction >> 12) & 0b111 != 0 {
+            return Some(Err("unknown accelerator instruction".to_owned()));
+        }
+        let reg = |x: u32| IRegister::from_int(x & 0b1_1111);
+        Some(Ok(Box::new(Accelerator::Mac {
+            dest: reg(instruction >> 7),
+            src1: reg(instruction >> 15),
+            src2: reg(instruction >> 20),
+        })))
+    }
+
+    fn assemble(
+        &self,
+        mnemonics: &[&str],
+        operands: &[&str],
+        _isa: &IsaConfig,
+    ) -> Option<Result<Box<dyn CustomInstruction>, String>> {
+        if mnemonics != ["acc", "mac"] {
+            return None;
+        }
+        if operands.len() != 3 {
+            return Some(Err("acc.mac instruction requires 3 operands".to_owned()));
+        }
+        let mac = || -> Result<Box<dyn CustomInstruction>, String> {
+            Ok(Box::new(Accelerator::Mac {
+                dest: IRegister::try_from(operands[0])?,
+                src1: IRegister::try_from(operands[1])?,
+                src2: IRegister::try_from(operands[2])?,
+            }))
+        };
+        Some(mac())
+    }
+}
+
+#[test]
+fn assemble_custom() {
+    let extensions = Extensions::new().with(AcceleratorExtension);
+    let expected = Accelerator::Mac {
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+    };
+    let bin = 0x00c5850b;
+
+    // check assembler
+    let i = assemble_line_with_extensions("acc.mac a0,a1,a2", &IsaConfig::default(), &extensions)
+        .unwrap()
+        .custom();
+    assert_eq!(i.as_any().downcast_ref::<Accelerator>(), Some(&expected));
+
+    // check encoder
+    assert_eq!(i.encode(), bin);
+
+    // check disassembler
+    assert_eq!(i.to_string(), "acc.mac a0,a1,a2");
+
+    // standard instructions are still assembled normally
+    let i2 = assemble_line_with_extensions("addi a0,a1,1", &IsaConfig::default(), &extensions);
+    assert!(matches!(
+        i2,
+        Ok(AssemblyResult::I(Instruction::Addi { .. }))
+    ));
+
+    // errors from the extension are reported
+    let i3 = assemble_line_with_extensions("acc.mac a0,a1", &IsaConfig::default(), &extensions);
+    assert_eq!(i3.unwrap_err(), "acc.mac instruction requires 3 operands");
+
+    // unknown mnemonics are still errors
+    let i4 = assemble_line_with_extensions("acc.foo a0", &IsaConfig::default(), &extensions);
+    assert_eq!(i4.unwrap_err(), "unknown mnemonic: acc.foo");
+}
+
+#[test]
+fn stream_decoder() {
+    let extensions = Extensions::new().with(AcceleratorExtension);
+    assert_eq!(extensions.names(), ["xacc"]);
+
+    // addi a0,a1,1; c.mv a0,a1; acc.mac a0,a1,a2; an unknown custom-0 encoding
+    let bytes = [
+        0x13, 0x85, 0x15, 0x00, 0x2e, 0x85, 0x0b, 0x85, 0xc5, 0x00, 0x0b, 0x95, 0xc5, 0x00,
+    ];
+    let decoder = StreamDecoder::new(&bytes, IsaConfig::default()).with_extensions(&extensions);
+    let decoded: Vec<_> = StreamDecoder::new(&bytes, IsaConfig::default())
+        .with_extensions(&extensions)
+        .collect();
+    assert_eq!(
+        decoded.iter().map(|x| x.offset).collect::<Vec<_>>(),
+        [0, 4, 6, 10]
+    );
+    assert_eq!(
+        decoded[0].result,
+        Ok(AssemblyResult::I(Instruction::Addi {
+            dest: IRegister::A0,
+            src: IRegister::A1,
+            imm: riscv_codec::immediates::IImmediate::try_from(1).unwrap(),
+        }))
+    );
+    assert_eq!(
+        decoded[1].result,
+        Ok(AssemblyResult::C(CInstruction::MV {
+            dest: IRegister::A0,
+            src: IRegister::A1
+        }))
+    );
+    assert_eq!(decoded[1].length, 2);
+    let disassembly: Vec<_> = decoded
+        .iter()
+        .map(|x| match &x.result {
+            Ok(i) => decoder.disassemble(i),
+            Err(e) => e.clone(),
+        })
+        .collect();
+    assert_eq!(
+        disassembly,
+        [
+            "addi a0,a1,1",
+            "c.mv a0,a1",
+            "acc.mac a0,a1,a2",
+            "unknown accelerator instruction"
+        ]
+    );
+}
+
+#[test]
+fn stream_decoder_without_extensions() {
+    let bytes = [0x0b, 0x85, 0xc5, 0x00, 0x13];
+    let decoded: Vec<_> = StreamDecoder::new(&bytes, IsaConfig::default()).collect();
+    assert_eq!(decoded.len(), 2);
+    assert_eq!(
+        decoded[0].result,
+        Err("instruction uses custom opcode Custom0, which requires an extension".to_owned())
+    );
+    assert_eq!(decoded[0].bits, 0x00c5850b);
+    assert_eq!(
+        decoded[1].result,
+        Err("truncated instruction at the end of the stream".to_owned())
+    );
+}
@@ -0,0 +1,377 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::instruction::{Instruction, disassemble_instruction};
+use riscv_codec::register::IRegister;
+
+#[test]
+fn hypervisor_fence_virtual_memory() {
+    let expected = Instruction::HfenceVvma { vaddr: IRegister::A0, asid: IRegister::A1 };
+    let bin = 0x22b50073;
+
+    // check assembler
+    let i = assemble_line("hfence.vvma a0, a1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_fence_guest_memory() {
+    let expected = Instruction::HfenceGvma { gaddr: IRegister::T0, vmid: IRegister::Zero };
+    let bin = 0x62028073;
+
+    // check assembler
+    let i = assemble_line("hfence.gvma t0").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_invalidate_virtual_memory() {
+    let expected = Instruction::HinvalVvma { vaddr: IRegister::A0, asid: IRegister::A1 };
+    let bin = 0x26b50073;
+
+    // check assembler
+    let i = assemble_line("hinval.vvma a0,a1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_invalidate_guest_memory() {
+    let expected = Instruction::HinvalGvma { gaddr: IRegister::S1, vmid: IRegister::A2 };
+    let bin = 0x66c48073;
+
+    // check assembler
+    let i = assemble_line("hinval.gvma s1,a2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_load_byte() {
+    let expected = Instruction::HlvB { dest: IRegister::A0, addr: IRegister::S2 };
+    let bin = 0x60094573;
+
+    // check assembler
+    let i = assemble_line("hlv.b a0, (s2)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_load_byte_unsigned() {
+    let expected = Instruction::HlvBu { dest: IRegister::A0, addr: IRegister::S2 };
+    let bin = 0x60194573;
+
+    // check assembler
+    let i = assemble_line("hlv.bu a0, (s2)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_load_half() {
+    let expected = Instruction::HlvH { dest: IRegister::A0, addr: IRegister::S2 };
+    let bin = 0x64094573;
+
+    // check assembler
+    let i = assemble_line("hlv.h a0, (s2)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_load_half_unsigned() {
+    let expected = Instruction::HlvHu { dest: IRegister::A0, addr: IRegister::S2 };
+    let bin = 0x64194573;
+
+    // check assembler
+    let i = assemble_line("hlv.hu a0, (s2)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_load_execute_half_unsigned() {
+    let expected = Instruction::HlvxHu { dest: IRegister::A0, addr: IRegister::S2 };
+    let bin = 0x64394573;
+
+    // check assembler
+    let i = assemble_line("hlvx.hu a0, (s2)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_load_word() {
+    let expected = Instruction::HlvW { dest: IRegister::A0, addr: IRegister::S2 };
+    let bin = 0x68094573;
+
+    // check assembler
+    let i = assemble_line("hlv.w a0, (s2)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_load_execute_word_unsigned() {
+    let expected = Instruction::HlvxWu { dest: IRegister::A0, addr: IRegister::S2 };
+    let bin = 0x68394573;
+
+    // check assembler
+    let i = assemble_line("hlvx.wu a0, (s2)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_load_word_unsigned() {
+    let expected = Instruction::HlvWu { dest: IRegister::A0, addr: IRegister::S2 };
+    let bin = 0x68194573;
+
+    // check assembler
+    let i = assemble_line("hlv.wu a0, (s2)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_load_double() {
+    let expected = Instruction::HlvD { dest: IRegister::A0, addr: IRegister::S2 };
+    let bin = 0x6c094573;
+
+    // check assembler
+    let i = assemble_line("hlv.d a0, (s2)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_store_byte() {
+    let expected = Instruction::HsvB { src: IRegister::T1, addr: IRegister::StackPointer };
+    let bin = 0x62614073;
+
+    // check assembler
+    let i = assemble_line("hsv.b t1,0(sp)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_store_half() {
+    let expected = Instruction::HsvH { src: IRegister::T1, addr: IRegister::StackPointer };
+    let bin = 0x66614073;
+
+    // check assembler
+    let i = assemble_line("hsv.h t1,0(sp)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_store_word() {
+    let expected = Instruction::HsvW { src: IRegister::T1, addr: IRegister::StackPointer };
+    let bin = 0x6a614073;
+
+    // check assembler
+    let i = assemble_line("hsv.w t1,0(sp)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hypervisor_store_double() {
+    let expected = Instruction::HsvD { src: IRegister::T1, addr: IRegister::StackPointer };
+    let bin = 0x6e614073;
+
+    // check assembler
+    let i = assemble_line("hsv.d t1,0(sp)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
@@ -0,0 +1,696 @@
+use riscv_codec::assembly::{AssemblyResult, assemble_line};
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::hart::{Exception, Hart, Privilege};
+use riscv_codec::instruction::Instruction;
+use riscv_codec::register::{FRegister, IRegister};
+
+/// Assembles a program into memory starting at address 0, followed by `data_size` zero bytes
+fn program(lines: &[&str], data_size: usize) -> Vec<u8> {
+    let mut memory = Vec::new();
+    for line in lines {
+        match assemble_line(line).unwrap() {
+            AssemblyResult::I(i) => {
+                memory.extend_from_slice(&Instruction::encode(&i).to_le_bytes())
+            }
+            AssemblyResult::C(c) => {
+                memory.extend_from_slice(&CInstruction::encode(&c).to_le_bytes())
+            }
+            _ => panic!("unexpected assembly result for {line}"),
+        }
+    }
+    memory.resize(memory.len() + data_size, 0);
+    memory
+}
+
+/// Executes single instructions on a hart with the given memory
+fn execute(hart: &mut Hart, memory: &mut Vec<u8>, line: &str) -> Result<(), Exception> {
+    match assemble_line(line).unwrap() {
+        AssemblyResult::I(i) => hart.execute(&i, memory),
+        AssemblyResult::C(c) => hart.execute_compressed(&c, memory),
+        _ => panic!("unexpected assembly result for {line}"),
+    }
+}
+
+#[test]
+fn sum_loop() {
+    let mut memory = program(
+        &[
+            "addi a0,zero,0",
+            "addi a1,zero,10",
+            "add a0,a0,a1",
+            "addi a1,a1,-1",
+            "bne a1,zero,-8",
+            "ecall",
+        ],
+        0,
+    );
+    let mut hart = Hart::new(0);
+    assert_eq!(hart.run(&mut memory), Exception::EnvironmentCall);
+    assert_eq!(hart.x(IRegister::A0), 55);
+    assert_eq!(hart.pc, 20);
+    assert_eq!(hart.instret, 32);
+}
+
+#[test]
+fn compressed_program() {
+    let mut memory = program(
+        &[
+            "c.li a0,5",
+            "c.lui a1,1",
+            "c.add a1,a0",
+            "c.addi a0,-1",
+            "c.ebreak",
+        ],
+        0,
+    );
+    let mut hart = Hart::new(0);
+    assert_eq!(hart.run(&mut memory), Exception::Breakpoint);
+    assert_eq!(hart.x(IRegister::A0), 4);
+    assert_eq!(hart.x(IRegister::A1), 0x1005);
+    assert_eq!(hart.pc, 8);
+}
+
+#[test]
+fn compressed_jump_and_link() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0x100);
+    hart.set_x(IRegister::A0, 0x200);
+    execute(&mut hart, &mut memory, "c.jalr a0").unwrap();
+    assert_eq!(hart.pc, 0x200);
+    assert_eq!(hart.x(IRegister::ReturnAddress), 0x102);
+
+    execute(&mut hart, &mut memory, "jalr t0,8(a0)").unwrap();
+    assert_eq!(hart.pc, 0x208);
+    assert_eq!(hart.x(IRegister::T0), 0x204);
+}
+
+#[test]
+fn zero_register() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    execute(&mut hart, &mut memory, "addi zero,zero,5").unwrap();
+    assert_eq!(hart.x(IRegister::Zero), 0);
+}
+
+#[test]
+fn prefetch_hints() {
+    // ori with rd = zero and the low bits of the offset clear is decoded as prefetch.i
+    let mut memory = program(&["ori zero,a0,32", "ecall"], 0);
+    let mut hart = Hart::new(0);
+    assert_eq!(hart.run(&mut memory), Exception::EnvironmentCall);
+    assert_eq!(hart.pc, 4);
+
+    execute(&mut hart, &mut memory, "prefetch.w 64(a0)").unwrap();
+    assert_eq!(hart.pc, 8);
+}
+
+#[test]
+fn word_operations() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    hart.set_x(IRegister::A1, 0x7fff_ffff);
+    execute(&mut hart, &mut memory, "addiw a0,a1,1").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0xffff_ffff_8000_0000);
+    execute(&mut hart, &mut memory, "srliw a2,a0,4").unwrap();
+    assert_eq!(hart.x(IRegister::A2), 0x0800_0000);
+    execute(&mut hart, &mut memory, "sraiw a2,a0,4").unwrap();
+    assert_eq!(hart.x(IRegister::A2), 0xffff_ffff_f800_0000);
+    execute(&mut hart, &mut memory, "lui a3,-1").unwrap();
+    assert_eq!(hart.x(IRegister::A3), 0xffff_ffff_ffff_f000);
+}
+
+#[test]
+fn division_by_zero() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    hart.set_x(IRegister::A1, 7);
+    execute(&mut hart, &mut memory, "div a0,a1,zero").unwrap();
+    assert_eq!(hart.x(IRegister::A0), u64::MAX);
+    execute(&mut hart, &mut memory, "divu a0,a1,zero").unwrap();
+    assert_eq!(hart.x(IRegister::A0), u64::MAX);
+    execute(&mut hart, &mut memory, "rem a0,a1,zero").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 7);
+    execute(&mut hart, &mut memory, "remu a0,a1,zero").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 7);
+    execute(&mut hart, &mut memory, "divuw a0,a1,zero").unwrap();
+    assert_eq!(hart.x(IRegister::A0), u64::MAX);
+}
+
+#[test]
+fn division_overflow() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    hart.set_x(IRegister::A1, i64::MIN as u64);
+    hart.set_x(IRegister::A2, u64::MAX);
+    execute(&mut hart, &mut memory, "div a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), i64::MIN as u64);
+    execute(&mut hart, &mut memory, "rem a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0);
+
+    hart.set_x(IRegister::A1, i32::MIN as u64);
+    execute(&mut hart, &mut memory, "divw a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), i32::MIN as i64 as u64);
+    execute(&mut hart, &mut memory, "remw a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0);
+}
+
+#[test]
+fn multiply_high() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    hart.set_x(IRegister::A1, u64::MAX);
+    hart.set_x(IRegister::A2, 2);
+    execute(&mut hart, &mut memory, "mulh a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), u64::MAX);
+    execute(&mut hart, &mut memory, "mulhu a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 1);
+    execute(&mut hart, &mut memory, "mulhsu a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), u64::MAX);
+}
+
+#[test]
+fn loads_and_stores() {
+    let mut memory = program(&[], 16);
+    let mut hart = Hart::new(0);
+    hart.set_x(IRegister::A1, 0x8081);
+    execute(&mut hart, &mut memory, "sh a1,2(zero)").unwrap();
+    assert_eq!(&memory[..4], [0, 0, 0x81, 0x80]);
+    execute(&mut hart, &mut memory, "lb a0,2(zero)").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0xffff_ffff_ffff_ff81);
+    execute(&mut hart, &mut memory, "lbu a0,2(zero)").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0x81);
+    execute(&mut hart, &mut memory, "lw a0,0(zero)").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0xffff_ffff_8081_0000);
+    execute(&mut hart, &mut memory, "lwu a0,0(zero)").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0x8081_0000);
+}
+
+#[test]
+fn access_fault() {
+    let mut memory = program(&[], 8);
+    let mut hart = Hart::new(0x40);
+    hart.set_x(IRegister::A1, 6);
+    assert_eq!(
+        execute(&mut hart, &mut memory, "lw a0,0(a1)"),
+        Err(Exception::LoadAccessFault(6))
+    );
+    assert_eq!(hart.pc, 0x40);
+    assert_eq!(hart.instret, 0);
+    assert_eq!(
+        hart.step(&mut memory),
+        Err(Exception::InstructionAccessFault(0x40))
+    );
+}
+
+#[test]
+fn load_reserved_store_conditional() {
+    let mut memory = program(&[], 16);
+    let mut hart = Hart::new(0);
+    hart.set_x(IRegister::A1, 8);
+    hart.set_x(IRegister::A2, 42);
+    execute(&mut hart, &mut memory, "sc.w a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 1);
+    assert_eq!(memory[8], 0);
+
+    execute(&mut hart, &mut memory, "lr.w a0,a1").unwrap();
+    execute(&mut hart, &mut memory, "sc.w a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0);
+    assert_eq!(memory[8], 42);
+
+    // the reservation is consumed by the first store conditional
+    execute(&mut hart, &mut memory, "sc.w a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 1);
+}
+
+#[test]
+fn atomics() {
+    let mut memory = program(&[], 16);
+    let mut hart = Hart::new(0);
+    memory[..4].copy_from_slice(&0x7fff_ffffu32.to_le_bytes());
+    hart.set_x(IRegister::A1, 0);
+    hart.set_x(IRegister::A2, 1);
+    execute(&mut hart, &mut memory, "amoadd.w a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0x7fff_ffff);
+    assert_eq!(memory[..4], 0x8000_0000u32.to_le_bytes());
+
+    execute(&mut hart, &mut memory, "amominu.w a0,a1,a2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0xffff_ffff_8000_0000);
+    assert_eq!(memory[..4], 1u32.to_le_bytes());
+
+    hart.set_x(IRegister::A1, 2);
+    assert_eq!(
+        execute(&mut hart, &mut memory, "amoswap.w a0,a1,a2"),
+        Err(Exception::StoreAddressMisaligned(2))
+    );
+}
+
+#[test]
+fn float_nan_boxing() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    hart.set_f32(FRegister::FA1, 1.5f32.to_bits());
+    hart.set_f32(FRegister::FA2, 2.25f32.to_bits());
+    execute(&mut hart, &mut memory, "fadd.s fa0,fa1,fa2").unwrap();
+    assert_eq!(
+        hart.f(FRegister::FA0),
+        0xffff_ffff_0000_0000 | 3.75f32.to_bits() as u64
+    );
+
+    // a double in a single precision operand isn't boxed, so it reads as the canonical NaN
+    hart.set_f(FRegister::FA2, 2.25f64.to_bits());
+    execute(&mut hart, &mut memory, "fadd.s fa0,fa1,fa2").unwrap();
+    assert_eq!(hart.f32(FRegister::FA0), 0x7fc0_0000);
+
+    hart.set_x(IRegister::A0, 0x1234_5678);
+    execute(&mut hart, &mut memory, "fmv.w.x fa0,a0").unwrap();
+    assert_eq!(hart.f(FRegister::FA0), 0xffff_ffff_1234_5678);
+    hart.set_f(FRegister::FA0, 0x8000_0000);
+    execute(&mut hart, &mut memory, "fmv.x.w a0,fa0").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0xffff_ffff_8000_0000);
+}
+
+#[test]
+fn float_to_int_rounding() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    let mut convert = |value: f32, rm: &str| {
+        hart.set_f32(FRegister::FA0, value.to_bits());
+        execute(&mut hart, &mut memory, &format!("fcvt.w.s a0,fa0,{rm}")).unwrap();
+        hart.x(IRegister::A0) as i64
+    };
+    assert_eq!(convert(2.5, "rne"), 2);
+    assert_eq!(convert(2.5, "rtz"), 2);
+    assert_eq!(convert(2.5, "rdn"), 2);
+    assert_eq!(convert(2.5, "rup"), 3);
+    assert_eq!(convert(2.5, "rmm"), 3);
+    assert_eq!(convert(-2.5, "rne"), -2);
+    assert_eq!(convert(-2.5, "rtz"), -2);
+    assert_eq!(convert(-2.5, "rdn"), -3);
+    assert_eq!(convert(-2.5, "rup"), -2);
+    assert_eq!(convert(-2.5, "rmm"), -3);
+    assert_eq!(convert(3e9, "rne"), i32::MAX as i64);
+    assert_eq!(convert(f32::NAN, "rne"), i32::MAX as i64);
+    assert_eq!(convert(f32::NEG_INFINITY, "rne"), i32::MIN as i64);
+    assert_eq!(hart.fcsr & 0x1f, 0b10001);
+}
+
+#[test]
+fn dynamic_rounding_mode() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    hart.set_f(FRegister::FA1, 1.0f64.to_bits());
+    hart.set_f(FRegister::FA2, 3.0f64.to_bits());
+    execute(&mut hart, &mut memory, "fdiv.d fa0,fa1,fa2").unwrap();
+    let nearest = hart.f(FRegister::FA0);
+    assert_eq!(nearest, (1.0f64 / 3.0).to_bits());
+    assert_eq!(hart.fcsr, 0b00001);
+
+    // round up
+    hart.fcsr = 0b011 << 5;
+    execute(&mut hart, &mut memory, "fdiv.d fa0,fa1,fa2").unwrap();
+    assert_eq!(hart.f(FRegister::FA0), nearest + 1);
+
+    hart.fcsr = 0b101 << 5;
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "fdiv.d fa0,fa1,fa2"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+    // a static rounding mode doesn't depend on frm
+    execute(&mut hart, &mut memory, "fdiv.d fa0,fa1,fa2,rtz").unwrap();
+    assert_eq!(hart.f(FRegister::FA0), nearest);
+}
+
+#[test]
+fn float_exception_flags() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    hart.set_f(FRegister::FA1, 1.0f64.to_bits());
+    execute(&mut hart, &mut memory, "fdiv.d fa0,fa1,fa2").unwrap();
+    assert_eq!(hart.f(FRegister::FA0), f64::INFINITY.to_bits());
+    assert_eq!(hart.fcsr, 0b01000);
+
+    hart.set_f(FRegister::FA1, (-4.0f64).to_bits());
+    execute(&mut hart, &mut memory, "fsqrt.d fa0,fa1").unwrap();
+    assert_eq!(hart.f(FRegister::FA0), 0x7ff8_0000_0000_0000);
+    assert_eq!(hart.fcsr, 0b11000);
+
+    execute(&mut hart, &mut memory, "csrrw a0,1,zero").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0b11000);
+    assert_eq!(hart.fcsr, 0);
+}
+
+#[test]
+fn fused_multiply_add() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    let x = 1.0 + f64::EPSILON;
+    hart.set_f(FRegister::FA1, x.to_bits());
+    hart.set_f(FRegister::FA2, x.to_bits());
+    hart.set_f(FRegister::FA3, (-1.0f64).to_bits());
+    // (1 + e)^2 - 1 = 2e + e^2, which is only exact with a single rounding
+    execute(&mut hart, &mut memory, "fmadd.d fa0,fa1,fa2,fa3").unwrap();
+    assert_eq!(
+        f64::from_bits(hart.f(FRegister::FA0)),
+        2.0 * f64::EPSILON + f64::EPSILON * f64::EPSILON
+    );
+    execute(&mut hart, &mut memory, "fnmsub.d fa0,fa1,fa2,fa3").unwrap();
+    assert_eq!(
+        f64::from_bits(hart.f(FRegister::FA0)),
+        -(2.0 + 2.0 * f64::EPSILON)
+    );
+}
+
+#[test]
+fn float_compare_and_classify() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    hart.set_f(FRegister::FA1, (-0.0f64).to_bits());
+    hart.set_f(FRegister::FA2, 0.0f64.to_bits());
+    execute(&mut hart, &mut memory, "feq.d a0,fa1,fa2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 1);
+    execute(&mut hart, &mut memory, "fmin.d fa0,fa2,fa1").unwrap();
+    assert_eq!(hart.f(FRegister::FA0), (-0.0f64).to_bits());
+    execute(&mut hart, &mut memory, "fclass.d a0,fa1").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 1 << 3);
+    assert_eq!(hart.fcsr, 0);
+
+    hart.set_f(FRegister::FA2, f64::NAN.to_bits());
+    execute(&mut hart, &mut memory, "flt.d a0,fa1,fa2").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0);
+    assert_eq!(hart.fcsr, 0b10000);
+    execute(&mut hart, &mut memory, "fmax.d fa0,fa1,fa2").unwrap();
+    assert_eq!(hart.f(FRegister::FA0), (-0.0f64).to_bits());
+}
+
+#[test]
+fn csr_registers() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    hart.set_x(IRegister::A1, 0xff);
+    execute(&mut hart, &mut memory, "csrrw zero,3,a1").unwrap();
+    assert_eq!(hart.fcsr, 0xff);
+    execute(&mut hart, &mut memory, "csrrci a0,2,5").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0b111);
+    execute(&mut hart, &mut memory, "csrrs a0,2,zero").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 0b010);
+    execute(&mut hart, &mut memory, "csrrs a0,3074,zero").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 3);
+
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "csrrw a0,3074,a1"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "csrrs a0,1984,zero"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+}
+
+#[test]
+fn unsupported_instruction() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    assert_eq!(
+        execute(&mut hart, &mut memory, "fadd.q fa0,fa1,fa2"),
+        Err(Exception::IllegalInstruction(
+            "fadd.q is not supported by the simulator".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn illegal_encoding() {
+    let mut memory = vec![0; 4];
+    let mut hart = Hart::new(0);
+    assert!(matches!(
+        hart.step(&mut memory),
+        Err(Exception::IllegalInstruction(_))
+    ));
+}
+
+const SSTATUS: u16 = 0x100;
+const STVEC: u16 = 0x105;
+const SEPC: u16 = 0x141;
+const SCAUSE: u16 = 0x142;
+const MSTATUS: u16 = 0x300;
+const MTVEC: u16 = 0x305;
+const MEPC: u16 = 0x341;
+const MCAUSE: u16 = 0x342;
+const MTVAL: u16 = 0x343;
+
+/// Writes a CSR with `csrrw`, using `t6` to hold the value
+fn write_csr(hart: &mut Hart, memory: &mut Vec<u8>, csr: u16, value: u64) {
+    hart.set_x(IRegister::T6, value);
+    execute(hart, memory, &format!("csrrw zero,{csr},t6")).unwrap();
+}
+
+#[test]
+fn machine_mode_trap_handler() {
+    let mut memory = program(
+        &[
+            "addi t0,zero,20",
+            "csrrw zero,773,t0",
+            "csrrs a0,1984,zero",
+            "ecall",
+            "ebreak",
+            // the handler skips the instruction that trapped
+            "csrrs a1,834,zero",
+            "csrrs a2,833,zero",
+            "csrrs a3,835,zero",
+            "addi a2,a2,4",
+            "csrrw zero,833,a2",
+            "mret",
+        ],
+        0,
+    );
+    let mut hart = Hart::new(0);
+    let exception = hart.run(&mut memory);
+    assert!(matches!(exception, Exception::IllegalInstruction(_)));
+    hart.trap(&exception);
+    assert_eq!(hart.pc, 20);
+    assert_eq!(hart.privilege, Privilege::Machine);
+    assert_eq!(hart.run(&mut memory), Exception::EnvironmentCall);
+    assert_eq!(hart.pc, 12);
+    assert_eq!(hart.x(IRegister::A1), 2);
+    assert_eq!(hart.x(IRegister::A2), 12);
+    // the encoding of the illegal instruction
+    assert_eq!(
+        hart.x(IRegister::A3),
+        u32::from_le_bytes(memory[8..12].try_into().unwrap()) as u64
+    );
+    // mret leaves MPP holding U-mode
+    assert_eq!(hart.csr(MSTATUS).unwrap() >> 11 & 0b11, 0);
+
+    hart.trap(&Exception::EnvironmentCall);
+    assert_eq!(hart.csr(MCAUSE), Some(11));
+    assert_eq!(hart.csr(MTVAL), Some(0));
+}
+
+#[test]
+fn user_mode_and_delegation() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    write_csr(&mut hart, &mut memory, MTVEC, 0x100);
+    write_csr(&mut hart, &mut memory, MEPC, 0x200);
+    hart.set_x(IRegister::A0, 0x1800);
+    execute(&mut hart, &mut memory, "csrrc zero,768,a0").unwrap();
+    execute(&mut hart, &mut memory, "mret").unwrap();
+    assert_eq!(hart.privilege, Privilege::User);
+    assert_eq!(hart.pc, 0x200);
+
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "csrrs a0,768,zero"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "mret"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+    let exception = execute(&mut hart, &mut memory, "ecall").unwrap_err();
+    hart.trap(&exception);
+    assert_eq!(hart.privilege, Privilege::Machine);
+    assert_eq!(hart.pc, 0x100);
+    assert_eq!(hart.csr(MCAUSE), Some(8));
+    assert_eq!(hart.csr(MEPC), Some(0x200));
+    assert_eq!(hart.csr(MSTATUS).unwrap() >> 11 & 0b11, 0);
+
+    // delegate environment calls from U-mode to S-mode
+    write_csr(&mut hart, &mut memory, 0x302, 1 << 8);
+    write_csr(&mut hart, &mut memory, STVEC, 0x300);
+    execute(&mut hart, &mut memory, "mret").unwrap();
+    assert_eq!(hart.privilege, Privilege::User);
+    hart.trap(&Exception::EnvironmentCall);
+    assert_eq!(hart.privilege, Privilege::Supervisor);
+    assert_eq!(hart.pc, 0x300);
+    assert_eq!(hart.csr(SCAUSE), Some(8));
+    assert_eq!(hart.csr(SEPC), Some(0x200));
+    assert_eq!(hart.csr(SSTATUS).unwrap() & 1 << 8, 0);
+
+    // environment calls from S-mode aren't delegated
+    hart.trap(&Exception::EnvironmentCall);
+    assert_eq!(hart.privilege, Privilege::Machine);
+    assert_eq!(hart.csr(MCAUSE), Some(9));
+    assert_eq!(hart.csr(MSTATUS).unwrap() >> 11 & 0b11, 1);
+    execute(&mut hart, &mut memory, "mret").unwrap();
+    assert_eq!(hart.privilege, Privilege::Supervisor);
+
+    execute(&mut hart, &mut memory, "sret").unwrap();
+    assert_eq!(hart.privilege, Privilege::User);
+    assert_eq!(hart.pc, 0x200);
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "sret"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+}
+
+#[test]
+fn trap_values() {
+    let mut memory = program(&[], 16);
+    let mut hart = Hart::new(0x40);
+    write_csr(&mut hart, &mut memory, MTVEC, 0x100);
+    hart.set_x(IRegister::A1, 6);
+    let pc = hart.pc;
+    let exception = execute(&mut hart, &mut memory, "amoadd.w a0,a1,a2").unwrap_err();
+    hart.trap(&exception);
+    assert_eq!(hart.csr(MCAUSE), Some(6));
+    assert_eq!(hart.csr(MTVAL), Some(6));
+    assert_eq!(hart.csr(MEPC), Some(pc));
+
+    hart.set_x(IRegister::A1, 0x1000);
+    let exception = execute(&mut hart, &mut memory, "lw a0,0(a1)").unwrap_err();
+    hart.trap(&exception);
+    assert_eq!(hart.csr(MCAUSE), Some(5));
+    assert_eq!(hart.csr(MTVAL), Some(0x1000));
+
+    hart.pc = 0x80;
+    hart.trap(&Exception::Breakpoint);
+    assert_eq!(hart.csr(MCAUSE), Some(3));
+    assert_eq!(hart.csr(MTVAL), Some(0x80));
+    assert_eq!(hart.pc, 0x100);
+}
+
+#[test]
+fn warl_fields() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    // RV64 with A, C, D, F, I, M, S and U
+    assert_eq!(hart.csr(0x301), Some(0x800000000014112d));
+    write_csr(&mut hart, &mut memory, 0x301, 0);
+    assert_eq!(hart.csr(0x301), Some(0x800000000014112d));
+
+    // reserved trap vector modes keep the previous mode
+    write_csr(&mut hart, &mut memory, MTVEC, 0x101);
+    write_csr(&mut hart, &mut memory, MTVEC, 0x203);
+    assert_eq!(hart.csr(MTVEC), Some(0x201));
+    write_csr(&mut hart, &mut memory, MEPC, 0x203);
+    assert_eq!(hart.csr(MEPC), Some(0x202));
+
+    // MPP can't hold the reserved mode, and UXL and SXL are fixed
+    write_csr(&mut hart, &mut memory, MSTATUS, 1 << 11);
+    write_csr(&mut hart, &mut memory, MSTATUS, 2 << 11 | 0xf << 32);
+    assert_eq!(hart.csr(MSTATUS), Some(1 << 11 | 0xa << 32));
+    assert_eq!(hart.csr(SSTATUS), Some(0x2 << 32));
+    write_csr(&mut hart, &mut memory, SSTATUS, u64::MAX);
+    assert_eq!(hart.csr(MSTATUS), Some(0x8000000a000c6122 | 1 << 11));
+
+    // an environment call from M-mode can't be delegated
+    write_csr(&mut hart, &mut memory, 0x302, u64::MAX);
+    assert_eq!(hart.csr(0x302), Some(0xb3ff));
+    write_csr(&mut hart, &mut memory, 0x303, u64::MAX);
+    assert_eq!(hart.csr(0x303), Some(0x222));
+    write_csr(&mut hart, &mut memory, 0x304, u64::MAX);
+    assert_eq!(hart.csr(0x304), Some(0xaaa));
+    assert_eq!(hart.csr(0x104), Some(0x222));
+    write_csr(&mut hart, &mut memory, 0x344, u64::MAX);
+    assert_eq!(hart.csr(0x344), Some(0x222));
+
+    // only bare addressing is supported
+    write_csr(&mut hart, &mut memory, 0x180, 8 << 60 | 0x1234);
+    assert_eq!(hart.csr(0x180), Some(0));
+
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "csrrw zero,3860,a0"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+    execute(&mut hart, &mut memory, "csrrs a0,3860,zero").unwrap();
+}
+
+#[test]
+fn counter_enables() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    write_csr(&mut hart, &mut memory, 0xb02, 100);
+    assert_eq!(hart.instret, 100);
+    hart.privilege = Privilege::User;
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "csrrs a0,3074,zero"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+
+    hart.privilege = Privilege::Machine;
+    write_csr(&mut hart, &mut memory, 0x306, 0b100);
+    hart.privilege = Privilege::Supervisor;
+    execute(&mut hart, &mut memory, "csrrs a0,3074,zero").unwrap();
+    assert_eq!(hart.x(IRegister::A0), 101);
+    write_csr(&mut hart, &mut memory, 0x106, 0b100);
+    hart.privilege = Privilege::User;
+    execute(&mut hart, &mut memory, "csrrs a0,3074,zero").unwrap();
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "csrrs a0,3072,zero"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+}
+
+#[test]
+fn supervisor_traps() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    write_csr(&mut hart, &mut memory, MSTATUS, 0b111 << 20);
+    execute(&mut hart, &mut memory, "wfi").unwrap();
+    hart.privilege = Privilege::Supervisor;
+    for line in ["sret", "wfi", "sfence.vma zero,zero", "csrrs a0,384,zero"] {
+        assert!(
+            matches!(
+                execute(&mut hart, &mut memory, line),
+                Err(Exception::IllegalInstruction(_))
+            ),
+            "{line}"
+        );
+    }
+    hart.privilege = Privilege::Machine;
+    write_csr(&mut hart, &mut memory, MSTATUS, 0);
+    hart.privilege = Privilege::Supervisor;
+    execute(&mut hart, &mut memory, "wfi").unwrap();
+    execute(&mut hart, &mut memory, "sfence.vma zero,zero").unwrap();
+    execute(&mut hart, &mut memory, "csrrs a0,384,zero").unwrap();
+    hart.privilege = Privilege::User;
+    execute(&mut hart, &mut memory, "wfi").unwrap();
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "sfence.vma zero,zero"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+}
+
+#[test]
+fn floating_point_state() {
+    let mut memory = program(&[], 0);
+    let mut hart = Hart::new(0);
+    assert_eq!(hart.csr(MSTATUS).unwrap() >> 13 & 0b11, 1);
+    execute(&mut hart, &mut memory, "fadd.d fa0,fa1,fa2").unwrap();
+    assert_eq!(hart.csr(MSTATUS).unwrap() >> 13 & 0b11, 3);
+    assert_eq!(hart.csr(MSTATUS).unwrap() >> 63, 1);
+
+    write_csr(&mut hart, &mut memory, MSTATUS, 0);
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "fadd.d fa0,fa1,fa2"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "c.fld fa0,0(a0)"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+    assert!(matches!(
+        execute(&mut hart, &mut memory, "csrrs a0,1,zero"),
+        Err(Exception::IllegalInstruction(_))
+    ));
+}
@@ -0,0 +1,146 @@
+use riscv_codec::assembly::{AssemblyResult, assemble_line};
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::instruction::Instruction;
+use riscv_codec::isa::IsaConfig;
+use riscv_codec::stream::StreamDecoder;
+
+fn raw(line: &str) -> u32 {
+    match assemble_line(line).unwrap() {
+        AssemblyResult::Raw(bits) => bits,
+        x => panic!("expected a raw instruction, found {x:?}"),
+    }
+}
+
+/// Checks that a `.insn` directive encodes the same instruction as a line of regular assembly
+fn check(insn: &str, line: &str) {
+    let bits = raw(insn);
+    match assemble_line(line).unwrap() {
+        AssemblyResult::I(i) => assert_eq!(Instruction::decode(bits), Ok(i)),
+        AssemblyResult::C(c) => assert_eq!(CInstruction::decode(bits as u16), Ok(c)),
+        x => panic!("unexpected instruction {x:?}"),
+    }
+}
+
+#[test]
+fn r_format() {
+    check(".insn r OP, 0, 0, a0, a1, a2", "add a0,a1,a2");
+    check(".insn r 0x33, 0, 0x20, a0, a1, a2", "sub a0,a1,a2");
+    check(".insn r OP_FP, 7, 0, fa0, fa1, fa2", "fadd.s fa0,fa1,fa2");
+}
+
+#[test]
+fn r4_format() {
+    check(
+        ".insn r4 MADD, 3, 0, ft2, fa1, fs3, ft3",
+        "fmadd.s ft2,fa1,fs3,ft3,rup",
+    );
+    check(
+        ".insn r MADD, 3, 0, ft2, fa1, fs3, ft3",
+        "fmadd.s ft2,fa1,fs3,ft3,rup",
+    );
+}
+
+#[test]
+fn i_format() {
+    check(".insn i OP_IMM, 0, a0, a1, -16", "addi a0,a1,-16");
+    check(".insn i LOAD, 3, a0, 8(a1)", "ld a0,8(a1)");
+}
+
+#[test]
+fn s_format() {
+    check(".insn s STORE, 3, a0, -8(a1)", "sd a0,-8(a1)");
+}
+
+#[test]
+fn b_format() {
+    check(".insn b BRANCH, 0, t2, sp, 2", "beq t2,sp,2");
+    check(".insn sb BRANCH, 1, a0, a1, -4096", "bne a0,a1,-4096");
+}
+
+#[test]
+fn u_format() {
+    check(".insn u LUI, s2, 400", "lui s2,400");
+    assert_eq!(raw(".insn u AUIPC, a0, 0xfffff"), 0xfffff517);
+}
+
+#[test]
+fn j_format() {
+    check(".insn j JAL, zero, -1016708", "jal zero,-1016708");
+    check(".insn uj 0x6f, ra, 2048", "jal ra,2048");
+}
+
+#[test]
+fn compressed_formats() {
+    check(".insn cr C2, 8, a0, a1", "c.mv a0,a1");
+    check(".insn ci C1, 0, t1, 12", "c.addi t1,12");
+    check(".insn ciw C0, 0, a0, 4", "c.addi4spn a0,64");
+    check(".insn cl C0, 2, a2, 4(a2)", "c.lw a2,8(a2)");
+    check(".insn cs C0, 6, a2, 4(a2)", "c.sw a2,8(a2)");
+    check(".insn ca C1, 0b100011, 0, a3, a0", "c.sub a3,a0");
+    check(".insn cb C1, 6, a0, 16", "c.beqz a0,16");
+    check(".insn cj C1, 5, -216", "c.j -216");
+    check(".insn css C2, 6, a0, 1", "c.swsp a0,64");
+}
+
+#[test]
+fn integer_forms() {
+    assert_eq!(raw(".insn 0x00c5850b"), 0x00c5850b);
+    assert_eq!(raw(".insn 4, 0x00c5850b"), 0x00c5850b);
+    assert_eq!(raw(".insn 0x852e"), 0x852e);
+    assert!(assemble_line(".insn 2, 0x00c5850b").is_err());
+    assert!(assemble_line(".insn 0x1852e").is_err());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        assemble_line(".insn r OP, 0, 0, a0, a1").unwrap_err(),
+        ".insn r requires 6 operands"
+    );
+    assert_eq!(
+        assemble_line(".insn r 0x30, 0, 0, a0, a1, a2").unwrap_err(),
+        "0x30 is not an opcode for a 32-bit instruction"
+    );
+    assert_eq!(
+        assemble_line(".insn ca C1, 0b100011, 0, a3, a6").unwrap_err(),
+        "a6 can't be used in a compressed instruction"
+    );
+    assert_eq!(
+        assemble_line(".insn x OP").unwrap_err(),
+        "unknown .insn format: x"
+    );
+}
+
+#[test]
+fn disassemble_undecodable() {
+    // a custom-0 instruction and a reserved compressed encoding
+    let bytes = [0x0b, 0x85, 0xc5, 0x00, 0x00, 0x00];
+    let decoder = StreamDecoder::new(&bytes, IsaConfig::default());
+    let lines: Vec<_> = StreamDecoder::new(&bytes, IsaConfig::default())
+        .map(|x| decoder.disassemble_decoded(&x))
+        .collect();
+    assert_eq!(lines, [".insn 0x00c5850b", ".insn 0x0000"]);
+
+    // the output can be assembled again
+    assert_eq!(raw(&lines[0]), 0x00c5850b);
+    assert_eq!(raw(&lines[1]), 0x0000);
+}
+
+#[test]
+fn disassemble_undecodable_lengths() {
+    // a halfword with a reserved length encoding, a 48-bit instruction and a truncated 32-bit instruction
+    let bytes = [0xff, 0xff, 0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x05];
+    let mut decoder = StreamDecoder::new(&bytes, IsaConfig::default());
+    let mut lines = Vec::new();
+    while let Some(x) = decoder.next() {
+        lines.push(decoder.disassemble_decoded(&x));
+    }
+    assert_eq!(
+        lines,
+        [
+            "# 0xffff: attempting to decode larger instruction as though it were 16 bits",
+            "# 0x001f: 48-bit instructions are not supported",
+            "# 0x0513: truncated instruction at the end of the stream",
+        ]
+    );
+}
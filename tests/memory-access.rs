@@ -0,0 +1,192 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::memory::{AccessKind, MemoryAccess};
+use riscv_codec::register::{FRegister, IRegister, Register};
+
+fn access(line: &str) -> Option<MemoryAccess> {
+    assemble_line(line).unwrap().i().memory_access()
+}
+
+fn compressed_access(line: &str) -> Option<MemoryAccess> {
+    assemble_line(line).unwrap().c().memory_access()
+}
+
+#[test]
+fn signed_load() {
+    assert_eq!(
+        access("lh a0,-6(a1)"),
+        Some(MemoryAccess {
+            kind: AccessKind::Load,
+            width: Some(2),
+            signed: true,
+            base: Some(IRegister::A1),
+            offset: -6,
+            dest: Some(Register::I(IRegister::A0)),
+            src: None,
+            aq: false,
+            rl: false,
+        })
+    );
+}
+
+#[test]
+fn unsigned_load() {
+    let a = access("lwu a0,8(a1)").unwrap();
+    assert_eq!(a.width, Some(4));
+    assert!(!a.signed);
+}
+
+#[test]
+fn float_load() {
+    let a = access("fld fa0,16(sp)").unwrap();
+    assert_eq!(a.kind, AccessKind::Load);
+    assert_eq!(a.width, Some(8));
+    assert_eq!(a.base, Some(IRegister::StackPointer));
+    assert_eq!(a.dest, Some(Register::F(FRegister::FA0)));
+}
+
+#[test]
+fn store() {
+    assert_eq!(
+        access("sb a0,100(a1)"),
+        Some(MemoryAccess {
+            kind: AccessKind::Store,
+            width: Some(1),
+            signed: false,
+            base: Some(IRegister::A1),
+            offset: 100,
+            dest: None,
+            src: Some(Register::I(IRegister::A0)),
+            aq: false,
+            rl: false,
+        })
+    );
+}
+
+#[test]
+fn float_store() {
+    let a = access("fsw fa1,-4(a0)").unwrap();
+    assert_eq!(a.kind, AccessKind::Store);
+    assert_eq!(a.width, Some(4));
+    assert_eq!(a.offset, -4);
+    assert_eq!(a.src, Some(Register::F(FRegister::FA1)));
+}
+
+#[test]
+fn amo() {
+    assert_eq!(
+        access("amoadd.d.aqrl a0,a1,a2"),
+        Some(MemoryAccess {
+            kind: AccessKind::Amo,
+            width: Some(8),
+            signed: true,
+            base: Some(IRegister::A1),
+            offset: 0,
+            dest: Some(Register::I(IRegister::A0)),
+            src: Some(Register::I(IRegister::A2)),
+            aq: true,
+            rl: true,
+        })
+    );
+}
+
+#[test]
+fn load_reserved() {
+    let a = access("lr.w.aq a0,a1").unwrap();
+    assert_eq!(a.kind, AccessKind::LoadReserved);
+    assert_eq!(a.width, Some(4));
+    assert_eq!(a.base, Some(IRegister::A1));
+    assert!(a.aq);
+    assert!(!a.rl);
+}
+
+#[test]
+fn store_conditional() {
+    let a = access("sc.w.rl ra,t4,a1").unwrap();
+    assert_eq!(a.kind, AccessKind::StoreConditional);
+    assert_eq!(a.base, Some(IRegister::T4));
+    assert_eq!(a.dest, Some(Register::I(IRegister::ReturnAddress)));
+    assert_eq!(a.src, Some(Register::I(IRegister::A1)));
+    assert!(!a.aq);
+    assert!(a.rl);
+}
+
+#[test]
+fn load_acquire() {
+    let a = access("lb.aq a0,(a1)").unwrap();
+    assert_eq!(a.kind, AccessKind::Load);
+    assert_eq!(a.width, Some(1));
+    assert!(a.aq);
+}
+
+#[test]
+fn store_release() {
+    let a = access("sb.rl a0,(a1)").unwrap();
+    assert_eq!(a.kind, AccessKind::Store);
+    assert!(a.rl);
+}
+
+#[test]
+fn fences() {
+    let a = access("fence ir,ow").unwrap();
+    assert_eq!(a.kind, AccessKind::Fence);
+    assert_eq!(a.base, None);
+    assert_eq!(a.width, None);
+
+    assert_eq!(access("fence.i").unwrap().kind, AccessKind::Fence);
+}
+
+#[test]
+fn cache_blocks() {
+    let a = access("cbo.zero (a0)").unwrap();
+    assert_eq!(a.kind, AccessKind::CacheBlock);
+    assert_eq!(a.base, Some(IRegister::A0));
+    assert_eq!(a.width, None);
+
+    let a = access("prefetch.i 32(a0)").unwrap();
+    assert_eq!(a.kind, AccessKind::Prefetch);
+    assert_eq!(a.offset, 32);
+}
+
+#[test]
+fn no_access() {
+    assert_eq!(access("add a0,a1,a2"), None);
+    assert_eq!(access("jalr ra,0(a0)"), None);
+}
+
+#[test]
+fn compressed_load() {
+    assert_eq!(
+        compressed_access("c.lw a0,4(a1)"),
+        Some(MemoryAccess {
+            kind: AccessKind::Load,
+            width: Some(4),
+            signed: true,
+            base: Some(IRegister::A1),
+            offset: 4,
+            dest: Some(Register::I(IRegister::A0)),
+            src: None,
+            aq: false,
+            rl: false,
+        })
+    );
+}
+
+#[test]
+fn compressed_stack_pointer() {
+    let a = compressed_access("c.lwsp s1,200").unwrap();
+    assert_eq!(a.kind, AccessKind::Load);
+    assert_eq!(a.base, Some(IRegister::StackPointer));
+    assert_eq!(a.offset, 200);
+    assert_eq!(a.dest, Some(Register::I(IRegister::S1)));
+
+    let a = compressed_access("c.fsdsp fs0,64").unwrap();
+    assert_eq!(a.kind, AccessKind::Store);
+    assert_eq!(a.width, Some(8));
+    assert_eq!(a.base, Some(IRegister::StackPointer));
+    assert_eq!(a.src, Some(Register::F(FRegister::FS0)));
+}
+
+#[test]
+fn compressed_no_access() {
+    assert_eq!(compressed_access("c.addi a0,1"), None);
+}
@@ -0,0 +1,166 @@
+use riscv_codec::assembly::{assemble_line, assemble_line_with};
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::instruction::Instruction;
+use riscv_codec::isa::IsaConfig;
+use riscv_codec::metadata::{COMPRESSED_INSTRUCTIONS, INSTRUCTIONS, instructions};
+use riscv_codec::operands::OperandRole;
+use riscv_codec::raw::Format;
+
+const CONFIGS: [IsaConfig; 4] = [
+    IsaConfig::new(),
+    IsaConfig {
+        zcmp: true,
+        ..IsaConfig::new()
+    },
+    IsaConfig {
+        zcmt: true,
+        ..IsaConfig::new()
+    },
+    IsaConfig {
+        zicfilp: true,
+        zicfiss: true,
+        ..IsaConfig::new()
+    },
+];
+
+#[test]
+fn add() {
+    let i = assemble_line("add a0,a1,a2").unwrap().i();
+    let info = i.info();
+    assert_eq!(info.mnemonic, "add");
+    assert_eq!(info.extension, "I");
+    assert_eq!(info.format, Format::R);
+    assert_eq!(info.mask, 0xfe00707f);
+    assert_eq!(info.match_bits, 0x00000033);
+    assert_eq!(
+        info.operands,
+        [OperandRole::Dest, OperandRole::Src1, OperandRole::Src2]
+    );
+}
+
+#[test]
+fn extensions() {
+    let extension = |line: &str| assemble_line(line).unwrap().i().info().extension;
+    assert_eq!(extension("mulw a0,a1,a2"), "M");
+    assert_eq!(extension("amoadd.w.aqrl a0,a1,a2"), "A");
+    assert_eq!(extension("fadd.s fa0,fa1,fa2"), "F");
+    assert_eq!(extension("fcvt.d.l fa0,a1"), "D");
+    assert_eq!(extension("csrrw a0,768,a1"), "Zicsr");
+    assert_eq!(extension("fence.i"), "Zifencei");
+    assert_eq!(extension("mret"), "Sm");
+}
+
+#[test]
+fn atomic_mnemonic() {
+    let i = assemble_line("amoadd.w.aqrl a0,a1,a2").unwrap().i();
+    assert_eq!(i.mnemonic(), "amoadd.w");
+}
+
+#[test]
+fn may_be_operations() {
+    let i = assemble_line("mop.r.17 a0,a1").unwrap().i();
+    assert_eq!(i.mnemonic(), "mop.r.17");
+    assert!(i.info().matches(Instruction::encode(&i)));
+    let i = assemble_line("mop.rr.5 a0,a1,a2").unwrap().i();
+    assert_eq!(i.mnemonic(), "mop.rr.5");
+    let c = assemble_line("c.mop.13").unwrap().c();
+    assert_eq!(c.mnemonic(), "c.mop.13");
+}
+
+#[test]
+fn compressed() {
+    let c = assemble_line("c.addi16sp 32").unwrap().c();
+    let info = c.info();
+    assert_eq!(info.mnemonic, "c.addi16sp");
+    assert_eq!(info.extension, "C");
+    assert_eq!(info.format, Format::CI);
+    assert_eq!(info.mask, 0xef83);
+    assert_eq!(info.match_bits, 0x6101);
+    assert!(info.is_compressed());
+
+    let c = assemble_line_with("cm.push {ra,s0-s1},-32", &CONFIGS[1])
+        .unwrap()
+        .c();
+    assert_eq!(c.info().extension, "Zcmp");
+    assert_eq!(
+        c.info().operands,
+        [OperandRole::RegisterList, OperandRole::Immediate]
+    );
+}
+
+#[test]
+fn table() {
+    assert_eq!(
+        instructions().count(),
+        INSTRUCTIONS.len() + COMPRESSED_INSTRUCTIONS.len()
+    );
+    for info in instructions() {
+        assert_eq!(info.match_bits & !info.mask, 0, "{}", info.mnemonic);
+        assert_eq!(info.mnemonic, info.mnemonic.to_lowercase());
+        assert_eq!(
+            instructions()
+                .filter(|other| other.mnemonic == info.mnemonic)
+                .count(),
+            1,
+            "{}",
+            info.mnemonic
+        );
+    }
+    assert!(INSTRUCTIONS.iter().all(|info| !info.is_compressed()));
+    assert!(
+        COMPRESSED_INSTRUCTIONS
+            .iter()
+            .all(|info| info.is_compressed())
+    );
+}
+
+#[test]
+fn decoded_instructions_match() {
+    for info in instructions() {
+        for isa in &CONFIGS {
+            if info.is_compressed() {
+                if let Ok(c) = CInstruction::decode_with(info.match_bits as u16, isa) {
+                    assert!(c.info().matches(info.match_bits), "{}", c);
+                    assert_eq!(c.mnemonic(), c.to_string().split(' ').next().unwrap());
+                }
+            } else if let Ok(i) = Instruction::decode_with(info.match_bits, isa) {
+                assert!(i.info().matches(info.match_bits), "{}", i);
+            }
+        }
+    }
+}
+
+#[test]
+fn match_bits_decode_to_own_entry() {
+    // Leaving every operand zero gives a hint or reserved encoding for these
+    const ZERO_OPERANDS: [&str; 12] = [
+        "ori",
+        "ssrdp",
+        "c.addi4spn",
+        "c.mv",
+        "c.jalr",
+        "c.add",
+        "cm.push",
+        "cm.pop",
+        "cm.popretz",
+        "cm.popret",
+        "cm.mvsa01",
+        "cm.jalt",
+    ];
+    for info in instructions() {
+        let decodes = CONFIGS.iter().any(|isa| {
+            if info.is_compressed() {
+                CInstruction::decode_with(info.match_bits as u16, isa)
+                    .is_ok_and(|c| c.info() == info)
+            } else {
+                Instruction::decode_with(info.match_bits, isa).is_ok_and(|i| i.info() == info)
+            }
+        });
+        assert_eq!(
+            decodes,
+            !ZERO_OPERANDS.contains(&info.mnemonic),
+            "{}",
+            info.mnemonic
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use riscv_codec::assembly::{assemble_line, assemble_line_with};
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::instruction::RoundingMode;
+use riscv_codec::isa::IsaConfig;
+use riscv_codec::operands::{Operand, OperandRole};
+use riscv_codec::register::{FRegister, IRegister, RegisterList};
+
+const ZCMP: IsaConfig = IsaConfig {
+    zcmp: true,
+    ..IsaConfig::new()
+};
+
+const ZICFISS: IsaConfig = IsaConfig {
+    zicfiss: true,
+    ..IsaConfig::new()
+};
+
+#[test]
+fn add_operands() {
+    let i = assemble_line("add a0,a1,a2").unwrap().i();
+    assert_eq!(
+        i.operands(),
+        [
+            (OperandRole::Dest, Operand::I(IRegister::A0)),
+            (OperandRole::Src1, Operand::I(IRegister::A1)),
+            (OperandRole::Src2, Operand::I(IRegister::A2)),
+        ]
+    );
+}
+
+#[test]
+fn store_operands() {
+    let i = assemble_line("sw a0,-4(sp)").unwrap().i();
+    assert_eq!(
+        i.operands(),
+        [
+            (OperandRole::Src1, Operand::I(IRegister::A0)),
+            (OperandRole::Base, Operand::I(IRegister::StackPointer)),
+            (OperandRole::Immediate, Operand::Immediate(-4)),
+        ]
+    );
+}
+
+#[test]
+fn float_operands() {
+    let i = assemble_line("fadd.d fa0,fa1,fa2,rup").unwrap().i();
+    assert_eq!(
+        i.operands(),
+        [
+            (OperandRole::Dest, Operand::F(FRegister::FA0)),
+            (OperandRole::Src1, Operand::F(FRegister::FA1)),
+            (OperandRole::Src2, Operand::F(FRegister::FA2)),
+            (
+                OperandRole::RoundingMode,
+                Operand::RoundingMode(RoundingMode::RUP)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn csr_operands() {
+    let i = assemble_line("csrrw a0,768,a1").unwrap().i();
+    assert!(
+        i.operands()
+            .contains(&(OperandRole::Csr, Operand::Csr(0x300)))
+    );
+}
+
+#[test]
+fn compressed_operands() {
+    let c = assemble_line("c.lwsp a0,8").unwrap().c();
+    assert_eq!(
+        c.operands(),
+        [
+            (OperandRole::Dest, Operand::I(IRegister::A0)),
+            (OperandRole::Immediate, Operand::Immediate(8)),
+        ]
+    );
+
+    let c = assemble_line("c.sw a0,4(a1)").unwrap().c();
+    assert_eq!(
+        c.operands(),
+        [
+            (OperandRole::Src1, Operand::I(IRegister::A0)),
+            (OperandRole::Base, Operand::I(IRegister::A1)),
+            (OperandRole::Immediate, Operand::Immediate(4)),
+        ]
+    );
+}
+
+#[test]
+fn register_list_operand() {
+    let c = assemble_line_with("cm.push {ra,s0-s1},-32", &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(
+        c.operands(),
+        [
+            (
+                OperandRole::RegisterList,
+                Operand::RegisterList(RegisterList::from_rlist(6).unwrap())
+            ),
+            (OperandRole::Immediate, Operand::Immediate(0)),
+        ]
+    );
+}
+
+#[test]
+fn set_register() {
+    let mut i = assemble_line("add a0,a1,a2").unwrap().i();
+    i.set_operand(OperandRole::Src2, Operand::I(IRegister::T3))
+        .unwrap();
+    assert_eq!(i.to_string(), "add a0,a1,t3");
+}
+
+#[test]
+fn set_immediate() {
+    let mut i = assemble_line("addi a0,a1,1").unwrap().i();
+    i.set_operand(OperandRole::Immediate, Operand::Immediate(-2048))
+        .unwrap();
+    assert_eq!(i.to_string(), "addi a0,a1,-2048");
+
+    assert!(
+        i.set_operand(OperandRole::Immediate, Operand::Immediate(2048))
+            .is_err()
+    );
+    assert_eq!(i.to_string(), "addi a0,a1,-2048");
+}
+
+#[test]
+fn set_rounding_mode() {
+    let mut i = assemble_line("fsqrt.s fa0,fa1").unwrap().i();
+    i.set_operand(
+        OperandRole::RoundingMode,
+        Operand::RoundingMode(RoundingMode::RTZ),
+    )
+    .unwrap();
+    assert_eq!(i.to_string(), "fsqrt.s fa0,fa1,rtz");
+}
+
+#[test]
+fn set_missing_role() {
+    let mut i = assemble_line("add a0,a1,a2").unwrap().i();
+    assert!(
+        i.set_operand(OperandRole::Immediate, Operand::Immediate(0))
+            .is_err()
+    );
+}
+
+#[test]
+fn set_wrong_register_file() {
+    let mut i = assemble_line("add a0,a1,a2").unwrap().i();
+    assert!(
+        i.set_operand(OperandRole::Dest, Operand::F(FRegister::FA0))
+            .is_err()
+    );
+    assert_eq!(i.to_string(), "add a0,a1,a2");
+}
+
+#[test]
+fn set_compressed_register() {
+    let mut c = assemble_line("c.sub a0,a1").unwrap().c();
+    c.set_operand(OperandRole::Src1, Operand::I(IRegister::S1))
+        .unwrap();
+    assert_eq!(c.to_string(), "c.sub a0,s1");
+
+    // only x8-x15 are available in the three bit register fields
+    assert!(
+        c.set_operand(OperandRole::Dest, Operand::I(IRegister::T0))
+            .is_err()
+    );
+    assert_eq!(c.to_string(), "c.sub a0,s1");
+}
+
+#[test]
+fn set_reserved_encoding() {
+    // cm.mvsa01 with the same register for both destinations is reserved
+    let mut c = assemble_line_with("cm.mvsa01 s0,s1", &ZCMP).unwrap().c();
+    assert!(
+        c.set_operand_with(
+            OperandRole::Dest2,
+            Operand::I(IRegister::FramePointer),
+            &ZCMP
+        )
+        .is_err()
+    );
+
+    // c.lui with a zero immediate and an odd register below x16 is a c.mop
+    let mut c = assemble_line("c.lui a1,1").unwrap().c();
+    assert!(
+        c.set_operand(OperandRole::Immediate, Operand::Immediate(0))
+            .is_err()
+    );
+}
+
+#[test]
+fn set_register_list() {
+    let mut c = assemble_line_with("cm.push {ra,s0-s1},-32", &ZCMP)
+        .unwrap()
+        .c();
+    let rlist = RegisterList::from_rlist(8).unwrap();
+    assert!(
+        c.set_operand(OperandRole::RegisterList, Operand::RegisterList(rlist))
+            .is_err()
+    );
+    c.set_operand_with(
+        OperandRole::RegisterList,
+        Operand::RegisterList(rlist),
+        &ZCMP,
+    )
+    .unwrap();
+    assert_eq!(c.to_string(), "cm.push {ra,s0-s3},-48");
+}
+
+#[test]
+fn map_registers() {
+    let mut i = assemble_line("amoadd.w a0,a1,a2").unwrap().i();
+    i.map_operands(|_, operand| match operand {
+        Operand::I(IRegister::A1) => Operand::I(IRegister::S2),
+        x => x,
+    })
+    .unwrap();
+    assert_eq!(i.to_string(), "amoadd.w a0,s2,a2");
+}
+
+#[test]
+fn map_compressed_registers() {
+    let mut c = assemble_line("c.lw a0,4(a1)").unwrap().c();
+    c.map_operands(|role, operand| match role {
+        OperandRole::Dest => Operand::I(IRegister::A5),
+        OperandRole::Immediate => Operand::Immediate(64),
+        _ => operand,
+    })
+    .unwrap();
+    assert_eq!(c.to_string(), "c.lw a5,64(a1)");
+
+    // the operands are validated together, so a failure leaves the instruction unchanged
+    assert!(
+        c.map_operands(|role, operand| match role {
+            OperandRole::Dest => Operand::I(IRegister::A0),
+            OperandRole::Immediate => Operand::Immediate(3),
+            _ => operand,
+        })
+        .is_err()
+    );
+    assert_eq!(c.to_string(), "c.lw a5,64(a1)");
+}
+
+#[test]
+fn map_identity() {
+    for isa in [IsaConfig::default(), ZCMP, ZICFISS] {
+        for x in 0..=u16::MAX {
+            if let Ok(mut c) = CInstruction::decode_with(x, &isa) {
+                let expected = c;
+                c.map_operands_with(&isa, |_, operand| operand).unwrap();
+                assert_eq!(c, expected);
+            }
+        }
+    }
+}
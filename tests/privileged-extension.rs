@@ -0,0 +1,289 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::instruction::{Instruction, disassemble_instruction};
+use riscv_codec::register::IRegister;
+
+#[test]
+fn environment_call() {
+    let expected = Instruction::Ecall;
+    let bin = 0x00000073;
+
+    // check assembler
+    let i = assemble_line("ecall").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn environment_break() {
+    let expected = Instruction::Ebreak;
+    let bin = 0x00100073;
+
+    // check assembler
+    let i = assemble_line("ebreak").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn supervisor_return() {
+    let expected = Instruction::Sret;
+    let bin = 0x10200073;
+
+    // check assembler
+    let i = assemble_line("sret").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn machine_return() {
+    let expected = Instruction::Mret;
+    let bin = 0x30200073;
+
+    // check assembler
+    let i = assemble_line("mret").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn wait_for_interrupt() {
+    let expected = Instruction::Wfi;
+    let bin = 0x10500073;
+
+    // check assembler
+    let i = assemble_line("wfi").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn supervisor_fence_virtual_memory() {
+    let expected = Instruction::SfenceVma { vaddr: IRegister::A0, asid: IRegister::A1 };
+    let bin = 0x12b50073;
+
+    // check assembler
+    let i = assemble_line("sfence.vma a0, a1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn supervisor_fence_virtual_memory_all() {
+    let expected = Instruction::SfenceVma { vaddr: IRegister::Zero, asid: IRegister::Zero };
+    let bin = 0x12000073;
+
+    // check assembler
+    let i = assemble_line("sfence.vma").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn supervisor_fence_virtual_memory_address() {
+    let expected = Instruction::SfenceVma { vaddr: IRegister::T0, asid: IRegister::Zero };
+    let bin = 0x12028073;
+
+    // check assembler
+    let i = assemble_line("sfence.vma t0").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn supervisor_invalidate_virtual_memory() {
+    let expected = Instruction::SinvalVma { vaddr: IRegister::A0, asid: IRegister::A1 };
+    let bin = 0x16b50073;
+
+    // check assembler
+    let i = assemble_line("sinval.vma a0,a1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn supervisor_fence_write_invalidate() {
+    let expected = Instruction::SfenceWInval;
+    let bin = 0x18000073;
+
+    // check assembler
+    let i = assemble_line("sfence.w.inval").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn supervisor_fence_invalidate_implicit_reads() {
+    let expected = Instruction::SfenceInvalIr;
+    let bin = 0x18100073;
+
+    // check assembler
+    let i = assemble_line("sfence.inval.ir").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn machine_nmi_return() {
+    let expected = Instruction::Mnret;
+    let bin = 0x70200073;
+
+    // check assembler
+    let i = assemble_line("mnret").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn debug_return() {
+    let expected = Instruction::Dret;
+    let bin = 0x7b200073;
+
+    // check assembler
+    let i = assemble_line("dret").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
@@ -0,0 +1,779 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::immediates::{IImmediate, SImmediate};
+use riscv_codec::instruction::{Instruction, RoundingMode, disassemble_instruction};
+use riscv_codec::register::{FRegister, IRegister};
+
+#[test]
+fn float_load_quad() {
+    let expected = Instruction::Flq {
+        dest: FRegister::FA0,
+        base: IRegister::A0,
+        offset: IImmediate::try_from(64).unwrap(),
+    };
+    let bin = 0x04054507;
+
+    // check assembler
+    let i = assemble_line("flq fa0,64(a0)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_store_quad() {
+    let expected = Instruction::Fsq {
+        src: FRegister::FA0,
+        base: IRegister::A0,
+        offset: SImmediate::try_from(64).unwrap(),
+    };
+    let bin = 0x04a54027;
+
+    // check assembler
+    let i = assemble_line("fsq fa0,64(a0)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_multiply_add_quad() {
+    let expected = Instruction::FmaddQ {
+        dest: FRegister::FS11,
+        src1: FRegister::FT2,
+        src2: FRegister::FT9,
+        src3: FRegister::FA6,
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0x87d17dc3;
+
+    // check assembler
+    let i = assemble_line("fmadd.q fs11, ft2, ft9, fa6").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_multiply_subtract_quad() {
+    let expected = Instruction::FmsubQ {
+        dest: FRegister::FS11,
+        src1: FRegister::FT2,
+        src2: FRegister::FT9,
+        src3: FRegister::FA6,
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0x87d17dc7;
+
+    // check assembler
+    let i = assemble_line("fmsub.q fs11, ft2, ft9, fa6").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_negate_multiply_subtract_quad() {
+    let expected = Instruction::FnmsubQ {
+        dest: FRegister::FS11,
+        src1: FRegister::FT2,
+        src2: FRegister::FT9,
+        src3: FRegister::FA6,
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0x87d17dcb;
+
+    // check assembler
+    let i = assemble_line("fnmsub.q fs11, ft2, ft9, fa6").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_negate_multiply_add_quad() {
+    let expected = Instruction::FnmaddQ {
+        dest: FRegister::FS11,
+        src1: FRegister::FT2,
+        src2: FRegister::FT9,
+        src3: FRegister::FA6,
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0x87d17dcf;
+
+    // check assembler
+    let i = assemble_line("fnmadd.q fs11, ft2, ft9, fa6").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_add_quad() {
+    let expected = Instruction::FaddQ {
+        dest: FRegister::FA7,
+        src1: FRegister::FS4,
+        src2: FRegister::FA2,
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0x06ca78d3;
+
+    // check assembler
+    let i = assemble_line("fadd.q fa7, fs4, fa2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_subtract_quad() {
+    let expected = Instruction::FsubQ {
+        dest: FRegister::FA7,
+        src1: FRegister::FS4,
+        src2: FRegister::FA2,
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0x0eca78d3;
+
+    // check assembler
+    let i = assemble_line("fsub.q fa7, fs4, fa2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_multiply_quad() {
+    let expected = Instruction::FmulQ {
+        dest: FRegister::FA7,
+        src1: FRegister::FS4,
+        src2: FRegister::FA2,
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0x16ca78d3;
+
+    // check assembler
+    let i = assemble_line("fmul.q fa7, fs4, fa2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_divide_quad() {
+    let expected = Instruction::FdivQ {
+        dest: FRegister::FA7,
+        src1: FRegister::FS4,
+        src2: FRegister::FA2,
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0x1eca78d3;
+
+    // check assembler
+    let i = assemble_line("fdiv.q fa7, fs4, fa2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_square_root_quad() {
+    let expected = Instruction::FsqrtQ {
+        dest: FRegister::FA7,
+        src: FRegister::FS4,
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0x5e0a78d3;
+
+    // check assembler
+    let i = assemble_line("fsqrt.q fa7, fs4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+
+    println!("disassembled result: {}", disassemble_instruction(&i));
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_sign_inject_quad() {
+    let expected = Instruction::FsgnjQ {
+        dest: FRegister::FA7,
+        src1: FRegister::FS4,
+        src2: FRegister::FT1,
+    };
+    let bin = 0x261a08d3;
+
+    // check assembler
+    let i = assemble_line("fsgnj.q fa7, fs4, ft1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_sign_inject_negate_quad() {
+    let expected = Instruction::FsgnjnQ {
+        dest: FRegister::FA7,
+        src1: FRegister::FS4,
+        src2: FRegister::FT1,
+    };
+    let bin = 0x261a18d3;
+
+    // check assembler
+    let i = assemble_line("fsgnjn.q fa7, fs4, ft1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_sign_inject_xor_quad() {
+    let expected = Instruction::FsgnjxQ {
+        dest: FRegister::FA7,
+        src1: FRegister::FS4,
+        src2: FRegister::FT1,
+    };
+    let bin = 0x261a28d3;
+
+    // check assembler
+    let i = assemble_line("fsgnjx.q fa7, fs4, ft1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_min_quad() {
+    let expected = Instruction::FminQ { dest: FRegister::FA6, src1: FRegister::FS0, src2: FRegister::FA1 };
+    let bin = 0x2eb40853;
+
+    // check assembler
+    let i = assemble_line("fmin.q fa6, fs0, fa1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_max_quad() {
+    let expected = Instruction::FmaxQ { dest: FRegister::FA6, src1: FRegister::FS0, src2: FRegister::FA1 };
+    let bin = 0x2eb41853;
+
+    // check assembler
+    let i = assemble_line("fmax.q fa6, fs0, fa1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_single_from_quad() {
+    let expected = Instruction::FcvtSQ { dest: FRegister::FS5, src: FRegister::FS10, rm: RoundingMode::DYN };
+    let bin = 0x403d7ad3;
+
+    // check assembler
+    let i = assemble_line("fcvt.s.q fs5, fs10").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_quad_from_single() {
+    let expected = Instruction::FcvtQS { dest: FRegister::FS5, src: FRegister::FS10, rm: RoundingMode::DYN };
+    let bin = 0x460d7ad3;
+
+    // check assembler
+    let i = assemble_line("fcvt.q.s fs5, fs10").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_double_from_quad() {
+    let expected = Instruction::FcvtDQ { dest: FRegister::FS5, src: FRegister::FS10, rm: RoundingMode::DYN };
+    let bin = 0x423d7ad3;
+
+    // check assembler
+    let i = assemble_line("fcvt.d.q fs5, fs10").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_quad_from_double() {
+    let expected = Instruction::FcvtQD { dest: FRegister::FS5, src: FRegister::FS10, rm: RoundingMode::DYN };
+    let bin = 0x461d7ad3;
+
+    // check assembler
+    let i = assemble_line("fcvt.q.d fs5, fs10").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_equal_quad() {
+     let expected = Instruction::FeqQ { dest: IRegister::T1, src1: FRegister::FS1, src2: FRegister::FS2 };
+    let bin = 0xa724a353;
+
+    // check assembler
+    let i = assemble_line("feq.q t1, fs1, fs2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_less_than_quad() {
+     let expected = Instruction::FltQ { dest: IRegister::T1, src1: FRegister::FS1, src2: FRegister::FS2 };
+    let bin = 0xa7249353;
+
+    // check assembler
+    let i = assemble_line("flt.q t1, fs1, fs2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_less_equal_quad() {
+     let expected = Instruction::FleQ { dest: IRegister::T1, src1: FRegister::FS1, src2: FRegister::FS2 };
+    let bin = 0xa7248353;
+
+    // check assembler
+    let i = assemble_line("fle.q t1, fs1, fs2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_class_quad() {
+     let expected = Instruction::FclassQ { dest: IRegister::S4, src: FRegister::FT0 };
+    let bin = 0xe6001a53;
+
+    // check assembler
+    let i = assemble_line("fclass.q s4, ft0").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_word_from_quad() {
+     let expected: Instruction = Instruction::FcvtWQ { dest: IRegister::T5, src: FRegister::FS8, rm: RoundingMode::DYN };
+    let bin = 0xc60c7f53;
+
+    // check assembler
+    let i = assemble_line("fcvt.w.q t5,fs8").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_unsigned_word_from_quad() {
+     let expected: Instruction = Instruction::FcvtWuQ { dest: IRegister::A6, src: FRegister::FA5, rm: RoundingMode::DYN };
+    let bin = 0xc617f853;
+
+    // check assembler
+    let i = assemble_line("fcvt.wu.q a6,fa5").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_quad_from_word() {
+     let expected: Instruction = Instruction::FcvtQW { dest: FRegister::FT10, src: IRegister::S8, rm: RoundingMode::DYN };
+    let bin = 0xd60c7f53;
+
+    // check assembler
+    let i = assemble_line("fcvt.q.w ft10, s8").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_quad_from_unsigned_word() {
+     let expected: Instruction = Instruction::FcvtQWu { dest: FRegister::FT10, src: IRegister::S8, rm: RoundingMode::DYN };
+    let bin = 0xd61c7f53;
+
+    // check assembler
+    let i = assemble_line("fcvt.q.wu ft10, s8").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_long_from_quad() {
+     let expected: Instruction = Instruction::FcvtLQ { dest: IRegister::FramePointer, src: FRegister::FS3, rm: RoundingMode::DYN };
+    let bin = 0xc629f453;
+
+    // check assembler
+    let i = assemble_line("fcvt.l.q fp, fs3").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_unsigned_long_from_quad() {
+     let expected: Instruction = Instruction::FcvtLuQ { dest: IRegister::FramePointer, src: FRegister::FS3, rm: RoundingMode::DYN };
+    let bin = 0xc639f453;
+
+    // check assembler
+    let i = assemble_line("fcvt.lu.q fp, fs3").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_quad_from_long() {
+     let expected: Instruction = Instruction::FcvtQL { dest: FRegister::FS0, src: IRegister::A4, rm: RoundingMode::DYN };
+    let bin = 0xd6277453;
+
+    // check assembler
+    let i = assemble_line("fcvt.q.l fs0, a4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_quad_from_unsigned_long() {
+     let expected: Instruction = Instruction::FcvtQLu { dest: FRegister::FS0, src: IRegister::A4, rm: RoundingMode::DYN };
+    let bin = 0xd6377453;
+
+    // check assembler
+    let i = assemble_line("fcvt.q.lu fs0, a4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
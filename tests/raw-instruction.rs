@@ -0,0 +1,260 @@
+use riscv_codec::assembly::{AssemblyResult, assemble_line};
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::immediates::{
+    BImmediate, CBImmediate, CIImmediate, CJImmediate, IImmediate, JImmediate, SImmediate,
+    UImmediate,
+};
+use riscv_codec::instruction::Instruction;
+use riscv_codec::raw::{Format, RawCInstruction, RawInstruction};
+
+fn word(line: &str) -> u32 {
+    Instruction::encode(&assemble_line(line).unwrap().i())
+}
+
+fn cword(line: &str) -> u16 {
+    CInstruction::encode(&assemble_line(line).unwrap().c())
+}
+
+/// Checks that the raw instruction is written as a `.insn` directive that assembles to the same word
+fn check_insn(bits: u32, text: &str) {
+    assert_eq!(assemble_line(text).unwrap(), AssemblyResult::Raw(bits));
+}
+
+#[test]
+fn r_format() {
+    let bin = word("add a0,a1,a2");
+    let expected = RawInstruction::R {
+        opcode: 0b011_0011,
+        rd: 10,
+        funct3: 0,
+        rs1: 11,
+        rs2: 12,
+        funct7: 0,
+    };
+    let raw = RawInstruction::decode(bin).unwrap();
+    assert_eq!(raw, expected);
+    assert_eq!(raw.format(), Format::R);
+    assert_eq!(
+        (raw.rd(), raw.rs1(), raw.rs2()),
+        (Some(10), Some(11), Some(12))
+    );
+    assert_eq!((raw.imm(), raw.rs3(), raw.fmt()), (None, None, None));
+    assert_eq!(raw.encode(), bin);
+    check_insn(bin, &raw.to_string());
+
+    // the format of float instructions is the lowest two bits of funct7
+    let raw = RawInstruction::decode(word("fadd.d fa0,fa1,fa2")).unwrap();
+    assert_eq!(raw.fmt(), Some(1));
+}
+
+#[test]
+fn r4_format() {
+    let bin = word("fmadd.d ft2,fa1,fs3,ft3,rup");
+    let expected = RawInstruction::R4 {
+        opcode: 0b100_0011,
+        rd: 2,
+        funct3: 0b011,
+        rs1: 11,
+        rs2: 19,
+        fmt: 1,
+        rs3: 3,
+    };
+    let raw = RawInstruction::decode(bin).unwrap();
+    assert_eq!(raw, expected);
+    assert_eq!((raw.fmt(), raw.rs3()), (Some(1), Some(3)));
+    assert_eq!(raw.encode(), bin);
+    check_insn(bin, &raw.to_string());
+}
+
+#[test]
+fn i_format() {
+    let bin = word("addi a0,a1,-16");
+    let expected = RawInstruction::I {
+        opcode: 0b001_0011,
+        rd: 10,
+        funct3: 0,
+        rs1: 11,
+        imm: IImmediate::try_from(-16).unwrap(),
+    };
+    let raw = RawInstruction::decode(bin).unwrap();
+    assert_eq!(raw, expected);
+    assert_eq!(raw.imm(), Some(-16));
+    assert_eq!(raw.encode(), bin);
+    check_insn(bin, &raw.to_string());
+}
+
+#[test]
+fn s_format() {
+    let bin = word("sd a0,-8(a1)");
+    let expected = RawInstruction::S {
+        opcode: 0b010_0011,
+        funct3: 0b011,
+        rs1: 11,
+        rs2: 10,
+        imm: SImmediate::try_from(-8).unwrap(),
+    };
+    let raw = RawInstruction::decode(bin).unwrap();
+    assert_eq!(raw, expected);
+    assert_eq!(raw.rd(), None);
+    assert_eq!(raw.encode(), bin);
+    check_insn(bin, &raw.to_string());
+}
+
+#[test]
+fn b_format() {
+    let bin = word("bne a0,a1,-4096");
+    let expected = RawInstruction::B {
+        opcode: 0b110_0011,
+        funct3: 0b001,
+        rs1: 10,
+        rs2: 11,
+        imm: BImmediate::try_from(-4096).unwrap(),
+    };
+    let raw = RawInstruction::decode(bin).unwrap();
+    assert_eq!(raw, expected);
+    assert_eq!(raw.imm(), Some(-4096));
+    assert_eq!(raw.encode(), bin);
+    check_insn(bin, &raw.to_string());
+}
+
+#[test]
+fn u_format() {
+    let bin = word("lui s2,400");
+    let expected = RawInstruction::U {
+        opcode: 0b011_0111,
+        rd: 18,
+        imm: UImmediate::try_from(400).unwrap(),
+    };
+    let raw = RawInstruction::decode(bin).unwrap();
+    assert_eq!(raw, expected);
+    assert_eq!(raw.encode(), bin);
+    check_insn(bin, &raw.to_string());
+}
+
+#[test]
+fn j_format() {
+    let bin = word("jal zero,-1016708");
+    let expected = RawInstruction::J {
+        opcode: 0b110_1111,
+        rd: 0,
+        imm: JImmediate::try_from(-1016708).unwrap(),
+    };
+    let raw = RawInstruction::decode(bin).unwrap();
+    assert_eq!(raw, expected);
+    assert_eq!(raw.encode(), bin);
+    check_insn(bin, &raw.to_string());
+}
+
+#[test]
+fn undecodable() {
+    // custom-0 instructions use the R format unless another one is requested
+    let bin = 0xfff5850b;
+    let raw = RawInstruction::decode(bin).unwrap();
+    assert_eq!(raw.format(), Format::R);
+    assert_eq!(raw.funct7(), Some(0b111_1111));
+    assert_eq!(raw.encode(), bin);
+    check_insn(bin, &raw.to_string());
+
+    let raw = RawInstruction::decode_as(bin, Format::I);
+    assert_eq!(raw.imm(), Some(-1));
+    assert_eq!(raw.encode(), bin);
+
+    assert!(RawInstruction::decode(0x852e).is_err());
+    assert!(RawCInstruction::decode(0xffff).is_err());
+}
+
+#[test]
+fn compressed_formats() {
+    let cases = [
+        (
+            "c.mv a0,a1",
+            RawCInstruction::CR {
+                opcode: 0b10,
+                funct4: 0b1000,
+                rd: 10,
+                rs2: 11,
+            },
+        ),
+        (
+            "c.addi t1,-3",
+            RawCInstruction::CI {
+                opcode: 0b01,
+                funct3: 0b000,
+                rd: 6,
+                imm: CIImmediate::try_from(-3).unwrap(),
+            },
+        ),
+        (
+            "c.swsp a0,64",
+            RawCInstruction::CSS {
+                opcode: 0b10,
+                funct3: 0b110,
+                rs2: 10,
+                imm: 1,
+            },
+        ),
+        (
+            "c.addi4spn a0,64",
+            RawCInstruction::CIW {
+                opcode: 0b00,
+                funct3: 0b000,
+                rd: 2,
+                imm: 4,
+            },
+        ),
+        (
+            "c.lw a2,8(a3)",
+            RawCInstruction::CL {
+                opcode: 0b00,
+                funct3: 0b010,
+                rd: 4,
+                rs1: 5,
+                imm: 4,
+            },
+        ),
+        (
+            "c.sw a2,8(a3)",
+            RawCInstruction::CS {
+                opcode: 0b00,
+                funct3: 0b110,
+                rs1: 5,
+                rs2: 4,
+                imm: 4,
+            },
+        ),
+        (
+            "c.sub a3,a0",
+            RawCInstruction::CA {
+                opcode: 0b01,
+                funct6: 0b100011,
+                rd: 5,
+                funct2: 0b00,
+                rs2: 2,
+            },
+        ),
+        (
+            "c.beqz a0,16",
+            RawCInstruction::CB {
+                opcode: 0b01,
+                funct3: 0b110,
+                rs1: 2,
+                imm: CBImmediate::try_from(16).unwrap(),
+            },
+        ),
+        (
+            "c.j -216",
+            RawCInstruction::CJ {
+                opcode: 0b01,
+                funct3: 0b101,
+                imm: CJImmediate::try_from(-216).unwrap(),
+            },
+        ),
+    ];
+    for (line, expected) in cases {
+        let bin = cword(line);
+        let raw = RawCInstruction::decode(bin).unwrap();
+        assert_eq!(raw, expected, "{line}");
+        assert_eq!(raw.encode(), bin, "{line}");
+        check_insn(bin as u32, &raw.to_string());
+    }
+}
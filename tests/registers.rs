@@ -0,0 +1,273 @@
+use riscv_codec::assembly::{assemble_line, assemble_line_with};
+use riscv_codec::isa::{IsaConfig, Xlen};
+use riscv_codec::register::{FRegister, IRegister, Register};
+
+use Register::{F, I};
+
+const RV32: IsaConfig = IsaConfig {
+    xlen: Xlen::Rv32,
+    ..IsaConfig::new()
+};
+
+const ZCMP: IsaConfig = IsaConfig {
+    zcmp: true,
+    ..IsaConfig::new()
+};
+
+const ZCMT: IsaConfig = IsaConfig {
+    zcmt: true,
+    ..IsaConfig::new()
+};
+
+const ZICFISS: IsaConfig = IsaConfig {
+    zicfiss: true,
+    ..IsaConfig::new()
+};
+
+#[test]
+fn add() {
+    let i = assemble_line("add a0,a1,a2").unwrap().i();
+    assert_eq!(i.registers_read(), [I(IRegister::A1), I(IRegister::A2)]);
+    assert_eq!(i.registers_written(), [I(IRegister::A0)]);
+}
+
+#[test]
+fn repeated_source() {
+    let i = assemble_line("add a0,a0,a0").unwrap().i();
+    assert_eq!(i.registers_read(), [I(IRegister::A0)]);
+    assert_eq!(i.registers_written(), [I(IRegister::A0)]);
+}
+
+#[test]
+fn zero_write() {
+    // zero always reads as zero, so it isn't a dependency
+    let i = assemble_line("addi zero,zero,0").unwrap().i();
+    assert_eq!(i.registers_read(), []);
+    assert_eq!(i.registers_written(), []);
+}
+
+#[test]
+fn store() {
+    let i = assemble_line("sw a0,4(sp)").unwrap().i();
+    assert_eq!(
+        i.registers_read(),
+        [I(IRegister::A0), I(IRegister::StackPointer)]
+    );
+    assert_eq!(i.registers_written(), []);
+}
+
+#[test]
+fn jal() {
+    let i = assemble_line("jal ra,16").unwrap().i();
+    assert_eq!(i.registers_read(), []);
+    assert_eq!(i.registers_written(), [I(IRegister::ReturnAddress)]);
+}
+
+#[test]
+fn float_static_rounding() {
+    let i = assemble_line("fadd.s fa0,fa1,fa2,rtz").unwrap().i();
+    assert_eq!(i.registers_read(), [F(FRegister::FA1), F(FRegister::FA2)]);
+    assert_eq!(i.registers_written(), [F(FRegister::FA0), Register::FFLAGS]);
+}
+
+#[test]
+fn float_dynamic_rounding() {
+    let i = assemble_line("fmadd.d fa0,fa1,fa2,fa3").unwrap().i();
+    assert_eq!(
+        i.registers_read(),
+        [
+            F(FRegister::FA1),
+            F(FRegister::FA2),
+            F(FRegister::FA3),
+            Register::FRM
+        ]
+    );
+    assert_eq!(i.registers_written(), [F(FRegister::FA0), Register::FFLAGS]);
+}
+
+#[test]
+fn float_exact_conversions() {
+    // widening conversions are exact, so they don't read the rounding mode
+    let i = assemble_line("fcvt.d.w fa0,a0").unwrap().i();
+    assert_eq!(i.registers_read(), [I(IRegister::A0)]);
+    assert_eq!(i.registers_written(), [F(FRegister::FA0)]);
+    let i = assemble_line("fcvt.d.wu fa0,a0").unwrap().i();
+    assert_eq!(i.registers_read(), [I(IRegister::A0)]);
+    assert_eq!(i.registers_written(), [F(FRegister::FA0)]);
+    // but a signaling NaN raises the invalid flag
+    let i = assemble_line("fcvt.d.s fa0,fa1").unwrap().i();
+    assert_eq!(i.registers_read(), [F(FRegister::FA1)]);
+    assert_eq!(i.registers_written(), [F(FRegister::FA0), Register::FFLAGS]);
+    // 64-bit integers don't fit in a double
+    let i = assemble_line("fcvt.d.l fa0,a0").unwrap().i();
+    assert_eq!(i.registers_read(), [I(IRegister::A0), Register::FRM]);
+    assert_eq!(i.registers_written(), [F(FRegister::FA0), Register::FFLAGS]);
+}
+
+#[test]
+fn float_compare() {
+    let i = assemble_line("flt.s a0,fa1,fa2").unwrap().i();
+    assert_eq!(i.registers_read(), [F(FRegister::FA1), F(FRegister::FA2)]);
+    assert_eq!(i.registers_written(), [I(IRegister::A0), Register::FFLAGS]);
+}
+
+#[test]
+fn float_sign_injection() {
+    let i = assemble_line("fsgnj.d fa0,fa1,fa2").unwrap().i();
+    assert_eq!(i.registers_written(), [F(FRegister::FA0)]);
+}
+
+#[test]
+fn csr_read() {
+    let i = assemble_line("csrrs a0,3,zero").unwrap().i();
+    assert_eq!(i.registers_read(), [Register::FFLAGS, Register::FRM]);
+    assert_eq!(i.registers_written(), [I(IRegister::A0)]);
+}
+
+#[test]
+fn fcsr_write() {
+    // fcsr is made up of frm and fflags, so writing it writes both
+    let i = assemble_line("csrrw zero,3,a0").unwrap().i();
+    assert_eq!(i.registers_read(), [I(IRegister::A0)]);
+    assert_eq!(i.registers_written(), [Register::FFLAGS, Register::FRM]);
+    assert_eq!(Register::FCSR.to_string(), "fcsr");
+}
+
+#[test]
+fn csr_write() {
+    let i = assemble_line("csrrw zero,2,a1").unwrap().i();
+    assert_eq!(i.registers_read(), [I(IRegister::A1)]);
+    assert_eq!(i.registers_written(), [Register::FRM]);
+}
+
+#[test]
+fn csr_swap_immediate() {
+    let i = assemble_line("csrrwi a0,1,3").unwrap().i();
+    assert_eq!(i.registers_read(), [Register::FFLAGS]);
+    assert_eq!(i.registers_written(), [I(IRegister::A0), Register::FFLAGS]);
+}
+
+#[test]
+fn amocas() {
+    let i = assemble_line("amocas.w a0,a1,a2").unwrap().i();
+    assert_eq!(
+        i.registers_read(),
+        [I(IRegister::A1), I(IRegister::A2), I(IRegister::A0)]
+    );
+    assert_eq!(i.registers_written(), [I(IRegister::A0)]);
+}
+
+#[test]
+fn amocas_pairs() {
+    // the dest and src of amocas.d are register pairs on RV32
+    let i = assemble_line_with("amocas.d a0,a2,a4", &RV32).unwrap().i();
+    assert_eq!(
+        i.registers_read_with(&RV32),
+        [
+            I(IRegister::A2),
+            I(IRegister::A4),
+            I(IRegister::A5),
+            I(IRegister::A0),
+            I(IRegister::A1)
+        ]
+    );
+    assert_eq!(
+        i.registers_written_with(&RV32),
+        [I(IRegister::A0), I(IRegister::A1)]
+    );
+    assert_eq!(i.registers_written(), [I(IRegister::A0)]);
+}
+
+#[test]
+fn sspush() {
+    let i = assemble_line_with("sspush ra", &ZICFISS).unwrap().i();
+    assert_eq!(
+        i.registers_read(),
+        [I(IRegister::ReturnAddress), Register::SSP]
+    );
+    assert_eq!(i.registers_written(), [Register::SSP]);
+}
+
+#[test]
+fn c_lwsp() {
+    let c = assemble_line("c.lwsp a0,8").unwrap().c();
+    assert_eq!(c.registers_read(), [I(IRegister::StackPointer)]);
+    assert_eq!(c.registers_written(), [I(IRegister::A0)]);
+}
+
+#[test]
+fn c_addi16sp() {
+    let c = assemble_line("c.addi16sp 32").unwrap().c();
+    assert_eq!(c.registers_read(), [I(IRegister::StackPointer)]);
+    assert_eq!(c.registers_written(), [I(IRegister::StackPointer)]);
+}
+
+#[test]
+fn c_jalr() {
+    let c = assemble_line("c.jalr a0").unwrap().c();
+    assert_eq!(c.registers_read(), [I(IRegister::A0)]);
+    assert_eq!(c.registers_written(), [I(IRegister::ReturnAddress)]);
+}
+
+#[test]
+fn c_sub() {
+    let c = assemble_line("c.sub a0,a1").unwrap().c();
+    assert_eq!(c.registers_read(), [I(IRegister::A0), I(IRegister::A1)]);
+    assert_eq!(c.registers_written(), [I(IRegister::A0)]);
+}
+
+#[test]
+fn cm_push() {
+    let c = assemble_line_with("cm.push {ra,s0-s1},-32", &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(
+        c.registers_read(),
+        [
+            I(IRegister::ReturnAddress),
+            I(IRegister::FramePointer),
+            I(IRegister::S1),
+            I(IRegister::StackPointer)
+        ]
+    );
+    assert_eq!(c.registers_written(), [I(IRegister::StackPointer)]);
+}
+
+#[test]
+fn cm_popretz() {
+    let c = assemble_line_with("cm.popretz {ra},16", &ZCMP).unwrap().c();
+    assert_eq!(c.registers_read(), [I(IRegister::StackPointer)]);
+    assert_eq!(
+        c.registers_written(),
+        [
+            I(IRegister::ReturnAddress),
+            I(IRegister::StackPointer),
+            I(IRegister::A0)
+        ]
+    );
+}
+
+#[test]
+fn cm_popret() {
+    // ra is restored from the stack, so its old value isn't read
+    let c = assemble_line_with("cm.popret {ra,s0-s1},32", &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(c.registers_read(), [I(IRegister::StackPointer)]);
+    assert_eq!(
+        c.registers_written(),
+        [
+            I(IRegister::ReturnAddress),
+            I(IRegister::FramePointer),
+            I(IRegister::S1),
+            I(IRegister::StackPointer)
+        ]
+    );
+}
+
+#[test]
+fn cm_jalt() {
+    let c = assemble_line_with("cm.jalt 32", &ZCMT).unwrap().c();
+    assert_eq!(c.registers_read(), [Register::JVT]);
+    assert_eq!(c.registers_written(), [I(IRegister::ReturnAddress)]);
+}
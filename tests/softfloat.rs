@@ -0,0 +1,354 @@
+use riscv_codec::instruction::RoundingMode;
+use riscv_codec::softfloat::{self, DZ, F32, F64, NV, NX, OF, SignInjection, UF};
+
+const MODES: [RoundingMode; 5] = [
+    RoundingMode::RNE,
+    RoundingMode::RTZ,
+    RoundingMode::RDN,
+    RoundingMode::RUP,
+    RoundingMode::RMM,
+];
+
+fn s(x: f32) -> u64 {
+    x.to_bits() as u64
+}
+
+fn d(x: f64) -> u64 {
+    x.to_bits()
+}
+
+#[test]
+fn rounding_modes() {
+    // 1/3 lies between two doubles and rounds down to nearest
+    let third = d(1.0 / 3.0);
+    let expected = [third, third, third, third + 1, third];
+    for (rm, expected) in MODES.into_iter().zip(expected) {
+        let mut flags = 0;
+        assert_eq!(
+            softfloat::div(F64, d(1.0), d(3.0), rm, &mut flags),
+            expected,
+            "{rm}"
+        );
+        assert_eq!(flags, NX);
+        let mut flags = 0;
+        let negative = softfloat::div(F64, d(-1.0), d(3.0), rm, &mut flags);
+        let expected = match rm {
+            RoundingMode::RDN => third + 1,
+            RoundingMode::RUP => third,
+            _ => expected,
+        };
+        assert_eq!(negative, expected | 1 << 63, "{rm}");
+    }
+}
+
+#[test]
+fn ties() {
+    // 1 + 2^-24 is exactly halfway between two singles
+    let halfway = d(1.0 + 2f64.powi(-24));
+    let mut flags = 0;
+    let round = |rm| softfloat::convert(F64, F32, halfway, rm, &mut 0);
+    assert_eq!(round(RoundingMode::RNE), s(1.0));
+    assert_eq!(round(RoundingMode::RMM), s(1.0) + 1);
+    assert_eq!(round(RoundingMode::RTZ), s(1.0));
+    assert_eq!(round(RoundingMode::RUP), s(1.0) + 1);
+    softfloat::convert(F64, F32, halfway, RoundingMode::RNE, &mut flags);
+    assert_eq!(flags, NX);
+}
+
+#[test]
+fn dynamic_rounding_mode() {
+    assert_eq!(
+        softfloat::resolve_rounding_mode(RoundingMode::DYN, 3),
+        Some(RoundingMode::RUP)
+    );
+    assert_eq!(
+        softfloat::resolve_rounding_mode(RoundingMode::RTZ, 3),
+        Some(RoundingMode::RTZ)
+    );
+    for frm in 5..8 {
+        assert_eq!(
+            softfloat::resolve_rounding_mode(RoundingMode::DYN, frm),
+            None
+        );
+    }
+}
+
+#[test]
+fn exception_flags() {
+    let mut flags = 0;
+    assert_eq!(
+        softfloat::div(F32, s(1.0), s(0.0), RoundingMode::RNE, &mut flags),
+        s(f32::INFINITY)
+    );
+    assert_eq!(flags, DZ);
+
+    let mut flags = 0;
+    let result = softfloat::mul(F32, s(f32::MAX), s(2.0), RoundingMode::RNE, &mut flags);
+    assert_eq!(result, s(f32::INFINITY));
+    assert_eq!(flags, OF | NX);
+    let mut flags = 0;
+    let result = softfloat::mul(F32, s(f32::MAX), s(2.0), RoundingMode::RTZ, &mut flags);
+    assert_eq!(result, s(f32::MAX));
+    assert_eq!(flags, OF | NX);
+
+    let mut flags = 0;
+    let result = softfloat::mul(
+        F64,
+        d(f64::MIN_POSITIVE),
+        d(0.75),
+        RoundingMode::RNE,
+        &mut flags,
+    );
+    assert_eq!(result, d(f64::MIN_POSITIVE * 0.75));
+    assert_eq!(flags, 0, "exact subnormal results don't underflow");
+    let mut flags = 0;
+    softfloat::mul(
+        F64,
+        d(f64::MIN_POSITIVE),
+        d(1.0 / 3.0),
+        RoundingMode::RNE,
+        &mut flags,
+    );
+    assert_eq!(flags, UF | NX);
+
+    let mut flags = 0;
+    softfloat::sqrt(F64, d(-1.0), RoundingMode::RNE, &mut flags);
+    assert_eq!(flags, NV);
+    let mut flags = 0;
+    assert_eq!(
+        softfloat::sqrt(F64, d(-0.0), RoundingMode::RNE, &mut flags),
+        d(-0.0)
+    );
+    assert_eq!(flags, 0);
+}
+
+#[test]
+fn canonical_nan() {
+    assert_eq!(F32.canonical_nan(), 0x7fc00000);
+    assert_eq!(F64.canonical_nan(), 0x7ff8000000000000);
+
+    // NaN payloads and signs are never propagated
+    let mut flags = 0;
+    let result = softfloat::add(F32, 0xffc12345, s(1.0), RoundingMode::RNE, &mut flags);
+    assert_eq!(result, F32.canonical_nan());
+    assert_eq!(flags, 0);
+    let result = softfloat::add(F32, 0x7f812345, s(1.0), RoundingMode::RNE, &mut flags);
+    assert_eq!(result, F32.canonical_nan());
+    assert_eq!(flags, NV);
+
+    let mut flags = 0;
+    let result = softfloat::add(
+        F64,
+        d(f64::INFINITY),
+        d(f64::NEG_INFINITY),
+        RoundingMode::RNE,
+        &mut flags,
+    );
+    assert_eq!(result, F64.canonical_nan());
+    assert_eq!(flags, NV);
+
+    let mut flags = 0;
+    let result = softfloat::convert(F32, F64, 0x7f812345, RoundingMode::RNE, &mut flags);
+    assert_eq!(result, F64.canonical_nan());
+    assert_eq!(flags, NV);
+}
+
+#[test]
+fn fused_multiply_add() {
+    // a * b + c is rounded once, so the low bits of the product survive
+    let a = d(1.0 + f64::EPSILON);
+    let b = d(1.0 - f64::EPSILON);
+    let mut flags = 0;
+    let result = softfloat::fma(F64, a, b, d(-1.0), RoundingMode::RNE, &mut flags);
+    assert_eq!(result, d(-f64::EPSILON * f64::EPSILON));
+    assert_eq!(flags, 0);
+
+    // 0 * inf is invalid even when the addend is a quiet NaN
+    let mut flags = 0;
+    let result = softfloat::fma(
+        F32,
+        s(0.0),
+        s(f32::INFINITY),
+        s(f32::NAN),
+        RoundingMode::RNE,
+        &mut flags,
+    );
+    assert_eq!(result, F32.canonical_nan());
+    assert_eq!(flags, NV);
+
+    // exact zero sums are negative only when rounding down
+    let result = softfloat::fma(F32, s(1.0), s(1.0), s(-1.0), RoundingMode::RDN, &mut 0);
+    assert_eq!(result, s(-0.0));
+    let result = softfloat::fma(F32, s(1.0), s(1.0), s(-1.0), RoundingMode::RNE, &mut 0);
+    assert_eq!(result, s(0.0));
+}
+
+#[test]
+fn min_max() {
+    let mut flags = 0;
+    assert_eq!(
+        softfloat::min_max(F32, s(-0.0), s(0.0), false, &mut flags),
+        s(-0.0)
+    );
+    assert_eq!(
+        softfloat::min_max(F32, s(-0.0), s(0.0), true, &mut flags),
+        s(0.0)
+    );
+    assert_eq!(
+        softfloat::min_max(F32, s(f32::NAN), s(2.0), false, &mut flags),
+        s(2.0)
+    );
+    assert_eq!(flags, 0);
+
+    // a signaling NaN is still ignored, but raises the invalid flag
+    let mut flags = 0;
+    assert_eq!(
+        softfloat::min_max(F32, 0x7f800001, s(2.0), true, &mut flags),
+        s(2.0)
+    );
+    assert_eq!(flags, NV);
+
+    let mut flags = 0;
+    let result = softfloat::min_max(F64, 0x7ff0000000000001, d(f64::NAN), true, &mut flags);
+    assert_eq!(result, F64.canonical_nan());
+    assert_eq!(flags, NV);
+}
+
+#[test]
+fn compare() {
+    let mut flags = 0;
+    assert!(softfloat::eq(F64, d(0.0), d(-0.0), &mut flags));
+    assert!(!softfloat::eq(F64, d(f64::NAN), d(f64::NAN), &mut flags));
+    assert_eq!(flags, 0);
+    assert!(!softfloat::eq(F64, 0x7ff0000000000001, d(1.0), &mut flags));
+    assert_eq!(flags, NV);
+
+    let mut flags = 0;
+    assert!(softfloat::le(F64, d(-0.0), d(0.0), &mut flags));
+    assert!(!softfloat::lt(F64, d(-0.0), d(0.0), &mut flags));
+    assert!(softfloat::lt(
+        F64,
+        d(f64::NEG_INFINITY),
+        d(f64::MIN),
+        &mut flags
+    ));
+    assert_eq!(flags, 0);
+    assert!(!softfloat::lt(F64, d(f64::NAN), d(1.0), &mut flags));
+    assert_eq!(flags, NV);
+}
+
+#[test]
+fn classify() {
+    let cases = [
+        (s(f32::NEG_INFINITY), 1 << 0),
+        (s(-1.0), 1 << 1),
+        (s(-1e-40), 1 << 2),
+        (s(-0.0), 1 << 3),
+        (s(0.0), 1 << 4),
+        (s(1e-40), 1 << 5),
+        (s(1.0), 1 << 6),
+        (s(f32::INFINITY), 1 << 7),
+        (0x7f800001, 1 << 8),
+        (0xffc00000, 1 << 9),
+    ];
+    for (value, mask) in cases {
+        assert_eq!(softfloat::classify(F32, value), mask, "{value:x}");
+    }
+    assert_eq!(softfloat::classify(F64, d(-f64::MIN_POSITIVE)), 1 << 1);
+    assert_eq!(softfloat::classify(F64, d(f64::MIN_POSITIVE / 2.0)), 1 << 5);
+}
+
+#[test]
+fn float_to_int() {
+    let mut flags = 0;
+    let convert = |value, signed, width, rm, flags: &mut u8| {
+        softfloat::to_int(F64, d(value), signed, width, rm, flags)
+    };
+    assert_eq!(convert(2.5, true, 64, RoundingMode::RNE, &mut flags), 2);
+    assert_eq!(convert(2.5, true, 64, RoundingMode::RMM, &mut flags), 3);
+    assert_eq!(
+        convert(-2.5, true, 64, RoundingMode::RDN, &mut flags),
+        -3i64 as u64
+    );
+    assert_eq!(
+        convert(-2.5, true, 64, RoundingMode::RUP, &mut flags),
+        -2i64 as u64
+    );
+    assert_eq!(flags, NX);
+
+    // out of range values saturate and are invalid rather than inexact
+    let mut flags = 0;
+    assert_eq!(
+        convert(1e10, true, 32, RoundingMode::RNE, &mut flags),
+        i32::MAX as u64
+    );
+    assert_eq!(flags, NV);
+    assert_eq!(
+        convert(-1e10, true, 32, RoundingMode::RNE, &mut flags),
+        i32::MIN as u64
+    );
+    assert_eq!(convert(-1.0, false, 64, RoundingMode::RNE, &mut flags), 0);
+    assert_eq!(
+        convert(f64::NAN, true, 64, RoundingMode::RNE, &mut flags),
+        i64::MAX as u64
+    );
+    assert_eq!(
+        convert(f64::NEG_INFINITY, true, 64, RoundingMode::RNE, &mut flags),
+        i64::MIN as u64
+    );
+
+    // small negative values round to zero for unsigned conversions, which is only inexact
+    let mut flags = 0;
+    assert_eq!(convert(-0.5, false, 32, RoundingMode::RTZ, &mut flags), 0);
+    assert_eq!(flags, NX);
+
+    // 32-bit results are sign-extended, including unsigned ones
+    let mut flags = 0;
+    assert_eq!(
+        convert(4e9, false, 32, RoundingMode::RNE, &mut flags),
+        4_000_000_000u32 as i32 as u64
+    );
+    assert_eq!(
+        convert(1e10, false, 32, RoundingMode::RNE, &mut flags),
+        u64::MAX
+    );
+    assert_eq!(flags, NV);
+}
+
+#[test]
+fn int_to_float() {
+    let mut flags = 0;
+    let result = softfloat::from_int(F32, u64::MAX, false, 64, RoundingMode::RNE, &mut flags);
+    assert_eq!(result, s(18446744073709551616.0));
+    assert_eq!(flags, NX);
+    let result = softfloat::from_int(F32, u64::MAX, false, 64, RoundingMode::RTZ, &mut 0);
+    assert_eq!(result, s(18446742974197923840.0));
+
+    // only the low 32 bits of the register are read for word conversions
+    let mut flags = 0;
+    let result = softfloat::from_int(
+        F64,
+        0x1234_5678_ffff_fffe,
+        true,
+        32,
+        RoundingMode::RNE,
+        &mut flags,
+    );
+    assert_eq!(result, d(-2.0));
+    assert_eq!(flags, 0);
+    let result = softfloat::from_int(F64, 0xffff_fffe, false, 32, RoundingMode::RNE, &mut flags);
+    assert_eq!(result, d(4294967294.0));
+}
+
+#[test]
+fn sign_injection() {
+    let inject = |a, b, op| softfloat::sign_inject(F32, s(a), s(b), op);
+    assert_eq!(inject(1.0, -2.0, SignInjection::Copy), s(-1.0));
+    assert_eq!(inject(-1.0, -2.0, SignInjection::Negate), s(1.0));
+    assert_eq!(inject(-1.0, -2.0, SignInjection::Xor), s(1.0));
+    // NaNs are not canonicalized
+    assert_eq!(
+        softfloat::sign_inject(F32, 0x7f800001, s(-1.0), SignInjection::Copy),
+        0xff800001
+    );
+}
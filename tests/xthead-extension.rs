@@ -0,0 +1,2680 @@
+use riscv_codec::assembly::assemble_line_with_extensions;
+use riscv_codec::extension::Extensions;
+use riscv_codec::immediates::{IncrementImmediate, Shamt, ShamtW};
+use riscv_codec::isa::{IsaConfig, Xlen};
+use riscv_codec::register::{FRegister, IRegister};
+use riscv_codec::stream::StreamDecoder;
+use riscv_codec::thead::{THeadInstruction, XThead};
+
+const RV32: IsaConfig = IsaConfig {
+    xlen: Xlen::Rv32,
+    ..IsaConfig::new()
+};
+
+fn assemble_with(line: &str, isa: &IsaConfig) -> Result<THeadInstruction, String> {
+    let extensions = Extensions::new().with(XThead);
+    let i = assemble_line_with_extensions(line, isa, &extensions)?.custom();
+    Ok(*i.as_any().downcast_ref::<THeadInstruction>().unwrap())
+}
+
+fn assemble(line: &str) -> THeadInstruction {
+    assemble_with(line, &IsaConfig::default()).unwrap()
+}
+
+fn decode(bin: u32) -> THeadInstruction {
+    let extensions = Extensions::new().with(XThead);
+    let decoder = StreamDecoder::new(&[], IsaConfig::default()).with_extensions(&extensions);
+    let i = decoder.decode_word(bin).unwrap().custom();
+    *i.as_any().downcast_ref::<THeadInstruction>().unwrap()
+}
+
+#[test]
+fn addsl() {
+    let expected = THeadInstruction::Addsl {
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+        shift: 2,
+    };
+    let bin = 0x04c5950b;
+
+    // check assembler
+    let i = assemble("th.addsl a0,a1,a2,2");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn srri() {
+    let expected = THeadInstruction::Srri {
+        dest: IRegister::A0,
+        src: IRegister::A1,
+        shamt: Shamt::try_from(37).unwrap(),
+    };
+    let bin = 0x1255950b;
+
+    // check assembler
+    let i = assemble("th.srri a0,a1,37");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn srriw() {
+    let expected = THeadInstruction::Srriw {
+        dest: IRegister::A0,
+        src: IRegister::A1,
+        shamt: ShamtW::try_from(17).unwrap(),
+    };
+    let bin = 0x1515950b;
+
+    // check assembler
+    let i = assemble("th.srriw a0,a1,17");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn ext() {
+    let expected = THeadInstruction::Ext {
+        dest: IRegister::A0,
+        src: IRegister::A1,
+        msb: 15,
+        lsb: 8,
+    };
+    let bin = 0x3c85a50b;
+
+    // check assembler
+    let i = assemble("th.ext a0,a1,15,8");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn extu() {
+    let expected = THeadInstruction::Extu {
+        dest: IRegister::A0,
+        src: IRegister::A1,
+        msb: 15,
+        lsb: 8,
+    };
+    let bin = 0x3c85b50b;
+
+    // check assembler
+    let i = assemble("th.extu a0,a1,15,8");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn ff0() {
+    let expected = THeadInstruction::Ff0 {
+        dest: IRegister::A0,
+        src: IRegister::A1,
+    };
+    let bin = 0x8405950b;
+
+    // check assembler
+    let i = assemble("th.ff0 a0,a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn ff1() {
+    let expected = THeadInstruction::Ff1 {
+        dest: IRegister::A0,
+        src: IRegister::A1,
+    };
+    let bin = 0x8605950b;
+
+    // check assembler
+    let i = assemble("th.ff1 a0,a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn rev() {
+    let expected = THeadInstruction::Rev {
+        dest: IRegister::A0,
+        src: IRegister::A1,
+    };
+    let bin = 0x8205950b;
+
+    // check assembler
+    let i = assemble("th.rev a0,a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn revw() {
+    let expected = THeadInstruction::Revw {
+        dest: IRegister::A0,
+        src: IRegister::A1,
+    };
+    let bin = 0x9005950b;
+
+    // check assembler
+    let i = assemble("th.revw a0,a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn tstnbz() {
+    let expected = THeadInstruction::Tstnbz {
+        dest: IRegister::A0,
+        src: IRegister::A1,
+    };
+    let bin = 0x8005950b;
+
+    // check assembler
+    let i = assemble("th.tstnbz a0,a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn tst() {
+    let expected = THeadInstruction::Tst {
+        dest: IRegister::A0,
+        src: IRegister::A1,
+        shamt: Shamt::try_from(37).unwrap(),
+    };
+    let bin = 0x8a55950b;
+
+    // check assembler
+    let i = assemble("th.tst a0,a1,37");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn mveqz() {
+    let expected = THeadInstruction::Mveqz {
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+    };
+    let bin = 0x40c5950b;
+
+    // check assembler
+    let i = assemble("th.mveqz a0,a1,a2");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn mvnez() {
+    let expected = THeadInstruction::Mvnez {
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+    };
+    let bin = 0x42c5950b;
+
+    // check assembler
+    let i = assemble("th.mvnez a0,a1,a2");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn mula() {
+    let expected = THeadInstruction::Mula {
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+    };
+    let bin = 0x20c5950b;
+
+    // check assembler
+    let i = assemble("th.mula a0,a1,a2");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn muls() {
+    let expected = THeadInstruction::Muls {
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+    };
+    let bin = 0x22c5950b;
+
+    // check assembler
+    let i = assemble("th.muls a0,a1,a2");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn mulaw() {
+    let expected = THeadInstruction::Mulaw {
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+    };
+    let bin = 0x24c5950b;
+
+    // check assembler
+    let i = assemble("th.mulaw a0,a1,a2");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn mulsw() {
+    let expected = THeadInstruction::Mulsw {
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+    };
+    let bin = 0x26c5950b;
+
+    // check assembler
+    let i = assemble("th.mulsw a0,a1,a2");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn mulah() {
+    let expected = THeadInstruction::Mulah {
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+    };
+    let bin = 0x28c5950b;
+
+    // check assembler
+    let i = assemble("th.mulah a0,a1,a2");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn mulsh() {
+    let expected = THeadInstruction::Mulsh {
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+    };
+    let bin = 0x2ac5950b;
+
+    // check assembler
+    let i = assemble("th.mulsh a0,a1,a2");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lbia() {
+    let expected = THeadInstruction::Lbia {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x1bd5c50b;
+
+    // check assembler
+    let i = assemble("th.lbia a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lbib() {
+    let expected = THeadInstruction::Lbib {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x0bd5c50b;
+
+    // check assembler
+    let i = assemble("th.lbib a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lbuia() {
+    let expected = THeadInstruction::Lbuia {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x9bd5c50b;
+
+    // check assembler
+    let i = assemble("th.lbuia a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lbuib() {
+    let expected = THeadInstruction::Lbuib {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x8bd5c50b;
+
+    // check assembler
+    let i = assemble("th.lbuib a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lhia() {
+    let expected = THeadInstruction::Lhia {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x3bd5c50b;
+
+    // check assembler
+    let i = assemble("th.lhia a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lhib() {
+    let expected = THeadInstruction::Lhib {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x2bd5c50b;
+
+    // check assembler
+    let i = assemble("th.lhib a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lhuia() {
+    let expected = THeadInstruction::Lhuia {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0xbbd5c50b;
+
+    // check assembler
+    let i = assemble("th.lhuia a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lhuib() {
+    let expected = THeadInstruction::Lhuib {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0xabd5c50b;
+
+    // check assembler
+    let i = assemble("th.lhuib a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lwia() {
+    let expected = THeadInstruction::Lwia {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x5bd5c50b;
+
+    // check assembler
+    let i = assemble("th.lwia a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lwib() {
+    let expected = THeadInstruction::Lwib {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x4bd5c50b;
+
+    // check assembler
+    let i = assemble("th.lwib a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lwuia() {
+    let expected = THeadInstruction::Lwuia {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0xdbd5c50b;
+
+    // check assembler
+    let i = assemble("th.lwuia a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lwuib() {
+    let expected = THeadInstruction::Lwuib {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0xcbd5c50b;
+
+    // check assembler
+    let i = assemble("th.lwuib a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn ldia() {
+    let expected = THeadInstruction::Ldia {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x7bd5c50b;
+
+    // check assembler
+    let i = assemble("th.ldia a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn ldib() {
+    let expected = THeadInstruction::Ldib {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x6bd5c50b;
+
+    // check assembler
+    let i = assemble("th.ldib a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn sbia() {
+    let expected = THeadInstruction::Sbia {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x1bd5d50b;
+
+    // check assembler
+    let i = assemble("th.sbia a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn sbib() {
+    let expected = THeadInstruction::Sbib {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x0bd5d50b;
+
+    // check assembler
+    let i = assemble("th.sbib a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn shia() {
+    let expected = THeadInstruction::Shia {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x3bd5d50b;
+
+    // check assembler
+    let i = assemble("th.shia a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn shib() {
+    let expected = THeadInstruction::Shib {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x2bd5d50b;
+
+    // check assembler
+    let i = assemble("th.shib a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn swia() {
+    let expected = THeadInstruction::Swia {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x5bd5d50b;
+
+    // check assembler
+    let i = assemble("th.swia a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn swib() {
+    let expected = THeadInstruction::Swib {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x4bd5d50b;
+
+    // check assembler
+    let i = assemble("th.swib a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn sdia() {
+    let expected = THeadInstruction::Sdia {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x7bd5d50b;
+
+    // check assembler
+    let i = assemble("th.sdia a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn sdib() {
+    let expected = THeadInstruction::Sdib {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        offset: IncrementImmediate::try_from(-3).unwrap(),
+        shift: 1,
+    };
+    let bin = 0x6bd5d50b;
+
+    // check assembler
+    let i = assemble("th.sdib a0,(a1),-3,1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lrb() {
+    let expected = THeadInstruction::Lrb {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x06c5c50b;
+
+    // check assembler
+    let i = assemble("th.lrb a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lurb() {
+    let expected = THeadInstruction::Lurb {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x16c5c50b;
+
+    // check assembler
+    let i = assemble("th.lurb a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lrbu() {
+    let expected = THeadInstruction::Lrbu {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x86c5c50b;
+
+    // check assembler
+    let i = assemble("th.lrbu a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lurbu() {
+    let expected = THeadInstruction::Lurbu {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x96c5c50b;
+
+    // check assembler
+    let i = assemble("th.lurbu a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lrh() {
+    let expected = THeadInstruction::Lrh {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x26c5c50b;
+
+    // check assembler
+    let i = assemble("th.lrh a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lurh() {
+    let expected = THeadInstruction::Lurh {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x36c5c50b;
+
+    // check assembler
+    let i = assemble("th.lurh a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lrhu() {
+    let expected = THeadInstruction::Lrhu {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0xa6c5c50b;
+
+    // check assembler
+    let i = assemble("th.lrhu a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lurhu() {
+    let expected = THeadInstruction::Lurhu {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0xb6c5c50b;
+
+    // check assembler
+    let i = assemble("th.lurhu a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lrw() {
+    let expected = THeadInstruction::Lrw {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x46c5c50b;
+
+    // check assembler
+    let i = assemble("th.lrw a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lurw() {
+    let expected = THeadInstruction::Lurw {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x56c5c50b;
+
+    // check assembler
+    let i = assemble("th.lurw a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lrwu() {
+    let expected = THeadInstruction::Lrwu {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0xc6c5c50b;
+
+    // check assembler
+    let i = assemble("th.lrwu a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lurwu() {
+    let expected = THeadInstruction::Lurwu {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0xd6c5c50b;
+
+    // check assembler
+    let i = assemble("th.lurwu a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lrd() {
+    let expected = THeadInstruction::Lrd {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x66c5c50b;
+
+    // check assembler
+    let i = assemble("th.lrd a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lurd() {
+    let expected = THeadInstruction::Lurd {
+        dest: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x76c5c50b;
+
+    // check assembler
+    let i = assemble("th.lurd a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn srb() {
+    let expected = THeadInstruction::Srb {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x06c5d50b;
+
+    // check assembler
+    let i = assemble("th.srb a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn surb() {
+    let expected = THeadInstruction::Surb {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x16c5d50b;
+
+    // check assembler
+    let i = assemble("th.surb a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn srh() {
+    let expected = THeadInstruction::Srh {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x26c5d50b;
+
+    // check assembler
+    let i = assemble("th.srh a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn surh() {
+    let expected = THeadInstruction::Surh {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x36c5d50b;
+
+    // check assembler
+    let i = assemble("th.surh a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn srw() {
+    let expected = THeadInstruction::Srw {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x46c5d50b;
+
+    // check assembler
+    let i = assemble("th.srw a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn surw() {
+    let expected = THeadInstruction::Surw {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x56c5d50b;
+
+    // check assembler
+    let i = assemble("th.surw a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn srd() {
+    let expected = THeadInstruction::Srd {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x66c5d50b;
+
+    // check assembler
+    let i = assemble("th.srd a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn surd() {
+    let expected = THeadInstruction::Surd {
+        src: IRegister::A0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x76c5d50b;
+
+    // check assembler
+    let i = assemble("th.surd a0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lwd() {
+    let expected = THeadInstruction::Lwd {
+        dest1: IRegister::A0,
+        dest2: IRegister::A2,
+        base: IRegister::A1,
+        offset: 2,
+    };
+    let bin = 0xe4c5c50b;
+
+    // check assembler
+    let i = assemble("th.lwd a0,a2,(a1),2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn lwud() {
+    let expected = THeadInstruction::Lwud {
+        dest1: IRegister::A0,
+        dest2: IRegister::A2,
+        base: IRegister::A1,
+        offset: 2,
+    };
+    let bin = 0xf4c5c50b;
+
+    // check assembler
+    let i = assemble("th.lwud a0,a2,(a1),2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn ldd() {
+    let expected = THeadInstruction::Ldd {
+        dest1: IRegister::A0,
+        dest2: IRegister::A2,
+        base: IRegister::A1,
+        offset: 2,
+    };
+    let bin = 0xfcc5c50b;
+
+    // check assembler
+    let i = assemble("th.ldd a0,a2,(a1),2,4");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn swd() {
+    let expected = THeadInstruction::Swd {
+        src1: IRegister::A0,
+        src2: IRegister::A2,
+        base: IRegister::A1,
+        offset: 2,
+    };
+    let bin = 0xe4c5d50b;
+
+    // check assembler
+    let i = assemble("th.swd a0,a2,(a1),2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn sdd() {
+    let expected = THeadInstruction::Sdd {
+        src1: IRegister::A0,
+        src2: IRegister::A2,
+        base: IRegister::A1,
+        offset: 2,
+    };
+    let bin = 0xfcc5d50b;
+
+    // check assembler
+    let i = assemble("th.sdd a0,a2,(a1),2,4");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn flrw() {
+    let expected = THeadInstruction::Flrw {
+        dest: FRegister::FA0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x46c5e50b;
+
+    // check assembler
+    let i = assemble("th.flrw fa0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn flrd() {
+    let expected = THeadInstruction::Flrd {
+        dest: FRegister::FA0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x66c5e50b;
+
+    // check assembler
+    let i = assemble("th.flrd fa0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn flurw() {
+    let expected = THeadInstruction::Flurw {
+        dest: FRegister::FA0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x56c5e50b;
+
+    // check assembler
+    let i = assemble("th.flurw fa0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn flurd() {
+    let expected = THeadInstruction::Flurd {
+        dest: FRegister::FA0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x76c5e50b;
+
+    // check assembler
+    let i = assemble("th.flurd fa0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn fsrw() {
+    let expected = THeadInstruction::Fsrw {
+        src: FRegister::FA0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x46c5f50b;
+
+    // check assembler
+    let i = assemble("th.fsrw fa0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn fsrd() {
+    let expected = THeadInstruction::Fsrd {
+        src: FRegister::FA0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x66c5f50b;
+
+    // check assembler
+    let i = assemble("th.fsrd fa0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn fsurw() {
+    let expected = THeadInstruction::Fsurw {
+        src: FRegister::FA0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x56c5f50b;
+
+    // check assembler
+    let i = assemble("th.fsurw fa0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn fsurd() {
+    let expected = THeadInstruction::Fsurd {
+        src: FRegister::FA0,
+        base: IRegister::A1,
+        index: IRegister::A2,
+        shift: 3,
+    };
+    let bin = 0x76c5f50b;
+
+    // check assembler
+    let i = assemble("th.fsurd fa0,a1,a2,3");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_call() {
+    let expected = THeadInstruction::DcacheCall;
+    let bin = 0x0010000b;
+
+    // check assembler
+    let i = assemble("th.dcache.call");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_iall() {
+    let expected = THeadInstruction::DcacheIall;
+    let bin = 0x0020000b;
+
+    // check assembler
+    let i = assemble("th.dcache.iall");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_ciall() {
+    let expected = THeadInstruction::DcacheCiall;
+    let bin = 0x0030000b;
+
+    // check assembler
+    let i = assemble("th.dcache.ciall");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_csw() {
+    let expected = THeadInstruction::DcacheCsw { src: IRegister::A1 };
+    let bin = 0x0215800b;
+
+    // check assembler
+    let i = assemble("th.dcache.csw a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_isw() {
+    let expected = THeadInstruction::DcacheIsw { src: IRegister::A1 };
+    let bin = 0x0225800b;
+
+    // check assembler
+    let i = assemble("th.dcache.isw a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_cisw() {
+    let expected = THeadInstruction::DcacheCisw { src: IRegister::A1 };
+    let bin = 0x0235800b;
+
+    // check assembler
+    let i = assemble("th.dcache.cisw a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_cval1() {
+    let expected = THeadInstruction::DcacheCval1 { src: IRegister::A1 };
+    let bin = 0x0245800b;
+
+    // check assembler
+    let i = assemble("th.dcache.cval1 a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_cva() {
+    let expected = THeadInstruction::DcacheCva { src: IRegister::A1 };
+    let bin = 0x0255800b;
+
+    // check assembler
+    let i = assemble("th.dcache.cva a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_iva() {
+    let expected = THeadInstruction::DcacheIva { src: IRegister::A1 };
+    let bin = 0x0265800b;
+
+    // check assembler
+    let i = assemble("th.dcache.iva a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_civa() {
+    let expected = THeadInstruction::DcacheCiva { src: IRegister::A1 };
+    let bin = 0x0275800b;
+
+    // check assembler
+    let i = assemble("th.dcache.civa a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_cpal1() {
+    let expected = THeadInstruction::DcacheCpal1 { src: IRegister::A1 };
+    let bin = 0x0285800b;
+
+    // check assembler
+    let i = assemble("th.dcache.cpal1 a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_cpa() {
+    let expected = THeadInstruction::DcacheCpa { src: IRegister::A1 };
+    let bin = 0x0295800b;
+
+    // check assembler
+    let i = assemble("th.dcache.cpa a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_ipa() {
+    let expected = THeadInstruction::DcacheIpa { src: IRegister::A1 };
+    let bin = 0x02a5800b;
+
+    // check assembler
+    let i = assemble("th.dcache.ipa a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn dcache_cipa() {
+    let expected = THeadInstruction::DcacheCipa { src: IRegister::A1 };
+    let bin = 0x02b5800b;
+
+    // check assembler
+    let i = assemble("th.dcache.cipa a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn icache_iall() {
+    let expected = THeadInstruction::IcacheIall;
+    let bin = 0x0100000b;
+
+    // check assembler
+    let i = assemble("th.icache.iall");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn icache_ialls() {
+    let expected = THeadInstruction::IcacheIalls;
+    let bin = 0x0110000b;
+
+    // check assembler
+    let i = assemble("th.icache.ialls");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn icache_iva() {
+    let expected = THeadInstruction::IcacheIva { src: IRegister::A1 };
+    let bin = 0x0305800b;
+
+    // check assembler
+    let i = assemble("th.icache.iva a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn icache_ipa() {
+    let expected = THeadInstruction::IcacheIpa { src: IRegister::A1 };
+    let bin = 0x0385800b;
+
+    // check assembler
+    let i = assemble("th.icache.ipa a1");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn l_2cache_call() {
+    let expected = THeadInstruction::L2cacheCall;
+    let bin = 0x0150000b;
+
+    // check assembler
+    let i = assemble("th.l2cache.call");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn l_2cache_iall() {
+    let expected = THeadInstruction::L2cacheIall;
+    let bin = 0x0160000b;
+
+    // check assembler
+    let i = assemble("th.l2cache.iall");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn l_2cache_ciall() {
+    let expected = THeadInstruction::L2cacheCiall;
+    let bin = 0x0170000b;
+
+    // check assembler
+    let i = assemble("th.l2cache.ciall");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn sfence_vmas() {
+    let expected = THeadInstruction::SfenceVmas {
+        vaddr: IRegister::A1,
+        asid: IRegister::A2,
+    };
+    let bin = 0x04c5800b;
+
+    // check assembler
+    let i = assemble("th.sfence.vmas a1,a2");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn sync() {
+    let expected = THeadInstruction::Sync;
+    let bin = 0x0180000b;
+
+    // check assembler
+    let i = assemble("th.sync");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn sync_s() {
+    let expected = THeadInstruction::SyncS;
+    let bin = 0x0190000b;
+
+    // check assembler
+    let i = assemble("th.sync.s");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn sync_i() {
+    let expected = THeadInstruction::SyncI;
+    let bin = 0x01a0000b;
+
+    // check assembler
+    let i = assemble("th.sync.i");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn sync_is() {
+    let expected = THeadInstruction::SyncIs;
+    let bin = 0x01b0000b;
+
+    // check assembler
+    let i = assemble("th.sync.is");
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = decode(bin);
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = i.encode();
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble(&i.to_string());
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn rv64_only() {
+    assert_eq!(
+        assemble_with("th.srriw a0,a1,17", &RV32),
+        Err("th.srriw a0,a1,17 is only available on RV64".to_owned())
+    );
+    assert_eq!(
+        assemble_with("th.srri a0,a1,37", &RV32),
+        Err("th.srri a0,a1,37 has a shift amount out of range for RV32".to_owned())
+    );
+    assert!(assemble_with("th.lwd a0,a2,(a1),2,3", &RV32).is_ok());
+}
+
+#[test]
+fn reserved_registers() {
+    assert_eq!(
+        assemble_with("th.lbia a0,(a0),1,0", &IsaConfig::default()),
+        Err("th.lbia a0,(a0),1,0 uses the same register for the destination and base".to_owned())
+    );
+    assert_eq!(
+        assemble_with("th.ldd a0,a0,(a1),0,4", &IsaConfig::default()),
+        Err("th.ldd a0,a0,(a1),0,4 uses overlapping destination and base registers".to_owned())
+    );
+    assert_eq!(
+        assemble_with("th.ldd a0,a2,(a1),0,3", &IsaConfig::default()),
+        Err("paired access requires a shift of 4".to_owned())
+    );
+}
+
+#[test]
+fn opt_in() {
+    // without the extension registered, T-Head instructions are unknown
+    let decoded: Vec<_> =
+        StreamDecoder::new(&0x00c5850bu32.to_le_bytes(), IsaConfig::default()).collect();
+    assert!(decoded[0].result.is_err());
+    assert!(
+        assemble_line_with_extensions(
+            "th.addsl a0,a1,a2,0",
+            &IsaConfig::default(),
+            &Extensions::new()
+        )
+        .is_err()
+    );
+}
@@ -0,0 +1,563 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::instruction::{Instruction, disassemble_instruction};
+use riscv_codec::register::IRegister;
+
+#[test]
+fn atomic_swap_byte() {
+    let expected = Instruction::AmoswapB {
+        dest: IRegister::A0,
+        addr: IRegister::A1,
+        src: IRegister::A2,
+        aq: false,
+        rl: false,
+    };
+    let bin = 0x08c5852f;
+
+    // check assembler
+    let i = assemble_line("amoswap.b a0,a1,a2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_add_byte() {
+    let expected = Instruction::AmoaddB {
+        dest: IRegister::T0,
+        addr: IRegister::StackPointer,
+        src: IRegister::S3,
+        aq: true,
+        rl: false,
+    };
+    let bin = 0x053102af;
+
+    // check assembler
+    let i = assemble_line("amoadd.b.aq t0,sp,s3").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_xor_byte() {
+    let expected = Instruction::AmoxorB {
+        dest: IRegister::ReturnAddress,
+        addr: IRegister::T6,
+        src: IRegister::A7,
+        aq: false,
+        rl: true,
+    };
+    let bin = 0x231f80af;
+
+    // check assembler
+    let i = assemble_line("amoxor.b.rl ra,t6,a7").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_and_byte() {
+    let expected = Instruction::AmoandB {
+        dest: IRegister::S1,
+        addr: IRegister::GlobalPointer,
+        src: IRegister::ThreadPointer,
+        aq: true,
+        rl: true,
+    };
+    let bin = 0x664184af;
+
+    // check assembler
+    let i = assemble_line("amoand.b.aqrl s1,gp,tp").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_or_byte() {
+    let expected = Instruction::AmoorB {
+        dest: IRegister::A0,
+        addr: IRegister::A1,
+        src: IRegister::A2,
+        aq: false,
+        rl: false,
+    };
+    let bin = 0x40c5852f;
+
+    // check assembler
+    let i = assemble_line("amoor.b a0,a1,a2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_minimum_byte() {
+    let expected = Instruction::AmominB {
+        dest: IRegister::T0,
+        addr: IRegister::StackPointer,
+        src: IRegister::S3,
+        aq: true,
+        rl: false,
+    };
+    let bin = 0x853102af;
+
+    // check assembler
+    let i = assemble_line("amomin.b.aq t0,sp,s3").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_maximum_byte() {
+    let expected = Instruction::AmomaxB {
+        dest: IRegister::ReturnAddress,
+        addr: IRegister::T6,
+        src: IRegister::A7,
+        aq: false,
+        rl: true,
+    };
+    let bin = 0xa31f80af;
+
+    // check assembler
+    let i = assemble_line("amomax.b.rl ra,t6,a7").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_minimum_unsigned_byte() {
+    let expected = Instruction::AmominuB {
+        dest: IRegister::S1,
+        addr: IRegister::GlobalPointer,
+        src: IRegister::ThreadPointer,
+        aq: true,
+        rl: true,
+    };
+    let bin = 0xc64184af;
+
+    // check assembler
+    let i = assemble_line("amominu.b.aqrl s1,gp,tp").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_maximum_unsigned_byte() {
+    let expected = Instruction::AmomaxuB {
+        dest: IRegister::A0,
+        addr: IRegister::A1,
+        src: IRegister::A2,
+        aq: false,
+        rl: false,
+    };
+    let bin = 0xe0c5852f;
+
+    // check assembler
+    let i = assemble_line("amomaxu.b a0,a1,a2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_compare_and_swap_byte() {
+    let expected = Instruction::AmocasB {
+        dest: IRegister::T0,
+        addr: IRegister::StackPointer,
+        src: IRegister::S3,
+        aq: true,
+        rl: false,
+    };
+    let bin = 0x2d3102af;
+
+    // check assembler
+    let i = assemble_line("amocas.b.aq t0,sp,s3").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_swap_halfword() {
+    let expected = Instruction::AmoswapH {
+        dest: IRegister::ReturnAddress,
+        addr: IRegister::T6,
+        src: IRegister::A7,
+        aq: false,
+        rl: true,
+    };
+    let bin = 0x0b1f90af;
+
+    // check assembler
+    let i = assemble_line("amoswap.h.rl ra,t6,a7").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_add_halfword() {
+    let expected = Instruction::AmoaddH {
+        dest: IRegister::S1,
+        addr: IRegister::GlobalPointer,
+        src: IRegister::ThreadPointer,
+        aq: true,
+        rl: true,
+    };
+    let bin = 0x064194af;
+
+    // check assembler
+    let i = assemble_line("amoadd.h.aqrl s1,gp,tp").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_xor_halfword() {
+    let expected = Instruction::AmoxorH {
+        dest: IRegister::A0,
+        addr: IRegister::A1,
+        src: IRegister::A2,
+        aq: false,
+        rl: false,
+    };
+    let bin = 0x20c5952f;
+
+    // check assembler
+    let i = assemble_line("amoxor.h a0,a1,a2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_and_halfword() {
+    let expected = Instruction::AmoandH {
+        dest: IRegister::T0,
+        addr: IRegister::StackPointer,
+        src: IRegister::S3,
+        aq: true,
+        rl: false,
+    };
+    let bin = 0x653112af;
+
+    // check assembler
+    let i = assemble_line("amoand.h.aq t0,sp,s3").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_or_halfword() {
+    let expected = Instruction::AmoorH {
+        dest: IRegister::ReturnAddress,
+        addr: IRegister::T6,
+        src: IRegister::A7,
+        aq: false,
+        rl: true,
+    };
+    let bin = 0x431f90af;
+
+    // check assembler
+    let i = assemble_line("amoor.h.rl ra,t6,a7").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_minimum_halfword() {
+    let expected = Instruction::AmominH {
+        dest: IRegister::S1,
+        addr: IRegister::GlobalPointer,
+        src: IRegister::ThreadPointer,
+        aq: true,
+        rl: true,
+    };
+    let bin = 0x864194af;
+
+    // check assembler
+    let i = assemble_line("amomin.h.aqrl s1,gp,tp").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_maximum_halfword() {
+    let expected = Instruction::AmomaxH {
+        dest: IRegister::A0,
+        addr: IRegister::A1,
+        src: IRegister::A2,
+        aq: false,
+        rl: false,
+    };
+    let bin = 0xa0c5952f;
+
+    // check assembler
+    let i = assemble_line("amomax.h a0,a1,a2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_minimum_unsigned_halfword() {
+    let expected = Instruction::AmominuH {
+        dest: IRegister::T0,
+        addr: IRegister::StackPointer,
+        src: IRegister::S3,
+        aq: true,
+        rl: false,
+    };
+    let bin = 0xc53112af;
+
+    // check assembler
+    let i = assemble_line("amominu.h.aq t0,sp,s3").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_maximum_unsigned_halfword() {
+    let expected = Instruction::AmomaxuH {
+        dest: IRegister::ReturnAddress,
+        addr: IRegister::T6,
+        src: IRegister::A7,
+        aq: false,
+        rl: true,
+    };
+    let bin = 0xe31f90af;
+
+    // check assembler
+    let i = assemble_line("amomaxu.h.rl ra,t6,a7").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_compare_and_swap_halfword() {
+    let expected = Instruction::AmocasH {
+        dest: IRegister::S1,
+        addr: IRegister::GlobalPointer,
+        src: IRegister::ThreadPointer,
+        aq: true,
+        rl: true,
+    };
+    let bin = 0x2e4194af;
+
+    // check assembler
+    let i = assemble_line("amocas.h.aqrl s1,gp,tp").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
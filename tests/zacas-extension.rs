@@ -0,0 +1,112 @@
+use riscv_codec::assembly::{assemble_line, assemble_line_with};
+use riscv_codec::instruction::{Instruction, disassemble_instruction};
+use riscv_codec::isa::{IsaConfig, Xlen};
+use riscv_codec::register::IRegister;
+
+const RV32: IsaConfig = IsaConfig {
+    xlen: Xlen::Rv32,
+    ..IsaConfig::new()
+};
+
+#[test]
+fn atomic_compare_and_swap_word() {
+    let expected = Instruction::AmocasW {
+        dest: IRegister::A0,
+        addr: IRegister::A1,
+        src: IRegister::A2,
+        aq: false,
+        rl: false,
+    };
+    let bin = 0x28c5a52f;
+
+    // check assembler
+    let i = assemble_line("amocas.w a0,a1,a2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_compare_and_swap_doubleword() {
+    let expected = Instruction::AmocasD {
+        dest: IRegister::T1,
+        addr: IRegister::FramePointer,
+        src: IRegister::A4,
+        aq: true,
+        rl: true,
+    };
+    let bin = 0x2ee4332f;
+
+    // check assembler
+    let i = assemble_line("amocas.d.aqrl t1,s0,a4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_compare_and_swap_quadword() {
+    let expected = Instruction::AmocasQ {
+        dest: IRegister::A2,
+        addr: IRegister::A5,
+        src: IRegister::S2,
+        aq: true,
+        rl: false,
+    };
+    let bin = 0x2d27c62f;
+
+    // check assembler
+    let i = assemble_line("amocas.q.aq a2,a5,s2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn atomic_compare_and_swap_register_pairs() {
+    // amocas.q operates on register pairs, which must start at an even register
+    assert!(assemble_line("amocas.q a1,a5,a2").is_err());
+    assert!(assemble_line("amocas.q a2,a5,a3").is_err());
+    assert!(Instruction::decode(0x28c7c5af).is_err());
+    // the first register of the pair may be zero
+    assert!(assemble_line("amocas.q zero,a5,a2").is_ok());
+    // amocas.d only uses register pairs on RV32
+    assert!(assemble_line("amocas.d a1,a5,a3").is_ok());
+    assert!(assemble_line_with("amocas.d a1,a5,a3", &RV32).is_err());
+    assert!(Instruction::decode_with(0x2ad7b5af, &RV32).is_err());
+    assert!(assemble_line_with("amocas.d a0,a5,a2", &RV32).is_ok());
+    // amocas.q is only defined for RV64
+    let i = assemble_line("amocas.q a0,a5,a2").unwrap().i();
+    assert!(assemble_line_with("amocas.q a0,a5,a2", &RV32).is_err());
+    assert!(Instruction::decode_with(Instruction::encode(&i), &RV32).is_err());
+}
@@ -0,0 +1,211 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::instruction::{Instruction, disassemble_instruction};
+use riscv_codec::register::IRegister;
+
+#[test]
+fn load_acquire_byte() {
+    let expected = Instruction::LbAq {
+        dest: IRegister::A0,
+        addr: IRegister::A1,
+        rl: false,
+    };
+    let bin = 0x3405852f;
+
+    // check assembler
+    let i = assemble_line("lb.aq a0,(a1)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn load_acquire_halfword() {
+    let expected = Instruction::LhAq {
+        dest: IRegister::T2,
+        addr: IRegister::StackPointer,
+        rl: true,
+    };
+    let bin = 0x360113af;
+
+    // check assembler
+    let i = assemble_line("lh.aqrl t2,(sp)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn load_acquire_word() {
+    let expected = Instruction::LwAq {
+        dest: IRegister::S4,
+        addr: IRegister::A3,
+        rl: false,
+    };
+    let bin = 0x3406aa2f;
+
+    // check assembler
+    let i = assemble_line("lw.aq s4,(a3)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn load_acquire_doubleword() {
+    let expected = Instruction::LdAq {
+        dest: IRegister::ReturnAddress,
+        addr: IRegister::T0,
+        rl: true,
+    };
+    let bin = 0x3602b0af;
+
+    // check assembler
+    let i = assemble_line("ld.aqrl ra,(t0)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn store_release_byte() {
+    let expected = Instruction::SbRl {
+        src: IRegister::A0,
+        addr: IRegister::A1,
+        aq: false,
+    };
+    let bin = 0x3aa5802f;
+
+    // check assembler
+    let i = assemble_line("sb.rl a0,(a1)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn store_release_halfword() {
+    let expected = Instruction::ShRl {
+        src: IRegister::T2,
+        addr: IRegister::StackPointer,
+        aq: true,
+    };
+    let bin = 0x3e71102f;
+
+    // check assembler
+    let i = assemble_line("sh.aqrl t2,(sp)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn store_release_word() {
+    let expected = Instruction::SwRl {
+        src: IRegister::S4,
+        addr: IRegister::A3,
+        aq: false,
+    };
+    let bin = 0x3b46a02f;
+
+    // check assembler
+    let i = assemble_line("sw.rl s4,(a3)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn store_release_doubleword() {
+    let expected = Instruction::SdRl {
+        src: IRegister::ReturnAddress,
+        addr: IRegister::T0,
+        aq: true,
+    };
+    let bin = 0x3e12b02f;
+
+    // check assembler
+    let i = assemble_line("sd.aqrl ra,(t0)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
@@ -0,0 +1,46 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::instruction::{Instruction, disassemble_instruction};
+
+#[test]
+fn wait_reservation_set_no_timeout() {
+    let expected = Instruction::WrsNto;
+    let bin = 0x00d00073;
+
+    // check assembler
+    let i = assemble_line("wrs.nto").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn wait_reservation_set_short_timeout() {
+    let expected = Instruction::WrsSto;
+    let bin = 0x01d00073;
+
+    // check assembler
+    let i = assemble_line("wrs.sto").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
@@ -0,0 +1,331 @@
+use riscv_codec::assembly::{assemble_line, assemble_line_with};
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::immediates::{CByteImmediate, CHalfImmediate};
+use riscv_codec::isa::{IsaConfig, Xlen};
+use riscv_codec::register::CIRegister;
+
+const RV32: IsaConfig = IsaConfig {
+    xlen: Xlen::Rv32,
+    ..IsaConfig::new()
+};
+
+#[test]
+fn compressed_load_byte_unsigned() {
+    let expected = CInstruction::LBU {
+        dest: CIRegister::A0,
+        base: CIRegister::A1,
+        offset: CByteImmediate::try_from(3).unwrap(),
+    };
+    let bin = 0x81e8;
+
+    // check assembler
+    let i = assemble_line("c.lbu a0,3(a1)").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_load_halfword_unsigned() {
+    let expected = CInstruction::LHU {
+        dest: CIRegister::S1,
+        base: CIRegister::A5,
+        offset: CHalfImmediate::try_from(2).unwrap(),
+    };
+    let bin = 0x87a4;
+
+    // check assembler
+    let i = assemble_line("c.lhu s1,2(a5)").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_load_halfword() {
+    let expected = CInstruction::LH {
+        dest: CIRegister::A2,
+        base: CIRegister::FramePointer,
+        offset: CHalfImmediate::try_from(0).unwrap(),
+    };
+    let bin = 0x8450;
+
+    // check assembler
+    let i = assemble_line("c.lh a2,0(s0)").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_store_byte() {
+    let expected = CInstruction::SB {
+        src: CIRegister::A3,
+        base: CIRegister::A4,
+        offset: CByteImmediate::try_from(1).unwrap(),
+    };
+    let bin = 0x8b54;
+
+    // check assembler
+    let i = assemble_line("c.sb a3,1(a4)").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_store_halfword() {
+    let expected = CInstruction::SH {
+        src: CIRegister::A0,
+        base: CIRegister::A2,
+        offset: CHalfImmediate::try_from(2).unwrap(),
+    };
+    let bin = 0x8e28;
+
+    // check assembler
+    let i = assemble_line("c.sh a0,2(a2)").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_zero_extend_byte() {
+    let expected = CInstruction::ZEXTB {
+        dest: CIRegister::A0,
+    };
+    let bin = 0x9d61;
+
+    // check assembler
+    let i = assemble_line("c.zext.b a0").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_sign_extend_byte() {
+    let expected = CInstruction::SEXTB {
+        dest: CIRegister::A1,
+    };
+    let bin = 0x9de5;
+
+    // check assembler
+    let i = assemble_line("c.sext.b a1").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_zero_extend_halfword() {
+    let expected = CInstruction::ZEXTH {
+        dest: CIRegister::FramePointer,
+    };
+    let bin = 0x9c69;
+
+    // check assembler
+    let i = assemble_line("c.zext.h s0").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_sign_extend_halfword() {
+    let expected = CInstruction::SEXTH {
+        dest: CIRegister::A5,
+    };
+    let bin = 0x9fed;
+
+    // check assembler
+    let i = assemble_line("c.sext.h a5").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_zero_extend_word() {
+    let expected = CInstruction::ZEXTW {
+        dest: CIRegister::S1,
+    };
+    let bin = 0x9cf1;
+
+    // check assembler
+    let i = assemble_line("c.zext.w s1").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_not() {
+    let expected = CInstruction::NOT {
+        dest: CIRegister::A3,
+    };
+    let bin = 0x9ef5;
+
+    // check assembler
+    let i = assemble_line("c.not a3").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_multiply() {
+    let expected = CInstruction::MUL {
+        dest: CIRegister::A4,
+        src: CIRegister::S1,
+    };
+    let bin = 0x9f45;
+
+    // check assembler
+    let i = assemble_line("c.mul a4,s1").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_zcb_reserved_encodings() {
+    // c.sh with bit 6 set is reserved
+    assert!(CInstruction::decode(0x8c40).is_err());
+    // c.zext.w is only available on RV64
+    assert!(assemble_line_with("c.zext.w s1", &RV32).is_err());
+    // loads and stores only take small offsets
+    assert!(assemble_line("c.lbu a0,4(a1)").is_err());
+    assert!(assemble_line("c.lh a0,1(a1)").is_err());
+}
+
+#[test]
+fn compressed_zcb_expand() {
+    let i = assemble_line("c.zext.b a0").unwrap().c();
+    assert_eq!(format!("{}", i.expand().unwrap()), "andi a0,a0,255");
+    // the sign and zero extensions only have Zbb and Zba equivalents
+    for line in ["c.sext.b a0", "c.zext.h a0", "c.sext.h a0", "c.zext.w a0"] {
+        let i = assemble_line(line).unwrap().c();
+        assert_eq!(i.expand(), None);
+    }
+}
@@ -0,0 +1,83 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::cinstruction::CInstruction;
+
+#[test]
+fn compressed_may_be_operation_1() {
+    let expected = CInstruction::MOP { n: 1 };
+    let bin = 0x6081;
+
+    // check assembler
+    let i = assemble_line("c.mop.1").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(CInstruction::disassemble(&i), "c.mop.1");
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_may_be_operation_7() {
+    let expected = CInstruction::MOP { n: 7 };
+    let bin = 0x6381;
+
+    // check assembler
+    let i = assemble_line("c.mop.7").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(CInstruction::disassemble(&i), "c.mop.7");
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_may_be_operation_15() {
+    let expected = CInstruction::MOP { n: 15 };
+    let bin = 0x6781;
+
+    // check assembler
+    let i = assemble_line("c.mop.15").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(CInstruction::disassemble(&i), "c.mop.15");
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_may_be_operation_encodings() {
+    // only odd n up to 15 are may-be-operations
+    assert!(assemble_line("c.mop.2").is_err());
+    assert!(assemble_line("c.mop.17").is_err());
+    // other registers with a zero immediate are still c.lui
+    assert!(matches!(
+        CInstruction::decode(0x6801).unwrap(),
+        CInstruction::LUI { .. }
+    ));
+}
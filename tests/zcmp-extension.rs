@@ -0,0 +1,277 @@
+use riscv_codec::assembly::assemble_line_with;
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::immediates::CStackAdjImmediate;
+use riscv_codec::isa::{IsaConfig, Xlen};
+use riscv_codec::register::{CSRegister, IRegister, RegisterList};
+
+const ZCMP: IsaConfig = IsaConfig {
+    zcmp: true,
+    ..IsaConfig::new()
+};
+
+const ZCMP_RV32: IsaConfig = IsaConfig {
+    xlen: Xlen::Rv32,
+    zcmp: true,
+    ..IsaConfig::new()
+};
+
+#[test]
+fn compressed_push() {
+    let expected = CInstruction::CMPUSH {
+        rlist: RegisterList::from_rlist(6).unwrap(),
+        stack_adj: CStackAdjImmediate::try_from(16).unwrap(),
+    };
+    let bin = 0xb866;
+
+    // check assembler
+    let i = assemble_line_with("cm.push {ra, s0-s1}, -48", &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode_with(bin, &ZCMP).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(
+        CInstruction::disassemble_with(&i, &ZCMP),
+        "cm.push {ra,s0-s1},-48"
+    );
+    let i3 = assemble_line_with(&CInstruction::disassemble_with(&i, &ZCMP), &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_pop() {
+    let expected = CInstruction::CMPOP {
+        rlist: RegisterList::from_rlist(4).unwrap(),
+        stack_adj: CStackAdjImmediate::try_from(0).unwrap(),
+    };
+    let bin = 0xba42;
+
+    // check assembler
+    let i = assemble_line_with("cm.pop {ra},16", &ZCMP).unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode_with(bin, &ZCMP).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(CInstruction::disassemble_with(&i, &ZCMP), "cm.pop {ra},16");
+    let i3 = assemble_line_with(&CInstruction::disassemble_with(&i, &ZCMP), &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_pop_return_zero() {
+    let expected = CInstruction::CMPOPRETZ {
+        rlist: RegisterList::from_rlist(15).unwrap(),
+        stack_adj: CStackAdjImmediate::try_from(48).unwrap(),
+    };
+    let bin = 0xbcfe;
+
+    // check assembler
+    let i = assemble_line_with("cm.popretz {ra, s0-s11}, 160", &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode_with(bin, &ZCMP).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(
+        CInstruction::disassemble_with(&i, &ZCMP),
+        "cm.popretz {ra,s0-s11},160"
+    );
+    let i3 = assemble_line_with(&CInstruction::disassemble_with(&i, &ZCMP), &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_pop_return() {
+    let expected = CInstruction::CMPOPRET {
+        rlist: RegisterList::from_rlist(5).unwrap(),
+        stack_adj: CStackAdjImmediate::try_from(32).unwrap(),
+    };
+    let bin = 0xbe5a;
+
+    // check assembler
+    let i = assemble_line_with("cm.popret {ra,s0},48", &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode_with(bin, &ZCMP).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(
+        CInstruction::disassemble_with(&i, &ZCMP),
+        "cm.popret {ra,s0},48"
+    );
+    let i3 = assemble_line_with(&CInstruction::disassemble_with(&i, &ZCMP), &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_push_rv32() {
+    let expected = CInstruction::CMPUSH {
+        rlist: RegisterList::from_rlist(8).unwrap(),
+        stack_adj: CStackAdjImmediate::try_from(0).unwrap(),
+    };
+    let bin = 0xb882;
+
+    // check assembler
+    let i = assemble_line_with("cm.push {ra, s0-s3}, -32", &ZCMP_RV32)
+        .unwrap()
+        .c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode_with(bin, &ZCMP_RV32).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(
+        CInstruction::disassemble_with(&i, &ZCMP_RV32),
+        "cm.push {ra,s0-s3},-32"
+    );
+    let i3 = assemble_line_with(&CInstruction::disassemble_with(&i, &ZCMP_RV32), &ZCMP_RV32)
+        .unwrap()
+        .c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_move_a0_a1_to_saved() {
+    let expected = CInstruction::CMMVSA01 {
+        dest1: CSRegister::S2,
+        dest2: CSRegister::FramePointer,
+    };
+    let bin = 0xad22;
+
+    // check assembler
+    let i = assemble_line_with("cm.mvsa01 s2,s0", &ZCMP).unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode_with(bin, &ZCMP).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line_with(&CInstruction::disassemble_with(&i, &ZCMP), &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_move_saved_to_a0_a1() {
+    let expected = CInstruction::CMMVA01S {
+        src1: CSRegister::S7,
+        src2: CSRegister::S1,
+    };
+    let bin = 0xafe6;
+
+    // check assembler
+    let i = assemble_line_with("cm.mva01s s7, s1", &ZCMP).unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode_with(bin, &ZCMP).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line_with(&CInstruction::disassemble_with(&i, &ZCMP), &ZCMP)
+        .unwrap()
+        .c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_push_pop_stack_adjustment() {
+    let rlist = RegisterList::try_from("{ra, s0-s11}").unwrap();
+    assert_eq!(rlist.count(), 13);
+    assert_eq!(rlist.registers().last(), Some(&IRegister::S11));
+    assert_eq!(rlist.base_stack_adjustment(Xlen::Rv64), 112);
+    assert_eq!(rlist.base_stack_adjustment(Xlen::Rv32), 64);
+    // the same encoding has a different stack adjustment on RV32
+    let i = CInstruction::decode_with(0xbef2, &ZCMP_RV32).unwrap();
+    assert_eq!(
+        CInstruction::disassemble_with(&i, &ZCMP_RV32),
+        "cm.popret {ra,s0-s11},64"
+    );
+    assert_eq!(
+        CInstruction::disassemble_with(&i, &ZCMP),
+        "cm.popret {ra,s0-s11},112"
+    );
+    // the adjustment must be a multiple of 16 covering the saved registers
+    assert!(assemble_line_with("cm.push {ra, s0-s1}, -16", &ZCMP).is_err());
+    assert!(assemble_line_with("cm.push {ra, s0-s1}, -40", &ZCMP).is_err());
+    assert!(assemble_line_with("cm.push {ra, s0-s1}, -96", &ZCMP).is_err());
+    // s10 can't end a register list
+    assert!(assemble_line_with("cm.push {ra, s0-s10}, -112", &ZCMP).is_err());
+    assert!(assemble_line_with("cm.push {ra, s1-s2}, -32", &ZCMP).is_err());
+}
+
+#[test]
+fn compressed_zcmp_isa() {
+    // Zcmp reuses the encodings of c.fsdsp
+    let bin = 0xb866;
+    assert!(matches!(
+        CInstruction::decode(bin).unwrap(),
+        CInstruction::FSDSP { .. }
+    ));
+    assert!(matches!(
+        CInstruction::decode_with(bin, &ZCMP).unwrap(),
+        CInstruction::CMPUSH { .. }
+    ));
+    assert!(assemble_line_with("cm.push {ra, s0-s1}, -48", &IsaConfig::default()).is_err());
+    assert!(assemble_line_with("c.fsdsp fa0,8", &ZCMP).is_err());
+    // reserved register lists and identical destinations
+    assert!(CInstruction::decode_with(0xb802, &ZCMP).is_err());
+    assert!(assemble_line_with("cm.mvsa01 s1,s1", &ZCMP).is_err());
+    assert!(assemble_line_with("cm.mvsa01 s0, fp", &ZCMP).is_err());
+    assert!(CInstruction::decode_with(0xaca6, &ZCMP).is_err());
+}
@@ -0,0 +1,65 @@
+use riscv_codec::assembly::assemble_line_with;
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::isa::IsaConfig;
+
+const ZCMT: IsaConfig = IsaConfig {
+    zcmt: true,
+    ..IsaConfig::new()
+};
+
+#[test]
+fn compressed_table_jump() {
+    let expected = CInstruction::CMJT { index: 7 };
+    let bin = 0xa01e;
+
+    // check assembler
+    let i = assemble_line_with("cm.jt 7", &ZCMT).unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode_with(bin, &ZCMT).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line_with(&CInstruction::disassemble_with(&i, &ZCMT), &ZCMT)
+        .unwrap()
+        .c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_table_jump_link() {
+    let expected = CInstruction::CMJALT { index: 200 };
+    let bin = 0xa322;
+
+    // check assembler
+    let i = assemble_line_with("cm.jalt 200", &ZCMT).unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode_with(bin, &ZCMT).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line_with(&CInstruction::disassemble_with(&i, &ZCMT), &ZCMT)
+        .unwrap()
+        .c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_table_jump_index() {
+    assert!(assemble_line_with("cm.jt 32", &ZCMT).is_err());
+    assert!(assemble_line_with("cm.jalt 31", &ZCMT).is_err());
+    assert!(assemble_line_with("cm.jalt 256", &ZCMT).is_err());
+    // Zcmt must be enabled
+    assert!(assemble_line_with("cm.jt 7", &IsaConfig::default()).is_err());
+}
@@ -0,0 +1,794 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::instruction::{FliConstant, Instruction, RoundingMode, disassemble_instruction};
+use riscv_codec::register::{FRegister, IRegister};
+
+#[test]
+fn float_load_immediate_single_min() {
+    let expected = Instruction::FliS { dest: FRegister::FA0, imm: FliConstant::from_int(1).unwrap() };
+    let bin = 0xf0108553;
+
+    // check assembler
+    let i = assemble_line("fli.s fa0,min").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_load_immediate_double_index_12() {
+    let expected = Instruction::FliD { dest: FRegister::FT0, imm: FliConstant::from_int(12).unwrap() };
+    let bin = 0xf2160053;
+
+    // check assembler
+    let i = assemble_line("fli.d ft0,0.5").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_load_immediate_half_inf() {
+    let expected = Instruction::FliH { dest: FRegister::FS1, imm: FliConstant::from_int(30).unwrap() };
+    let bin = 0xf41f04d3;
+
+    // check assembler
+    let i = assemble_line("fli.h fs1,inf").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_load_immediate_quad_nan() {
+    let expected = Instruction::FliQ { dest: FRegister::FA7, imm: FliConstant::from_int(31).unwrap() };
+    let bin = 0xf61f88d3;
+
+    // check assembler
+    let i = assemble_line("fli.q fa7,nan").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_load_immediate_single_index_2() {
+    let expected = Instruction::FliS { dest: FRegister::FT3, imm: FliConstant::from_int(2).unwrap() };
+    let bin = 0xf01101d3;
+
+    // check assembler
+    let i = assemble_line("fli.s ft3,1.52587890625e-5").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_load_immediate_double_index_0() {
+    let expected = Instruction::FliD { dest: FRegister::FS4, imm: FliConstant::from_int(0).unwrap() };
+    let bin = 0xf2100a53;
+
+    // check assembler
+    let i = assemble_line("fli.d fs4,-1.0").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_minimum_number_single() {
+    let expected = Instruction::FminmS { dest: FRegister::FA6, src1: FRegister::FS0, src2: FRegister::FA1 };
+    let bin = 0x28b42853;
+
+    // check assembler
+    let i = assemble_line("fminm.s fa6, fs0, fa1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_minimum_number_double() {
+    let expected = Instruction::FminmD { dest: FRegister::FA6, src1: FRegister::FS0, src2: FRegister::FA1 };
+    let bin = 0x2ab42853;
+
+    // check assembler
+    let i = assemble_line("fminm.d fa6, fs0, fa1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_minimum_number_half() {
+    let expected = Instruction::FminmH { dest: FRegister::FA6, src1: FRegister::FS0, src2: FRegister::FA1 };
+    let bin = 0x2cb42853;
+
+    // check assembler
+    let i = assemble_line("fminm.h fa6, fs0, fa1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_minimum_number_quad() {
+    let expected = Instruction::FminmQ { dest: FRegister::FA6, src1: FRegister::FS0, src2: FRegister::FA1 };
+    let bin = 0x2eb42853;
+
+    // check assembler
+    let i = assemble_line("fminm.q fa6, fs0, fa1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_maximum_number_single() {
+    let expected = Instruction::FmaxmS { dest: FRegister::FA6, src1: FRegister::FS0, src2: FRegister::FA1 };
+    let bin = 0x28b43853;
+
+    // check assembler
+    let i = assemble_line("fmaxm.s fa6, fs0, fa1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_maximum_number_double() {
+    let expected = Instruction::FmaxmD { dest: FRegister::FA6, src1: FRegister::FS0, src2: FRegister::FA1 };
+    let bin = 0x2ab43853;
+
+    // check assembler
+    let i = assemble_line("fmaxm.d fa6, fs0, fa1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_maximum_number_half() {
+    let expected = Instruction::FmaxmH { dest: FRegister::FA6, src1: FRegister::FS0, src2: FRegister::FA1 };
+    let bin = 0x2cb43853;
+
+    // check assembler
+    let i = assemble_line("fmaxm.h fa6, fs0, fa1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_maximum_number_quad() {
+    let expected = Instruction::FmaxmQ { dest: FRegister::FA6, src1: FRegister::FS0, src2: FRegister::FA1 };
+    let bin = 0x2eb43853;
+
+    // check assembler
+    let i = assemble_line("fmaxm.q fa6, fs0, fa1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_round_single() {
+    let expected = Instruction::FroundS { dest: FRegister::FA7, src: FRegister::FS4, rm: RoundingMode::DYN };
+    let bin = 0x404a78d3;
+
+    // check assembler
+    let i = assemble_line("fround.s fa7, fs4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_round_double() {
+    let expected = Instruction::FroundD { dest: FRegister::FA7, src: FRegister::FS4, rm: RoundingMode::DYN };
+    let bin = 0x424a78d3;
+
+    // check assembler
+    let i = assemble_line("fround.d fa7, fs4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_round_half() {
+    let expected = Instruction::FroundH { dest: FRegister::FA7, src: FRegister::FS4, rm: RoundingMode::DYN };
+    let bin = 0x444a78d3;
+
+    // check assembler
+    let i = assemble_line("fround.h fa7, fs4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_round_quad() {
+    let expected = Instruction::FroundQ { dest: FRegister::FA7, src: FRegister::FS4, rm: RoundingMode::DYN };
+    let bin = 0x464a78d3;
+
+    // check assembler
+    let i = assemble_line("fround.q fa7, fs4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_round_single_rounding_mode() {
+    let expected = Instruction::FroundS { dest: FRegister::FT1, src: FRegister::FT2, rm: RoundingMode::RUP };
+    let bin = 0x404130d3;
+
+    // check assembler
+    let i = assemble_line("fround.s ft1,ft2,rup").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_round_inexact_single() {
+    let expected = Instruction::FroundnxS { dest: FRegister::FA7, src: FRegister::FS4, rm: RoundingMode::DYN };
+    let bin = 0x405a78d3;
+
+    // check assembler
+    let i = assemble_line("froundnx.s fa7, fs4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_round_inexact_double() {
+    let expected = Instruction::FroundnxD { dest: FRegister::FA7, src: FRegister::FS4, rm: RoundingMode::DYN };
+    let bin = 0x425a78d3;
+
+    // check assembler
+    let i = assemble_line("froundnx.d fa7, fs4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_round_inexact_half() {
+    let expected = Instruction::FroundnxH { dest: FRegister::FA7, src: FRegister::FS4, rm: RoundingMode::DYN };
+    let bin = 0x445a78d3;
+
+    // check assembler
+    let i = assemble_line("froundnx.h fa7, fs4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_round_inexact_quad() {
+    let expected = Instruction::FroundnxQ { dest: FRegister::FA7, src: FRegister::FS4, rm: RoundingMode::DYN };
+    let bin = 0x465a78d3;
+
+    // check assembler
+    let i = assemble_line("froundnx.q fa7, fs4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_round_inexact_single_rounding_mode() {
+    let expected = Instruction::FroundnxS { dest: FRegister::FT1, src: FRegister::FT2, rm: RoundingMode::RUP };
+    let bin = 0x405130d3;
+
+    // check assembler
+    let i = assemble_line("froundnx.s ft1,ft2,rup").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_modular_word_from_double() {
+    let expected = Instruction::FcvtmodWD { dest: IRegister::T5, src: FRegister::FS8 };
+    let bin = 0xc28c1f53;
+
+    // check assembler
+    let i = assemble_line("fcvtmod.w.d t5,fs8,rtz").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_less_equal_quiet_single() {
+    let expected = Instruction::FleqS { dest: IRegister::T1, src1: FRegister::FS1, src2: FRegister::FS2 };
+    let bin = 0xa124c353;
+
+    // check assembler
+    let i = assemble_line("fleq.s t1, fs1, fs2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_less_equal_quiet_double() {
+    let expected = Instruction::FleqD { dest: IRegister::T1, src1: FRegister::FS1, src2: FRegister::FS2 };
+    let bin = 0xa324c353;
+
+    // check assembler
+    let i = assemble_line("fleq.d t1, fs1, fs2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_less_equal_quiet_half() {
+    let expected = Instruction::FleqH { dest: IRegister::T1, src1: FRegister::FS1, src2: FRegister::FS2 };
+    let bin = 0xa524c353;
+
+    // check assembler
+    let i = assemble_line("fleq.h t1, fs1, fs2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_less_equal_quiet_quad() {
+    let expected = Instruction::FleqQ { dest: IRegister::T1, src1: FRegister::FS1, src2: FRegister::FS2 };
+    let bin = 0xa724c353;
+
+    // check assembler
+    let i = assemble_line("fleq.q t1, fs1, fs2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_less_than_quiet_single() {
+    let expected = Instruction::FltqS { dest: IRegister::T1, src1: FRegister::FS1, src2: FRegister::FS2 };
+    let bin = 0xa124d353;
+
+    // check assembler
+    let i = assemble_line("fltq.s t1, fs1, fs2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_less_than_quiet_double() {
+    let expected = Instruction::FltqD { dest: IRegister::T1, src1: FRegister::FS1, src2: FRegister::FS2 };
+    let bin = 0xa324d353;
+
+    // check assembler
+    let i = assemble_line("fltq.d t1, fs1, fs2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_less_than_quiet_half() {
+    let expected = Instruction::FltqH { dest: IRegister::T1, src1: FRegister::FS1, src2: FRegister::FS2 };
+    let bin = 0xa524d353;
+
+    // check assembler
+    let i = assemble_line("fltq.h t1, fs1, fs2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_less_than_quiet_quad() {
+    let expected = Instruction::FltqQ { dest: IRegister::T1, src1: FRegister::FS1, src2: FRegister::FS2 };
+    let bin = 0xa724d353;
+
+    // check assembler
+    let i = assemble_line("fltq.q t1, fs1, fs2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_move_x_from_high_double() {
+    let expected = Instruction::FmvhXD { dest: IRegister::A6, src: FRegister::FT11 };
+    let bin = 0xe21f8853;
+
+    // check assembler
+    let i = assemble_line("fmvh.x.d a6,ft11").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_move_double_from_x_pair() {
+    let expected = Instruction::FmvpDX { dest: FRegister::FS7, src1: IRegister::A0, src2: IRegister::A1 };
+    let bin = 0xb2b50bd3;
+
+    // check assembler
+    let i = assemble_line("fmvp.d.x fs7, a0, a1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_load_immediate_table() {
+    let values = [
+        "-1.0", "min", "1.52587890625e-5", "3.0517578125e-5", "0.00390625", "0.0078125", "0.0625",
+        "0.125", "0.25", "0.3125", "0.375", "0.4375", "0.5", "0.625", "0.75", "0.875", "1.0",
+        "1.25", "1.5", "1.75", "2.0", "2.5", "3.0", "4.0", "8.0", "16.0", "128.0", "256.0",
+        "32768.0", "65536.0", "inf", "nan",
+    ];
+    for (i, v) in values.iter().enumerate() {
+        let c = FliConstant::from_int(i as u32).unwrap();
+        assert_eq!(format!("{c}"), *v);
+        assert_eq!(FliConstant::from_str(v).unwrap(), c);
+    }
+    // other spellings of the same value are accepted
+    assert_eq!(FliConstant::from_str("2").unwrap().index(), 20);
+    assert_eq!(FliConstant::from_str("0.1e1").unwrap().index(), 16);
+    // values outside of the table are rejected
+    assert!(assemble_line("fli.s fa0,0.1").is_err());
+    assert!(assemble_line("fli.d fa0,-2.0").is_err());
+}
@@ -0,0 +1,316 @@
+use riscv_codec::assembly::assemble_line_with;
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::instruction::{Instruction, RoundingMode, disassemble_instruction_with};
+use riscv_codec::isa::{IsaConfig, Xlen};
+use riscv_codec::operands::{Operand, OperandRole};
+use riscv_codec::register::{FRegister, IRegister, Register};
+
+const ZFINX: IsaConfig = IsaConfig {
+    zfinx: true,
+    ..IsaConfig::new()
+};
+
+const ZDINX_RV32: IsaConfig = IsaConfig {
+    xlen: Xlen::Rv32,
+    zfinx: true,
+    zdinx: true,
+    ..IsaConfig::new()
+};
+
+const ZDINX_RV64: IsaConfig = IsaConfig {
+    zfinx: true,
+    zdinx: true,
+    ..IsaConfig::new()
+};
+
+#[test]
+fn float_add_single_zfinx() {
+    let expected = Instruction::FaddS {
+        dest: FRegister::Integer(IRegister::A0),
+        src1: FRegister::Integer(IRegister::A1),
+        src2: FRegister::Integer(IRegister::A2),
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0x00c5f553;
+
+    // check assembler
+    let i = assemble_line_with("fadd.s a0,a1,a2", &ZFINX).unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZFINX).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(
+        disassemble_instruction_with(&i, &ZFINX),
+        "fadd.s a0,a1,a2,dyn"
+    );
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZFINX), &ZFINX)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_convert_word_from_single_zfinx() {
+    let expected = Instruction::FcvtWS {
+        dest: IRegister::A0,
+        src: FRegister::Integer(IRegister::T1),
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0xc0037553;
+
+    // check assembler
+    let i = assemble_line_with("fcvt.w.s a0, t1", &ZFINX).unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZFINX).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(
+        disassemble_instruction_with(&i, &ZFINX),
+        "fcvt.w.s a0,t1,dyn"
+    );
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZFINX), &ZFINX)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn float_multiply_add_double_zdinx_rv32() {
+    let expected = Instruction::FmaddD {
+        dest: FRegister::Integer(IRegister::S2),
+        src1: FRegister::Integer(IRegister::A4),
+        src2: FRegister::Integer(IRegister::A6),
+        src3: FRegister::Integer(IRegister::T3),
+        rm: RoundingMode::DYN,
+    };
+    let bin = 0xe3077943;
+
+    // check assembler
+    let i = assemble_line_with("fmadd.d s2, a4, a6, t3", &ZDINX_RV32)
+        .unwrap()
+        .i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZDINX_RV32).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZDINX_RV32), &ZDINX_RV32)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn display_zfinx() {
+    use riscv_codec::instruction::disassemble_instruction;
+
+    // the float operands of a decoded instruction are integer registers, whichever way it is displayed
+    let i = Instruction::decode_with(0x00c5f553, &ZFINX).unwrap();
+    assert_eq!(i.to_string(), "fadd.s a0,a1,a2,dyn");
+    assert_eq!(disassemble_instruction(&i), "fadd.s a0,a1,a2,dyn");
+    assert_eq!(
+        i.operands()[0],
+        (
+            OperandRole::Dest,
+            Operand::F(FRegister::Integer(IRegister::A0))
+        )
+    );
+    assert_eq!(
+        i.registers_read(),
+        vec![
+            Register::I(IRegister::A1),
+            Register::I(IRegister::A2),
+            Register::FRM,
+        ]
+    );
+
+    let i = assemble_line_with("fcvt.w.s a0, t1", &ZFINX).unwrap().i();
+    assert_eq!(i.to_string(), "fcvt.w.s a0,t1,dyn");
+
+    // the same bits decode with float registers by default
+    let i = Instruction::decode(0x00c5f553).unwrap();
+    assert_eq!(i.to_string(), "fadd.s fa0,fa1,fa2,dyn");
+}
+
+#[test]
+fn zdinx_register_pairs() {
+    // odd registers can't hold doubles on RV32
+    assert!(assemble_line_with("fadd.d a0, a1, a3", &ZDINX_RV32).is_err());
+    assert!(Instruction::decode_with(0x02d5f553, &ZDINX_RV32).is_err());
+    assert!(assemble_line_with("fsqrt.d a0, a1", &ZDINX_RV32).is_err());
+    // but they are fine on RV64
+    assert!(assemble_line_with("fadd.d a0, a1, a3", &ZDINX_RV64).is_ok());
+    assert!(Instruction::decode_with(0x02d5f553, &ZDINX_RV64).is_ok());
+    // only the double precision operands need to be pairs
+    assert!(assemble_line_with("fcvt.s.d a1, a2", &ZDINX_RV32).is_ok());
+    assert!(assemble_line_with("fcvt.w.d a1, a2", &ZDINX_RV32).is_ok());
+    assert!(assemble_line_with("fcvt.w.d a2, a1", &ZDINX_RV32).is_err());
+    // double precision instructions require zdinx
+    assert!(assemble_line_with("fadd.d a0, a2, a4", &ZFINX).is_err());
+}
+
+#[test]
+fn zfinx_rejected_instructions() {
+    // float loads, stores and moves
+    assert!(assemble_line_with("flw a0,0(a1)", &ZFINX).is_err());
+    assert!(Instruction::decode_with(0x0005a507, &ZFINX).is_err());
+    assert!(assemble_line_with("fsw a0,0(a1)", &ZFINX).is_err());
+    assert!(assemble_line_with("fmv.x.w a0,a1", &ZFINX).is_err());
+    assert!(assemble_line_with("fmv.w.x a0,a1", &ZFINX).is_err());
+    assert!(assemble_line_with("fld a0,0(a1)", &ZDINX_RV64).is_err());
+    assert!(assemble_line_with("fmv.x.d a0,a1", &ZDINX_RV64).is_err());
+    // compressed float loads and stores
+    assert!(CInstruction::decode_with(0x2588, &ZDINX_RV64).is_err());
+    assert!(CInstruction::decode_with(0x2588, &IsaConfig::default()).is_ok());
+    assert!(assemble_line_with("c.fld fa0,8(a1)", &ZDINX_RV64).is_err());
+    assert!(assemble_line_with("c.fsdsp fa0,8", &ZDINX_RV64).is_err());
+    // float register names
+    assert!(assemble_line_with("fadd.s fa0,fa1,fa2", &ZFINX).is_err());
+}
+
+#[test]
+fn zfinx_default_config_unchanged() {
+    let bin = 0x00c5f553;
+    assert_eq!(
+        Instruction::decode_with(bin, &IsaConfig::default()).unwrap(),
+        Instruction::decode(bin).unwrap()
+    );
+    let i = Instruction::decode(bin).unwrap();
+    assert_eq!(
+        disassemble_instruction_with(&i, &IsaConfig::default()),
+        "fadd.s fa0,fa1,fa2,dyn"
+    );
+}
+
+#[test]
+fn operands_zfinx() {
+    let i = assemble_line_with("fmadd.s a0,a1,a2,zero,rne", &ZFINX)
+        .unwrap()
+        .i();
+    assert_eq!(
+        i.operands_with(&ZFINX),
+        vec![
+            (OperandRole::Dest, Operand::I(IRegister::A0)),
+            (OperandRole::Src1, Operand::I(IRegister::A1)),
+            (OperandRole::Src2, Operand::I(IRegister::A2)),
+            (OperandRole::Src3, Operand::I(IRegister::Zero)),
+            (
+                OperandRole::RoundingMode,
+                Operand::RoundingMode(RoundingMode::RNE)
+            ),
+        ]
+    );
+
+    let mut i2 = i;
+    i2.set_operand_with(OperandRole::Dest, Operand::I(IRegister::T0), &ZFINX)
+        .unwrap();
+    assert_eq!(
+        disassemble_instruction_with(&i2, &ZFINX),
+        "fmadd.s t0,a1,a2,zero,rne"
+    );
+    i2.map_operands_with(&ZFINX, |_, value| match value {
+        Operand::I(IRegister::A1) => Operand::I(IRegister::S1),
+        value => value,
+    })
+    .unwrap();
+    assert_eq!(
+        disassemble_instruction_with(&i2, &ZFINX),
+        "fmadd.s t0,s1,a2,zero,rne"
+    );
+}
+
+#[test]
+fn registers_zfinx() {
+    let i = assemble_line_with("fadd.s zero,a1,a2", &ZFINX).unwrap().i();
+    assert_eq!(
+        i.registers_read_with(&ZFINX),
+        vec![
+            Register::I(IRegister::A1),
+            Register::I(IRegister::A2),
+            Register::FRM,
+        ]
+    );
+    // writes to zero are discarded
+    assert_eq!(i.registers_written_with(&ZFINX), vec![Register::FFLAGS]);
+
+    let i = assemble_line_with("fcvt.d.w a0,a1", &ZDINX_RV64)
+        .unwrap()
+        .i();
+    assert_eq!(
+        i.registers_read_with(&ZDINX_RV64),
+        vec![Register::I(IRegister::A1)]
+    );
+    assert_eq!(
+        i.registers_written_with(&ZDINX_RV64),
+        vec![Register::I(IRegister::A0)]
+    );
+}
+
+#[test]
+fn registers_zdinx_pairs() {
+    use Register::I;
+    // doubles are held in even/odd register pairs on RV32
+    let i = assemble_line_with("fadd.d a0,a2,a4", &ZDINX_RV32)
+        .unwrap()
+        .i();
+    assert_eq!(
+        i.registers_read_with(&ZDINX_RV32),
+        vec![
+            I(IRegister::A2),
+            I(IRegister::A3),
+            I(IRegister::A4),
+            I(IRegister::A5),
+            Register::FRM,
+        ]
+    );
+    assert_eq!(
+        i.registers_written_with(&ZDINX_RV32),
+        vec![I(IRegister::A0), I(IRegister::A1), Register::FFLAGS]
+    );
+    // but not on RV64
+    assert_eq!(
+        i.registers_written_with(&ZDINX_RV64),
+        vec![I(IRegister::A0), Register::FFLAGS]
+    );
+
+    let i = assemble_line_with("fcvt.d.w a2,a1", &ZDINX_RV32)
+        .unwrap()
+        .i();
+    assert_eq!(
+        i.registers_written_with(&ZDINX_RV32),
+        vec![I(IRegister::A2), I(IRegister::A3)]
+    );
+
+    // only the double source of a narrowing conversion is a pair
+    let i = assemble_line_with("fcvt.s.d a0,a0", &ZDINX_RV32)
+        .unwrap()
+        .i();
+    assert_eq!(
+        i.registers_read_with(&ZDINX_RV32),
+        vec![I(IRegister::A0), I(IRegister::A1), Register::FRM]
+    );
+    assert_eq!(
+        i.registers_written_with(&ZDINX_RV32),
+        vec![I(IRegister::A0), Register::FFLAGS]
+    );
+}
@@ -0,0 +1,222 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::immediates::{IImmediate, PrefetchImmediate};
+use riscv_codec::instruction::{Instruction, disassemble_instruction};
+use riscv_codec::isa::IsaConfig;
+use riscv_codec::register::IRegister;
+
+#[test]
+fn cache_block_clean() {
+    let expected = Instruction::CboClean { base: IRegister::A0 };
+    let bin = 0x0015200f;
+
+    // check assembler
+    let i = assemble_line("cbo.clean (a0)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn cache_block_flush() {
+    let expected = Instruction::CboFlush { base: IRegister::A0 };
+    let bin = 0x0025200f;
+
+    // check assembler
+    let i = assemble_line("cbo.flush (a0)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn cache_block_invalidate() {
+    let expected = Instruction::CboInval { base: IRegister::A0 };
+    let bin = 0x0005200f;
+
+    // check assembler
+    let i = assemble_line("cbo.inval (a0)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn cache_block_zero() {
+    let expected = Instruction::CboZero { base: IRegister::A0 };
+    let bin = 0x0045200f;
+
+    // check assembler
+    let i = assemble_line("cbo.zero (a0)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn cache_block_clean_zero_offset() {
+    let expected = Instruction::CboClean { base: IRegister::T1 };
+    let bin = 0x0013200f;
+
+    // check assembler
+    let i = assemble_line("cbo.clean 0(t1)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn prefetch_instruction() {
+    let expected = Instruction::PrefetchI { base: IRegister::A0, offset: PrefetchImmediate::try_from(32).unwrap() };
+    let bin = 0x02056013;
+
+    // check assembler
+    let i = assemble_line("prefetch.i 32(a0)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn prefetch_read() {
+    let expected = Instruction::PrefetchR { base: IRegister::StackPointer, offset: PrefetchImmediate::try_from(-64).unwrap() };
+    let bin = 0xfc116013;
+
+    // check assembler
+    let i = assemble_line("prefetch.r -64(sp)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn prefetch_write() {
+    let expected = Instruction::PrefetchW { base: IRegister::A1, offset: PrefetchImmediate::try_from(2016).unwrap() };
+    let bin = 0x7e35e013;
+
+    // check assembler
+    let i = assemble_line("prefetch.w 2016(a1)").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn prefetch_offset_alignment() {
+    assert!(assemble_line("prefetch.r 16(a0)").is_err());
+    assert!(assemble_line("prefetch.r 2048(a0)").is_err());
+    assert!(assemble_line("prefetch.r -2048(a0)").is_ok());
+}
+
+#[test]
+fn prefetch_without_offset() {
+    assert_eq!(
+        assemble_line("prefetch.r a0").unwrap_err(),
+        "prefetch requires an offset(base) operand"
+    );
+    // the other loads and stores also reject addresses without parentheses
+    assert!(assemble_line("flq fa0,a0").is_err());
+    assert!(assemble_line("c.lbu a0,a1").is_err());
+}
+
+#[test]
+fn prefetch_as_ori() {
+    let bin = 0x02156013;
+    let isa = IsaConfig {
+        zicbop: false,
+        ..IsaConfig::new()
+    };
+    let expected = Instruction::Ori {
+        dest: IRegister::Zero,
+        src: IRegister::A0,
+        imm: IImmediate::try_from(33).unwrap(),
+    };
+    assert_eq!(Instruction::decode_with(bin, &isa).unwrap(), expected);
+    // ori instructions that don't match a prefetch are unchanged
+    let bin = 0x02256013;
+    let expected = Instruction::Ori {
+        dest: IRegister::Zero,
+        src: IRegister::A0,
+        imm: IImmediate::try_from(34).unwrap(),
+    };
+    assert_eq!(Instruction::decode(bin).unwrap(), expected);
+}
@@ -0,0 +1,82 @@
+use riscv_codec::assembly::{assemble_line, assemble_line_with};
+use riscv_codec::immediates::{LpadLabel, UImmediate};
+use riscv_codec::instruction::{Instruction, disassemble_instruction_with};
+use riscv_codec::isa::IsaConfig;
+use riscv_codec::register::IRegister;
+
+const ZICFILP: IsaConfig = IsaConfig {
+    zicfilp: true,
+    ..IsaConfig::new()
+};
+
+#[test]
+fn landing_pad() {
+    let expected = Instruction::Lpad {
+        label: LpadLabel::try_from(0x12345).unwrap(),
+    };
+    let bin = 0x12345017;
+
+    // check assembler
+    let i = assemble_line_with("lpad 74565", &ZICFILP).unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZICFILP).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(disassemble_instruction_with(&i, &ZICFILP), "lpad 74565");
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZICFILP), &ZICFILP)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn landing_pad_unlabeled() {
+    let expected = Instruction::Lpad {
+        label: LpadLabel::try_from(0).unwrap(),
+    };
+    let bin = 0x00000017;
+
+    // check assembler
+    let i = assemble_line_with("lpad 0", &ZICFILP).unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZICFILP).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZICFILP), &ZICFILP)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn landing_pad_isa() {
+    // without Zicfilp, lpad is a plain auipc
+    assert_eq!(
+        Instruction::decode(0x12345017).unwrap(),
+        Instruction::Auipc {
+            dest: IRegister::Zero,
+            imm: UImmediate::try_from(0x12345).unwrap()
+        }
+    );
+    assert!(assemble_line("lpad 5").is_err());
+    // auipc with a non-zero destination is unaffected
+    assert!(matches!(
+        Instruction::decode_with(0x12345517, &ZICFILP).unwrap(),
+        Instruction::Auipc { .. }
+    ));
+    assert!(assemble_line_with("lpad 1048576", &ZICFILP).is_err());
+}
@@ -0,0 +1,305 @@
+use riscv_codec::assembly::{assemble_line, assemble_line_with};
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::instruction::{Instruction, disassemble_instruction_with};
+use riscv_codec::isa::IsaConfig;
+use riscv_codec::register::IRegister;
+
+const ZICFISS: IsaConfig = IsaConfig {
+    zicfiss: true,
+    ..IsaConfig::new()
+};
+
+#[test]
+fn shadow_stack_push_ra() {
+    let expected = Instruction::Sspush {
+        src: IRegister::ReturnAddress,
+    };
+    let bin = 0xce104073;
+
+    // check assembler
+    let i = assemble_line_with("sspush ra", &ZICFISS).unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZICFISS).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(disassemble_instruction_with(&i, &ZICFISS), "sspush ra");
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZICFISS), &ZICFISS)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn shadow_stack_push_t0() {
+    let expected = Instruction::Sspush { src: IRegister::T0 };
+    let bin = 0xce504073;
+
+    // check assembler
+    let i = assemble_line_with("sspush t0", &ZICFISS).unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZICFISS).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZICFISS), &ZICFISS)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn shadow_stack_pop_check_ra() {
+    let expected = Instruction::Sspopchk {
+        src: IRegister::ReturnAddress,
+    };
+    let bin = 0xcdc0c073;
+
+    // check assembler
+    let i = assemble_line_with("sspopchk ra", &ZICFISS).unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZICFISS).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(disassemble_instruction_with(&i, &ZICFISS), "sspopchk ra");
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZICFISS), &ZICFISS)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn shadow_stack_pop_check_t0() {
+    let expected = Instruction::Sspopchk { src: IRegister::T0 };
+    let bin = 0xcdc2c073;
+
+    // check assembler
+    let i = assemble_line_with("sspopchk t0", &ZICFISS).unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZICFISS).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZICFISS), &ZICFISS)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn shadow_stack_read_pointer() {
+    let expected = Instruction::Ssrdp {
+        dest: IRegister::A0,
+    };
+    let bin = 0xcdc04573;
+
+    // check assembler
+    let i = assemble_line_with("ssrdp a0", &ZICFISS).unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZICFISS).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(disassemble_instruction_with(&i, &ZICFISS), "ssrdp a0");
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZICFISS), &ZICFISS)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn shadow_stack_atomic_swap_word() {
+    let expected = Instruction::SsamoswapW {
+        dest: IRegister::A0,
+        addr: IRegister::A1,
+        src: IRegister::A2,
+        aq: false,
+        rl: false,
+    };
+    let bin = 0x48c5a52f;
+
+    // check assembler
+    let i = assemble_line_with("ssamoswap.w a0,a1,a2", &ZICFISS)
+        .unwrap()
+        .i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZICFISS).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZICFISS), &ZICFISS)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn shadow_stack_atomic_swap_doubleword() {
+    let expected = Instruction::SsamoswapD {
+        dest: IRegister::T1,
+        addr: IRegister::S2,
+        src: IRegister::A5,
+        aq: true,
+        rl: true,
+    };
+    let bin = 0x4ef9332f;
+
+    // check assembler
+    let i = assemble_line_with("ssamoswap.d.aqrl t1,s2,a5", &ZICFISS)
+        .unwrap()
+        .i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode_with(bin, &ZICFISS).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line_with(&disassemble_instruction_with(&i, &ZICFISS), &ZICFISS)
+        .unwrap()
+        .i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_shadow_stack_push() {
+    let expected = CInstruction::SSPUSH;
+    let bin = 0x6081;
+
+    // check assembler
+    let i = assemble_line_with("c.sspush ra", &ZICFISS).unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode_with(bin, &ZICFISS).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(CInstruction::disassemble_with(&i, &ZICFISS), "c.sspush ra");
+    let i3 = assemble_line_with(&CInstruction::disassemble_with(&i, &ZICFISS), &ZICFISS)
+        .unwrap()
+        .c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_shadow_stack_pop_check() {
+    let expected = CInstruction::SSPOPCHK;
+    let bin = 0x6281;
+
+    // check assembler
+    let i = assemble_line_with("c.sspopchk t0", &ZICFISS).unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode_with(bin, &ZICFISS).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(
+        CInstruction::disassemble_with(&i, &ZICFISS),
+        "c.sspopchk t0"
+    );
+    let i3 = assemble_line_with(&CInstruction::disassemble_with(&i, &ZICFISS), &ZICFISS)
+        .unwrap()
+        .c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn shadow_stack_registers() {
+    // only ra and t0 can be pushed and checked
+    assert!(assemble_line_with("sspush a0", &ZICFISS).is_err());
+    assert!(assemble_line_with("sspopchk s0", &ZICFISS).is_err());
+    assert!(assemble_line_with("c.sspush t0", &ZICFISS).is_err());
+    assert!(assemble_line_with("c.sspopchk ra", &ZICFISS).is_err());
+    assert!(assemble_line_with("ssrdp zero", &ZICFISS).is_err());
+    // other may-be-operation encodings aren't shadow stack instructions
+    assert!(matches!(
+        Instruction::decode_with(0xce204073, &ZICFISS).unwrap(),
+        Instruction::MopRr { n: 7, .. }
+    ));
+    assert!(matches!(
+        CInstruction::decode_with(0x6181, &ZICFISS).unwrap(),
+        CInstruction::MOP { n: 3 }
+    ));
+}
+
+#[test]
+fn shadow_stack_isa() {
+    assert!(assemble_line("sspush ra").is_err());
+    assert!(assemble_line("ssamoswap.w a0,a1,a2").is_err());
+    // without Zicfiss the shadow stack instructions are may-be-operations
+    assert_eq!(
+        Instruction::decode(0xce104073).unwrap(),
+        Instruction::MopRr {
+            n: 7,
+            dest: IRegister::Zero,
+            src1: IRegister::Zero,
+            src2: IRegister::ReturnAddress
+        }
+    );
+    assert_eq!(
+        Instruction::decode(0xcdc04573).unwrap(),
+        Instruction::MopR {
+            n: 28,
+            dest: IRegister::A0,
+            src: IRegister::Zero
+        }
+    );
+    // without Zicfiss the compressed encodings are may-be-operations
+    assert_eq!(
+        CInstruction::decode(0x6081).unwrap(),
+        CInstruction::MOP { n: 1 }
+    );
+    assert!(assemble_line("c.sspush ra").is_err());
+}
@@ -0,0 +1,55 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::instruction::{Instruction, disassemble_instruction};
+use riscv_codec::register::IRegister;
+
+#[test]
+fn conditional_zero_equal_zero() {
+    let expected = Instruction::CzeroEqz {
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+    };
+    let bin = 0x0ec5d533;
+
+    // check assembler
+    let i = assemble_line("czero.eqz a0,a1,a2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn conditional_zero_not_equal_zero() {
+    let expected = Instruction::CzeroNez {
+        dest: IRegister::T3,
+        src1: IRegister::S5,
+        src2: IRegister::T0,
+    };
+    let bin = 0x0e5afe33;
+
+    // check assembler
+    let i = assemble_line("czero.nez t3, s5, t0").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
@@ -0,0 +1,259 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::cinstruction::CInstruction;
+use riscv_codec::instruction::{Instruction, disassemble_instruction};
+use riscv_codec::register::IRegister;
+
+#[test]
+fn pause() {
+    let expected = Instruction::Fence {
+        rd: IRegister::Zero,
+        rs1: IRegister::Zero,
+        ops: 0b0001_0000,
+        fm: 0,
+    };
+    let bin = 0x0100000f;
+
+    // check assembler
+    let i = assemble_line("pause").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(disassemble_instruction(&i), "pause");
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn non_temporal_innermost_private() {
+    let expected = Instruction::Add {
+        dest: IRegister::Zero,
+        src1: IRegister::Zero,
+        src2: IRegister::StackPointer,
+    };
+    let bin = 0x00200033;
+
+    // check assembler
+    let i = assemble_line("ntl.p1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(disassemble_instruction(&i), "ntl.p1");
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn non_temporal_all_private() {
+    let expected = Instruction::Add {
+        dest: IRegister::Zero,
+        src1: IRegister::Zero,
+        src2: IRegister::GlobalPointer,
+    };
+    let bin = 0x00300033;
+
+    // check assembler
+    let i = assemble_line("ntl.pall").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(disassemble_instruction(&i), "ntl.pall");
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn non_temporal_innermost_shared() {
+    let expected = Instruction::Add {
+        dest: IRegister::Zero,
+        src1: IRegister::Zero,
+        src2: IRegister::ThreadPointer,
+    };
+    let bin = 0x00400033;
+
+    // check assembler
+    let i = assemble_line("ntl.s1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(disassemble_instruction(&i), "ntl.s1");
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn non_temporal_all() {
+    let expected = Instruction::Add {
+        dest: IRegister::Zero,
+        src1: IRegister::Zero,
+        src2: IRegister::T0,
+    };
+    let bin = 0x00500033;
+
+    // check assembler
+    let i = assemble_line("ntl.all").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(disassemble_instruction(&i), "ntl.all");
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_non_temporal_innermost_private() {
+    let expected = CInstruction::ADD {
+        dest: IRegister::Zero,
+        src: IRegister::StackPointer,
+    };
+    let bin = 0x900a;
+
+    // check assembler
+    let i = assemble_line("c.ntl.p1").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(CInstruction::disassemble(&i), "c.ntl.p1");
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_non_temporal_all_private() {
+    let expected = CInstruction::ADD {
+        dest: IRegister::Zero,
+        src: IRegister::GlobalPointer,
+    };
+    let bin = 0x900e;
+
+    // check assembler
+    let i = assemble_line("c.ntl.pall").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(CInstruction::disassemble(&i), "c.ntl.pall");
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_non_temporal_innermost_shared() {
+    let expected = CInstruction::ADD {
+        dest: IRegister::Zero,
+        src: IRegister::ThreadPointer,
+    };
+    let bin = 0x9012;
+
+    // check assembler
+    let i = assemble_line("c.ntl.s1").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(CInstruction::disassemble(&i), "c.ntl.s1");
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn compressed_non_temporal_all() {
+    let expected = CInstruction::ADD {
+        dest: IRegister::Zero,
+        src: IRegister::T0,
+    };
+    let bin = 0x9016;
+
+    // check assembler
+    let i = assemble_line("c.ntl.all").unwrap().c();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = CInstruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = CInstruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    assert_eq!(CInstruction::disassemble(&i), "c.ntl.all");
+    let i3 = assemble_line(&CInstruction::disassemble(&i)).unwrap().c();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn hints_only_for_exact_encodings() {
+    // a fence that doesn't match the pause encoding is still shown as a fence
+    let fence = Instruction::decode(0x0300000f).unwrap();
+    assert_eq!(disassemble_instruction(&fence), "fence rw,");
+    // add x0,x0,x6 is a hint without a name
+    let add = Instruction::decode(0x00600033).unwrap();
+    assert_eq!(disassemble_instruction(&add), "add zero,zero,t1");
+    // an add with a non-zero destination is a normal add
+    let add = Instruction::decode(0x00200533).unwrap();
+    assert_eq!(disassemble_instruction(&add), "add a0,zero,sp");
+    let c_add = CInstruction::decode(0x950a).unwrap();
+    assert_eq!(CInstruction::disassemble(&c_add), "c.add a0,sp");
+}
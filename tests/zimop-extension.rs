@@ -0,0 +1,185 @@
+use riscv_codec::assembly::assemble_line;
+use riscv_codec::instruction::{Instruction, disassemble_instruction};
+use riscv_codec::register::IRegister;
+
+#[test]
+fn may_be_operation_r_0() {
+    let expected = Instruction::MopR {
+        n: 0,
+        dest: IRegister::A0,
+        src: IRegister::A1,
+    };
+    let bin = 0x81c5c573;
+
+    // check assembler
+    let i = assemble_line("mop.r.0 a0,a1").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn may_be_operation_r_13() {
+    let expected = Instruction::MopR {
+        n: 13,
+        dest: IRegister::T0,
+        src: IRegister::S3,
+    };
+    let bin = 0x8dd9c2f3;
+
+    // check assembler
+    let i = assemble_line("mop.r.13 t0,s3").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn may_be_operation_r_31() {
+    let expected = Instruction::MopR {
+        n: 31,
+        dest: IRegister::A7,
+        src: IRegister::T6,
+    };
+    let bin = 0xcdffc8f3;
+
+    // check assembler
+    let i = assemble_line("mop.r.31 a7,t6").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn may_be_operation_rr_0() {
+    let expected = Instruction::MopRr {
+        n: 0,
+        dest: IRegister::A0,
+        src1: IRegister::A1,
+        src2: IRegister::A2,
+    };
+    let bin = 0x82c5c573;
+
+    // check assembler
+    let i = assemble_line("mop.rr.0 a0,a1,a2").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn may_be_operation_rr_5() {
+    let expected = Instruction::MopRr {
+        n: 5,
+        dest: IRegister::S1,
+        src1: IRegister::T2,
+        src2: IRegister::A4,
+    };
+    let bin = 0xc6e3c4f3;
+
+    // check assembler
+    let i = assemble_line("mop.rr.5 s1,t2,a4").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn may_be_operation_rr_7() {
+    let expected = Instruction::MopRr {
+        n: 7,
+        dest: IRegister::T3,
+        src1: IRegister::S11,
+        src2: IRegister::A3,
+    };
+    let bin = 0xceddce73;
+
+    // check assembler
+    let i = assemble_line("mop.rr.7 t3,s11,a3").unwrap().i();
+    assert_eq!(i, expected);
+
+    // check decoder
+    let i2 = Instruction::decode(bin).unwrap();
+    assert_eq!(i2, expected);
+
+    // check encoder
+    let b = Instruction::encode(&i);
+    assert_eq!(b, bin);
+
+    // check disassembler
+    let i3 = assemble_line(&disassemble_instruction(&i)).unwrap().i();
+    assert_eq!(i, i3);
+}
+
+#[test]
+fn may_be_operation_all_indices() {
+    for n in 0..32 {
+        let i = Instruction::MopR {
+            n,
+            dest: IRegister::A0,
+            src: IRegister::A1,
+        };
+        assert_eq!(Instruction::decode(Instruction::encode(&i)).unwrap(), i);
+    }
+    for n in 0..8 {
+        let i = Instruction::MopRr {
+            n,
+            dest: IRegister::A0,
+            src1: IRegister::A1,
+            src2: IRegister::A2,
+        };
+        assert_eq!(Instruction::decode(Instruction::encode(&i)).unwrap(), i);
+    }
+    assert!(assemble_line("mop.r.32 a0,a1").is_err());
+    assert!(assemble_line("mop.rr.8 a0,a1,a2").is_err());
+}